@@ -1,10 +1,10 @@
 // Copyright (C) 2025 Tim Blechmann
 // SPDX-License-Identifier: MIT
 
-#![feature(portable_simd)]
+#![cfg_attr(feature = "nightly", feature(portable_simd))]
 
 use criterion::{Criterion, criterion_group, criterion_main};
-use nova_easing::EasingArgument;
+use nova_easing::{Easing, EasingArgument, batch};
 use paste::paste;
 use std::hint::black_box;
 
@@ -327,6 +327,19 @@ criterion_group!(
     bench_f64x4_ease_in_out_elastic
 );
 
+// Whole buffers, with the instruction set selected at runtime, see `batch::instruction_set`.
+fn bench_batch(c: &mut Criterion) {
+    let t: Vec<f32> = (0..4096).map(|i| i as f32 / 4095.0).collect();
+    let mut dst = vec![0.0f32; t.len()];
+    for easing in [Easing::InOutCubic, Easing::OutBounce, Easing::InOutBack] {
+        c.bench_function(&format!("batch_{}", easing.name()), |b| {
+            b.iter(|| batch::ease_slice(&mut dst, black_box(&t), easing))
+        });
+    }
+}
+
+criterion_group!(benches_batch, bench_batch);
+
 #[cfg(feature = "nightly")]
 criterion_main!(
     benches_f32,
@@ -334,7 +347,8 @@ criterion_main!(
     benches_f32x4,
     benches_f32x8,
     benches_f64x2,
-    benches_f64x4
+    benches_f64x4,
+    benches_batch
 );
 #[cfg(not(feature = "nightly"))]
-criterion_main!(benches_f32, benches_f64);
+criterion_main!(benches_f32, benches_f64, benches_batch);
@@ -0,0 +1,170 @@
+// Copyright (C) 2025 Tim Blechmann
+// SPDX-License-Identifier: MIT
+
+use std::f64::consts::PI;
+use std::fmt::Write;
+
+const TABLE_SIZE: usize = 256;
+
+// Reference implementations, evaluated in double precision for the `baked` tables.
+
+fn ease_out_bounce(x: f64) -> f64 {
+    let n1 = 7.5625;
+    let d1 = 2.75;
+    if x < 1.0 / d1 {
+        n1 * x * x
+    } else if x < 2.0 / d1 {
+        let x = x - 1.5 / d1;
+        n1 * x * x + 0.75
+    } else if x < 2.5 / d1 {
+        let x = x - 2.25 / d1;
+        n1 * x * x + 0.9375
+    } else {
+        let x = x - 2.625 / d1;
+        n1 * x * x + 0.984375
+    }
+}
+
+fn ease_in_out_pow(x: f64, n: i32) -> f64 {
+    if x < 0.5 {
+        2f64.powi(n - 1) * x.powi(n)
+    } else {
+        1.0 - (2.0 - 2.0 * x).powi(n) / 2.0
+    }
+}
+
+type Reference = fn(f64) -> f64;
+
+const EASINGS: &[(&str, Reference)] = &[
+    ("EASE_IN_QUAD", |x| x.powi(2)),
+    ("EASE_OUT_QUAD", |x| 1.0 - (1.0 - x).powi(2)),
+    ("EASE_IN_OUT_QUAD", |x| ease_in_out_pow(x, 2)),
+    ("EASE_IN_CUBIC", |x| x.powi(3)),
+    ("EASE_OUT_CUBIC", |x| 1.0 - (1.0 - x).powi(3)),
+    ("EASE_IN_OUT_CUBIC", |x| ease_in_out_pow(x, 3)),
+    ("EASE_IN_QUART", |x| x.powi(4)),
+    ("EASE_OUT_QUART", |x| 1.0 - (1.0 - x).powi(4)),
+    ("EASE_IN_OUT_QUART", |x| ease_in_out_pow(x, 4)),
+    ("EASE_IN_QUINT", |x| x.powi(5)),
+    ("EASE_OUT_QUINT", |x| 1.0 - (1.0 - x).powi(5)),
+    ("EASE_IN_OUT_QUINT", |x| ease_in_out_pow(x, 5)),
+    ("EASE_IN_SINE", |x| 1.0 - (x * PI / 2.0).cos()),
+    ("EASE_OUT_SINE", |x| (x * PI / 2.0).sin()),
+    ("EASE_IN_OUT_SINE", |x| -((PI * x).cos() - 1.0) / 2.0),
+    ("EASE_IN_CIRC", |x| 1.0 - (1.0 - x * x).sqrt()),
+    ("EASE_OUT_CIRC", |x| (1.0 - (x - 1.0).powi(2)).sqrt()),
+    ("EASE_IN_OUT_CIRC", |x| {
+        if x < 0.5 {
+            (1.0 - (1.0 - (2.0 * x).powi(2)).sqrt()) / 2.0
+        } else {
+            ((1.0 - (2.0 - 2.0 * x).powi(2)).sqrt() + 1.0) / 2.0
+        }
+    }),
+    ("EASE_IN_BACK", |x| {
+        2.70158 * x.powi(3) - 1.70158 * x.powi(2)
+    }),
+    ("EASE_OUT_BACK", |x| {
+        1.0 + 2.70158 * (x - 1.0).powi(3) + 1.70158 * (x - 1.0).powi(2)
+    }),
+    ("EASE_IN_OUT_BACK", |x| {
+        let c2 = 1.70158 * 1.525;
+        if x < 0.5 {
+            (2.0 * x).powi(2) * ((c2 + 1.0) * 2.0 * x - c2) / 2.0
+        } else {
+            ((2.0 * x - 2.0).powi(2) * ((c2 + 1.0) * (2.0 * x - 2.0) + c2) + 2.0) / 2.0
+        }
+    }),
+    ("EASE_IN_BOUNCE", |x| 1.0 - ease_out_bounce(1.0 - x)),
+    ("EASE_OUT_BOUNCE", ease_out_bounce),
+    ("EASE_IN_OUT_BOUNCE", |x| {
+        if x < 0.5 {
+            (1.0 - ease_out_bounce(1.0 - 2.0 * x)) / 2.0
+        } else {
+            (1.0 + ease_out_bounce(2.0 * x - 1.0)) / 2.0
+        }
+    }),
+    ("EASE_IN_EXPO", |x| {
+        if x == 0.0 {
+            0.0
+        } else {
+            2f64.powf(10.0 * x - 10.0)
+        }
+    }),
+    ("EASE_OUT_EXPO", |x| {
+        if x == 1.0 {
+            1.0
+        } else {
+            1.0 - 2f64.powf(-10.0 * x)
+        }
+    }),
+    ("EASE_IN_OUT_EXPO", |x| {
+        if x == 0.0 {
+            0.0
+        } else if x == 1.0 {
+            1.0
+        } else if x < 0.5 {
+            2f64.powf(20.0 * x - 10.0) / 2.0
+        } else {
+            (2.0 - 2f64.powf(-20.0 * x + 10.0)) / 2.0
+        }
+    }),
+    ("EASE_IN_ELASTIC", |x| {
+        let c4 = 2.0 * PI / 3.0;
+        if x == 0.0 || x == 1.0 {
+            x
+        } else {
+            -(2f64.powf(10.0 * x - 10.0)) * ((x * 10.0 - 10.75) * c4).sin()
+        }
+    }),
+    ("EASE_OUT_ELASTIC", |x| {
+        let c4 = 2.0 * PI / 3.0;
+        if x == 0.0 || x == 1.0 {
+            x
+        } else {
+            2f64.powf(-10.0 * x) * ((x * 10.0 - 0.75) * c4).sin() + 1.0
+        }
+    }),
+    ("EASE_IN_OUT_ELASTIC", |x| {
+        let c5 = 2.0 * PI / 4.5;
+        if x == 0.0 || x == 1.0 {
+            x
+        } else if x < 0.5 {
+            -(2f64.powf(20.0 * x - 10.0) * ((20.0 * x - 11.125) * c5).sin()) / 2.0
+        } else {
+            2f64.powf(-20.0 * x + 10.0) * ((20.0 * x - 11.125) * c5).sin() / 2.0 + 1.0
+        }
+    }),
+    ("SMOOTHSTEP", |x| x * x * (3.0 - 2.0 * x)),
+    ("SMOOTHERSTEP", |x| {
+        x.powi(3) * (x * (x * 6.0 - 15.0) + 10.0)
+    }),
+    ("SMOOTHESTSTEP", |x| {
+        x.powi(4) * (x * (x * (x * -20.0 + 70.0) - 84.0) + 35.0)
+    }),
+];
+
+fn generate_tables() -> String {
+    let mut out = String::new();
+    for (name, f) in EASINGS {
+        writeln!(out, "/// Baked table of `{}`.", name.to_lowercase()).unwrap();
+        writeln!(out, "pub static {name}: Table = [").unwrap();
+        for i in 0..TABLE_SIZE {
+            let t = i as f64 / (TABLE_SIZE - 1) as f64;
+            writeln!(out, "    {:?},", f(t) as f32).unwrap();
+        }
+        writeln!(out, "];").unwrap();
+    }
+    out
+}
+
+fn main() {
+    println!("cargo:rerun-if-changed=build.rs");
+
+    if std::env::var_os("CARGO_FEATURE_BAKED").is_none() {
+        return;
+    }
+
+    let out_dir = std::env::var_os("OUT_DIR").unwrap();
+    let path = std::path::Path::new(&out_dir).join("baked_tables.rs");
+    std::fs::write(path, generate_tables()).unwrap();
+}
@@ -0,0 +1,114 @@
+// Copyright (C) 2025 Tim Blechmann
+// SPDX-License-Identifier: MIT
+
+//! Derive macros for `nova-easing`. Use them through the `derive` feature of `nova-easing`.
+
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::{quote, quote_spanned};
+use syn::spanned::Spanned;
+use syn::{Data, DeriveInput, Fields, Index, parse_macro_input, parse_quote};
+
+/// Derives `Interpolatable` for a struct, interpolating it field by field.
+///
+/// Every field must be `Interpolatable` itself. By default all fields follow the easing of the
+/// tween; `#[ease(...)]` overrides it for a single field, naming an `Easing` variant
+/// (`#[ease(OutBack)]`, `#[ease(InCurve(2.0))]`) or any expression evaluating to an `Easing`.
+#[proc_macro_derive(Ease, attributes(ease))]
+pub fn derive_ease(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    expand(input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+fn expand(mut input: DeriveInput) -> syn::Result<TokenStream2> {
+    let Data::Struct(data) = &input.data else {
+        return Err(syn::Error::new(
+            input.span(),
+            "`Ease` can only be derived for structs",
+        ));
+    };
+
+    let mut lerp_fields = Vec::new();
+    let mut ease_fields = Vec::new();
+    for (index, field) in data.fields.iter().enumerate() {
+        let member = match &field.ident {
+            Some(ident) => quote!(#ident),
+            None => {
+                let index = Index::from(index);
+                quote!(#index)
+            }
+        };
+
+        let easing = match easing_override(&field.attrs)? {
+            Some(easing) => quote!(#easing),
+            None => quote!(easing),
+        };
+
+        let span = field.ty.span();
+        lerp_fields.push(quote_spanned! {span=>
+            #member: ::nova_easing::Interpolatable::lerp(self.#member, other.#member, t)
+        });
+        ease_fields.push(quote_spanned! {span=>
+            #member: ::nova_easing::Interpolatable::ease(self.#member, other.#member, t, #easing)
+        });
+    }
+
+    let (lerp, ease) = match data.fields {
+        Fields::Unit => (quote!(Self), quote!(Self)),
+        _ => {
+            (
+                quote!(Self { #(#lerp_fields,)* }),
+                quote!(Self { #(#ease_fields,)* }),
+            )
+        }
+    };
+
+    let type_params: Vec<_> = input
+        .generics
+        .type_params()
+        .map(|param| param.ident.clone())
+        .collect();
+    let where_clause = input.generics.make_where_clause();
+    for param in type_params {
+        where_clause
+            .predicates
+            .push(parse_quote!(#param: ::nova_easing::Interpolatable));
+    }
+
+    let name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    Ok(quote! {
+        impl #impl_generics ::nova_easing::Interpolatable for #name #ty_generics #where_clause {
+            fn lerp(self, other: Self, t: f32) -> Self {
+                #lerp
+            }
+
+            #[allow(unused_variables)]
+            fn ease(self, other: Self, t: f32, easing: ::nova_easing::Easing) -> Self {
+                #ease
+            }
+        }
+    })
+}
+
+/// Parses the `#[ease(...)]` attribute of a field.
+fn easing_override(attrs: &[syn::Attribute]) -> syn::Result<Option<TokenStream2>> {
+    let mut easing = None;
+    for attr in attrs.iter().filter(|attr| attr.path().is_ident("ease")) {
+        if easing.is_some() {
+            return Err(syn::Error::new(attr.span(), "duplicate `ease` attribute"));
+        }
+
+        let expr: TokenStream2 = attr.parse_args()?;
+        // Bare variant names (`OutBack`, `InCurve(2.0)`) resolve against `Easing`.
+        easing = Some(quote!({
+            #[allow(unused_imports)]
+            use ::nova_easing::Easing::*;
+            #expr
+        }));
+    }
+    Ok(easing)
+}
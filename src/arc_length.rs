@@ -0,0 +1,234 @@
+// Copyright (C) 2025 Tim Blechmann
+// SPDX-License-Identifier: MIT
+
+//! Arc-length reparametrization, for motion at constant speed along a curve.
+//!
+//! A parametric path rarely moves at a constant speed: points of a Bézier curve bunch up where its
+//! control points are close, and the graph of an easing is steep in some places and flat in
+//! others. An [`ArcLengthTable`] samples the path once and maps a fraction of its length back to
+//! the parameter, so equal steps of progress cover equal distances. [`ConstantSpeed`] applies this
+//! to the graph `(t, easing(t))` of an easing:
+//!
+//! ```
+//! # use nova_easing::{Easing, arc_length::ArcLengthTable};
+//! // A path which is slow at its start.
+//! let path = |u: f32| [10.0 * u * u];
+//! let table = ArcLengthTable::new(path, 256);
+//! assert!((table.length() - 10.0).abs() < 1e-4);
+//! // Half of the length is reached at `u = sqrt(0.5)`.
+//! assert!((table.parameter(0.5) - 0.5f32.sqrt()).abs() < 1e-3);
+//! ```
+
+use alloc::vec::Vec;
+#[allow(unused_imports)]
+use num_traits::Float;
+
+use crate::Easing;
+
+/// The cumulative arc length of a path over the parameter range [0, 1], sampled at a fixed
+/// resolution.
+///
+/// Between the samples, the path is approximated by straight segments, so the resolution trades
+/// memory and construction time for accuracy.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ArcLengthTable {
+    // The length from the start to `u = i / resolution`.
+    lengths: Vec<f32>,
+}
+
+impl ArcLengthTable {
+    /// Samples the arc length of `path` at `resolution + 1` evenly spaced parameters in [0, 1].
+    ///
+    /// `path` returns a point with `N` coordinates for a parameter `u`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `resolution` is zero.
+    pub fn new<const N: usize>(path: impl Fn(f32) -> [f32; N], resolution: usize) -> Self {
+        assert!(resolution > 0, "resolution must not be zero");
+        let mut lengths = Vec::with_capacity(resolution + 1);
+        let mut previous = path(0.0);
+        let mut length = 0.0f64;
+        lengths.push(0.0);
+        for i in 1..=resolution {
+            let point = path(i as f32 / resolution as f32);
+            let squared: f64 = (0..N)
+                .map(|axis| (point[axis] - previous[axis]) as f64)
+                .map(|delta| delta * delta)
+                .sum();
+            length += squared.sqrt();
+            lengths.push(length as f32);
+            previous = point;
+        }
+        Self { lengths }
+    }
+
+    /// The number of segments the path is divided into.
+    pub fn resolution(&self) -> usize {
+        self.lengths.len() - 1
+    }
+
+    /// The total length of the path.
+    pub fn length(&self) -> f32 {
+        *self.lengths.last().unwrap()
+    }
+
+    /// The parameter `u` at which the path has covered the fraction `s` of its length.
+    ///
+    /// `s` is clamped to [0, 1]. Paths of zero length map `s` to itself.
+    pub fn parameter(&self, s: f32) -> f32 {
+        let s = s.clamp(0.0, 1.0);
+        let length = self.length();
+        if length <= 0.0 {
+            return s;
+        }
+        let target = s * length;
+        let index = self
+            .lengths
+            .partition_point(|&length| length < target)
+            .clamp(1, self.resolution());
+        let (low, high) = (self.lengths[index - 1], self.lengths[index]);
+        let fraction = if high > low {
+            (target - low) / (high - low)
+        } else {
+            0.0
+        };
+        ((index - 1) as f32 + fraction) / self.resolution() as f32
+    }
+
+    /// The fraction of the length covered at the parameter `u`, the inverse of
+    /// [`parameter`](Self::parameter).
+    ///
+    /// `u` is clamped to [0, 1]. Paths of zero length map `u` to itself.
+    pub fn fraction(&self, u: f32) -> f32 {
+        let u = u.clamp(0.0, 1.0);
+        let length = self.length();
+        if length <= 0.0 {
+            return u;
+        }
+        let position = u * self.resolution() as f32;
+        let index = (position as usize).min(self.resolution() - 1);
+        let (low, high) = (self.lengths[index], self.lengths[index + 1]);
+        let within = position - index as f32;
+        (low + (high - low) * within) / length
+    }
+}
+
+/// An easing resampled so the point `(t, easing(t))` moves along its graph at constant speed.
+///
+/// Steep stretches of the easing, where the value changes quickly, are slowed down and flat
+/// stretches sped up, e.g. to trace the drawn curve of an easing in an editor, or to move an object
+/// along a path shaped like the easing without sudden jumps in speed.
+///
+/// ```
+/// # use nova_easing::{Easing, arc_length::ConstantSpeed};
+/// let resampled = ConstantSpeed::new(Easing::InOutExpo, 512);
+/// assert_eq!(resampled.apply(0.0), 0.0);
+/// assert!((resampled.apply(1.0) - 1.0).abs() < 1e-6);
+/// // The flat start of `InOutExpo` is passed quickly.
+/// assert!(resampled.parameter(0.25) > 0.25);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct ConstantSpeed {
+    easing: Easing,
+    table: ArcLengthTable,
+}
+
+impl ConstantSpeed {
+    /// Samples the graph of `easing` with `resolution` segments, see [`ArcLengthTable::new`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if `resolution` is zero.
+    pub fn new(easing: Easing, resolution: usize) -> Self {
+        let table = ArcLengthTable::new(|t| [t, easing.apply(t)], resolution);
+        Self { easing, table }
+    }
+
+    /// The resampled easing.
+    pub fn easing(&self) -> Easing {
+        self.easing
+    }
+
+    /// The cached arc-length table of the graph.
+    pub fn table(&self) -> &ArcLengthTable {
+        &self.table
+    }
+
+    /// The argument of the easing reached after the fraction `s` of the graph, see
+    /// [`ArcLengthTable::parameter`].
+    pub fn parameter(&self, s: f32) -> f32 {
+        self.table.parameter(s)
+    }
+
+    /// Evaluates the easing after the fraction `s` of its graph, which is clamped to [0, 1].
+    pub fn apply(&self, s: f32) -> f32 {
+        self.easing.apply(self.parameter(s))
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::{ArcLengthTable, ConstantSpeed};
+    use crate::Easing;
+    use approx::assert_relative_eq;
+    use core::f32::consts::{FRAC_PI_2, PI};
+
+    #[test]
+    fn uniform_paths() {
+        // A quarter circle at constant speed, up to the chords of the table.
+        let table = ArcLengthTable::new(|u| [(u * FRAC_PI_2).cos(), (u * FRAC_PI_2).sin()], 1000);
+        assert_eq!(table.resolution(), 1000);
+        assert_relative_eq!(table.length(), FRAC_PI_2, epsilon = 1e-5);
+        for s in [0.0f32, 0.1, 0.5, 0.77, 1.0] {
+            assert_relative_eq!(table.parameter(s), s, epsilon = 1e-5);
+            assert_relative_eq!(table.fraction(s), s, epsilon = 1e-5);
+        }
+
+        // A point does not move.
+        let point = ArcLengthTable::new(|_| [1.0, 2.0, 3.0], 8);
+        assert_eq!(point.length(), 0.0);
+        assert_eq!(point.parameter(0.3), 0.3);
+        assert_eq!(point.fraction(0.3), 0.3);
+    }
+
+    #[test]
+    fn round_trip() {
+        // A cycloid, which stops at its cusps. Around them, several parameters give the same point.
+        let path = |u: f32| [u * 2.0 * PI - (u * 2.0 * PI).sin()];
+        let table = ArcLengthTable::new(path, 4096);
+        for i in 0..=20 {
+            let u = i as f32 / 20.0;
+            let round_trip = path(table.parameter(table.fraction(u)));
+            assert_relative_eq!(round_trip[0], path(u)[0], epsilon = 1e-4);
+        }
+        assert_eq!(table.parameter(-1.0), 0.0);
+        assert_relative_eq!(path(table.parameter(2.0))[0], 2.0 * PI, epsilon = 1e-4);
+    }
+
+    #[test]
+    fn constant_speed_easing() {
+        let resampled = ConstantSpeed::new(Easing::InOutExpo, 2048);
+        let point = |s: f32| {
+            let t = resampled.parameter(s);
+            (t, resampled.easing().apply(t))
+        };
+        let distance = |a: (f32, f32), b: (f32, f32)| (b.0 - a.0).hypot(b.1 - a.1);
+        let step = resampled.table().length() / 50.0;
+        for i in 0..50 {
+            let (a, b) = (point(i as f32 / 50.0), point((i + 1) as f32 / 50.0));
+            // Chords are shorter than the arcs where the curve bends.
+            assert_relative_eq!(distance(a, b), step, max_relative = 0.02);
+        }
+        assert_eq!(resampled.apply(0.0), 0.0);
+        assert_relative_eq!(resampled.apply(1.0), 1.0, epsilon = 1e-6);
+    }
+
+    #[test]
+    #[should_panic(expected = "resolution must not be zero")]
+    fn zero_resolution() {
+        ConstantSpeed::new(Easing::Linear, 0);
+    }
+}
@@ -0,0 +1,264 @@
+// Copyright (C) 2025 Tim Blechmann
+// SPDX-License-Identifier: MIT
+
+//! Automatic easing between two states under an acceleration limit.
+//!
+//! Camera moves, motorized faders or robot joints often start from a moving state and must not
+//! exceed an acceleration limit. [`solve`] picks the shortest duration and a matching ease from a
+//! family of cubic curves, which continue the start velocity and come to rest at the target.
+
+use crate::curve::Curve;
+use crate::custom::EasingValue;
+
+/// A solved ease from [`solve`], moving from `from` to `to` within its [`duration`](Self::duration).
+///
+/// The normalized curve is the cubic Hermite spline `f(u)` with `f(0) = 0`, `f(1) = 1`, the start
+/// slope `f'(0) = m` and the end slope `f'(1) = 0`. Its acceleration changes linearly, i.e. the
+/// jerk is constant. Large start velocities towards the target make it overshoot (`m > 3`), start
+/// velocities away from it make it swing back first (`m < 0`).
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct AutoEase {
+    from: f64,
+    to: f64,
+    duration: f64,
+    slope: f64,
+}
+
+/// Finds the shortest ease from `from` to `to` starting at `start_velocity` (units per second),
+/// whose acceleration never exceeds `max_acceleration` (units per second squared).
+///
+/// Returns `None` if `max_acceleration` is not positive, or if `from == to` with a non-zero start
+/// velocity, which cannot be expressed as an ease between two values.
+///
+/// ```
+/// # use nova_easing::auto_ease::solve;
+/// // A fader at rest moves 1 unit with at most 6 units/s²: smoothstep over one second.
+/// let ease = solve(0.0, 1.0, 0.0, 6.0).unwrap();
+/// assert!((ease.duration() - 1.0).abs() < 1e-12);
+/// assert!((ease.position(0.5) - 0.5).abs() < 1e-12);
+///
+/// // Already moving towards the target: the move gets shorter.
+/// let ease = solve(0.0, 1.0, 2.0, 6.0).unwrap();
+/// assert!(ease.duration() < 1.0);
+/// assert!((ease.velocity(0.0) - 2.0).abs() < 1e-12);
+/// ```
+pub fn solve(from: f64, to: f64, start_velocity: f64, max_acceleration: f64) -> Option<AutoEase> {
+    if max_acceleration.is_nan() || max_acceleration <= 0.0 {
+        return None;
+    }
+    let distance = to - from;
+    if distance == 0.0 {
+        return (start_velocity == 0.0).then_some(AutoEase {
+            from,
+            to,
+            duration: 0.0,
+            slope: 0.0,
+        });
+    }
+
+    // With `s = 1 / duration` and `w = v0 / distance`, the slope is `m = w / s` and the normalized
+    // acceleration `6 - 4m + (6m - 12) u` peaks at `u = 0` or `u = 1`. Scaled back to real units,
+    // the constraint at both ends is `|6 s^2 - b s| <= k`, with `b = 4w` and `b = 2w`.
+    let w = start_velocity / distance;
+    let k = max_acceleration / distance.abs();
+    let limits = [4.0 * w, 2.0 * w].map(|b| QuadraticLimit::new(b, k));
+
+    // The largest `s`, i.e. the shortest duration, which satisfies both ends.
+    let mut s = limits
+        .iter()
+        .map(|limit| limit.upper)
+        .fold(f64::INFINITY, f64::min);
+    for _ in 0..limits.len() {
+        for limit in &limits {
+            if let Some((low, high)) = limit.excluded
+                && low < s
+                && s < high
+            {
+                s = low;
+            }
+        }
+    }
+
+    let duration = 1.0 / s;
+    Some(AutoEase {
+        from,
+        to,
+        duration,
+        slope: w * duration,
+    })
+}
+
+// The feasible `s >= 0` of `-k <= 6 s^2 - b s <= k`: `[0, upper]`, minus the open interval
+// `excluded` where the parabola dips below `-k`.
+struct QuadraticLimit {
+    upper: f64,
+    excluded: Option<(f64, f64)>,
+}
+
+impl QuadraticLimit {
+    fn new(b: f64, k: f64) -> Self {
+        let upper = (b + (b * b + 24.0 * k).sqrt()) / 12.0;
+        let discriminant = b * b - 24.0 * k;
+        let excluded = (discriminant > 0.0).then(|| {
+            let root = discriminant.sqrt();
+            ((b - root) / 12.0, (b + root) / 12.0)
+        });
+        Self { upper, excluded }
+    }
+}
+
+impl AutoEase {
+    /// The start value.
+    pub fn from(&self) -> f64 {
+        self.from
+    }
+
+    /// The end value.
+    pub fn to(&self) -> f64 {
+        self.to
+    }
+
+    /// The duration in seconds.
+    pub fn duration(&self) -> f64 {
+        self.duration
+    }
+
+    /// The slope `m` of the normalized curve at its start.
+    pub fn start_slope(&self) -> f64 {
+        self.slope
+    }
+
+    /// The value at `time` seconds, holding the end value after [`duration`](Self::duration).
+    pub fn position(&self, time: f64) -> f64 {
+        let u = self.progress(time);
+        let m = self.slope;
+        let eased = u * (m + u * ((3.0 - 2.0 * m) + u * (m - 2.0)));
+        self.from + (self.to - self.from) * eased
+    }
+
+    /// The velocity at `time` seconds, in units per second.
+    pub fn velocity(&self, time: f64) -> f64 {
+        if self.duration == 0.0 {
+            return 0.0;
+        }
+        let u = self.progress(time);
+        let m = self.slope;
+        let slope = m + u * (2.0 * (3.0 - 2.0 * m) + u * 3.0 * (m - 2.0));
+        (self.to - self.from) * slope / self.duration
+    }
+
+    /// The largest absolute acceleration of the ease, in units per second squared.
+    pub fn max_acceleration(&self) -> f64 {
+        if self.duration == 0.0 {
+            return 0.0;
+        }
+        let m = self.slope;
+        let peak = (6.0 - 4.0 * m).abs().max((2.0 * m - 6.0).abs());
+        (self.to - self.from).abs() * peak / (self.duration * self.duration)
+    }
+
+    /// The constant jerk of the ease, in units per second cubed.
+    pub fn jerk(&self) -> f64 {
+        if self.duration == 0.0 {
+            return 0.0;
+        }
+        (self.to - self.from) * 6.0 * (self.slope - 2.0) / self.duration.powi(3)
+    }
+
+    fn progress(&self, time: f64) -> f64 {
+        if time >= self.duration {
+            1.0
+        } else {
+            (time / self.duration).max(0.0)
+        }
+    }
+}
+
+/// The normalized curve `f(u)`, e.g. for composition with other curves.
+impl Curve for AutoEase {
+    #[inline]
+    fn eval<T: EasingValue>(&self, t: T) -> T {
+        let m = self.slope as f32;
+        t * t.polynomial(&[m - 2.0, 3.0 - 2.0 * m, m])
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::solve;
+    use crate::curve::Curve;
+    use approx::assert_relative_eq;
+
+    // Checks the boundary conditions and that the limit is reached, but never exceeded.
+    fn check(from: f64, to: f64, start_velocity: f64, max_acceleration: f64) {
+        let ease = solve(from, to, start_velocity, max_acceleration).unwrap();
+        let duration = ease.duration();
+        assert_relative_eq!(ease.position(0.0), from, epsilon = 1e-9);
+        assert_relative_eq!(ease.position(duration), to, epsilon = 1e-9);
+        assert_relative_eq!(ease.velocity(0.0), start_velocity, epsilon = 1e-9);
+        assert_relative_eq!(ease.velocity(duration), 0.0, epsilon = 1e-9);
+        assert_relative_eq!(
+            ease.max_acceleration(),
+            max_acceleration,
+            max_relative = 1e-9
+        );
+
+        // Numerically, from the positions.
+        let h = duration * 1e-4;
+        for i in 1..100 {
+            let time = duration * i as f64 / 100.0;
+            let acceleration = (ease.position(time + h) - 2.0 * ease.position(time)
+                + ease.position(time - h))
+                / (h * h);
+            assert!(acceleration.abs() <= max_acceleration * (1.0 + 1e-4));
+        }
+
+        // Any shorter duration would violate the limit.
+        let shorter = super::AutoEase {
+            duration: duration * 0.99,
+            slope: start_velocity / (to - from) * duration * 0.99,
+            ..ease
+        };
+        assert!(shorter.max_acceleration() > max_acceleration);
+    }
+
+    #[test]
+    fn from_rest() {
+        let ease = solve(2.0, -1.0, 0.0, 12.0).unwrap();
+        assert_eq!(ease.start_slope(), 0.0);
+        assert_relative_eq!(ease.duration(), 1.5f64.sqrt());
+        check(2.0, -1.0, 0.0, 12.0);
+    }
+
+    #[test]
+    fn moving_states() {
+        check(0.0, 1.0, 0.5, 4.0);
+        check(0.0, 1.0, 5.0, 4.0);
+        check(0.0, 1.0, -2.0, 4.0);
+        check(10.0, 0.0, 30.0, 100.0);
+        check(0.0, 100.0, 400.0, 50.0);
+    }
+
+    #[test]
+    fn curve() {
+        let ease = solve(0.0, 4.0, 3.0, 2.0).unwrap();
+        for u in [0.0, 0.25, 0.5, 0.9, 1.0] {
+            let expected = ease.position(u * ease.duration()) / 4.0;
+            assert_relative_eq!(ease.eval(u), expected, epsilon = 1e-6);
+        }
+    }
+
+    #[test]
+    fn degenerate() {
+        assert!(solve(0.0, 1.0, 0.0, 0.0).is_none());
+        assert!(solve(0.0, 1.0, 0.0, f64::NAN).is_none());
+        assert!(solve(1.0, 1.0, 2.0, 1.0).is_none());
+        let ease = solve(1.0, 1.0, 0.0, 1.0).unwrap();
+        assert_eq!(ease.duration(), 0.0);
+        assert_eq!(ease.position(0.5), 1.0);
+        assert_eq!(ease.velocity(0.0), 0.0);
+    }
+}
@@ -0,0 +1,163 @@
+// Copyright (C) 2025 Tim Blechmann
+// SPDX-License-Identifier: MIT
+
+//! Precomputed tables of the easing functions.
+//!
+//! Each table holds [`TABLE_SIZE`] values of one easing, sampled at `t = i / (TABLE_SIZE - 1)`.
+//! The tables are generated at build time, so looking up a value costs no transcendental
+//! functions, which helps on constrained targets.
+//!
+//! Parametric easings (the `curve` family) have no tables.
+
+use crate::Easing;
+
+/// The number of entries per table.
+pub const TABLE_SIZE: usize = 256;
+
+/// A baked easing table.
+pub type Table = [f32; TABLE_SIZE];
+
+include!(concat!(env!("OUT_DIR"), "/baked_tables.rs"));
+
+/// Identity table, for [`Easing::Linear`].
+pub static LINEAR: Table = {
+    let mut table = [0.0; TABLE_SIZE];
+    let mut i = 0;
+    while i < TABLE_SIZE {
+        table[i] = i as f32 / (TABLE_SIZE - 1) as f32;
+        i += 1;
+    }
+    table
+};
+
+/// Returns the baked table of `easing`, or `None` for parametric easings.
+pub fn table(easing: Easing) -> Option<&'static Table> {
+    let table = match easing {
+        Easing::Linear => &LINEAR,
+        Easing::InQuad => &EASE_IN_QUAD,
+        Easing::OutQuad => &EASE_OUT_QUAD,
+        Easing::InOutQuad => &EASE_IN_OUT_QUAD,
+        Easing::InCubic => &EASE_IN_CUBIC,
+        Easing::OutCubic => &EASE_OUT_CUBIC,
+        Easing::InOutCubic => &EASE_IN_OUT_CUBIC,
+        Easing::InQuart => &EASE_IN_QUART,
+        Easing::OutQuart => &EASE_OUT_QUART,
+        Easing::InOutQuart => &EASE_IN_OUT_QUART,
+        Easing::InQuint => &EASE_IN_QUINT,
+        Easing::OutQuint => &EASE_OUT_QUINT,
+        Easing::InOutQuint => &EASE_IN_OUT_QUINT,
+        Easing::InSine => &EASE_IN_SINE,
+        Easing::OutSine => &EASE_OUT_SINE,
+        Easing::InOutSine => &EASE_IN_OUT_SINE,
+        Easing::InCirc => &EASE_IN_CIRC,
+        Easing::OutCirc => &EASE_OUT_CIRC,
+        Easing::InOutCirc => &EASE_IN_OUT_CIRC,
+        Easing::InBack => &EASE_IN_BACK,
+        Easing::OutBack => &EASE_OUT_BACK,
+        Easing::InOutBack => &EASE_IN_OUT_BACK,
+        Easing::InBounce => &EASE_IN_BOUNCE,
+        Easing::OutBounce => &EASE_OUT_BOUNCE,
+        Easing::InOutBounce => &EASE_IN_OUT_BOUNCE,
+        Easing::InExpo => &EASE_IN_EXPO,
+        Easing::OutExpo => &EASE_OUT_EXPO,
+        Easing::InOutExpo => &EASE_IN_OUT_EXPO,
+        Easing::InElastic => &EASE_IN_ELASTIC,
+        Easing::OutElastic => &EASE_OUT_ELASTIC,
+        Easing::InOutElastic => &EASE_IN_OUT_ELASTIC,
+        Easing::Smoothstep => &SMOOTHSTEP,
+        Easing::Smootherstep => &SMOOTHERSTEP,
+        Easing::Smootheststep => &SMOOTHESTSTEP,
+        Easing::InCurve(_) | Easing::OutCurve(_) | Easing::InOutCurve(_) => return None,
+        Easing::InPow(_) | Easing::OutPow(_) | Easing::InOutPow(_) => return None,
+        Easing::InExpoScaled(_) | Easing::OutExpoScaled(_) | Easing::InOutExpoScaled(_) => {
+            return None;
+        }
+    };
+    Some(table)
+}
+
+/// Looks up the table entry nearest to `t`, which is clamped to [0, 1].
+pub fn lookup(table: &Table, t: f32) -> f32 {
+    let position = t.clamp(0.0, 1.0) * (TABLE_SIZE - 1) as f32;
+    table[(position + 0.5) as usize]
+}
+
+/// Looks up `t` with linear interpolation between neighbouring table entries. `t` is clamped to
+/// [0, 1].
+pub fn lookup_linear(table: &Table, t: f32) -> f32 {
+    let position = t.clamp(0.0, 1.0) * (TABLE_SIZE - 1) as f32;
+    let index = (position as usize).min(TABLE_SIZE - 2);
+    let fraction = position - index as f32;
+    table[index] + (table[index + 1] - table[index]) * fraction
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::{TABLE_SIZE, lookup, lookup_linear, table};
+    use crate::Easing;
+    use approx::assert_relative_eq;
+
+    const EASINGS: [Easing; 34] = [
+        Easing::Linear,
+        Easing::InQuad,
+        Easing::OutQuad,
+        Easing::InOutQuad,
+        Easing::InCubic,
+        Easing::OutCubic,
+        Easing::InOutCubic,
+        Easing::InQuart,
+        Easing::OutQuart,
+        Easing::InOutQuart,
+        Easing::InQuint,
+        Easing::OutQuint,
+        Easing::InOutQuint,
+        Easing::InSine,
+        Easing::OutSine,
+        Easing::InOutSine,
+        Easing::InCirc,
+        Easing::OutCirc,
+        Easing::InOutCirc,
+        Easing::InBack,
+        Easing::OutBack,
+        Easing::InOutBack,
+        Easing::InBounce,
+        Easing::OutBounce,
+        Easing::InOutBounce,
+        Easing::InExpo,
+        Easing::OutExpo,
+        Easing::InOutExpo,
+        Easing::InElastic,
+        Easing::OutElastic,
+        Easing::InOutElastic,
+        Easing::Smoothstep,
+        Easing::Smootherstep,
+        Easing::Smootheststep,
+    ];
+
+    #[test]
+    fn tables_match_easing_functions() {
+        for easing in EASINGS {
+            let table = table(easing).unwrap();
+            for (i, &value) in table.iter().enumerate() {
+                let t = i as f64 / (TABLE_SIZE - 1) as f64;
+                assert_relative_eq!(value as f64, easing.apply(t), epsilon = 1e-6);
+            }
+        }
+        assert!(table(Easing::InCurve(1.0)).is_none());
+    }
+
+    #[test]
+    fn lookups() {
+        let table = table(Easing::InQuad).unwrap();
+        assert_eq!(lookup(table, 0.0), 0.0);
+        assert_eq!(lookup(table, 1.0), 1.0);
+        assert_eq!(lookup(table, 2.0), 1.0);
+        assert_eq!(lookup(table, -1.0), 0.0);
+        assert_relative_eq!(lookup(table, 0.5), 0.5f32 * 0.5, epsilon = 1e-2);
+
+        assert_eq!(lookup_linear(table, 1.0), 1.0);
+        assert_relative_eq!(lookup_linear(table, 0.3), 0.09, epsilon = 1e-4);
+    }
+}
@@ -0,0 +1,1249 @@
+// Copyright (C) 2025 Tim Blechmann
+// SPDX-License-Identifier: MIT
+
+//! Easing applied to whole buffers of `f32` or `f64` values.
+//!
+//! With the `nightly` feature, buffers are processed in `f32x8` or `f64x4` chunks using the SIMD
+//! implementations, with the remainder handled by the scalar path. The dispatch is internal, so
+//! callers pass slices of any length and never deal with lanes or alignment themselves.
+//!
+//! With the `std` feature, the kernels are additionally compiled for AVX2 and AVX-512 on x86-64,
+//! and the best [`InstructionSet`] of the machine is selected at runtime. A single binary built
+//! for the baseline target therefore uses the wide registers wherever they are available.
+
+#[cfg(feature = "alloc")]
+use alloc::{vec, vec::Vec};
+
+use crate::internal::CurveParam;
+use crate::{Easing, EasingArgument, EasingImplHelper, Scalar};
+
+#[cfg(feature = "nightly")]
+use core::simd::{f32x8, f64x4};
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// The instruction sets the batch functions are compiled for.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum InstructionSet {
+    /// The instructions of the compilation target, e.g. SSE2 on x86-64.
+    Baseline,
+    /// AVX2 and FMA on x86-64.
+    Avx2,
+    /// AVX-512 on x86-64.
+    Avx512,
+    /// NEON on AArch64, where it is always available.
+    Neon,
+}
+
+/// The instruction set the batch functions use on this machine.
+///
+/// It is detected on first use and cached. Without the `std` feature, only the instruction sets
+/// enabled at compile time are used.
+pub fn instruction_set() -> InstructionSet {
+    #[cfg(all(feature = "std", target_arch = "x86_64"))]
+    {
+        static DETECTED: std::sync::OnceLock<InstructionSet> = std::sync::OnceLock::new();
+        *DETECTED.get_or_init(|| {
+            if std::is_x86_feature_detected!("avx512f") {
+                InstructionSet::Avx512
+            } else if std::is_x86_feature_detected!("avx2") && std::is_x86_feature_detected!("fma")
+            {
+                InstructionSet::Avx2
+            } else {
+                InstructionSet::Baseline
+            }
+        })
+    }
+    #[cfg(target_arch = "aarch64")]
+    {
+        InstructionSet::Neon
+    }
+    #[cfg(not(any(all(feature = "std", target_arch = "x86_64"), target_arch = "aarch64")))]
+    {
+        InstructionSet::Baseline
+    }
+}
+
+// Runs `f` compiled for the instruction set of the machine.
+fn dispatch<R>(f: impl FnOnce() -> R) -> R {
+    // SAFETY: the instruction set has been detected on this machine.
+    unsafe { dispatch_to(instruction_set(), f) }
+}
+
+// Runs `f` compiled for `isa`. The closures passed here, the loop drivers, the kernels and the
+// easing math down to the lane operations are all `#[inline(always)]`, so they are compiled into
+// the wrappers enabling the target features rather than called in their baseline versions. Only
+// `libm` functions like `sinf` remain calls, once per lane.
+//
+// Safety: the machine must support `isa`.
+unsafe fn dispatch_to<R>(isa: InstructionSet, f: impl FnOnce() -> R) -> R {
+    match isa {
+        #[cfg(all(feature = "std", target_arch = "x86_64"))]
+        InstructionSet::Avx512 => unsafe { with_avx512(f) },
+        #[cfg(all(feature = "std", target_arch = "x86_64"))]
+        InstructionSet::Avx2 => unsafe { with_avx2(f) },
+        _ => f(),
+    }
+}
+
+#[cfg(all(feature = "std", target_arch = "x86_64"))]
+#[target_feature(enable = "avx2,fma")]
+unsafe fn with_avx2<R>(f: impl FnOnce() -> R) -> R {
+    f()
+}
+
+#[cfg(all(feature = "std", target_arch = "x86_64"))]
+#[target_feature(enable = "avx512f,avx2,fma")]
+unsafe fn with_avx512<R>(f: impl FnOnce() -> R) -> R {
+    f()
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+// Conversion of a buffer element to a kernel argument, i.e. to itself or to a splatted vector.
+trait FromSample<S> {
+    fn from_sample(sample: S) -> Self;
+}
+
+// Element types of the buffers, with the SIMD vector used for their bulk.
+trait Sample: Scalar + EasingImplHelper + CurveParam<Self> + FromSample<Self> {
+    #[cfg(feature = "nightly")]
+    const LANES: usize;
+    #[cfg(feature = "nightly")]
+    type Vector: EasingImplHelper + CurveParam<Self::Vector> + FromSample<Self>;
+
+    #[cfg(feature = "nightly")]
+    fn load(slice: &[Self]) -> Self::Vector;
+    #[cfg(feature = "nightly")]
+    fn store(vector: Self::Vector, slice: &mut [Self]);
+    // Ramp positions for the SIMD chunk starting at element `start`, counting down from `start` if
+    // `reversed`.
+    #[cfg(feature = "nightly")]
+    fn ramp_chunk(start: usize, denom: Self, reversed: bool) -> Self::Vector;
+
+    fn from_index(index: usize) -> Self;
+}
+
+macro_rules! impl_sample {
+    ($scalar:ty, $vector:ty, [$($lane:literal),+]) => {
+        impl FromSample<$scalar> for $scalar {
+            fn from_sample(sample: $scalar) -> Self {
+                sample
+            }
+        }
+
+        #[cfg(feature = "nightly")]
+        impl FromSample<$scalar> for $vector {
+            fn from_sample(sample: $scalar) -> Self {
+                <$vector>::splat(sample)
+            }
+        }
+
+        impl Sample for $scalar {
+            #[cfg(feature = "nightly")]
+            const LANES: usize = <$vector>::LEN;
+            #[cfg(feature = "nightly")]
+            type Vector = $vector;
+
+            #[cfg(feature = "nightly")]
+            fn load(slice: &[Self]) -> Self::Vector {
+                <$vector>::from_slice(slice)
+            }
+            #[cfg(feature = "nightly")]
+            fn store(vector: Self::Vector, slice: &mut [Self]) {
+                vector.copy_to_slice(slice)
+            }
+            #[cfg(feature = "nightly")]
+            fn ramp_chunk(start: usize, denom: Self, reversed: bool) -> Self::Vector {
+                let lane_index = <$vector>::from_array([$($lane),+]);
+                let start = <$vector>::splat(start as $scalar);
+                let position = if reversed { start - lane_index } else { start + lane_index };
+                position / <$vector>::splat(denom)
+            }
+
+            fn from_index(index: usize) -> Self {
+                index as $scalar
+            }
+        }
+    };
+}
+
+impl_sample!(f32, f32x8, [0.0, 1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0]);
+impl_sample!(f64, f64x4, [0.0, 1.0, 2.0, 3.0]);
+
+// A function that can be evaluated on scalars as well as on SIMD vectors of `S`.
+trait Kernel<S> {
+    fn eval<T>(&self, t: T) -> T
+    where
+        T: EasingImplHelper + CurveParam<T> + FromSample<S>;
+}
+
+impl<S> Kernel<S> for Easing {
+    #[inline(always)]
+    fn eval<T>(&self, t: T) -> T
+    where
+        T: EasingImplHelper + CurveParam<T> + FromSample<S>,
+    {
+        self.apply_inlined(t)
+    }
+}
+
+// Evaluates the kernel and passes each result through `f`, writing back to `buffer`.
+#[inline(always)]
+fn process_map<S, K, F>(buffer: &mut [S], kernel: &K, mut f: F)
+where
+    S: Sample,
+    K: Kernel<S>,
+    F: FnMut(S) -> S,
+{
+    #[cfg(feature = "nightly")]
+    let buffer = {
+        let bulk = buffer.len() - buffer.len() % S::LANES;
+        let (buffer_bulk, buffer_tail) = buffer.split_at_mut(bulk);
+        for chunk in buffer_bulk.chunks_exact_mut(S::LANES) {
+            S::store(kernel.eval(S::load(chunk)), chunk);
+            chunk.iter_mut().for_each(|v| *v = f(*v));
+        }
+        buffer_tail
+    };
+
+    for v in buffer {
+        *v = f(kernel.eval(*v));
+    }
+}
+
+// Evaluates the kernel for every element of `src`, writing the results to `dst`.
+#[inline(always)]
+fn process_unary<S: Sample, K: Kernel<S>>(dst: &mut [S], src: &[S], kernel: &K) {
+    assert_eq!(dst.len(), src.len(), "buffer length mismatch");
+
+    #[cfg(feature = "nightly")]
+    let (dst, src) = {
+        let bulk = dst.len() - dst.len() % S::LANES;
+        let (dst_bulk, dst_tail) = dst.split_at_mut(bulk);
+        for (d, s) in dst_bulk
+            .chunks_exact_mut(S::LANES)
+            .zip(src.chunks_exact(S::LANES))
+        {
+            S::store(kernel.eval(S::load(s)), d);
+        }
+        (dst_tail, &src[bulk..])
+    };
+
+    for (d, &s) in dst.iter_mut().zip(src) {
+        *d = kernel.eval(s);
+    }
+}
+
+// Denominator for `t` evenly spaced over [0, 1], including both endpoints.
+fn ramp_denominator<S: Sample>(len: usize) -> S {
+    S::from_index(len.saturating_sub(1).max(1))
+}
+
+// Evaluates the kernel for `t` evenly spaced over [0, 1], including both endpoints, running from 1
+// to 0 if `reversed`.
+#[inline(always)]
+fn process_ramp<S: Sample, K: Kernel<S>>(dst: &mut [S], kernel: &K, reversed: bool) {
+    let start = if reversed {
+        dst.len().saturating_sub(1)
+    } else {
+        0
+    };
+    process_ramp_at(dst, kernel, start, ramp_denominator(dst.len()), reversed);
+}
+
+// Evaluates the kernel for `t = (start + i) / denom`, or `t = (start - i) / denom` if `reversed`,
+// for each element `i` of `dst`.
+#[inline(always)]
+fn process_ramp_at<S: Sample, K: Kernel<S>>(
+    dst: &mut [S],
+    kernel: &K,
+    start: usize,
+    denom: S,
+    reversed: bool,
+) {
+    let position = |offset: usize| {
+        if reversed {
+            start - offset
+        } else {
+            start + offset
+        }
+    };
+
+    #[cfg(feature = "nightly")]
+    let (dst, offset) = {
+        let bulk = dst.len() - dst.len() % S::LANES;
+        let (dst_bulk, dst_tail) = dst.split_at_mut(bulk);
+        for (chunk_index, chunk) in dst_bulk.chunks_exact_mut(S::LANES).enumerate() {
+            let t = S::ramp_chunk(position(chunk_index * S::LANES), denom, reversed);
+            S::store(kernel.eval(t), chunk);
+        }
+        (dst_tail, bulk)
+    };
+    #[cfg(not(feature = "nightly"))]
+    let offset = 0;
+
+    for (i, d) in dst.iter_mut().enumerate() {
+        *d = kernel.eval(S::from_index(position(offset + i)) / denom);
+    }
+}
+
+// A function of the ramp position and two buffer elements.
+trait RampKernel<S> {
+    fn eval<T>(&self, t: T, a: T, b: T) -> T
+    where
+        T: EasingImplHelper + CurveParam<T> + FromSample<S>;
+}
+
+// Evaluates the kernel on pairs of buffer elements, for `t` evenly spaced over [0, 1].
+#[inline(always)]
+fn process_ramp_binary<S, K>(dst: &mut [S], a: &[S], b: &[S], kernel: &K)
+where
+    S: Sample,
+    K: RampKernel<S>,
+{
+    assert_eq!(dst.len(), a.len(), "buffer length mismatch");
+    assert_eq!(dst.len(), b.len(), "buffer length mismatch");
+    let denom = ramp_denominator::<S>(dst.len());
+
+    #[cfg(feature = "nightly")]
+    let (dst, a, b, offset) = {
+        let bulk = dst.len() - dst.len() % S::LANES;
+        let (dst_bulk, dst_tail) = dst.split_at_mut(bulk);
+        for (chunk_index, ((d, a), b)) in dst_bulk
+            .chunks_exact_mut(S::LANES)
+            .zip(a.chunks_exact(S::LANES))
+            .zip(b.chunks_exact(S::LANES))
+            .enumerate()
+        {
+            let t = S::ramp_chunk(chunk_index * S::LANES, denom, false);
+            S::store(kernel.eval(t, S::load(a), S::load(b)), d);
+        }
+        (dst_tail, &a[bulk..], &b[bulk..], bulk)
+    };
+    #[cfg(not(feature = "nightly"))]
+    let offset = 0;
+
+    for (i, ((d, &a), &b)) in dst.iter_mut().zip(a).zip(b).enumerate() {
+        *d = kernel.eval(S::from_index(offset + i) / denom, a, b);
+    }
+}
+
+// `from + easing(t) * (to - from)`
+struct Lerp<S> {
+    easing: Easing,
+    from: S,
+    to: S,
+}
+
+impl<S: Sample> Kernel<S> for Lerp<S> {
+    #[inline(always)]
+    fn eval<T>(&self, t: T) -> T
+    where
+        T: EasingImplHelper + CurveParam<T> + FromSample<S>,
+    {
+        let eased = self.easing.apply_inlined(t);
+        eased.mul_add(
+            T::from_sample(self.to - self.from),
+            T::from_sample(self.from),
+        )
+    }
+}
+
+// A `Lerp` over a part of [0, 1], where the ramp index `i` maps to `t = position + i * step`.
+#[cfg(feature = "alloc")]
+struct LerpSegment<S> {
+    lerp: Lerp<S>,
+    position: S,
+    step: S,
+}
+
+#[cfg(feature = "alloc")]
+impl<S: Sample> Kernel<S> for LerpSegment<S> {
+    #[inline(always)]
+    fn eval<T>(&self, index: T) -> T
+    where
+        T: EasingImplHelper + CurveParam<T> + FromSample<S>,
+    {
+        let t = index.mul_add(T::from_sample(self.step), T::from_sample(self.position));
+        self.lerp.eval(t)
+    }
+}
+
+// `easing(t)` passed through `transform`.
+struct Transformed<S> {
+    easing: Easing,
+    transform: Transform<S>,
+}
+
+impl<S: Sample> Kernel<S> for Transformed<S> {
+    #[inline(always)]
+    fn eval<T>(&self, t: T) -> T
+    where
+        T: EasingImplHelper + CurveParam<T> + FromSample<S>,
+    {
+        let eased = self.easing.apply_inlined(t);
+        match self.transform {
+            Transform::Affine { gain, offset } => {
+                eased.mul_add(T::from_sample(gain), T::from_sample(offset))
+            }
+            Transform::DecibelsToAmplitude { gain, offset } => {
+                // `10^(db / 20) = exp(db * ln(10) / 20)`, with the factor folded into the affine map.
+                let scale = S::from_f64(core::f64::consts::LN_10 / 20.0).unwrap();
+                eased
+                    .mul_add(T::from_sample(gain * scale), T::from_sample(offset * scale))
+                    .exp()
+            }
+        }
+    }
+}
+
+// `a + easing(t) * (b - a)`
+struct Mix {
+    easing: Easing,
+}
+
+impl<S> RampKernel<S> for Mix {
+    #[inline(always)]
+    fn eval<T>(&self, t: T, a: T, b: T) -> T
+    where
+        T: EasingImplHelper + CurveParam<T> + FromSample<S>,
+    {
+        self.easing.apply_inlined(t).mul_add(b - a, a)
+    }
+}
+
+// A function of two arguments that can be evaluated on scalars as well as on SIMD vectors.
+trait BinaryKernel {
+    fn eval<T>(&self, a: T, b: T) -> T
+    where
+        T: EasingImplHelper + CurveParam<T>;
+}
+
+#[inline(always)]
+fn process_binary<S: Sample, K: BinaryKernel>(dst: &mut [S], a: &[S], b: &[S], kernel: &K) {
+    assert_eq!(dst.len(), a.len(), "buffer length mismatch");
+    assert_eq!(dst.len(), b.len(), "buffer length mismatch");
+
+    #[cfg(feature = "nightly")]
+    let (dst, a, b) = {
+        let bulk = dst.len() - dst.len() % S::LANES;
+        let (dst_bulk, dst_tail) = dst.split_at_mut(bulk);
+        for ((d, a), b) in dst_bulk
+            .chunks_exact_mut(S::LANES)
+            .zip(a.chunks_exact(S::LANES))
+            .zip(b.chunks_exact(S::LANES))
+        {
+            S::store(kernel.eval(S::load(a), S::load(b)), d);
+        }
+        (dst_tail, &a[bulk..], &b[bulk..])
+    };
+
+    for ((d, &a), &b) in dst.iter_mut().zip(a).zip(b) {
+        *d = kernel.eval(a, b);
+    }
+}
+
+struct InCurve;
+struct OutCurve;
+struct InOutCurve;
+
+impl BinaryKernel for InCurve {
+    #[inline(always)]
+    fn eval<T>(&self, t: T, curve: T) -> T
+    where
+        T: EasingImplHelper + CurveParam<T>,
+    {
+        EasingArgument::ease_in_curve(t, curve)
+    }
+}
+
+impl BinaryKernel for OutCurve {
+    #[inline(always)]
+    fn eval<T>(&self, t: T, curve: T) -> T
+    where
+        T: EasingImplHelper + CurveParam<T>,
+    {
+        EasingArgument::ease_out_curve(t, curve)
+    }
+}
+
+impl BinaryKernel for InOutCurve {
+    #[inline(always)]
+    fn eval<T>(&self, t: T, curve: T) -> T
+    where
+        T: EasingImplHelper + CurveParam<T>,
+    {
+        EasingArgument::ease_in_out_curve(t, curve)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Applies `easing` to every element of `buffer`.
+///
+/// ```
+/// # use nova_easing::{Easing, batch::ease_slice_in_place};
+/// let mut particles = [0.0f32, 0.25, 0.5, 0.75, 1.0];
+/// ease_slice_in_place(&mut particles, Easing::InQuad);
+/// assert_eq!(particles, [0.0, 0.0625, 0.25, 0.5625, 1.0]);
+/// ```
+#[allow(private_bounds)]
+pub fn ease_slice_in_place<S: Sample>(buffer: &mut [S], easing: Easing) {
+    dispatch(
+        #[inline(always)]
+        || process_map(buffer, &easing, |v| v),
+    );
+}
+
+/// Applies `easing` to every element of `src`, writing the results to `dst`.
+///
+/// # Panics
+///
+/// Panics if `src` has a different length than `dst`.
+#[allow(private_bounds)]
+pub fn ease_slice<S: Sample>(dst: &mut [S], src: &[S], easing: Easing) {
+    dispatch(
+        #[inline(always)]
+        || process_unary(dst, src, &easing),
+    );
+}
+
+/// Applies `easing` to every element of `buffer` and passes the result through `f`, in a single
+/// pass.
+///
+/// The easing itself is vectorized, `f` is called once per element. This avoids a second traversal
+/// and an intermediate buffer when eased values need to be post-processed.
+#[allow(private_bounds)]
+pub fn ease_map<S, F>(buffer: &mut [S], easing: Easing, f: F)
+where
+    S: Sample,
+    F: FnMut(S) -> S,
+{
+    dispatch(
+        #[inline(always)]
+        || process_map(buffer, &easing, f),
+    );
+}
+
+/// A transform applied to the eased values of [`ease_slice_with`], [`ease_slice_in_place_with`] and
+/// [`sample_n_with`] within the same pass, before they are stored.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Transform<S> {
+    /// `value * gain + offset`, e.g. to map an easing onto a parameter range.
+    Affine {
+        /// The factor of the eased value.
+        gain: S,
+        /// The offset added after scaling.
+        offset: S,
+    },
+    /// `10^((value * gain + offset) / 20)`: the scaled value is a level in decibels, which is
+    /// converted to an amplitude, e.g. for fades which are eased on a logarithmic scale.
+    DecibelsToAmplitude {
+        /// The factor of the eased value, in decibels.
+        gain: S,
+        /// The level at an eased value of 0, in decibels.
+        offset: S,
+    },
+}
+
+/// Applies `easing` and `transform` to every element of `buffer`, in a single pass.
+///
+/// Unlike [`ease_map`], the transform is evaluated on the SIMD vectors as well.
+#[allow(private_bounds)]
+pub fn ease_slice_in_place_with<S: Sample>(
+    buffer: &mut [S],
+    easing: Easing,
+    transform: Transform<S>,
+) {
+    dispatch(
+        #[inline(always)]
+        || process_map(buffer, &Transformed { easing, transform }, |v| v),
+    );
+}
+
+/// Applies `easing` and `transform` to every element of `src`, writing the results to `dst`.
+///
+/// # Panics
+///
+/// Panics if `src` has a different length than `dst`.
+///
+/// ```
+/// # use nova_easing::{Easing, batch::{Transform, ease_slice_with}};
+/// let mut volume = [0.0f32; 3];
+/// let transform = Transform::Affine { gain: 100.0, offset: 20.0 };
+/// ease_slice_with(&mut volume, &[0.0, 0.5, 1.0], Easing::InQuad, transform);
+/// assert_eq!(volume, [20.0, 45.0, 120.0]);
+/// ```
+#[allow(private_bounds)]
+pub fn ease_slice_with<S: Sample>(
+    dst: &mut [S],
+    src: &[S],
+    easing: Easing,
+    transform: Transform<S>,
+) {
+    dispatch(
+        #[inline(always)]
+        || process_unary(dst, src, &Transformed { easing, transform }),
+    );
+}
+
+/// Fills `dst` with `transform(easing(t))`, for `t` evenly spaced over [0, 1] like [`sample_n`].
+///
+/// A fade from -60 dB to 0 dB, eased in decibels and rendered as amplitudes:
+///
+/// ```
+/// # use nova_easing::{Easing, batch::{Transform, sample_n_with}};
+/// let mut gains = [0.0f32; 64];
+/// let transform = Transform::DecibelsToAmplitude { gain: 60.0, offset: -60.0 };
+/// sample_n_with(&mut gains, Easing::OutQuad, transform);
+/// assert!((gains[0] - 0.001).abs() < 1e-6);
+/// assert!((gains[63] - 1.0).abs() < 1e-6);
+/// ```
+#[allow(private_bounds)]
+pub fn sample_n_with<S: Sample>(dst: &mut [S], easing: Easing, transform: Transform<S>) {
+    dispatch(
+        #[inline(always)]
+        || process_ramp(dst, &Transformed { easing, transform }, false),
+    );
+}
+
+/// Fills `dst` with `easing(t)`, for `t` evenly spaced over [0, 1].
+///
+/// The first element receives `t = 0` and the last element `t = 1`, e.g. for plots or lookup
+/// tables. A single element receives `t = 0`.
+///
+/// ```
+/// # use nova_easing::{Easing, batch::sample_n};
+/// let mut plot = [0.0f32; 5];
+/// sample_n(&mut plot, Easing::InQuad);
+/// assert_eq!(plot, [0.0, 0.0625, 0.25, 0.5625, 1.0]);
+/// ```
+#[allow(private_bounds)]
+pub fn sample_n<S: Sample>(dst: &mut [S], easing: Easing) {
+    dispatch(
+        #[inline(always)]
+        || process_ramp(dst, &easing, false),
+    );
+}
+
+/// Fills `dst` with `easing(t)`, for `t` evenly spaced from 1 down to 0.
+///
+/// The result is [`sample_n`] in reverse order, without reversing the buffer: the first element
+/// receives `t = 1` and the last element `t = 0`. A single element receives `t = 0`.
+///
+/// ```
+/// # use nova_easing::{Easing, batch::sample_n_reversed};
+/// let mut plot = [0.0f32; 5];
+/// sample_n_reversed(&mut plot, Easing::InQuad);
+/// assert_eq!(plot, [1.0, 0.5625, 0.25, 0.0625, 0.0]);
+/// ```
+#[allow(private_bounds)]
+pub fn sample_n_reversed<S: Sample>(dst: &mut [S], easing: Easing) {
+    dispatch(
+        #[inline(always)]
+        || process_ramp(dst, &easing, true),
+    );
+}
+
+/// Returns `n` samples of `easing`, see [`sample_n`].
+#[cfg(feature = "alloc")]
+#[allow(private_bounds)]
+pub fn sample_n_vec<S: Sample>(n: usize, easing: Easing) -> Vec<S> {
+    let mut samples = vec![S::zero(); n];
+    sample_n(&mut samples, easing);
+    samples
+}
+
+/// Fills `dst` with `from + easing(t) * (to - from)`, for `t` evenly spaced over [0, 1].
+///
+/// The first element receives `t = 0` and the last element `t = 1`. This is the inner loop of
+/// gradient generation and gain ramps, computed in a single pass.
+#[allow(private_bounds)]
+pub fn fill_eased_lerp<S: Sample>(dst: &mut [S], from: S, to: S, easing: Easing) {
+    dispatch(
+        #[inline(always)]
+        || process_ramp(dst, &Lerp { easing, from, to }, false),
+    );
+}
+
+/// Fills `dst` with `from + easing(t) * (to - from)`, for `t` evenly spaced from 1 down to 0.
+///
+/// The result is [`fill_eased_lerp`] in reverse order, i.e. the ramp played backwards in time: the
+/// first element is `to` and the last element `from`. With the parameters of a fade-in, this
+/// renders the matching fade-out.
+#[allow(private_bounds)]
+pub fn fill_eased_lerp_reversed<S: Sample>(dst: &mut [S], from: S, to: S, easing: Easing) {
+    dispatch(
+        #[inline(always)]
+        || process_ramp(dst, &Lerp { easing, from, to }, true),
+    );
+}
+
+// Fills `dst` with `from + easing(t) * (to - from)` for `t = position + (first + i) * step`, i.e.
+// the part of an envelope segment within a block, starting `first` samples into the segment.
+#[cfg(feature = "alloc")]
+#[allow(private_bounds)]
+pub(crate) fn fill_eased_segment<S: Sample>(
+    dst: &mut [S],
+    first: usize,
+    position: S,
+    step: S,
+    from: S,
+    to: S,
+    easing: Easing,
+) {
+    let lerp = Lerp { easing, from, to };
+    let kernel = LerpSegment {
+        lerp,
+        position,
+        step,
+    };
+    dispatch(
+        #[inline(always)]
+        || process_ramp_at(dst, &kernel, first, S::from_index(1), false),
+    );
+}
+
+/// Crossfades from buffer `a` to buffer `b` across the block, writing the result to `dst`.
+///
+/// The weight of `b` follows `easing(t)` for `t` evenly spaced over [0, 1], so the first element
+/// is taken from `a` and the last element from `b`.
+///
+/// # Panics
+///
+/// Panics if `a` or `b` have a different length than `dst`.
+#[allow(private_bounds)]
+pub fn mix_buffers<S: Sample>(dst: &mut [S], a: &[S], b: &[S], easing: Easing) {
+    dispatch(
+        #[inline(always)]
+        || process_ramp_binary(dst, a, b, &Mix { easing }),
+    );
+}
+
+/// Applies a gain ramp to a buffer of interleaved frames with `CHANNELS` channels.
+///
+/// The gain of each frame is `from + easing(t) * (to - from)`, for `t` evenly spaced over the
+/// frames, and is applied to all channels of the frame. Gains are computed in blocks via the
+/// vectorized ramp, so any channel layout (surround, ambisonics, ...) gets the SIMD path.
+///
+/// # Panics
+///
+/// Panics if `CHANNELS` is zero or the buffer length is not a multiple of `CHANNELS`.
+#[allow(private_bounds)]
+pub fn fade_frames<const CHANNELS: usize, S: Sample>(
+    buffer: &mut [S],
+    from: S,
+    to: S,
+    easing: Easing,
+) {
+    assert_frames::<CHANNELS, _>(buffer);
+    dispatch(
+        #[inline(always)]
+        || {
+            apply_gain_ramp(
+                buffer,
+                CHANNELS,
+                CHANNELS,
+                &Lerp { easing, from, to },
+                false,
+            )
+        },
+    );
+}
+
+/// Applies the gain ramp of [`fade_frames`] played backwards in time, from `to` at the first frame
+/// down to `from` at the last frame.
+///
+/// With the parameters of a fade-in, this renders the time-reversed fade-out, so the fades at both
+/// ends of a region are symmetric.
+///
+/// # Panics
+///
+/// Panics if `CHANNELS` is zero or the buffer length is not a multiple of `CHANNELS`.
+#[allow(private_bounds)]
+pub fn fade_frames_reversed<const CHANNELS: usize, S: Sample>(
+    buffer: &mut [S],
+    from: S,
+    to: S,
+    easing: Easing,
+) {
+    assert_frames::<CHANNELS, _>(buffer);
+    dispatch(
+        #[inline(always)]
+        || apply_gain_ramp(buffer, CHANNELS, CHANNELS, &Lerp { easing, from, to }, true),
+    );
+}
+
+/// Applies a gain ramp to every `stride`-th element of `buffer`, starting with the first.
+///
+/// The gain of each element is `from + easing(t) * (to - from)`, for `t` evenly spaced over the
+/// elements which are faded. Passing `&mut buffer[channel..]` and the channel count as `stride`
+/// fades a single channel of an interleaved buffer, with a channel count chosen at runtime.
+///
+/// # Panics
+///
+/// Panics if `stride` is zero.
+#[allow(private_bounds)]
+pub fn fade_strided<S: Sample>(buffer: &mut [S], stride: usize, from: S, to: S, easing: Easing) {
+    assert!(stride > 0, "stride must not be zero");
+    dispatch(
+        #[inline(always)]
+        || apply_gain_ramp(buffer, stride, 1, &Lerp { easing, from, to }, false),
+    );
+}
+
+/// Applies the gain ramp of [`fade_strided`] played backwards in time, from `to` at the first
+/// faded element down to `from` at the last one.
+///
+/// # Panics
+///
+/// Panics if `stride` is zero.
+#[allow(private_bounds)]
+pub fn fade_strided_reversed<S: Sample>(
+    buffer: &mut [S],
+    stride: usize,
+    from: S,
+    to: S,
+    easing: Easing,
+) {
+    assert!(stride > 0, "stride must not be zero");
+    dispatch(
+        #[inline(always)]
+        || apply_gain_ramp(buffer, stride, 1, &Lerp { easing, from, to }, true),
+    );
+}
+
+fn assert_frames<const CHANNELS: usize, S>(buffer: &[S]) {
+    assert!(CHANNELS > 0, "channel count must not be zero");
+    assert_eq!(
+        buffer.len() % CHANNELS,
+        0,
+        "buffer length must be a multiple of the channel count"
+    );
+}
+
+// Multiplies the first `width` elements of every `stride` elements of `buffer` by the ramp of
+// `kernel`, one ramp step per `stride` elements. Gains are computed in blocks via the vectorized
+// ramp.
+#[inline(always)]
+fn apply_gain_ramp<S: Sample, K: Kernel<S>>(
+    buffer: &mut [S],
+    stride: usize,
+    width: usize,
+    kernel: &K,
+    reversed: bool,
+) {
+    const BLOCK_FRAMES: usize = 64;
+
+    let frames = buffer.len().div_ceil(stride);
+    let denom = ramp_denominator(frames);
+    let mut gains = [S::zero(); BLOCK_FRAMES];
+    for (block_index, block) in buffer.chunks_mut(BLOCK_FRAMES * stride).enumerate() {
+        let gains = &mut gains[..block.len().div_ceil(stride)];
+        let offset = block_index * BLOCK_FRAMES;
+        let start = if reversed {
+            frames - 1 - offset
+        } else {
+            offset
+        };
+        process_ramp_at(gains, kernel, start, denom, reversed);
+
+        for (frame, &gain) in block.chunks_mut(stride).zip(gains.iter()) {
+            let faded = frame.len().min(width);
+            frame[..faded]
+                .iter_mut()
+                .for_each(|sample| *sample = *sample * gain);
+        }
+    }
+}
+
+/// Applies [`ease_in_curve`](crate::EasingArgument::ease_in_curve) to pairs of `t` values and
+/// per-element curve parameters, writing the results to `dst`.
+///
+/// # Panics
+///
+/// Panics if `t` or `curve` have a different length than `dst`.
+#[allow(private_bounds)]
+pub fn ease_in_curve_zipped<S: Sample>(dst: &mut [S], t: &[S], curve: &[S]) {
+    dispatch(
+        #[inline(always)]
+        || process_binary(dst, t, curve, &InCurve),
+    );
+}
+
+/// Applies [`ease_out_curve`](crate::EasingArgument::ease_out_curve) to pairs of `t` values and
+/// per-element curve parameters, writing the results to `dst`.
+///
+/// # Panics
+///
+/// Panics if `t` or `curve` have a different length than `dst`.
+#[allow(private_bounds)]
+pub fn ease_out_curve_zipped<S: Sample>(dst: &mut [S], t: &[S], curve: &[S]) {
+    dispatch(
+        #[inline(always)]
+        || process_binary(dst, t, curve, &OutCurve),
+    );
+}
+
+/// Applies [`ease_in_out_curve`](crate::EasingArgument::ease_in_out_curve) to pairs of `t` values
+/// and per-element curve parameters, writing the results to `dst`.
+///
+/// # Panics
+///
+/// Panics if `t` or `curve` have a different length than `dst`.
+#[allow(private_bounds)]
+pub fn ease_in_out_curve_zipped<S: Sample>(dst: &mut [S], t: &[S], curve: &[S]) {
+    dispatch(
+        #[inline(always)]
+        || process_binary(dst, t, curve, &InOutCurve),
+    );
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        InstructionSet, Mix, Transform, dispatch_to, ease_in_curve_zipped,
+        ease_in_out_curve_zipped, ease_map, ease_out_curve_zipped, ease_slice, ease_slice_in_place,
+        ease_slice_in_place_with, ease_slice_with, fade_frames, fade_frames_reversed, fade_strided,
+        fade_strided_reversed, fill_eased_lerp, fill_eased_lerp_reversed, instruction_set,
+        mix_buffers, process_ramp_binary, process_unary, sample_n, sample_n_reversed, sample_n_vec,
+        sample_n_with,
+    };
+    use crate::{Easing, EasingArgument};
+    use approx::assert_relative_eq;
+
+    // 19 elements: two full SIMD chunks plus a scalar tail
+    fn inputs() -> (Vec<f32>, Vec<f32>) {
+        let t = (0..19).map(|i| i as f32 / 18.0).collect();
+        let curve = (0..19).map(|i| (i as f32 - 9.0) * 0.5).collect();
+        (t, curve)
+    }
+
+    #[test]
+    fn ease_map_matches_scalar() {
+        let (t, _) = inputs();
+        let mut buffer = t.clone();
+        ease_map(&mut buffer, Easing::InOutCubic, |x| x * 2.0 - 1.0);
+        for i in 0..t.len() {
+            let expected = t[i].ease_in_out_cubic() * 2.0 - 1.0;
+            assert_relative_eq!(buffer[i], expected, epsilon = 1e-6);
+        }
+    }
+
+    #[test]
+    fn ease_slice_matches_scalar() {
+        let (t, _) = inputs();
+        for easing in [Easing::InOutCubic, Easing::OutBounce, Easing::InCurve(-3.0)] {
+            let mut in_place = t.clone();
+            ease_slice_in_place(&mut in_place, easing);
+            let mut out_of_place = vec![0.0; t.len()];
+            ease_slice(&mut out_of_place, &t, easing);
+            for i in 0..t.len() {
+                assert_relative_eq!(in_place[i], easing.apply(t[i]), epsilon = 1e-6);
+                assert_eq!(out_of_place[i], in_place[i]);
+            }
+        }
+
+        let t: Vec<f64> = (0..7).map(|i| i as f64 / 6.0).collect();
+        let mut dst = vec![0.0; t.len()];
+        ease_slice(&mut dst, &t, Easing::OutExpo);
+        for i in 0..t.len() {
+            assert_relative_eq!(dst[i], t[i].ease_out_expo(), epsilon = 1e-12);
+        }
+        ease_slice_in_place::<f32>(&mut [], Easing::Linear);
+    }
+
+    // Every length up to three chunks of 8 lanes, so each split of bulk and tail is covered.
+    #[test]
+    fn chunk_boundaries() {
+        for len in 0..=25 {
+            let t: Vec<f32> = (0..len).map(|i| i as f32 / 25.0).collect();
+            let expected: Vec<f32> = t.iter().map(|t| t.ease_out_back()).collect();
+
+            let mut dst = vec![0.0; len];
+            ease_slice(&mut dst, &t, Easing::OutBack);
+            assert_eq!(dst, expected);
+
+            let mut buffer = t.clone();
+            ease_slice_in_place(&mut buffer, Easing::OutBack);
+            assert_eq!(buffer, expected);
+
+            let mut buffer = t.clone();
+            ease_map(&mut buffer, Easing::OutBack, |x| x + 1.0);
+            assert!(buffer.iter().zip(&expected).all(|(v, e)| *v == e + 1.0));
+
+            ease_in_curve_zipped(&mut dst, &t, &vec![2.0; len]);
+            assert!(dst.iter().zip(&t).all(|(v, t)| *v == t.ease_in_curve(2.0)));
+
+            let t: Vec<f64> = t.iter().map(|&t| t as f64).collect();
+            let mut dst = vec![0.0; len];
+            ease_slice(&mut dst, &t, Easing::InOutSine);
+            assert!(dst.iter().zip(&t).all(|(v, t)| *v == t.ease_in_out_sine()));
+        }
+    }
+
+    #[test]
+    fn instruction_sets_agree() {
+        let supported = match instruction_set() {
+            InstructionSet::Avx512 => vec![InstructionSet::Avx2, InstructionSet::Avx512],
+            InstructionSet::Avx2 => vec![InstructionSet::Avx2],
+            _ => vec![],
+        };
+
+        let t: Vec<f32> = (0..37).map(|i| i as f32 / 36.0).collect();
+        let b: Vec<f32> = t.iter().map(|t| 1.0 - t).collect();
+        let run = |isa| {
+            let (mut eased, mut mixed) = (vec![0.0; t.len()], vec![0.0; t.len()]);
+            let mix = Mix {
+                easing: Easing::InOutElastic,
+            };
+            // SAFETY: only instruction sets detected on this machine are passed.
+            unsafe {
+                dispatch_to(isa, || process_unary(&mut eased, &t, &Easing::OutBounce));
+                dispatch_to(isa, || process_ramp_binary(&mut mixed, &t, &b, &mix));
+            }
+            (eased, mixed)
+        };
+
+        let baseline = run(InstructionSet::Baseline);
+        for isa in supported {
+            assert_eq!(run(isa), baseline, "{isa:?}");
+        }
+    }
+
+    #[test]
+    fn fill_eased_lerp_matches_scalar() {
+        let mut dst = [0.0f32; 21];
+        fill_eased_lerp(&mut dst, 2.0, -2.0, Easing::OutQuad);
+        for (i, &v) in dst.iter().enumerate() {
+            let t = i as f32 / 20.0;
+            assert_relative_eq!(v, 2.0 - 4.0 * t.ease_out_quad(), epsilon = 1e-6);
+        }
+        assert_eq!(dst[0], 2.0);
+        assert_eq!(dst[20], -2.0);
+
+        let mut single = [0.0f32];
+        fill_eased_lerp(&mut single, 1.0, 3.0, Easing::Linear);
+        assert_eq!(single[0], 1.0);
+    }
+
+    #[test]
+    fn sample_n_matches_scalar() {
+        let mut dst = [0.0f32; 19];
+        sample_n(&mut dst, Easing::OutElastic);
+        for (i, &v) in dst.iter().enumerate() {
+            assert_relative_eq!(v, (i as f32 / 18.0).ease_out_elastic(), epsilon = 1e-6);
+        }
+        assert_eq!(dst[0], 0.0);
+        assert_eq!(dst[18], 1.0);
+
+        let samples = sample_n_vec::<f64>(3, Easing::InOutCubic);
+        assert_eq!(samples, [0.0, 0.5, 1.0]);
+        assert_eq!(sample_n_vec::<f32>(1, Easing::OutQuad), [0.0]);
+        assert!(sample_n_vec::<f32>(0, Easing::OutQuad).is_empty());
+    }
+
+    #[test]
+    fn transforms_match_scalar() {
+        let (t, _) = inputs();
+        let affine = Transform::Affine {
+            gain: 4.0,
+            offset: -1.0,
+        };
+        let mut dst = vec![0.0; t.len()];
+        ease_slice_with(&mut dst, &t, Easing::OutBack, affine);
+        let mut buffer = t.clone();
+        ease_slice_in_place_with(&mut buffer, Easing::OutBack, affine);
+        for i in 0..t.len() {
+            let expected = t[i].ease_out_back() * 4.0 - 1.0;
+            assert_relative_eq!(dst[i], expected, epsilon = 1e-5);
+            assert_eq!(buffer[i], dst[i]);
+        }
+
+        let decibels = Transform::DecibelsToAmplitude {
+            gain: 48.0,
+            offset: -48.0,
+        };
+        let mut gains = [0.0f64; 11];
+        sample_n_with(&mut gains, Easing::InOutSine, decibels);
+        for (i, &gain) in gains.iter().enumerate() {
+            let db = (i as f64 / 10.0).ease_in_out_sine() * 48.0 - 48.0;
+            assert_relative_eq!(gain, 10.0f64.powf(db / 20.0), max_relative = 1e-12);
+        }
+        assert_relative_eq!(gains[10], 1.0, epsilon = 1e-12);
+    }
+
+    #[test]
+    fn mix_buffers_matches_scalar() {
+        let a: Vec<f32> = (0..19).map(|i| (i as f32).sin()).collect();
+        let b: Vec<f32> = (0..19).map(|i| (i as f32).cos()).collect();
+        let mut dst = vec![0.0; a.len()];
+        mix_buffers(&mut dst, &a, &b, Easing::InOutSine);
+        for i in 0..dst.len() {
+            let w = (i as f32 / 18.0).ease_in_out_sine();
+            assert_relative_eq!(dst[i], a[i] + w * (b[i] - a[i]), epsilon = 1e-6);
+        }
+        assert_eq!(dst[0], a[0]);
+        assert_relative_eq!(dst[18], b[18], epsilon = 1e-6);
+    }
+
+    #[test]
+    fn zipped_curves_match_scalar() {
+        let (t, curve) = inputs();
+        let mut dst = vec![0.0; t.len()];
+
+        ease_in_curve_zipped(&mut dst, &t, &curve);
+        for i in 0..t.len() {
+            assert_relative_eq!(dst[i], t[i].ease_in_curve(curve[i]), epsilon = 1e-6);
+        }
+
+        ease_out_curve_zipped(&mut dst, &t, &curve);
+        for i in 0..t.len() {
+            assert_relative_eq!(dst[i], t[i].ease_out_curve(curve[i]), epsilon = 1e-6);
+        }
+
+        ease_in_out_curve_zipped(&mut dst, &t, &curve);
+        for i in 0..t.len() {
+            assert_relative_eq!(dst[i], t[i].ease_in_out_curve(curve[i]), epsilon = 1e-6);
+        }
+    }
+
+    #[test]
+    fn fade_frames_applies_gain_per_frame() {
+        // 5.1 surround, 150 frames: several gain blocks, each with a scalar tail.
+        const CHANNELS: usize = 6;
+        let mut buffer: Vec<f32> = (0..150 * CHANNELS)
+            .map(|i| (i % CHANNELS) as f32 + 1.0)
+            .collect();
+        fade_frames::<CHANNELS, _>(&mut buffer, 1.0, 0.0, Easing::InOutSine);
+
+        for (index, frame) in buffer.chunks_exact(CHANNELS).enumerate() {
+            let gain = 1.0 - (index as f32 / 149.0).ease_in_out_sine();
+            for (channel, &sample) in frame.iter().enumerate() {
+                assert_relative_eq!(sample, (channel as f32 + 1.0) * gain, epsilon = 1e-5);
+            }
+        }
+        assert_eq!(&buffer[..CHANNELS], &[1.0, 2.0, 3.0, 4.0, 5.0, 6.0]);
+        assert!(
+            buffer[149 * CHANNELS..]
+                .iter()
+                .all(|&sample| sample.abs() < 1e-6)
+        );
+    }
+
+    #[test]
+    fn reversed_ramps() {
+        let assert_reversed = |reversed: &[f32], forward: &[f32]| {
+            for (&r, &f) in reversed.iter().zip(forward.iter().rev()) {
+                assert_relative_eq!(r, f, epsilon = 1e-6);
+            }
+        };
+        for len in 0..=25 {
+            let (mut forward, mut reversed) = (vec![0.0f32; len], vec![0.0f32; len]);
+            sample_n(&mut forward, Easing::OutElastic);
+            sample_n_reversed(&mut reversed, Easing::OutElastic);
+            assert_reversed(&reversed, &forward);
+
+            fill_eased_lerp(&mut forward, 0.5, 2.0, Easing::InOutSine);
+            fill_eased_lerp_reversed(&mut reversed, 0.5, 2.0, Easing::InOutSine);
+            assert_reversed(&reversed, &forward);
+        }
+
+        let mut single = [0.0f64];
+        sample_n_reversed(&mut single, Easing::OutQuad);
+        assert_eq!(single, [0.0]);
+        let mut ramp = [0.0f64; 3];
+        fill_eased_lerp_reversed(&mut ramp, 1.0, 3.0, Easing::InQuad);
+        assert_eq!(ramp, [3.0, 1.5, 1.0]);
+    }
+
+    #[test]
+    fn reversed_fades() {
+        // 150 stereo frames: several gain blocks, each with a scalar tail.
+        let signal: Vec<f32> = (0..300).map(|i| (i % 2) as f32 + 1.0).collect();
+        let mut fade_in = signal.clone();
+        fade_frames::<2, _>(&mut fade_in, 0.0, 1.0, Easing::OutCubic);
+        let mut fade_out = signal.clone();
+        fade_frames_reversed::<2, _>(&mut fade_out, 0.0, 1.0, Easing::OutCubic);
+        for (out_frame, in_frame) in fade_out.chunks(2).zip(fade_in.chunks(2).rev()) {
+            assert_relative_eq!(out_frame[0], in_frame[0], epsilon = 1e-6);
+            assert_relative_eq!(out_frame[1], in_frame[1], epsilon = 1e-6);
+        }
+        assert_eq!(&fade_out[..2], &[1.0, 2.0]);
+        assert_eq!(&fade_out[298..], &[0.0, 0.0]);
+
+        // The right channel of 3-channel frames, with a partial last frame.
+        let mut buffer = vec![1.0f32; 3 * 100 - 1];
+        fade_strided(&mut buffer[1..], 3, 1.0, 0.0, Easing::InQuad);
+        for (index, frame) in buffer.chunks(3).enumerate() {
+            let gain = 1.0 - (index as f32 / 99.0).ease_in_quad();
+            assert_eq!(frame[0], 1.0);
+            assert_relative_eq!(frame[1], gain, epsilon = 1e-6);
+            assert!(frame[2..].iter().all(|&sample| sample == 1.0));
+        }
+
+        let mut reversed = vec![1.0f32; 3 * 100 - 1];
+        fade_strided_reversed(&mut reversed[1..], 3, 1.0, 0.0, Easing::InQuad);
+        for (index, frame) in reversed.chunks(3).enumerate() {
+            assert_relative_eq!(frame[1], buffer[3 * (99 - index) + 1], epsilon = 1e-6);
+        }
+        fade_strided_reversed::<f64>(&mut [], 2, 0.0, 1.0, Easing::Linear);
+    }
+
+    #[test]
+    fn double_precision_buffers() {
+        // 11 elements: two full `f64x4` chunks plus a scalar tail
+        let t: Vec<f64> = (0..11).map(|i| i as f64 / 10.0).collect();
+
+        let mut buffer = t.clone();
+        ease_map(&mut buffer, Easing::InOutCubic, |x| x * 2.0);
+        for (&v, &t) in buffer.iter().zip(&t) {
+            assert_relative_eq!(v, t.ease_in_out_cubic() * 2.0, epsilon = 1e-12);
+        }
+
+        let mut dst = vec![0.0f64; t.len()];
+        fill_eased_lerp(&mut dst, 1.0, 3.0, Easing::OutExpo);
+        for (&v, &t) in dst.iter().zip(&t) {
+            assert_relative_eq!(v, 1.0 + 2.0 * t.ease_out_expo(), epsilon = 1e-12);
+        }
+
+        let b: Vec<f64> = t.iter().map(|t| 1.0 - t).collect();
+        mix_buffers(&mut dst, &t, &b, Easing::Linear);
+        for (&v, &t) in dst.iter().zip(&t) {
+            assert_relative_eq!(v, t + t * (1.0 - 2.0 * t), epsilon = 1e-12);
+        }
+
+        let curve = vec![-2.5f64; t.len()];
+        ease_in_out_curve_zipped(&mut dst, &t, &curve);
+        for (&v, &t) in dst.iter().zip(&t) {
+            assert_relative_eq!(v, t.ease_in_out_curve(-2.5), epsilon = 1e-12);
+        }
+
+        let mut frames = vec![1.0f64; 2 * 101];
+        fade_frames::<2, _>(&mut frames, 0.0, 1.0, Easing::InQuad);
+        assert_relative_eq!(frames[100], 0.25, epsilon = 1e-12);
+        assert_relative_eq!(frames[101], 0.25, epsilon = 1e-12);
+    }
+
+    #[test]
+    #[should_panic(expected = "buffer length must be a multiple of the channel count")]
+    fn fade_frames_partial_frame() {
+        fade_frames::<4, _>(&mut [0.0; 6], 0.0, 1.0, Easing::Linear);
+    }
+
+    #[test]
+    #[should_panic(expected = "buffer length mismatch")]
+    fn ease_slice_length_mismatch() {
+        ease_slice(&mut [0.0f32; 4], &[0.0; 5], Easing::Linear);
+    }
+
+    #[test]
+    #[should_panic(expected = "buffer length mismatch")]
+    fn zipped_length_mismatch() {
+        let mut dst = [0.0; 4];
+        ease_in_curve_zipped(&mut dst, &[0.0; 4], &[0.0; 3]);
+    }
+}
@@ -4,23 +4,18 @@
 #![feature(portable_simd)]
 
 
-use nova_easing::EasingArgument;
+use nova_easing::{Easing, EasingArgument, batch};
 use plotters::prelude::*;
 use std::path::Path;
 
 #[cfg(feature = "nightly")]
 use std::simd::f32x4;
 
-fn generate_samples_f32<F>(func: F) -> Vec<(f32, f32)>
-where
-    F: Fn(f32) -> f32,
-{
-    (0..512)
-        .map(|i| {
-            let x = i as f32 / 511.0;
-            let y = func(x);
-            (x, y)
-        })
+fn generate_samples_f32(easing: Easing) -> Vec<(f32, f32)> {
+    batch::sample_n_vec(512, easing)
+        .into_iter()
+        .enumerate()
+        .map(|(i, y)| (i as f32 / 511.0, y))
         .collect()
 }
 
@@ -61,7 +56,8 @@ fn plot_samples(samples: &[(f32, f32)], filename: &str) -> Result<(), Box<dyn st
 
 macro_rules! generate_plots {
     ($func_name:ident) => {{
-        let samples = generate_samples_f32(|x| EasingArgument::$func_name(x));
+        let easing: Easing = stringify!($func_name).parse().unwrap();
+        let samples = generate_samples_f32(easing);
         plot_samples(
             &samples,
             concat!("demo_plots/f32/", stringify!($func_name), ".png"),
@@ -119,7 +115,7 @@ fn main() {
     // Generate plots for ease_in_curve with different curve factors
     let curve_factors = [-4.0, -1.0, 0.0, 1.0, 4.0];
     for &curve in &curve_factors {
-        let samples = generate_samples_f32(|x| EasingArgument::ease_in_curve(x, curve));
+        let samples = generate_samples_f32(Easing::InCurve(curve));
         let filename = format!(
             "demo_plots/f32/ease_in_curve_{}.png",
             if curve < 0.0 {
@@ -152,7 +148,7 @@ fn main() {
 
     // Generate plots for ease_out_curve with different curve factors
     for &curve in &curve_factors {
-        let samples = generate_samples_f32(|x| EasingArgument::ease_out_curve(x, curve));
+        let samples = generate_samples_f32(Easing::OutCurve(curve));
         let filename = format!(
             "demo_plots/f32/ease_out_curve_{}.png",
             if curve < 0.0 {
@@ -185,7 +181,7 @@ fn main() {
 
     // Generate plots for ease_in_out_curve with different curve factors
     for &curve in &curve_factors {
-        let samples = generate_samples_f32(|x| EasingArgument::ease_in_out_curve(x, curve));
+        let samples = generate_samples_f32(Easing::InOutCurve(curve));
         let filename = format!(
             "demo_plots/f32/ease_in_out_curve_{}.png",
             if curve < 0.0 {
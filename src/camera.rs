@@ -0,0 +1,261 @@
+// Copyright (C) 2025 Tim Blechmann
+// SPDX-License-Identifier: MIT
+
+//! Camera moves for cinematics and cutscenes, on the points and rotations of `nalgebra`.
+//!
+//! A [`CameraMove`] interpolates the position and rotation of a [`CameraPose`] over a number of
+//! frames, holding still for a few frames at both ends so the cut into and out of the shot does not
+//! land on a moving camera. The rotation can be pulled towards a look-at target, e.g. to keep a
+//! subject framed during a dolly shot:
+//!
+//! ```
+//! # use nova_easing::camera::{CameraMove, CameraPose, CRANE};
+//! # use nalgebra::{Point3, UnitQuaternion};
+//! let from = CameraPose::new(Point3::new(0.0, 1.5, -10.0), UnitQuaternion::identity());
+//! let to = CameraPose::new(Point3::new(0.0, 6.0, -4.0), UnitQuaternion::identity());
+//! let shot = CameraMove::new(from, to, 96)
+//!     .with_easing(CRANE)
+//!     .with_holds(12, 24)
+//!     .with_look_at(Point3::origin(), 1.0);
+//! assert_eq!(shot.frame_count(), 132);
+//! assert_eq!(shot.pose(6.0).position, from.position);
+//! assert_eq!(shot.pose(120.0).position, to.position);
+//! ```
+
+use nalgebra::{Point3, UnitQuaternion, Vector3};
+
+use crate::{Easing, Interpolatable};
+
+/// The classic slow-in/slow-out of a hand-operated dolly or pan.
+pub const SLOW_IN_SLOW_OUT: Easing = Easing::InOutCubic;
+
+/// A heavy crane or jib arm, with a long acceleration and a long settle.
+pub const CRANE: Easing = Easing::InOutQuart;
+
+/// A gentle drift for establishing shots, barely noticeable at the ends.
+pub const DRIFT: Easing = Easing::InOutSine;
+
+/// A push-in which starts at full speed, e.g. after a cut, and eases into the close-up.
+pub const PUSH_IN: Easing = Easing::OutQuart;
+
+/// A whip pan, accelerating and braking sharply around a blurred middle.
+pub const WHIP_PAN: Easing = Easing::InOutExpo;
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// The position and rotation of a camera.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct CameraPose {
+    /// The position of the camera.
+    pub position: Point3<f32>,
+    /// The rotation of the camera.
+    pub rotation: UnitQuaternion<f32>,
+}
+
+impl CameraPose {
+    /// Creates a pose from its position and rotation.
+    pub fn new(position: Point3<f32>, rotation: UnitQuaternion<f32>) -> Self {
+        Self { position, rotation }
+    }
+
+    /// Creates a pose at `position`, rotated like [`UnitQuaternion::face_towards`] so its local
+    /// `+z` axis points at `target`, with the `+y` axis up.
+    pub fn looking_at(position: Point3<f32>, target: Point3<f32>) -> Self {
+        Self::new(
+            position,
+            face_towards(position, target).unwrap_or_else(UnitQuaternion::identity),
+        )
+    }
+}
+
+/// Interpolates the position component-wise and the rotation along the shortest arc.
+impl Interpolatable for CameraPose {
+    fn lerp(self, other: Self, t: f32) -> Self {
+        Self::new(
+            self.position.lerp(other.position, t),
+            self.rotation.lerp(other.rotation, t),
+        )
+    }
+}
+
+// The rotation facing `target`, if it is not at `position`.
+fn face_towards(position: Point3<f32>, target: Point3<f32>) -> Option<UnitQuaternion<f32>> {
+    let direction = target - position;
+    (direction.norm_squared() > f32::EPSILON)
+        .then(|| UnitQuaternion::face_towards(&direction, &Vector3::y()))
+}
+
+/// A camera move between two poses, with hold frames at both ends. See the
+/// [module documentation](self).
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct CameraMove {
+    from: CameraPose,
+    to: CameraPose,
+    frames: u32,
+    easing: Easing,
+    head: u32,
+    tail: u32,
+    look_at: Option<(Point3<f32>, f32)>,
+}
+
+impl CameraMove {
+    /// Creates a move from `from` to `to` taking `frames` frames, following [`SLOW_IN_SLOW_OUT`]
+    /// and without holds.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `frames` is zero.
+    pub fn new(from: CameraPose, to: CameraPose, frames: u32) -> Self {
+        assert!(frames > 0, "camera move needs at least one frame");
+        Self {
+            from,
+            to,
+            frames,
+            easing: SLOW_IN_SLOW_OUT,
+            head: 0,
+            tail: 0,
+            look_at: None,
+        }
+    }
+
+    /// Sets the easing of the position and rotation, e.g. one of the presets of this module.
+    pub fn with_easing(mut self, easing: Easing) -> Self {
+        self.easing = easing;
+        self
+    }
+
+    /// Holds the start pose for `head` frames before the move and the end pose for `tail` frames
+    /// after it.
+    pub fn with_holds(mut self, head: u32, tail: u32) -> Self {
+        self.head = head;
+        self.tail = tail;
+        self
+    }
+
+    /// Blends the rotation towards facing `target`, see [`CameraPose::looking_at`], by `weight`
+    /// from 0 (the interpolated rotation) to 1 (always facing the target).
+    ///
+    /// The blend applies to the whole shot including the holds, so a weight of 1 keeps the target
+    /// framed from the first to the last frame.
+    pub fn with_look_at(mut self, target: Point3<f32>, weight: f32) -> Self {
+        self.look_at = Some((target, weight));
+        self
+    }
+
+    /// The pose at the start of the move.
+    pub fn from(&self) -> CameraPose {
+        self.from
+    }
+
+    /// The pose at the end of the move.
+    pub fn to(&self) -> CameraPose {
+        self.to
+    }
+
+    /// The easing of the move.
+    pub fn easing(&self) -> Easing {
+        self.easing
+    }
+
+    /// The number of frames of the shot, including the holds.
+    pub fn frame_count(&self) -> u32 {
+        self.head + self.frames + self.tail
+    }
+
+    /// The pose at `frame`, counted from the start of the head hold. Fractional frames give the
+    /// poses in between, e.g. for motion blur. Frames outside of the shot hold the end poses.
+    pub fn pose(&self, frame: f32) -> CameraPose {
+        let t = ((frame - self.head as f32) / self.frames as f32).clamp(0.0, 1.0);
+        let mut pose = self.from.ease(self.to, t, self.easing);
+        if let Some((target, weight)) = self.look_at
+            && let Some(facing) = face_towards(pose.position, target)
+        {
+            pose.rotation = pose.rotation.slerp(&facing, weight);
+        }
+        pose
+    }
+
+    /// The poses of all frames of the shot, one per frame.
+    pub fn poses(&self) -> impl Iterator<Item = CameraPose> + '_ {
+        (0..self.frame_count()).map(|frame| self.pose(frame as f32))
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::{CameraMove, CameraPose, PUSH_IN, WHIP_PAN};
+    use crate::EasingArgument;
+    use approx::assert_relative_eq;
+    use core::f32::consts::FRAC_PI_2;
+    use nalgebra::{Point3, UnitQuaternion, Vector3};
+
+    fn poses() -> (CameraPose, CameraPose) {
+        let from = CameraPose::new(Point3::new(0.0, 0.0, 0.0), UnitQuaternion::identity());
+        let to = CameraPose::new(
+            Point3::new(10.0, 0.0, 0.0),
+            UnitQuaternion::from_euler_angles(0.0, FRAC_PI_2, 0.0),
+        );
+        (from, to)
+    }
+
+    #[test]
+    fn holds() {
+        let (from, to) = poses();
+        let shot = CameraMove::new(from, to, 20)
+            .with_easing(PUSH_IN)
+            .with_holds(5, 10);
+        let poses: Vec<_> = shot.poses().collect();
+        assert_eq!(poses.len(), 35);
+        assert!(poses[..=5].iter().all(|&pose| pose == from));
+        assert!(poses[25..].iter().all(|&pose| pose == to));
+
+        let pose = shot.pose(10.0);
+        assert_relative_eq!(pose.position.x, 10.0 * 0.25f32.ease_out_quart());
+        assert_relative_eq!(
+            pose.rotation.angle(),
+            FRAC_PI_2 * 0.25f32.ease_out_quart(),
+            epsilon = 1e-5
+        );
+        assert_eq!(shot.pose(-3.0), from);
+        assert_eq!(shot.pose(100.0), to);
+    }
+
+    #[test]
+    fn look_at() {
+        let (from, to) = poses();
+        let target = Point3::new(5.0, 0.0, 5.0);
+        let shot = CameraMove::new(from, to, 10)
+            .with_easing(WHIP_PAN)
+            .with_look_at(target, 1.0);
+        for pose in shot.poses() {
+            let forward = pose.rotation * Vector3::z();
+            let direction = (target - pose.position).normalize();
+            assert_relative_eq!(forward, direction, epsilon = 1e-5);
+        }
+
+        // Half way between the interpolated rotation and facing the target.
+        let half = CameraMove::new(from, from, 1).with_look_at(Point3::new(1.0, 0.0, 0.0), 0.5);
+        assert_relative_eq!(
+            half.pose(0.0).rotation.angle(),
+            FRAC_PI_2 / 2.0,
+            epsilon = 1e-5
+        );
+
+        // A target at the camera position leaves the rotation alone.
+        let on_target = CameraMove::new(from, from, 1).with_look_at(from.position, 1.0);
+        assert_eq!(on_target.pose(0.5), from);
+        assert_eq!(
+            CameraPose::looking_at(Point3::origin(), Point3::new(0.0, 0.0, 3.0)).rotation,
+            UnitQuaternion::identity()
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "camera move needs at least one frame")]
+    fn empty_move() {
+        let (from, to) = poses();
+        CameraMove::new(from, to, 0);
+    }
+}
@@ -0,0 +1,143 @@
+// Copyright (C) 2025 Tim Blechmann
+// SPDX-License-Identifier: MIT
+
+//! Evaluation cost of easings, and cheaper substitutes.
+
+use crate::Easing;
+use crate::metrics::max_error;
+
+/// The relative cost of evaluating an easing, from cheapest to most expensive.
+///
+/// The tiers order by the most expensive operation of the formula, which dominates on low-power
+/// devices and in the SIMD paths.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Cost {
+    /// Multiplications and additions only, including the piecewise `Bounce`.
+    Polynomial,
+    /// A square root, i.e. `Circ`.
+    SquareRoot,
+    /// A sine or cosine, i.e. `Sine`.
+    Trigonometric,
+    /// An exponential or arbitrary power: `Expo`, `ExpoScaled`, `Elastic`, `Curve`
+    /// and `Pow`.
+    Exponential,
+}
+
+// Polynomial substitutes without overshoot, which approximate the smooth expensive easings.
+const CANDIDATES: [Easing; 13] = [
+    Easing::Linear,
+    Easing::InQuad,
+    Easing::OutQuad,
+    Easing::InOutQuad,
+    Easing::InCubic,
+    Easing::OutCubic,
+    Easing::InOutCubic,
+    Easing::InQuart,
+    Easing::OutQuart,
+    Easing::InOutQuart,
+    Easing::InQuint,
+    Easing::OutQuint,
+    Easing::InOutQuint,
+];
+
+impl Easing {
+    /// The cost tier of evaluating the easing.
+    pub fn cost(self) -> Cost {
+        match self {
+            Easing::InSine | Easing::OutSine | Easing::InOutSine => Cost::Trigonometric,
+            Easing::InCirc | Easing::OutCirc | Easing::InOutCirc => Cost::SquareRoot,
+            Easing::InExpo
+            | Easing::OutExpo
+            | Easing::InOutExpo
+            | Easing::InElastic
+            | Easing::OutElastic
+            | Easing::InOutElastic
+            | Easing::InCurve(_)
+            | Easing::OutCurve(_)
+            | Easing::InOutCurve(_)
+            | Easing::InPow(_)
+            | Easing::OutPow(_)
+            | Easing::InOutPow(_)
+            | Easing::InExpoScaled(_)
+            | Easing::OutExpoScaled(_)
+            | Easing::InOutExpoScaled(_) => Cost::Exponential,
+            _ => Cost::Polynomial,
+        }
+    }
+
+    /// Returns the polynomial easing closest to this one, if it deviates by at most
+    /// `max_deviation` at any `t` in [0, 1], or the easing itself otherwise.
+    ///
+    /// Engines can downgrade curves on low-power devices with a deviation that stays invisible,
+    /// e.g. `0.01` of the animated range. The candidates are compared numerically with
+    /// [`metrics::max_error`](crate::metrics::max_error), so this should be called once when
+    /// configuring an animation rather than per frame.
+    ///
+    /// ```
+    /// # use nova_easing::{Cost, Easing};
+    /// assert_eq!(Easing::InOutSine.fast_equivalent(0.05), Easing::InOutQuad);
+    /// assert_eq!(Easing::InPow(3.0).fast_equivalent(0.0), Easing::InCubic);
+    ///
+    /// // Nothing polynomial looks like an elastic.
+    /// assert_eq!(Easing::OutElastic.fast_equivalent(0.05), Easing::OutElastic);
+    /// assert_eq!(Easing::InOutQuad.fast_equivalent(0.05).cost(), Cost::Polynomial);
+    /// ```
+    pub fn fast_equivalent(self, max_deviation: f64) -> Easing {
+        self.closest_polynomial()
+            .filter(|&(_, deviation)| deviation <= max_deviation)
+            .map_or(self, |(easing, _)| easing)
+    }
+
+    /// The polynomial easing closest to this one and its maximum deviation, or `None` if the
+    /// easing is polynomial already.
+    pub fn closest_polynomial(self) -> Option<(Easing, f64)> {
+        if self.cost() == Cost::Polynomial {
+            return None;
+        }
+        CANDIDATES
+            .into_iter()
+            .map(|candidate| {
+                let deviation = max_error(|t| self.apply(t), |t| candidate.apply(t));
+                (candidate, deviation)
+            })
+            .min_by(|a, b| a.1.total_cmp(&b.1))
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::Cost;
+    use crate::Easing;
+    use crate::easing::tests::EASINGS;
+
+    #[test]
+    fn tiers() {
+        assert_eq!(Easing::OutBounce.cost(), Cost::Polynomial);
+        assert_eq!(Easing::InOutBack.cost(), Cost::Polynomial);
+        assert_eq!(Easing::OutCirc.cost(), Cost::SquareRoot);
+        assert_eq!(Easing::InSine.cost(), Cost::Trigonometric);
+        assert_eq!(Easing::InOutCurve(2.0).cost(), Cost::Exponential);
+        assert!(Cost::Polynomial < Cost::SquareRoot && Cost::Trigonometric < Cost::Exponential);
+    }
+
+    #[test]
+    fn fast_equivalents() {
+        for easing in EASINGS {
+            let fast = easing.fast_equivalent(0.1);
+            assert!(fast.cost() <= easing.cost());
+            if fast != easing {
+                assert_eq!(fast.cost(), Cost::Polynomial);
+                let (closest, deviation) = easing.closest_polynomial().unwrap();
+                assert_eq!(closest, fast);
+                assert!(deviation <= 0.1);
+            }
+        }
+
+        assert_eq!(Easing::OutSine.fast_equivalent(0.1), Easing::OutQuad);
+        assert_eq!(Easing::OutPow(2.0).fast_equivalent(0.0), Easing::OutQuad);
+        assert_eq!(Easing::InOutSine.fast_equivalent(0.001), Easing::InOutSine);
+        assert_eq!(Easing::InOutCubic.closest_polynomial(), None);
+    }
+}
@@ -0,0 +1,379 @@
+// Copyright (C) 2025 Tim Blechmann
+// SPDX-License-Identifier: MIT
+
+//! CSS timing functions, as found in stylesheets and in the exports of web design tools.
+//!
+//! [`TimingFunction`] represents the values of the CSS `transition-timing-function` and
+//! `animation-timing-function` properties: the keywords, `cubic-bezier()` and `steps()`. It parses
+//! from and formats to CSS syntax, and evaluates like browsers do.
+//!
+//! ```
+//! use nova_easing::css::TimingFunction;
+//! use nova_easing::curve::Curve;
+//!
+//! let ease: TimingFunction = "cubic-bezier(0.42, 0, 0.58, 1)".parse().unwrap();
+//! assert_eq!(ease, "ease-in-out".parse().unwrap());
+//! assert!((ease.eval(0.5f64) - 0.5).abs() < 1e-6);
+//!
+//! let frames: TimingFunction = "steps(4, end)".parse().unwrap();
+//! assert_eq!(frames.eval(0.3f32), 0.25);
+//! assert_eq!(frames.to_string(), "steps(4)");
+//! ```
+
+use core::fmt;
+use core::str::FromStr;
+
+use crate::cubic_bezier::CubicBezier;
+use crate::curve::Curve;
+use crate::custom::EasingValue;
+
+/// A CSS timing function. See the [module documentation](self).
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum TimingFunction {
+    /// `linear`, the identity.
+    Linear,
+    /// `cubic-bezier(x1, y1, x2, y2)`, including the keywords `ease`, `ease-in`, `ease-out` and
+    /// `ease-in-out`.
+    CubicBezier(CubicBezier),
+    /// `steps(count, position)`, including the keywords `step-start` and `step-end`.
+    Steps(u32, StepPosition),
+}
+
+/// Where the jumps of a `steps()` timing function happen.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum StepPosition {
+    /// `jump-start` or `start`: the first jump happens at `t = 0`.
+    JumpStart,
+    /// `jump-end` or `end`: the last jump happens at `t = 1`.
+    #[default]
+    JumpEnd,
+    /// `jump-none`: no jump at either end, so both 0 and 1 are held for a step.
+    JumpNone,
+    /// `jump-both`: jumps at both ends, so neither 0 nor 1 is held.
+    JumpBoth,
+}
+
+impl TimingFunction {
+    /// `steps(count, position)`, or `None` if `count` is invalid: zero, or one for
+    /// [`StepPosition::JumpNone`].
+    pub fn steps(count: u32, position: StepPosition) -> Option<Self> {
+        let minimum = if position == StepPosition::JumpNone {
+            2
+        } else {
+            1
+        };
+        (count >= minimum).then_some(TimingFunction::Steps(count, position))
+    }
+}
+
+impl Curve for TimingFunction {
+    #[inline]
+    fn eval<T: EasingValue>(&self, t: T) -> T {
+        match *self {
+            TimingFunction::Linear => t,
+            TimingFunction::CubicBezier(bezier) => bezier.eval(t),
+            TimingFunction::Steps(count, position) => steps(t, count, position),
+        }
+    }
+}
+
+// The steps of CSS Easing Functions Level 1: the step index is clamped to the valid steps only
+// within [0, 1], so the output continues to step outside of it.
+#[inline]
+pub(crate) fn steps<T: EasingValue>(t: T, count: u32, position: StepPosition) -> T {
+    let count = count.max(if position == StepPosition::JumpNone {
+        2
+    } else {
+        1
+    });
+    let (jumps, offset) = match position {
+        StepPosition::JumpStart => (count, 1.0),
+        StepPosition::JumpEnd => (count, 0.0),
+        StepPosition::JumpNone => (count - 1, 0.0),
+        StepPosition::JumpBoth => (count + 1, 1.0),
+    };
+    let zero = T::splat(0.0);
+    let (one, jumps) = (T::splat(1.0), T::splat(jumps as f32));
+
+    let step = (t * T::splat(count as f32)).floor() + T::splat(offset);
+    let step = t.select_lt(zero, step, step.select_lt(zero, zero, step));
+    let step = one.select_lt(t, step, jumps.select_lt(step, jumps, step));
+    step / jumps
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+const KEYWORDS: [(&str, TimingFunction); 7] = [
+    ("linear", TimingFunction::Linear),
+    ("ease", TimingFunction::CubicBezier(CubicBezier::EASE)),
+    ("ease-in", TimingFunction::CubicBezier(CubicBezier::EASE_IN)),
+    (
+        "ease-out",
+        TimingFunction::CubicBezier(CubicBezier::EASE_OUT),
+    ),
+    (
+        "ease-in-out",
+        TimingFunction::CubicBezier(CubicBezier::EASE_IN_OUT),
+    ),
+    (
+        "step-start",
+        TimingFunction::Steps(1, StepPosition::JumpStart),
+    ),
+    ("step-end", TimingFunction::Steps(1, StepPosition::JumpEnd)),
+];
+
+const POSITIONS: [(&str, StepPosition); 6] = [
+    ("jump-start", StepPosition::JumpStart),
+    ("jump-end", StepPosition::JumpEnd),
+    ("jump-none", StepPosition::JumpNone),
+    ("jump-both", StepPosition::JumpBoth),
+    ("start", StepPosition::JumpStart),
+    ("end", StepPosition::JumpEnd),
+];
+
+/// Formats the timing function in CSS syntax, using the keywords where possible, e.g. `ease` or
+/// `steps(4, jump-none)`.
+impl fmt::Display for TimingFunction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some((keyword, _)) = KEYWORDS.iter().find(|(_, function)| function == self) {
+            return f.write_str(keyword);
+        }
+        match self {
+            TimingFunction::Linear => f.write_str("linear"),
+            TimingFunction::CubicBezier(bezier) => {
+                let [x1, y1, x2, y2] = bezier.control_points();
+                write!(f, "cubic-bezier({x1}, {y1}, {x2}, {y2})")
+            }
+            TimingFunction::Steps(count, StepPosition::JumpEnd) => write!(f, "steps({count})"),
+            TimingFunction::Steps(count, position) => {
+                let (name, _) = POSITIONS
+                    .iter()
+                    .find(|(_, candidate)| candidate == position)
+                    .expect("every step position has a name");
+                write!(f, "steps({count}, {name})")
+            }
+        }
+    }
+}
+
+/// Parses CSS syntax, ignoring surrounding whitespace and the case of keywords.
+///
+/// ```
+/// # use nova_easing::css::{ParseTimingFunctionError, StepPosition, TimingFunction};
+/// assert_eq!("step-end".parse(), Ok(TimingFunction::Steps(1, StepPosition::JumpEnd)));
+/// assert_eq!(
+///     "cubic-bezier(1.5, 0, 1, 1)".parse::<TimingFunction>(),
+///     Err(ParseTimingFunctionError::InvalidArguments)
+/// );
+/// ```
+impl FromStr for TimingFunction {
+    type Err = ParseTimingFunctionError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        if let Some(&(_, function)) = KEYWORDS
+            .iter()
+            .find(|(keyword, _)| keyword.eq_ignore_ascii_case(s))
+        {
+            return Ok(function);
+        }
+
+        let (name, arguments) = s
+            .split_once('(')
+            .ok_or(ParseTimingFunctionError::UnknownFunction)?;
+        let arguments = arguments
+            .strip_suffix(')')
+            .ok_or(ParseTimingFunctionError::InvalidArguments)?;
+        let mut arguments = arguments.split(',').map(str::trim);
+        let name = name.trim_end();
+
+        if name.eq_ignore_ascii_case("cubic-bezier") {
+            let mut next = || {
+                arguments
+                    .next()
+                    .and_then(|argument| argument.parse::<f32>().ok())
+                    .filter(|value| value.is_finite())
+                    .ok_or(ParseTimingFunctionError::InvalidArguments)
+            };
+            let (x1, y1, x2, y2) = (next()?, next()?, next()?, next()?);
+            let in_range = |x: f32| (0.0..=1.0).contains(&x);
+            if arguments.next().is_some() || !in_range(x1) || !in_range(x2) {
+                return Err(ParseTimingFunctionError::InvalidArguments);
+            }
+            return Ok(TimingFunction::CubicBezier(CubicBezier::new(
+                x1, y1, x2, y2,
+            )));
+        }
+
+        if name.eq_ignore_ascii_case("steps") {
+            let count = arguments
+                .next()
+                .and_then(|argument| argument.parse().ok())
+                .ok_or(ParseTimingFunctionError::InvalidArguments)?;
+            let position = match arguments.next() {
+                None => StepPosition::JumpEnd,
+                Some(argument) => {
+                    POSITIONS
+                        .iter()
+                        .find(|(name, _)| name.eq_ignore_ascii_case(argument))
+                        .map(|&(_, position)| position)
+                        .ok_or(ParseTimingFunctionError::InvalidArguments)?
+                }
+            };
+            if arguments.next().is_some() {
+                return Err(ParseTimingFunctionError::InvalidArguments);
+            }
+            return TimingFunction::steps(count, position)
+                .ok_or(ParseTimingFunctionError::InvalidArguments);
+        }
+
+        Err(ParseTimingFunctionError::UnknownFunction)
+    }
+}
+
+/// The error of parsing a [`TimingFunction`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ParseTimingFunctionError {
+    /// Neither a keyword nor a supported function.
+    UnknownFunction,
+    /// The arguments of `cubic-bezier()` or `steps()` are missing, malformed or out of range.
+    InvalidArguments,
+}
+
+impl fmt::Display for ParseTimingFunctionError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseTimingFunctionError::UnknownFunction => f.write_str("unknown timing function"),
+            ParseTimingFunctionError::InvalidArguments => {
+                f.write_str("invalid timing function arguments")
+            }
+        }
+    }
+}
+
+impl core::error::Error for ParseTimingFunctionError {}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::{ParseTimingFunctionError, StepPosition, TimingFunction};
+    use crate::cubic_bezier::CubicBezier;
+    use crate::curve::Curve;
+
+    fn parse(s: &str) -> Result<TimingFunction, ParseTimingFunctionError> {
+        s.parse()
+    }
+
+    #[test]
+    fn keywords_and_functions() {
+        assert_eq!(parse("linear"), Ok(TimingFunction::Linear));
+        assert_eq!(
+            parse(" Ease-Out "),
+            Ok(TimingFunction::CubicBezier(CubicBezier::EASE_OUT))
+        );
+        assert_eq!(parse("ease"), parse("cubic-bezier(0.25, 0.1, 0.25, 1)"));
+        assert_eq!(
+            parse("cubic-bezier(0.68,-0.55,0.265,1.55)"),
+            Ok(TimingFunction::CubicBezier(CubicBezier::new(
+                0.68, -0.55, 0.265, 1.55
+            )))
+        );
+        assert_eq!(
+            parse("steps(4, end)"),
+            Ok(TimingFunction::Steps(4, StepPosition::JumpEnd))
+        );
+        assert_eq!(parse("steps(4)"), parse("steps(4, jump-end)"));
+        assert_eq!(parse("step-start"), parse("steps(1, start)"));
+        assert_eq!(
+            parse("steps( 3 , JUMP-BOTH )"),
+            Ok(TimingFunction::Steps(3, StepPosition::JumpBoth))
+        );
+    }
+
+    #[test]
+    fn errors() {
+        use ParseTimingFunctionError::{InvalidArguments, UnknownFunction};
+
+        assert_eq!(parse("ease-sideways"), Err(UnknownFunction));
+        assert_eq!(parse("bezier(0, 0, 1, 1)"), Err(UnknownFunction));
+        assert_eq!(parse(""), Err(UnknownFunction));
+        for input in [
+            "cubic-bezier(0, 0, 1)",
+            "cubic-bezier(0, 0, 1, 1, 0)",
+            "cubic-bezier(-0.1, 0, 1, 1)",
+            "cubic-bezier(0, 0, 1, inf)",
+            "cubic-bezier(0, 0, 1, 1",
+            "steps(0)",
+            "steps(1, jump-none)",
+            "steps(-2)",
+            "steps(2.5)",
+            "steps(2, middle)",
+            "steps(2, end, end)",
+        ] {
+            assert_eq!(parse(input), Err(InvalidArguments), "{input}");
+        }
+    }
+
+    #[test]
+    fn display_round_trips() {
+        for input in [
+            "linear",
+            "ease",
+            "ease-in-out",
+            "step-end",
+            "cubic-bezier(0.1, -0.6, 0.2, 0)",
+            "steps(4)",
+            "steps(2, jump-none)",
+            "steps(3, jump-start)",
+        ] {
+            let function = parse(input).unwrap();
+            assert_eq!(function.to_string(), input);
+            assert_eq!(parse(&function.to_string()), Ok(function));
+        }
+    }
+
+    #[test]
+    fn css_steps() {
+        let values = |input: &str| {
+            let function = parse(input).unwrap();
+            [0.0, 0.1, 0.3, 0.5, 0.7, 0.99, 1.0].map(|t: f32| function.eval(t))
+        };
+        assert_eq!(
+            values("steps(4, jump-end)"),
+            [0.0, 0.0, 0.25, 0.5, 0.5, 0.75, 1.0]
+        );
+        assert_eq!(
+            values("steps(4, jump-start)"),
+            [0.25, 0.25, 0.5, 0.75, 0.75, 1.0, 1.0]
+        );
+        assert_eq!(
+            values("steps(3, jump-none)"),
+            [0.0, 0.0, 0.0, 0.5, 1.0, 1.0, 1.0]
+        );
+        assert_eq!(
+            values("steps(3, jump-both)"),
+            [0.25, 0.25, 0.25, 0.5, 0.75, 0.75, 1.0]
+        );
+
+        // Outside of [0, 1], the steps continue.
+        let end = parse("steps(4)").unwrap();
+        assert_eq!(end.eval(-0.1f64), -0.25);
+        assert_eq!(end.eval(1.3f64), 1.25);
+    }
+
+    #[cfg(feature = "nightly")]
+    #[test]
+    fn simd() {
+        use core::simd::f32x4;
+
+        for input in ["steps(3, jump-both)", "ease-in", "linear"] {
+            let function = parse(input).unwrap();
+            let t = [-0.2f32, 0.1, 0.6, 1.0];
+            let simd = function.eval(f32x4::from_array(t)).to_array();
+            assert_eq!(simd, t.map(|t| function.eval(t)));
+        }
+    }
+}
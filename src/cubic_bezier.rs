@@ -0,0 +1,269 @@
+// Copyright (C) 2025 Tim Blechmann
+// SPDX-License-Identifier: MIT
+
+//! CSS `cubic-bezier()` timing functions.
+//!
+//! A [`CubicBezier`] is the curve from (0, 0) to (1, 1) with the control points (x1, y1) and
+//! (x2, y2). For an argument `t`, the curve parameter `s` with `x(s) = t` is solved numerically and
+//! `y(s)` is returned, as browsers do for CSS transitions. Outside of [0, 1], the curve continues
+//! along its tangents at the endpoints, as specified by CSS Easing Functions Level 1.
+//!
+//! [`CubicBezier`] implements [`Curve`], so it is evaluated for `f32`, `f64` and (with the `nightly`
+//! feature) SIMD vectors:
+//!
+//! ```
+//! use nova_easing::cubic_bezier::CubicBezier;
+//! use nova_easing::curve::Curve;
+//!
+//! let ease = CubicBezier::new(0.25, 0.1, 0.25, 1.0);
+//! assert_eq!(ease, CubicBezier::EASE);
+//! assert!((ease.eval(0.5f32) - 0.8024).abs() < 1e-4);
+//! assert_eq!(ease.eval(1.0f64), 1.0);
+//! ```
+
+use crate::curve::Curve;
+use crate::custom::EasingValue;
+
+/// A CSS `cubic-bezier(x1, y1, x2, y2)` timing function. See the [module documentation](self).
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CubicBezier {
+    x1: f32,
+    y1: f32,
+    x2: f32,
+    y2: f32,
+}
+
+impl CubicBezier {
+    /// CSS `ease`, i.e. `cubic-bezier(0.25, 0.1, 0.25, 1)`.
+    pub const EASE: Self = Self::new(0.25, 0.1, 0.25, 1.0);
+    /// CSS `ease-in`, i.e. `cubic-bezier(0.42, 0, 1, 1)`.
+    pub const EASE_IN: Self = Self::new(0.42, 0.0, 1.0, 1.0);
+    /// CSS `ease-out`, i.e. `cubic-bezier(0, 0, 0.58, 1)`.
+    pub const EASE_OUT: Self = Self::new(0.0, 0.0, 0.58, 1.0);
+    /// CSS `ease-in-out`, i.e. `cubic-bezier(0.42, 0, 0.58, 1)`.
+    pub const EASE_IN_OUT: Self = Self::new(0.42, 0.0, 0.58, 1.0);
+
+    // Iterations of the solver. Newton's method converges quadratically except where `x` is flat,
+    // at the ends of e.g. `ease-out`; there it only halves the error per step, and 16 steps reach
+    // `f64` precision down to `t = 1e-9`.
+    const ITERATIONS: usize = 16;
+
+    /// Creates the timing function with the control points (x1, y1) and (x2, y2).
+    ///
+    /// CSS rejects x coordinates outside of [0, 1], since the curve would not be a function of
+    /// `t`; they are clamped here. The y coordinates are unrestricted and overshoot for values
+    /// outside of [0, 1].
+    pub const fn new(x1: f32, y1: f32, x2: f32, y2: f32) -> Self {
+        Self {
+            x1: clamp_unit(x1),
+            y1,
+            x2: clamp_unit(x2),
+            y2,
+        }
+    }
+
+    /// The control points as `[x1, y1, x2, y2]`.
+    pub const fn control_points(&self) -> [f32; 4] {
+        [self.x1, self.y1, self.x2, self.y2]
+    }
+
+    /// Evaluates the timing function at `t`, a shorthand for [`Curve::eval`].
+    #[inline]
+    pub fn apply<T: EasingValue>(&self, t: T) -> T {
+        self.eval(t)
+    }
+
+    // The slopes of the tangents at (0, 0) and (1, 1), following the CSS rules for degenerate
+    // control points, which coincide with the endpoints.
+    fn end_slopes(&self) -> (f32, f32) {
+        let start = if self.x1 > 0.0 {
+            self.y1 / self.x1
+        } else if self.y1 == 0.0 && self.x2 > 0.0 {
+            self.y2 / self.x2
+        } else {
+            0.0
+        };
+        let end = if self.x2 < 1.0 {
+            (self.y2 - 1.0) / (self.x2 - 1.0)
+        } else if self.y2 == 1.0 && self.x1 < 1.0 {
+            (self.y1 - 1.0) / (self.x1 - 1.0)
+        } else {
+            0.0
+        };
+        (start, end)
+    }
+
+    // Solves `x(s) = t` for `t` in [0, 1], by Newton's method within a bracket of the solution.
+    // Newton steps which leave the bracket fall back to bisection, so the iteration converges even
+    // where `x` is flat, e.g. at the ends of `ease-in` and `ease-out`. The iteration count is
+    // fixed, which keeps SIMD lanes in lockstep.
+    #[inline]
+    fn solve<T: EasingValue>(&self, t: T) -> T {
+        let (cx, bx, ax) = coefficients::<T>(self.x1, self.x2);
+        let (zero, one, half) = (T::splat(0.0), T::splat(1.0), T::splat(0.5));
+        let min_slope = T::splat(1e-6);
+
+        let (mut low, mut high, mut s) = (zero, one, t);
+        for _ in 0..Self::ITERATIONS {
+            let error = ((ax * s + bx) * s + cx) * s - t;
+            low = error.select_lt(zero, s, low);
+            high = error.select_lt(zero, high, s);
+
+            let slope = (T::splat(3.0) * ax * s + bx.double()) * s + cx;
+            let newton = s - error / slope.select_lt(min_slope, min_slope, slope);
+            let bisection = (low + high) * half;
+            let inside = newton.select_lt(low, zero, high.select_lt(newton, zero, one));
+            s = inside.select_lt(half, bisection, newton);
+        }
+        s
+    }
+}
+
+impl Curve for CubicBezier {
+    #[inline]
+    fn eval<T: EasingValue>(&self, t: T) -> T {
+        let (zero, one) = (T::splat(0.0), T::splat(1.0));
+        let clamped = t.select_lt(zero, zero, one.select_lt(t, one, t));
+        let s = self.solve(clamped);
+        let (cy, by, ay) = coefficients::<T>(self.y1, self.y2);
+        let y = ((ay * s + by) * s + cy) * s;
+
+        let (start, end) = self.end_slopes();
+        let before = t * T::splat(start);
+        let after = (t - one).mul_add(T::splat(end), one);
+        let y = t.select_lt(zero, before, y);
+        one.select_lt(t, after, y)
+    }
+}
+
+// The power basis `c s + b s² + a s³` of the Bezier coordinate with the inner control values `p1`
+// and `p2`, as `(c, b, a)`. Computed in `T`, so `f64` keeps its precision.
+#[inline]
+fn coefficients<T: EasingValue>(p1: f32, p2: f32) -> (T, T, T) {
+    let (p1, p2, three) = (T::splat(p1), T::splat(p2), T::splat(3.0));
+    let c = three * p1;
+    let b = three * (p2 - p1) - c;
+    (c, b, T::splat(1.0) - c - b)
+}
+
+const fn clamp_unit(x: f32) -> f32 {
+    if x < 0.0 {
+        0.0
+    } else if x > 1.0 {
+        1.0
+    } else {
+        x
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::CubicBezier;
+    use crate::curve::Curve;
+    use approx::assert_relative_eq;
+
+    // Reference solution by plain bisection in `f64`.
+    fn reference(bezier: &CubicBezier, t: f64) -> f64 {
+        let [x1, y1, x2, y2] = bezier.control_points().map(f64::from);
+        let eval = |p1: f64, p2: f64, s: f64| {
+            let r = 1.0 - s;
+            3.0 * r * r * s * p1 + 3.0 * r * s * s * p2 + s * s * s
+        };
+        let (mut low, mut high) = (0.0, 1.0);
+        for _ in 0..100 {
+            let s = (low + high) * 0.5;
+            if eval(x1, x2, s) < t {
+                low = s;
+            } else {
+                high = s;
+            }
+        }
+        eval(y1, y2, (low + high) * 0.5)
+    }
+
+    const CURVES: [CubicBezier; 10] = [
+        CubicBezier::EASE,
+        CubicBezier::EASE_IN,
+        CubicBezier::EASE_OUT,
+        CubicBezier::EASE_IN_OUT,
+        CubicBezier::new(0.68, -0.55, 0.265, 1.55),
+        CubicBezier::new(0.0, 0.0, 1.0, 1.0),
+        CubicBezier::new(0.0, 0.5, 1.0, 1.0),
+        CubicBezier::new(1.0, 0.0, 0.0, 1.0),
+        CubicBezier::new(0.0, 1.0, 1.0, 0.0),
+        CubicBezier::new(0.9, 0.1, 0.95, 0.2),
+    ];
+
+    #[test]
+    fn matches_reference() {
+        for (index, bezier) in CURVES.into_iter().enumerate() {
+            // The last curves are nearly vertical in places, where `y` is ill-conditioned.
+            let (tolerance, tolerance32) = if index < 7 {
+                (1e-12, 1e-5)
+            } else {
+                (1e-5, 1e-3)
+            };
+            let samples = (0..=1000).map(|i| i as f64 / 1000.0);
+            for t in samples.chain([1e-9, 1e-7, 1e-5, 1.0 - 1e-5, 1.0 - 1e-7]) {
+                let expected = reference(&bezier, t);
+                assert_relative_eq!(bezier.eval(t), expected, epsilon = tolerance);
+                let expected = reference(&bezier, t as f32 as f64) as f32;
+                assert_relative_eq!(bezier.eval(t as f32), expected, epsilon = tolerance32);
+            }
+        }
+    }
+
+    #[test]
+    fn css_keywords() {
+        assert_relative_eq!(CubicBezier::EASE.eval(0.25f64), 0.4085106, epsilon = 1e-7);
+        assert_relative_eq!(CubicBezier::EASE.eval(0.5f64), 0.8024034, epsilon = 1e-7);
+        assert_relative_eq!(CubicBezier::EASE_IN_OUT.eval(0.5f64), 0.5, epsilon = 1e-7);
+        for t in [0.0f32, 1.0] {
+            for bezier in CURVES {
+                assert_eq!(bezier.apply(t), t);
+            }
+        }
+
+        let linear = CubicBezier::new(0.3, 0.3, 0.7, 0.7);
+        for i in 0..=10 {
+            let t = i as f64 / 10.0;
+            assert_relative_eq!(linear.eval(t), t, epsilon = 1e-12);
+        }
+    }
+
+    #[test]
+    fn extrapolation() {
+        // Outside of [0, 1], along the tangents at the endpoints.
+        let back = CubicBezier::new(0.5, -0.5, 0.5, 1.5);
+        assert_relative_eq!(back.eval(-0.5f64), 0.5);
+        assert_relative_eq!(back.eval(1.5f64), 0.5);
+
+        // Degenerate control points fall back to the other one.
+        assert_relative_eq!(CubicBezier::EASE_OUT.eval(-1.0f32), -1.0 / 0.58);
+        assert_relative_eq!(CubicBezier::EASE_IN.eval(2.0f32), 1.0 + 1.0 / 0.58);
+        assert_eq!(CubicBezier::new(0.0, 0.0, 1.0, 1.0).eval(-1.0f32), -1.0);
+
+        // x coordinates are clamped.
+        assert_eq!(
+            CubicBezier::new(-1.0, 0.0, 2.0, 1.0).control_points(),
+            [0.0, 0.0, 1.0, 1.0]
+        );
+    }
+
+    #[cfg(feature = "nightly")]
+    #[test]
+    fn simd() {
+        use core::simd::f32x4;
+
+        for bezier in CURVES {
+            let t = [-0.2f32, 0.1, 0.6, 1.3];
+            let simd = bezier.eval(f32x4::from_array(t)).to_array();
+            for (t, value) in t.into_iter().zip(simd) {
+                assert_eq!(value, bezier.eval(t));
+            }
+        }
+    }
+}
@@ -0,0 +1,977 @@
+// Copyright (C) 2025 Tim Blechmann
+// SPDX-License-Identifier: MIT
+
+//! Type-level composition of easing curves.
+//!
+//! [`Easing`] selects a curve at runtime, which costs a `match` per evaluation unless the compiler
+//! can prove the variant. The types in this module encode the curve, and every combinator applied
+//! to it, in the type instead: evaluating `Scaled<Chained<Mirrored<InQuad>, OutSine>>` is generic
+//! and `#[inline]` all the way down, so it compiles to straight-line code for `f32`, `f64` and
+//! (with the `nightly` feature) SIMD vectors.
+//!
+//! ```
+//! use nova_easing::curve::{Curve, InCubic, InQuad};
+//!
+//! let curve = InQuad.mirrored().chain(InCubic).scaled(0.5);
+//! assert_eq!(curve.eval(0.0f32), 0.0);
+//! assert_eq!(curve.eval(1.0f64), 0.5);
+//! ```
+//!
+//! Curves also combine pointwise: `a + b`, `a * b`, [`min`](Curve::min) and [`max`](Curve::max),
+//! e.g. to modulate an envelope by an LFO or to limit one curve by another.
+//!
+//! ```
+//! use nova_easing::curve::{Constant, Curve, InQuad, OutSine};
+//!
+//! let tremolo = Constant(0.5) + InQuad.quantize_input(8).scaled(0.5);
+//! let modulated = OutSine * tremolo;
+//! assert_eq!(modulated.eval(1.0f32), 1.0);
+//! assert_eq!(OutSine.min(InQuad).eval(0.5f64), 0.25);
+//! ```
+
+use core::ops::{Add, Mul};
+
+use crate::Easing;
+use crate::css::TimingFunction;
+use crate::cubic_bezier::CubicBezier;
+use crate::custom::EasingValue;
+use crate::spring::Spring;
+
+/// A curve which can be evaluated for every [`EasingValue`].
+///
+/// Implemented by the built-in easings of this module, by the combinators and by [`Easing`].
+/// Custom curves implement [`eval`](Self::eval) in terms of [`EasingValue`], like the bodies of
+/// [`define_easing!`](crate::define_easing).
+pub trait Curve {
+    /// Evaluates the curve at `t`.
+    fn eval<T: EasingValue>(&self, t: T) -> T;
+
+    /// Feeds the output of this curve into `next`, i.e. `next(self(t))`.
+    #[inline]
+    fn chain<B: Curve>(self, next: B) -> Chained<Self, B>
+    where
+        Self: Sized,
+    {
+        Chained(self, next)
+    }
+
+    /// Point-mirrors the curve at (0.5, 0.5), i.e. `1 - self(1 - t)`, which turns an ease-in into
+    /// the matching ease-out and vice versa.
+    #[inline]
+    fn mirrored(self) -> Mirrored<Self>
+    where
+        Self: Sized,
+    {
+        Mirrored(self)
+    }
+
+    /// Plays the curve backwards, i.e. `self(1 - t)`, which runs from the end value to the start
+    /// value, e.g. a fade-out with the shape of a fade-in. Unlike [`mirrored`](Self::mirrored),
+    /// the values are not flipped.
+    #[inline]
+    fn reversed(self) -> Reversed<Self>
+    where
+        Self: Sized,
+    {
+        Reversed(self)
+    }
+
+    /// Plays the curve forwards on [0, 0.5] and backwards on [0.5, 1], i.e. `self(2 t)` and then
+    /// `self(2 - 2 t)`, for pulses and breathing animations which return to their start.
+    ///
+    /// The result is symmetric around 0.5 and peaks there at `self(1)`, also for SIMD arguments,
+    /// as both halves evaluate the curve at the same argument `1 - |2 t - 1|`.
+    #[inline]
+    fn yoyo(self) -> Yoyo<Self>
+    where
+        Self: Sized,
+    {
+        Yoyo(self)
+    }
+
+    /// Uses the curve as the ease-in of the first half and `out` as the ease-out of the second
+    /// half, e.g. `InQuad.in_out(OutBounce)`, like the symmetric `InOut` easings do with matching
+    /// curves.
+    ///
+    /// Both halves are compressed to half of the time and half of the distance. The halves are
+    /// summed rather than selected, `self(min(2 t, 1)) / 2 + out(max(2 t - 1, 0)) / 2`, so the
+    /// result is continuous at the join for all continuous curves, and exactly the familiar form
+    /// for curves from 0 to 1.
+    #[inline]
+    fn in_out<B: Curve>(self, out: B) -> InOut<Self, B>
+    where
+        Self: Sized,
+    {
+        InOut(self, out)
+    }
+
+    /// Moves the transition of an in-out curve from 0.5 to `split`, e.g. `0.3` for a short
+    /// acceleration and a long deceleration.
+    ///
+    /// The first half of the curve is compressed to [0, `split`] and covers that fraction of the
+    /// distance, the second half is stretched over the rest. As time and distance are scaled
+    /// alike, the velocity at the transition stays that of the original curve, so smooth in-out
+    /// easings stay smooth. The curve is expected to pass through (0.5, 0.5), like all symmetric
+    /// in-out easings do.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `split` is not within (0, 1).
+    #[inline]
+    fn split_at(self, split: f32) -> SplitAt<Self>
+    where
+        Self: Sized,
+    {
+        assert!(split > 0.0 && split < 1.0, "split must be within (0, 1)");
+        SplitAt(self, split)
+    }
+
+    /// Multiplies the output of the curve by `factor`.
+    #[inline]
+    fn scaled(self, factor: f32) -> Scaled<Self>
+    where
+        Self: Sized,
+    {
+        Scaled(self, factor)
+    }
+
+    /// Holds the input at multiples of `1 / steps`, like an animation running at `steps` frames.
+    ///
+    /// Unlike CSS `steps()`, the held values still follow the shape of the curve.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `steps` is 0.
+    #[inline]
+    fn quantize_input(self, steps: u32) -> QuantizedInput<Self>
+    where
+        Self: Sized,
+    {
+        assert!(steps > 0, "steps must be positive");
+        QuantizedInput(self, steps)
+    }
+
+    /// Rounds the output to the nearest multiple of `1 / steps`, for a fixed number of levels.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `steps` is 0.
+    #[inline]
+    fn quantize_output(self, steps: u32) -> QuantizedOutput<Self>
+    where
+        Self: Sized,
+    {
+        assert!(steps > 0, "steps must be positive");
+        QuantizedOutput(self, steps)
+    }
+
+    /// Clamps the input to [0, 1] before evaluating the curve, so arguments slightly outside of
+    /// the range, e.g. from an overshooting timer, give the values at the endpoints instead of
+    /// whatever the formula produces there.
+    #[inline]
+    fn clamp_input(self) -> ClampedInput<Self>
+    where
+        Self: Sized,
+    {
+        ClampedInput(self)
+    }
+
+    /// Clamps the output to [0, 1], which cuts off the overshoot of `Back` and `Elastic` easings.
+    #[inline]
+    fn clamp_output(self) -> ClampedOutput<Self>
+    where
+        Self: Sized,
+    {
+        ClampedOutput(self)
+    }
+
+    /// The pointwise sum `self(t) + other(t)`, like `self + other` for the curves of this crate.
+    #[inline]
+    fn sum<B: Curve>(self, other: B) -> Sum<Self, B>
+    where
+        Self: Sized,
+    {
+        Sum(self, other)
+    }
+
+    /// The pointwise product `self(t) * other(t)`, like `self * other` for the curves of this
+    /// crate, e.g. an envelope modulated by a tremolo.
+    #[inline]
+    fn product<B: Curve>(self, other: B) -> Product<Self, B>
+    where
+        Self: Sized,
+    {
+        Product(self, other)
+    }
+
+    /// The pointwise minimum of `self(t)` and `other(t)`, e.g. to keep a curve below a limit.
+    #[inline]
+    fn min<B: Curve>(self, other: B) -> Min<Self, B>
+    where
+        Self: Sized,
+    {
+        Min(self, other)
+    }
+
+    /// The pointwise maximum of `self(t)` and `other(t)`.
+    #[inline]
+    fn max<B: Curve>(self, other: B) -> Max<Self, B>
+    where
+        Self: Sized,
+    {
+        Max(self, other)
+    }
+}
+
+impl<C: Curve + ?Sized> Curve for &C {
+    #[inline]
+    fn eval<T: EasingValue>(&self, t: T) -> T {
+        (**self).eval(t)
+    }
+}
+
+impl Curve for Easing {
+    #[inline]
+    fn eval<T: EasingValue>(&self, t: T) -> T {
+        t.eased(*self)
+    }
+}
+
+impl Easing {
+    /// Feeds the output of this easing into `next`, i.e. `next(self(t))`, like [`Curve::chain`]
+    /// but without importing the trait.
+    ///
+    /// ```
+    /// # use nova_easing::{Easing, EasingArgument, curve::Curve};
+    /// // The exponential rise, shaped through a smoothstep.
+    /// let shaped = Easing::InExpo.then(Easing::Smoothstep);
+    /// assert_eq!(shaped.eval(0.7f32), 0.7f32.ease_in_expo().smoothstep());
+    /// ```
+    #[inline]
+    pub fn then<B: Curve>(self, next: B) -> Chained<Easing, B> {
+        Chained(self, next)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Two curves applied after each other, see [`Curve::chain`].
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Chained<A, B>(pub A, pub B);
+
+impl<A: Curve, B: Curve> Curve for Chained<A, B> {
+    #[inline]
+    fn eval<T: EasingValue>(&self, t: T) -> T {
+        self.1.eval(self.0.eval(t))
+    }
+}
+
+/// A point-mirrored curve, see [`Curve::mirrored`].
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Mirrored<A>(pub A);
+
+impl<A: Curve> Curve for Mirrored<A> {
+    #[inline]
+    fn eval<T: EasingValue>(&self, t: T) -> T {
+        let one = T::splat(1.0);
+        one - self.0.eval(one - t)
+    }
+}
+
+/// A curve played backwards, see [`Curve::reversed`].
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Reversed<A>(pub A);
+
+impl<A: Curve> Curve for Reversed<A> {
+    #[inline]
+    fn eval<T: EasingValue>(&self, t: T) -> T {
+        self.0.eval(T::splat(1.0) - t)
+    }
+}
+
+/// A curve played forwards and backwards, see [`Curve::yoyo`].
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Yoyo<A>(pub A);
+
+impl<A: Curve> Curve for Yoyo<A> {
+    #[inline]
+    fn eval<T: EasingValue>(&self, t: T) -> T {
+        let one = T::splat(1.0);
+        let distance = t.double() - one;
+        let abs = distance.select_lt(T::splat(0.0), -distance, distance);
+        self.0.eval(one - abs)
+    }
+}
+
+/// An ease-in followed by an ease-out, see [`Curve::in_out`].
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct InOut<A, B>(pub A, pub B);
+
+impl<A: Curve, B: Curve> Curve for InOut<A, B> {
+    #[inline]
+    fn eval<T: EasingValue>(&self, t: T) -> T {
+        let (zero, one, half) = (T::splat(0.0), T::splat(1.0), T::splat(0.5));
+        let double = t.double();
+        let first = double.select_lt(one, double, one);
+        let second = double - one;
+        let second = second.select_lt(zero, zero, second);
+        (self.0.eval(first) + self.1.eval(second)) * half
+    }
+}
+
+/// An in-out curve with a moved transition, see [`Curve::split_at`].
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SplitAt<A>(pub A, pub f32);
+
+impl<A: Curve> Curve for SplitAt<A> {
+    #[inline]
+    fn eval<T: EasingValue>(&self, t: T) -> T {
+        let (one, half) = (T::splat(1.0), T::splat(0.5));
+        let (split, rest) = (T::splat(self.1), one - T::splat(self.1));
+        // Like `InOut`, both halves are evaluated with clamped arguments and summed: the half
+        // which is not active evaluates the curve at 0.5 and contributes nothing.
+        let first = t / split.double();
+        let first = first.select_lt(half, first, half);
+        let second = (t - split) / rest.double() + half;
+        let second = second.select_lt(half, half, second);
+        split.double() * self.0.eval(first) + rest * (self.0.eval(second).double() - one)
+    }
+}
+
+/// A curve with scaled output, see [`Curve::scaled`].
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Scaled<A>(pub A, pub f32);
+
+impl<A: Curve> Curve for Scaled<A> {
+    #[inline]
+    fn eval<T: EasingValue>(&self, t: T) -> T {
+        self.0.eval(t) * T::splat(self.1)
+    }
+}
+
+/// A curve evaluated at quantized inputs, see [`Curve::quantize_input`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct QuantizedInput<A>(pub A, pub u32);
+
+impl<A: Curve> Curve for QuantizedInput<A> {
+    #[inline]
+    fn eval<T: EasingValue>(&self, t: T) -> T {
+        let steps = T::splat(self.1 as f32);
+        self.0.eval((t * steps).floor() / steps)
+    }
+}
+
+/// A curve with quantized output, see [`Curve::quantize_output`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct QuantizedOutput<A>(pub A, pub u32);
+
+impl<A: Curve> Curve for QuantizedOutput<A> {
+    #[inline]
+    fn eval<T: EasingValue>(&self, t: T) -> T {
+        let steps = T::splat(self.1 as f32);
+        (self.0.eval(t) * steps + T::splat(0.5)).floor() / steps
+    }
+}
+
+/// A curve evaluated at inputs clamped to [0, 1], see [`Curve::clamp_input`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ClampedInput<A>(pub A);
+
+impl<A: Curve> Curve for ClampedInput<A> {
+    #[inline]
+    fn eval<T: EasingValue>(&self, t: T) -> T {
+        self.0.eval(clamp_unit(t))
+    }
+}
+
+/// A curve with output clamped to [0, 1], see [`Curve::clamp_output`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ClampedOutput<A>(pub A);
+
+impl<A: Curve> Curve for ClampedOutput<A> {
+    #[inline]
+    fn eval<T: EasingValue>(&self, t: T) -> T {
+        clamp_unit(self.0.eval(t))
+    }
+}
+
+// Clamps every lane of `x` to [0, 1]. NaN lanes stay NaN.
+#[inline]
+fn clamp_unit<T: EasingValue>(x: T) -> T {
+    let (zero, one) = (T::splat(0.0), T::splat(1.0));
+    x.select_lt(zero, zero, one.select_lt(x, one, x))
+}
+
+/// A curve with the same value everywhere, e.g. the offset of a modulation.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Constant(pub f32);
+
+impl Curve for Constant {
+    #[inline]
+    fn eval<T: EasingValue>(&self, _t: T) -> T {
+        T::splat(self.0)
+    }
+}
+
+/// The pointwise sum of two curves, see [`Curve::sum`].
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Sum<A, B>(pub A, pub B);
+
+impl<A: Curve, B: Curve> Curve for Sum<A, B> {
+    #[inline]
+    fn eval<T: EasingValue>(&self, t: T) -> T {
+        self.0.eval(t) + self.1.eval(t)
+    }
+}
+
+/// The pointwise product of two curves, see [`Curve::product`].
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Product<A, B>(pub A, pub B);
+
+impl<A: Curve, B: Curve> Curve for Product<A, B> {
+    #[inline]
+    fn eval<T: EasingValue>(&self, t: T) -> T {
+        self.0.eval(t) * self.1.eval(t)
+    }
+}
+
+/// The pointwise minimum of two curves, see [`Curve::min`].
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Min<A, B>(pub A, pub B);
+
+impl<A: Curve, B: Curve> Curve for Min<A, B> {
+    #[inline]
+    fn eval<T: EasingValue>(&self, t: T) -> T {
+        let (a, b) = (self.0.eval(t), self.1.eval(t));
+        a.select_lt(b, a, b)
+    }
+}
+
+/// The pointwise maximum of two curves, see [`Curve::max`].
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Max<A, B>(pub A, pub B);
+
+impl<A: Curve, B: Curve> Curve for Max<A, B> {
+    #[inline]
+    fn eval<T: EasingValue>(&self, t: T) -> T {
+        let (a, b) = (self.0.eval(t), self.1.eval(t));
+        a.select_lt(b, b, a)
+    }
+}
+
+/// The pointwise mix of two curves, see [`blend`].
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Blend<A, B>(pub A, pub B, pub f32);
+
+impl<A: Curve, B: Curve> Curve for Blend<A, B> {
+    #[inline]
+    fn eval<T: EasingValue>(&self, t: T) -> T {
+        let a = self.0.eval(t);
+        (self.1.eval(t) - a).mul_add(T::splat(self.2), a)
+    }
+}
+
+/// Mixes `a` and `b` pointwise, `a(t) + (b(t) - a(t)) * weight`: a weight of 0 gives `a`, 1 gives
+/// `b`, and the weights in between interpolate between the two, e.g. to soften an elastic
+/// easing or to tween between presets. Weights outside of [0, 1] extrapolate.
+///
+/// ```
+/// # use nova_easing::{Easing, curve::{self, Curve}};
+/// // Half of the wobble of an elastic easing.
+/// let softened = curve::blend(Easing::OutElastic, Easing::OutCubic, 0.5);
+/// assert_eq!(softened.eval(1.0f32), 1.0);
+/// ```
+#[inline]
+pub fn blend<A: Curve, B: Curve>(a: A, b: B, weight: f32) -> Blend<A, B> {
+    Blend(a, b, weight)
+}
+
+/// [`Curve::mirrored`] for a closure: `1 - f(1 - t)`, e.g. to derive the ease-out version of a
+/// custom ease-in.
+///
+/// ```
+/// # use nova_easing::{EasingArgument, curve};
+/// let ease_out = curve::mirror(|t: f32| t * t * t);
+/// assert_eq!(ease_out(0.5), 0.5f32.ease_out_cubic());
+/// ```
+pub fn mirror<T: EasingValue>(f: impl Fn(T) -> T) -> impl Fn(T) -> T {
+    move |t| {
+        let one = T::splat(1.0);
+        one - f(one - t)
+    }
+}
+
+/// The composition `f ∘ g` of two closures, i.e. `f(g(t))`, like `g` [chained](Curve::chain) into
+/// `f`.
+///
+/// ```
+/// # use nova_easing::{EasingArgument, curve};
+/// let shaped = curve::compose(|t: f32| t.smoothstep(), |t: f32| t.ease_in_expo());
+/// assert_eq!(shaped(0.7), 0.7f32.ease_in_expo().smoothstep());
+/// ```
+pub fn compose<T: EasingValue>(f: impl Fn(T) -> T, g: impl Fn(T) -> T) -> impl Fn(T) -> T {
+    move |t| f(g(t))
+}
+
+/// [`Curve::reversed`] for a closure: `f(1 - t)`, the curve played backwards.
+///
+/// ```
+/// # use nova_easing::curve;
+/// let fade_out = curve::reverse(|t: f64| t * t);
+/// assert_eq!((fade_out(0.0), fade_out(0.75)), (1.0, 0.0625));
+/// ```
+pub fn reverse<T: EasingValue>(f: impl Fn(T) -> T) -> impl Fn(T) -> T {
+    move |t| f(T::splat(1.0) - t)
+}
+
+// `+` and `*` for the curves of this crate. A blanket implementation for every `Curve` is not
+// possible, so curves of other crates use `sum` and `product`.
+macro_rules! curve_operators {
+    ($([$($generic:ident),*] $curve:ty),+ $(,)?) => {
+        $(
+            impl<$($generic,)* Rhs: Curve> Add<Rhs> for $curve {
+                type Output = Sum<Self, Rhs>;
+
+                #[inline]
+                fn add(self, rhs: Rhs) -> Self::Output {
+                    Sum(self, rhs)
+                }
+            }
+
+            impl<$($generic,)* Rhs: Curve> Mul<Rhs> for $curve {
+                type Output = Product<Self, Rhs>;
+
+                #[inline]
+                fn mul(self, rhs: Rhs) -> Self::Output {
+                    Product(self, rhs)
+                }
+            }
+        )+
+    };
+}
+
+curve_operators!(
+    [] Easing,
+    [] CubicBezier,
+    [] Spring,
+    [] TimingFunction,
+    [] Constant,
+    [A, B] Chained<A, B>,
+    [A] Mirrored<A>,
+    [A] Reversed<A>,
+    [A] Yoyo<A>,
+    [A, B] InOut<A, B>,
+    [A] SplitAt<A>,
+    [A] Scaled<A>,
+    [A] QuantizedInput<A>,
+    [A] QuantizedOutput<A>,
+    [A] ClampedInput<A>,
+    [A] ClampedOutput<A>,
+    [A, B] Sum<A, B>,
+    [A, B] Product<A, B>,
+    [A, B] Min<A, B>,
+    [A, B] Max<A, B>,
+    [A, B] Blend<A, B>,
+);
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+// Zero-sized curves for the built-in easings. Their `Easing` is a constant, so the `match` of
+// `Easing::apply` folds away.
+macro_rules! builtin_curves {
+    ($($name:ident),+ $(,)?) => {
+        $(
+            #[doc = concat!("[`Easing::", stringify!($name), "`] as a zero-sized curve.")]
+            #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+            #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+            pub struct $name;
+
+            impl Curve for $name {
+                #[inline]
+                fn eval<T: EasingValue>(&self, t: T) -> T {
+                    t.eased(Easing::$name)
+                }
+            }
+
+            curve_operators!([] $name);
+        )+
+    };
+}
+
+builtin_curves!(
+    Linear,
+    InQuad,
+    OutQuad,
+    InOutQuad,
+    InCubic,
+    OutCubic,
+    InOutCubic,
+    InQuart,
+    OutQuart,
+    InOutQuart,
+    InQuint,
+    OutQuint,
+    InOutQuint,
+    InSine,
+    OutSine,
+    InOutSine,
+    InCirc,
+    OutCirc,
+    InOutCirc,
+    InBack,
+    OutBack,
+    InOutBack,
+    InBounce,
+    OutBounce,
+    InOutBounce,
+    InExpo,
+    OutExpo,
+    InOutExpo,
+    InElastic,
+    OutElastic,
+    InOutElastic,
+);
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    #[cfg(feature = "serde")]
+    use super::QuantizedOutput;
+    use super::{
+        Chained, Constant, Curve, InCubic, InOutSine, InQuad, InQuart, Linear, OutBack, OutCubic,
+        OutQuad, blend,
+    };
+    use crate::easing::tests::EASINGS;
+    use crate::{Easing, EasingArgument};
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn builtins_match_easing() {
+        for t in [0.0f32, 0.2, 0.5, 0.7, 1.0] {
+            assert_eq!(Linear.eval(t), t);
+            assert_eq!(InQuad.eval(t), Easing::InQuad.apply(t));
+            assert_eq!(InOutSine.eval(t as f64), (t as f64).ease_in_out_sine());
+            assert_eq!(Easing::InCurve(2.0).eval(t), t.ease_in_curve(2.0));
+        }
+    }
+
+    #[test]
+    fn combinators() {
+        for t in [0.0f64, 0.2, 0.5, 0.7, 1.0] {
+            assert_relative_eq!(InQuad.mirrored().eval(t), OutQuad.eval(t), epsilon = 1e-12);
+            assert_eq!(
+                InQuad.chain(InCubic).eval(t),
+                t.ease_in_quad().ease_in_cubic()
+            );
+            assert_eq!(OutQuad.scaled(2.0).eval(t), 2.0 * t.ease_out_quad());
+            assert_eq!(Chained(&InQuad, Easing::Linear).eval(t), t.ease_in_quad());
+            assert_eq!(
+                Easing::OutExpo.then(InOutSine).eval(t),
+                t.ease_out_expo().ease_in_out_sine()
+            );
+            let composed = super::compose(|t: f64| t.smoothstep(), |t: f64| t.ease_in_cubic());
+            assert_eq!(
+                composed(t),
+                Easing::InCubic.then(Easing::Smoothstep).eval(t)
+            );
+        }
+    }
+
+    #[test]
+    fn directions() {
+        for t in [0.0f64, 0.125, 0.5, 0.75, 1.0] {
+            assert_eq!(InQuad.reversed().eval(t), (1.0 - t).ease_in_quad());
+            assert_eq!(InQuad.reversed().reversed().eval(t), InQuad.eval(t));
+            // Reversing and flipping the values gives the mirrored curve.
+            assert_relative_eq!(
+                1.0 - InCubic.reversed().eval(t),
+                InCubic.mirrored().eval(t),
+                epsilon = 1e-15
+            );
+
+            let smooth_in = |t: f64| t * t * t * t;
+            assert_eq!(super::mirror(smooth_in)(t), InQuart.mirrored().eval(t));
+            assert_eq!(super::reverse(smooth_in)(t), InQuart.reversed().eval(t));
+        }
+    }
+
+    #[test]
+    fn yoyo() {
+        let pulse = Easing::OutCubic.yoyo();
+        assert_eq!(pulse.eval(0.0f32), 0.0);
+        assert_eq!(pulse.eval(0.5f32), 1.0);
+        assert_eq!(pulse.eval(1.0f32), 0.0);
+        for i in 0..=64 {
+            let t = i as f64 / 128.0;
+            assert_eq!(pulse.eval(t), pulse.eval(1.0 - t));
+            assert_eq!(pulse.eval(t), (2.0 * t).ease_out_cubic());
+        }
+        // Overshoots are kept, at the same distance from the peak on both sides.
+        let back = OutBack.yoyo();
+        assert!(back.eval(0.4f32) > 1.0);
+        assert_eq!(back.eval(0.375f32), back.eval(0.625f32));
+    }
+
+    #[test]
+    fn asymmetric_in_out() {
+        for i in 0..=64 {
+            let t = i as f64 / 64.0;
+            assert_relative_eq!(InQuad.in_out(OutQuad).eval(t), t.ease_in_out_quad());
+            assert_relative_eq!(
+                Easing::InCubic.in_out(Easing::OutCubic).eval(t),
+                t.ease_in_out_cubic(),
+                epsilon = 1e-12
+            );
+        }
+
+        let bounce = InQuad.in_out(Easing::OutBounce);
+        assert_eq!(bounce.eval(0.25f32), 0.125);
+        assert_eq!(bounce.eval(0.5f32), 0.5);
+        assert_eq!(bounce.eval(0.75f32), 0.5 + 0.5 * 0.5f32.ease_out_bounce());
+        assert_eq!(bounce.eval(1.0f32), 1.0);
+
+        // Joined at 0.5 for every combination of the easings, and continuous there unless the
+        // easings themselves jump at their ends, like `InExpo` at 0. `Circ` is steep there.
+        let jumps =
+            |easing: Easing, t: f64, u: f64| (easing.apply(t) - easing.apply(u)).abs() > 1e-4;
+        for a in EASINGS {
+            for b in EASINGS {
+                let curve = a.in_out(b);
+                let join = curve.eval(0.5f64);
+                assert_relative_eq!(join, 0.5, epsilon = 1e-6);
+                if !jumps(a, 1.0 - 2e-9, 1.0) {
+                    assert_relative_eq!(curve.eval(0.5 - 1e-9), join, epsilon = 1e-4);
+                }
+                if !jumps(b, 2e-9, 0.0) {
+                    assert_relative_eq!(curve.eval(0.5 + 1e-9), join, epsilon = 1e-4);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn split_point() {
+        let quad = Easing::InOutQuad.split_at(0.25);
+        assert_eq!(quad.eval(0.0f64), 0.0);
+        assert_eq!(quad.eval(0.25f64), 0.25);
+        assert_eq!(quad.eval(1.0f64), 1.0);
+        // The acceleration covers a quarter of the distance in a quarter of the time.
+        assert_eq!(quad.eval(0.125f64), 0.25 * 0.5f64.ease_in_quad());
+        assert_relative_eq!(quad.eval(0.625f64), 0.25 + 0.75 * 0.5f64.ease_out_quad());
+
+        let slope = |curve: &dyn Fn(f64) -> f64, t: f64| (curve(t + 1e-7) - curve(t - 1e-7)) / 2e-7;
+        for easing in EASINGS {
+            if easing.apply(0.5f64) != 0.5 {
+                continue;
+            }
+            // At 0.5, the curve is unchanged.
+            for i in 0..=32 {
+                let t = i as f64 / 32.0;
+                assert_relative_eq!(
+                    easing.split_at(0.5).eval(t),
+                    easing.apply(t),
+                    epsilon = 1e-12
+                );
+            }
+            // Elsewhere, the transition keeps its value and velocity, except for the vertical
+            // tangent of `InOutCirc`.
+            let vertical = easing == Easing::InOutCirc;
+            for split in [0.2, 0.7] {
+                let curve = easing.split_at(split);
+                assert_relative_eq!(curve.eval(split as f64), split as f64, epsilon = 1e-6);
+                if vertical {
+                    continue;
+                }
+                assert_relative_eq!(
+                    slope(&|t| curve.eval(t), split as f64),
+                    slope(&|t| easing.apply(t), 0.5),
+                    epsilon = 1e-4,
+                    max_relative = 1e-3
+                );
+            }
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "split must be within (0, 1)")]
+    fn split_point_out_of_range() {
+        let _ = InOutSine.split_at(1.0);
+    }
+
+    #[test]
+    #[should_panic(expected = "steps must be positive")]
+    fn quantize_input_without_steps() {
+        let _ = Linear.quantize_input(0);
+    }
+
+    #[test]
+    #[should_panic(expected = "steps must be positive")]
+    fn quantize_output_without_steps() {
+        let _ = Linear.quantize_output(0);
+    }
+
+    #[test]
+    fn quantization() {
+        let frames = InQuad.quantize_input(4);
+        assert_eq!(frames.eval(0.0f32), 0.0);
+        assert_eq!(frames.eval(0.3f32), 0.0625);
+        assert_eq!(frames.eval(0.49f32), 0.0625);
+        assert_eq!(frames.eval(0.5f32), 0.25);
+        assert_eq!(frames.eval(1.0f32), 1.0);
+
+        let levels = OutQuad.quantize_output(10);
+        assert_eq!(levels.eval(0.0f64), 0.0);
+        assert_eq!(levels.eval(0.5f64), 0.8); // 0.75 rounds up
+        assert_eq!(levels.eval(0.1f64), 0.2); // 0.19
+        assert_eq!(levels.eval(1.0f64), 1.0);
+
+        // Overshoots are quantized on the same grid.
+        let back = Easing::OutBack.quantize_output(10);
+        assert_eq!(back.eval(0.6f64), 1.1);
+    }
+
+    #[test]
+    fn clamping() {
+        // `InExpo` jumps back to its formula outside of [0, 1].
+        let expo = Easing::InExpo.clamp_input();
+        assert_eq!(expo.eval(1.2f32), 1.0);
+        assert_eq!(expo.eval(-0.5f32), 0.0);
+        assert_eq!(expo.eval(0.5f32), 0.5f32.ease_in_expo());
+        assert_eq!(Easing::InCirc.clamp_input().eval(1.1f64), 1.0);
+
+        let back = OutBack.clamp_output();
+        assert_eq!(back.eval(0.6f64), 1.0);
+        assert_eq!(back.eval(0.2f64), 0.2f64.ease_out_back());
+        assert_eq!(Easing::InBack.clamp_output().eval(0.3f32), 0.0);
+
+        let both = Easing::OutElastic.clamp_input().clamp_output();
+        for t in [-1.0f32, 0.0, 0.1, 0.3, 0.7, 1.0, 1.5] {
+            let value = both.eval(t);
+            assert!((0.0..=1.0).contains(&value), "{t}: {value}");
+        }
+        assert_eq!(both.eval(2.0f32), 1.0);
+    }
+
+    #[test]
+    fn arithmetic() {
+        let tremolo = Constant(0.75) + Easing::InOutSine.scaled(0.25);
+        let envelope = OutQuad * tremolo;
+        let limited = InQuad.max(Constant(0.1)).min(OutQuad.scaled(0.5));
+        for t in [0.0f64, 0.2, 0.5, 0.7, 1.0] {
+            let modulation = 0.75 + 0.25 * t.ease_in_out_sine();
+            assert_eq!(envelope.eval(t), t.ease_out_quad() * modulation);
+            assert_eq!(
+                InQuad.sum(OutQuad).eval(t),
+                InQuad.eval(t) + OutQuad.eval(t)
+            );
+            assert_eq!(
+                Easing::InCubic.product(InQuad).eval(t),
+                (InCubic * InQuad).eval(t)
+            );
+
+            let expected = t
+                .ease_in_quad()
+                .max(0.1f32 as f64)
+                .min(0.5 * t.ease_out_quad());
+            assert_eq!(limited.eval(t), expected);
+        }
+        assert_eq!(Constant(2.5).eval(0.3f32), 2.5);
+    }
+
+    #[test]
+    fn blending() {
+        let softened = blend(Easing::OutElastic, OutCubic, 0.75);
+        for t in [0.0f64, 0.1, 0.3, 0.5, 0.8, 1.0] {
+            let (elastic, cubic) = (t.ease_out_elastic(), t.ease_out_cubic());
+            assert_relative_eq!(
+                softened.eval(t),
+                0.25 * elastic + 0.75 * cubic,
+                epsilon = 1e-12
+            );
+            assert_eq!(blend(InQuad, OutQuad, 0.0).eval(t), t.ease_in_quad());
+            assert_eq!(blend(InQuad, OutQuad, 1.0).eval(t), t.ease_out_quad());
+        }
+        // The overshoot shrinks with the weight of the elastic easing.
+        let peak = |weight| {
+            (0..=100)
+                .map(|i| blend(OutCubic, Easing::OutElastic, weight).eval(i as f64 / 100.0))
+                .fold(0.0, f64::max)
+        };
+        assert!(peak(1.0) > peak(0.5) && peak(0.5) > peak(0.25) && peak(0.25) > 1.0);
+        assert_eq!(peak(0.0), 1.0);
+    }
+
+    #[test]
+    fn glob_import() {
+        // Only `Curve::eval` is in scope, so the methods are not ambiguous.
+        use crate::curve::*;
+        assert_eq!(InQuad.eval(0.5f32), 0.25);
+        assert_eq!(Easing::OutQuad.eval(0.5f64), 0.75);
+    }
+
+    #[cfg(feature = "nightly")]
+    #[test]
+    fn simd() {
+        use core::simd::f32x4;
+
+        let t = [0.1f32, 0.4, 0.6, 0.9];
+        let arithmetic = (InQuad * OutQuad + Constant(0.1)).max(InOutSine);
+        let simd = arithmetic.eval(f32x4::from_array(t)).to_array();
+        assert_eq!(simd, t.map(|t| arithmetic.eval(t)));
+
+        let blended = super::blend(InQuad, OutBack, 0.3);
+        let simd = blended.eval(f32x4::from_array(t)).to_array();
+        assert_eq!(simd, t.map(|t| blended.eval(t)));
+
+        let stitched = InQuad.in_out(OutBack);
+        let simd = stitched.eval(f32x4::from_array(t)).to_array();
+        assert_eq!(simd, t.map(|t| stitched.eval(t)));
+
+        let split = Easing::InOutCubic.split_at(0.3);
+        let simd = split.eval(f32x4::from_array(t)).to_array();
+        assert_eq!(simd, t.map(|t| split.eval(t)));
+
+        let yoyo = OutQuad.yoyo().eval(f32x4::from_array(t)).to_array();
+        assert_eq!(yoyo, t.map(|t| OutQuad.yoyo().eval(t)));
+
+        let curve = InQuad.mirrored().chain(InOutSine).scaled(0.5);
+        let t = [0.1f32, 0.4, 0.6, 0.9];
+        let simd = curve.eval(f32x4::from_array(t)).to_array();
+        for (t, value) in t.into_iter().zip(simd) {
+            assert_relative_eq!(value, curve.eval(t), epsilon = 1e-6);
+        }
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde_round_trip() {
+        let curve = InQuad
+            .mirrored()
+            .chain(Easing::OutPow(2.5))
+            .quantize_output(8);
+        let json = serde_json::to_string(&curve).unwrap();
+        assert_eq!(json, r#"[[null,{"OutPow":2.5}],8]"#);
+        assert_eq!(
+            serde_json::from_str::<QuantizedOutput<_>>(&json).unwrap(),
+            curve
+        );
+    }
+}
@@ -0,0 +1,368 @@
+// Copyright (C) 2025 Tim Blechmann
+// SPDX-License-Identifier: MIT
+
+//! Custom easings with the same scalar and SIMD support as the built-in ones.
+//!
+//! See [`define_easing!`](crate::define_easing). The numeric operations of [`EasingValue`] can also
+//! be used directly, to write generic curves or DSP helpers which compile to both backends:
+//!
+//! ```
+//! use nova_easing::custom::EasingValue;
+//!
+//! // Soft clipping: a cubic up to 1, constant above.
+//! fn soft_clip<T: EasingValue>(x: T) -> T {
+//!     let cubic = x.polynomial(&[-0.5, 0.0, 1.5, 0.0]);
+//!     x.select_lt(T::splat(1.0), cubic, T::splat(1.0))
+//! }
+//!
+//! assert_eq!(soft_clip(0.5f32), 0.6875);
+//! assert_eq!(soft_clip(2.0f64), 1.0);
+//! ```
+
+use core::ops::*;
+
+use crate::internal::CurveParam;
+use crate::{Easing, EasingImplHelper, internal};
+
+/// The numeric operations available to custom easings defined with
+/// [`define_easing!`](crate::define_easing), and to generic code in general.
+///
+/// Implemented for `f32`, `f64`, [`Lanes`](crate::Lanes) and, with the `nightly` or `wide`
+/// feature, SIMD vectors of them. Code written against this trait compiles to both the scalar and
+/// the SIMD backends.
+pub trait EasingValue:
+    internal::Sealed
+    + Sub<Self, Output = Self>
+    + Add<Self, Output = Self>
+    + Mul<Self, Output = Self>
+    + Div<Self, Output = Self>
+    + Neg<Output = Self>
+    + Sized
+    + Copy
+{
+    /// Broadcasts a constant.
+    fn splat(value: f32) -> Self;
+    /// Sine, in radians.
+    fn sin(self) -> Self;
+    /// Cosine, in radians.
+    fn cos(self) -> Self;
+    /// Square root.
+    fn sqrt(self) -> Self;
+    /// Largest integer less than or equal to `self`.
+    fn floor(self) -> Self;
+    /// Exponential function, `e^self`.
+    fn exp(self) -> Self;
+    /// Raises to an integer power.
+    fn powi(self, n: i32) -> Self;
+    /// Raises to a floating point power.
+    fn powf(self, n: Self) -> Self;
+    /// Fused multiply-add, `self * a + b`.
+    fn mul_add(self, a: Self, b: Self) -> Self;
+    /// `self + self`, which is exact and cheaper than a multiplication by 2.
+    fn double(self) -> Self;
+    /// Evaluates the polynomial with the given `coefficients` at `self`, highest degree first.
+    ///
+    /// Uses Horner's scheme with one [`mul_add`](Self::mul_add) per coefficient, so `[a, b, c]`
+    /// computes `(a * self + b) * self + c`. No coefficients evaluate to 0.
+    fn polynomial(self, coefficients: &[f32]) -> Self;
+    /// Branch replacement: `if_less` where `self < threshold`, otherwise `otherwise`.
+    ///
+    /// Both sides are evaluated, which is what a SIMD backend has to do anyway.
+    fn select_lt(self, threshold: Self, if_less: Self, otherwise: Self) -> Self;
+    /// Applies one of the built-in easings.
+    fn eased(self, easing: Easing) -> Self;
+}
+
+impl<T> EasingValue for T
+where
+    T: EasingImplHelper + CurveParam<T> + Neg<Output = T>,
+{
+    fn splat(value: f32) -> Self {
+        T::from_f32(value)
+    }
+    fn sin(self) -> Self {
+        EasingImplHelper::sin(self)
+    }
+    fn cos(self) -> Self {
+        EasingImplHelper::cos(self)
+    }
+    fn sqrt(self) -> Self {
+        EasingImplHelper::sqrt(self)
+    }
+    fn floor(self) -> Self {
+        EasingImplHelper::floor(self)
+    }
+    fn exp(self) -> Self {
+        EasingImplHelper::exp(self)
+    }
+    fn powi(self, n: i32) -> Self {
+        EasingImplHelper::powi(self, n)
+    }
+    fn powf(self, n: Self) -> Self {
+        EasingImplHelper::powf(self, n)
+    }
+    fn mul_add(self, a: Self, b: Self) -> Self {
+        EasingImplHelper::mul_add(self, a, b)
+    }
+    fn double(self) -> Self {
+        EasingImplHelper::double(self)
+    }
+    #[inline]
+    fn polynomial(self, coefficients: &[f32]) -> Self {
+        coefficients
+            .iter()
+            .fold(T::from_f32(0.0), |sum, &coefficient| {
+                EasingImplHelper::mul_add(sum, self, T::from_f32(coefficient))
+            })
+    }
+    fn select_lt(self, threshold: Self, if_less: Self, otherwise: Self) -> Self {
+        EasingImplHelper::select_lt(self, threshold, if_less, otherwise)
+    }
+    #[inline]
+    fn eased(self, easing: Easing) -> Self {
+        easing.apply(self)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Defines custom easings with both a scalar and a SIMD implementation.
+///
+/// Each easing is written once as an expression of its argument, using the operators and the
+/// methods of [`EasingValue`](crate::custom::EasingValue). Constants are written as
+/// `Self::splat(..)` and branches via [`select_lt`](crate::custom::EasingValue::select_lt), so the
+/// same expression compiles for `f32`, `f64` and, with the `nightly` feature, SIMD vectors.
+///
+/// The macro generates an extension trait implemented for all these types, plus a test per easing
+/// which compares the `f32` backend to `f64` and, with `nightly`, the SIMD backends to the scalar
+/// ones.
+///
+/// ```
+/// use nova_easing::define_easing;
+///
+/// define_easing! {
+///     /// Custom easings.
+///     pub trait CustomEasing {
+///         /// Hermite smoothstep.
+///         fn smoothstep(t) = t * t * (Self::splat(3.0) - Self::splat(2.0) * t);
+///
+///         /// Piecewise: quadratic in, then linear.
+///         fn quad_then_linear(t) = t.select_lt(
+///             Self::splat(0.5),
+///             t * t * Self::splat(2.0),
+///             t * Self::splat(1.5) - Self::splat(0.5),
+///         );
+///     }
+/// }
+///
+/// assert_eq!(0.5f32.smoothstep(), 0.5);
+/// assert_eq!(1.0f64.quad_then_linear(), 1.0);
+/// ```
+#[macro_export]
+macro_rules! define_easing {
+    (
+        $(#[$attr:meta])*
+        $vis:vis trait $trait:ident {
+            $(
+                $(#[$fn_attr:meta])*
+                fn $name:ident($t:ident) = $body:expr;
+            )+
+        }
+    ) => {
+        $(#[$attr])*
+        $vis trait $trait: $crate::custom::EasingValue {
+            $(
+                $(#[$fn_attr])*
+                fn $name(self) -> Self;
+            )+
+        }
+
+        impl<T: $crate::custom::EasingValue> $trait for T {
+            $(
+                #[inline]
+                fn $name(self) -> Self {
+                    let $t = self;
+                    $body
+                }
+            )+
+        }
+
+        $(
+            #[cfg(test)]
+            #[allow(non_snake_case)]
+            mod $name {
+                #[test]
+                fn backends_agree() {
+                    use super::$trait;
+
+                    $crate::custom::__private::compare_scalar(
+                        <f32 as $trait>::$name,
+                        <f64 as $trait>::$name,
+                    );
+                    $crate::__private_if_nightly! {
+                        $crate::custom::__private::compare_simd(
+                            <f32 as $trait>::$name,
+                            <$crate::custom::__private::F32x4 as $trait>::$name,
+                            <$crate::custom::__private::F32x8 as $trait>::$name,
+                        );
+                        $crate::custom::__private::compare_simd(
+                            <f64 as $trait>::$name,
+                            <$crate::custom::__private::F64x2 as $trait>::$name,
+                            <$crate::custom::__private::F64x4 as $trait>::$name,
+                        );
+                    }
+                }
+            }
+        )+
+    };
+}
+
+#[cfg(feature = "nightly")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __private_if_nightly {
+    ($($tt:tt)*) => {
+        $($tt)*
+    };
+}
+
+#[cfg(not(feature = "nightly"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __private_if_nightly {
+    ($($tt:tt)*) => {};
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Support code for the tests generated by [`define_easing!`].
+#[doc(hidden)]
+pub mod __private {
+    #[cfg(feature = "nightly")]
+    use core::simd::{LaneCount, Simd, SimdElement, SupportedLaneCount};
+
+    #[cfg(feature = "nightly")]
+    pub type F32x4 = Simd<f32, 4>;
+    #[cfg(feature = "nightly")]
+    pub type F32x8 = Simd<f32, 8>;
+    #[cfg(feature = "nightly")]
+    pub type F64x2 = Simd<f64, 2>;
+    #[cfg(feature = "nightly")]
+    pub type F64x4 = Simd<f64, 4>;
+
+    const SAMPLES: usize = 64;
+
+    fn sample_point(i: usize) -> f64 {
+        i as f64 / (SAMPLES - 1) as f64
+    }
+
+    #[track_caller]
+    fn assert_close(actual: f64, expected: f64, epsilon: f64, t: f64) {
+        let tolerance = epsilon * expected.abs().max(1.0);
+        assert!(
+            (actual - expected).abs() <= tolerance,
+            "backends disagree at t = {t}: {actual} != {expected}"
+        );
+    }
+
+    /// Compares the `f32` implementation against the `f64` one.
+    #[track_caller]
+    pub fn compare_scalar(f32: fn(f32) -> f32, f64: fn(f64) -> f64) {
+        for i in 0..SAMPLES {
+            let t = sample_point(i);
+            assert_close(f32(t as f32) as f64, f64(t), 1e-5, t);
+        }
+    }
+
+    /// Compares the SIMD implementations against the scalar one, lane by lane.
+    #[cfg(feature = "nightly")]
+    #[track_caller]
+    pub fn compare_simd<T, const A: usize, const B: usize>(
+        scalar: fn(T) -> T,
+        narrow: fn(Simd<T, A>) -> Simd<T, A>,
+        wide: fn(Simd<T, B>) -> Simd<T, B>,
+    ) where
+        T: SimdElement + num_traits::Float + num_traits::FromPrimitive + Into<f64>,
+        LaneCount<A>: SupportedLaneCount,
+        LaneCount<B>: SupportedLaneCount,
+    {
+        compare_lanes(scalar, narrow);
+        compare_lanes(scalar, wide);
+    }
+
+    #[cfg(feature = "nightly")]
+    #[track_caller]
+    fn compare_lanes<T, const N: usize>(scalar: fn(T) -> T, simd: fn(Simd<T, N>) -> Simd<T, N>)
+    where
+        T: SimdElement + num_traits::Float + num_traits::FromPrimitive + Into<f64>,
+        LaneCount<N>: SupportedLaneCount,
+    {
+        for chunk in 0..SAMPLES / N {
+            let input: [T; N] =
+                core::array::from_fn(|lane| T::from_f64(sample_point(chunk * N + lane)).unwrap());
+            let output = simd(Simd::from_array(input)).to_array();
+            for (t, value) in input.into_iter().zip(output) {
+                assert_close(value.into(), scalar(t).into(), 1e-5, t.into());
+            }
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use approx::assert_relative_eq;
+
+    crate::define_easing! {
+        trait TestEasing {
+            fn smoothstep(t) = t * t * (Self::splat(3.0) - Self::splat(2.0) * t);
+            fn smooth_in_quad_out(t) = t.select_lt(
+                Self::splat(0.5),
+                t * t * Self::splat(2.0),
+                -Self::splat(2.0) * (t - Self::splat(1.0)).powi(2) + Self::splat(1.0),
+            );
+            fn sine_wobble(t) =
+                t + (t * Self::splat(core::f32::consts::TAU)).sin() * Self::splat(0.1);
+        }
+    }
+
+    #[test]
+    fn custom_easings() {
+        assert_eq!(0.0f32.smoothstep(), 0.0);
+        assert_eq!(1.0f32.smoothstep(), 1.0);
+        assert_relative_eq!(0.25f64.smoothstep(), 0.15625);
+
+        assert_relative_eq!(0.25f32.smooth_in_quad_out(), 0.125);
+        assert_relative_eq!(0.75f32.smooth_in_quad_out(), 0.875);
+
+        assert_relative_eq!(0.25f64.sine_wobble(), 0.35, epsilon = 1e-6);
+    }
+
+    #[test]
+    fn numeric_helpers() {
+        use super::EasingValue;
+
+        assert_eq!(1.5f32.double(), 3.0);
+        assert_eq!(2.0f64.polynomial(&[]), 0.0);
+        assert_eq!(2.0f64.polynomial(&[7.0]), 7.0);
+        assert_eq!(2.0f64.polynomial(&[1.0, -3.0, 0.5]), -1.5);
+        for t in [0.0f32, 0.25, 0.6, 1.0] {
+            assert_relative_eq!(t.polynomial(&[-2.0, 3.0, 0.0, 0.0]), t.smoothstep());
+        }
+    }
+
+    #[cfg(feature = "nightly")]
+    #[test]
+    fn custom_easings_simd() {
+        use std::simd::f32x4;
+
+        let t = f32x4::from_array([0.0, 0.25, 0.75, 1.0]);
+        let eased = t.smooth_in_quad_out().to_array();
+        assert_eq!(eased, [0.0, 0.125, 0.875, 1.0]);
+
+        use super::EasingValue;
+        let polynomial = t.polynomial(&[-2.0, 3.0, 0.0, 0.0]).to_array();
+        assert_eq!(polynomial, t.smoothstep().to_array());
+    }
+}
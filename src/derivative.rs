@@ -0,0 +1,451 @@
+// Copyright (C) 2025 Tim Blechmann
+// SPDX-License-Identifier: MIT
+
+//! Combined evaluation of easings and their derivatives.
+
+use crate::internal::CurveParam;
+use crate::{Easing, EasingArgument, EasingImplHelper};
+
+impl Easing {
+    /// Returns the eased value and the derivative `d/dt` at `t`, from one evaluation.
+    ///
+    /// Both share their subexpressions: the trigonometric and exponential terms are computed once,
+    /// which matters most for SIMD arguments. The value agrees with [`apply`](Self::apply) up to
+    /// rounding. At the kinks of `Bounce` and the jumps of `Expo` and `Elastic` at the endpoints,
+    /// the derivative is the one of the formula, i.e. the one-sided limit from within (0, 1).
+    ///
+    /// ```
+    /// # use nova_easing::Easing;
+    /// let (value, velocity) = Easing::InQuad.apply_derivative_pair(0.5f32);
+    /// assert_eq!((value, velocity), (0.25, 1.0));
+    /// ```
+    #[allow(private_bounds)]
+    #[inline]
+    pub fn apply_derivative_pair<T>(self, t: T) -> (T, T)
+    where
+        T: EasingImplHelper + CurveParam<T>,
+    {
+        pair(self, t)
+    }
+
+    /// Returns the derivative `d/dt` at `t`, i.e. the velocity of an animation driven by the
+    /// easing, in distances per unit of `t`.
+    ///
+    /// See [`apply_derivative_pair`](Self::apply_derivative_pair) for the endpoints.
+    #[allow(private_bounds)]
+    #[inline]
+    pub fn derivative<T>(self, t: T) -> T
+    where
+        T: EasingImplHelper + CurveParam<T>,
+    {
+        pair(self, t).1
+    }
+}
+
+// The match behind `apply_derivative_pair`, which the `*_derivative` methods of `EasingArgument`
+// call with a constant easing.
+#[inline]
+pub(crate) fn pair<T: EasingImplHelper>(easing: Easing, t: T) -> (T, T) {
+    match easing {
+        Easing::Linear => (t, T::from_f32(1.0)),
+        Easing::InQuad => in_powi(t, 2),
+        Easing::OutQuad => out_powi(t, 2),
+        Easing::InOutQuad => in_out_powi(t, 2),
+        Easing::InCubic => in_powi(t, 3),
+        Easing::OutCubic => out_powi(t, 3),
+        Easing::InOutCubic => in_out_powi(t, 3),
+        Easing::InQuart => in_powi(t, 4),
+        Easing::OutQuart => out_powi(t, 4),
+        Easing::InOutQuart => in_out_powi(t, 4),
+        Easing::InQuint => in_powi(t, 5),
+        Easing::OutQuint => out_powi(t, 5),
+        Easing::InOutQuint => in_out_powi(t, 5),
+        Easing::InSine => in_sine(t),
+        Easing::OutSine => out_sine(t),
+        Easing::InOutSine => in_out_sine(t),
+        Easing::InCirc => in_circ(t),
+        Easing::OutCirc => out_circ(t),
+        Easing::InOutCirc => in_out_circ(t),
+        Easing::InBack => in_back(t),
+        Easing::OutBack => out_back(t),
+        Easing::InOutBack => in_out_back(t),
+        Easing::InBounce => mirror(t, out_bounce),
+        Easing::OutBounce => out_bounce(t),
+        Easing::InOutBounce => in_out_bounce(t),
+        Easing::InExpo => in_expo(t),
+        Easing::OutExpo => out_expo(t),
+        Easing::InOutExpo => in_out_expo(t),
+        Easing::InElastic => in_elastic(t),
+        Easing::OutElastic => out_elastic(t),
+        Easing::InOutElastic => in_out_elastic(t),
+        Easing::InCurve(curve) => in_curve(t, curve),
+        Easing::OutCurve(curve) => mirror(t, |u| in_curve(u, curve)),
+        Easing::InOutCurve(curve) => in_out(t, |u| in_curve(u, curve)),
+        Easing::InPow(exponent) => in_pow(t, exponent),
+        Easing::OutPow(exponent) => mirror(t, |u| in_pow(u, exponent)),
+        Easing::InOutPow(exponent) => in_out(t, |u| in_pow(u, exponent)),
+        Easing::InExpoScaled(scale) => in_curve(t, scale * core::f32::consts::LN_2),
+        Easing::OutExpoScaled(scale) => mirror(t, |u| in_curve(u, scale * core::f32::consts::LN_2)),
+        Easing::InOutExpoScaled(scale) => {
+            in_out(t, |u| in_curve(u, scale * core::f32::consts::LN_2))
+        }
+        Easing::Smoothstep => (t.smoothstep(), smoothstep_slope(t, 1, 6.0)),
+        Easing::Smootherstep => (t.smootherstep(), smoothstep_slope(t, 2, 30.0)),
+        Easing::Smootheststep => (t.smootheststep(), smoothstep_slope(t, 3, 140.0)),
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+// `1 - f(1 - t)`: the ease-out of the ease-in `f`, with the same slope at mirrored points.
+fn mirror<T, F>(t: T, ease_in: F) -> (T, T)
+where
+    T: EasingImplHelper,
+    F: FnOnce(T) -> (T, T),
+{
+    let one = T::from_f32(1.0);
+    let (value, derivative) = ease_in(one - t);
+    (one - value, derivative)
+}
+
+// The ease-in `f` compressed into the first half and mirrored into the second one. Both halves
+// evaluate `f` at the doubled distance to the nearer end, so the slope is `f'` in both.
+fn in_out<T, F>(t: T, ease_in: F) -> (T, T)
+where
+    T: EasingImplHelper,
+    F: FnOnce(T) -> (T, T),
+{
+    let half = T::from_f32(0.5);
+    let one = T::from_f32(1.0);
+    let distance = t.select_lt(half, t, one - t).double();
+    let (value, derivative) = ease_in(distance);
+    let sign = t.select_lt(half, half, T::from_f32(-0.5));
+    let base = t.select_lt(half, T::from_f32(0.0), one);
+    (sign.mul_add(value, base), derivative)
+}
+
+// `if_zero` where `t == 0`, via `select_lt` only, which is all SIMD arguments provide.
+fn select_zero<T: EasingImplHelper>(t: T, if_zero: T, otherwise: T) -> T {
+    let zero = T::from_f32(0.0);
+    zero.select_lt(t, otherwise, t.select_lt(zero, otherwise, if_zero))
+}
+
+fn in_powi<T: EasingImplHelper>(t: T, n: i32) -> (T, T) {
+    let lower = t.powi(n - 1);
+    (lower * t, T::from_f32(n as f32) * lower)
+}
+
+fn out_powi<T: EasingImplHelper>(t: T, n: i32) -> (T, T) {
+    mirror(t, |u| in_powi(u, n))
+}
+
+// 2^(n-1) t^n is (2t)^n / 2, whose slope n (2t)^(n-1) is again what `in_out` expects.
+fn in_out_powi<T: EasingImplHelper>(t: T, n: i32) -> (T, T) {
+    in_out(t, |u| in_powi(u, n))
+}
+
+fn in_pow<T: EasingImplHelper>(t: T, exponent: f32) -> (T, T) {
+    // `t^e * e / t` would be NaN at `t == 0`.
+    let value = t.powf(T::from_f32(exponent));
+    let derivative = T::from_f32(exponent) * t.powf(T::from_f32(exponent - 1.0));
+    (value, derivative)
+}
+
+// The smoothstep of order `2 n + 1` has the slope `scale (t (1 - t))^n`.
+fn smoothstep_slope<T: EasingImplHelper>(t: T, n: i32, scale: f32) -> T {
+    let product = t * (T::from_f32(1.0) - t);
+    T::from_f32(scale) * product.powi(n)
+}
+
+fn in_sine<T: EasingImplHelper>(t: T) -> (T, T) {
+    let angle = t * T::frac_pi_2();
+    let value = T::from_f32(1.0) - angle.cos();
+    (value, T::frac_pi_2() * angle.sin())
+}
+
+fn out_sine<T: EasingImplHelper>(t: T) -> (T, T) {
+    let angle = t * T::frac_pi_2();
+    (angle.sin(), T::frac_pi_2() * angle.cos())
+}
+
+fn in_out_sine<T: EasingImplHelper>(t: T) -> (T, T) {
+    let angle = t * T::pi();
+    let value = angle.cos().mul_add(T::from_f32(-0.5), T::from_f32(0.5));
+    (value, T::frac_pi_2() * angle.sin())
+}
+
+fn in_circ<T: EasingImplHelper>(t: T) -> (T, T) {
+    let one = T::from_f32(1.0);
+    let root = (one - t * t).sqrt();
+    (one - root, t / root)
+}
+
+fn out_circ<T: EasingImplHelper>(t: T) -> (T, T) {
+    mirror(t, in_circ)
+}
+
+fn in_out_circ<T: EasingImplHelper>(t: T) -> (T, T) {
+    in_out(t, in_circ)
+}
+
+fn in_back<T: EasingImplHelper>(t: T) -> (T, T) {
+    let (c1, c3) = (T::back_c1(), T::back_c3());
+    let squared = t * t;
+    let value = c3 * squared * t - c1 * squared;
+    let derivative = T::from_f32(3.0) * c3 * squared - c1.double() * t;
+    (value, derivative)
+}
+
+fn out_back<T: EasingImplHelper>(t: T) -> (T, T) {
+    mirror(t, in_back)
+}
+
+// Each half is (2x)^2 ((c2 + 1) 2x -/+ c2) / 2 around its end, so `in_out` does not apply.
+fn in_out_back<T: EasingImplHelper>(t: T) -> (T, T) {
+    let half = T::from_f32(0.5);
+    let c2 = T::back_c1() * T::from_f32(1.525);
+    let c2_plus_1 = c2 + T::from_f32(1.0);
+
+    let two_x = t.double();
+    let shifted = t.select_lt(half, two_x, two_x - T::from_f32(2.0));
+    let c = t.select_lt(half, T::from_f32(0.0) - c2, c2);
+    let base = t.select_lt(half, T::from_f32(0.0), T::from_f32(1.0));
+
+    let squared = shifted * shifted;
+    let value = (squared * c2_plus_1.mul_add(shifted, c)).mul_add(half, base);
+    let derivative = shifted * (T::from_f32(3.0) * c2_plus_1).mul_add(shifted, c.double());
+    (value, derivative)
+}
+
+// Four parabolas n1 (t - center)^2 + floor.
+fn out_bounce<T: EasingImplHelper>(t: T) -> (T, T) {
+    const N1: f32 = 7.5625;
+    const D1: f32 = 2.75;
+    let f = T::from_f32;
+
+    let center = t.select_lt(
+        f(1.0 / D1),
+        f(0.0),
+        t.select_lt(
+            f(2.0 / D1),
+            f(1.5 / D1),
+            t.select_lt(f(2.5 / D1), f(2.25 / D1), f(2.625 / D1)),
+        ),
+    );
+    let floor = t.select_lt(
+        f(1.0 / D1),
+        f(0.0),
+        t.select_lt(
+            f(2.0 / D1),
+            f(0.75),
+            t.select_lt(f(2.5 / D1), f(0.9375), f(0.984375)),
+        ),
+    );
+
+    let offset = t - center;
+    let value = (offset * offset).mul_add(f(N1), floor);
+    (value, f(2.0 * N1) * offset)
+}
+
+// The in-out bounce combines two out-bounces, not in-bounces, so `in_out` does not apply.
+fn in_out_bounce<T: EasingImplHelper>(t: T) -> (T, T) {
+    let half = T::from_f32(0.5);
+    let one = T::from_f32(1.0);
+    let argument = t.select_lt(half, one - t.double(), t.double() - one);
+    let (value, derivative) = out_bounce(argument);
+    let sign = t.select_lt(half, T::from_f32(-0.5), half);
+    (sign.mul_add(value, half), derivative)
+}
+
+// 2^(10t - 10), with its slope 10 ln(2) 2^(10t - 10).
+fn in_expo<T: EasingImplHelper>(t: T) -> (T, T) {
+    let power = T::from_f32(2.0).powf(T::from_f32(10.0).mul_add(t, T::from_f32(-10.0)));
+    let value = select_zero(t, T::from_f32(0.0), power);
+    (value, T::from_f32(10.0) * T::ln_2() * power)
+}
+
+fn out_expo<T: EasingImplHelper>(t: T) -> (T, T) {
+    mirror(t, in_expo)
+}
+
+fn in_out_expo<T: EasingImplHelper>(t: T) -> (T, T) {
+    let half = T::from_f32(0.5);
+    let one = T::from_f32(1.0);
+    let distance = t.select_lt(half, t, one - t);
+    let power = T::from_f32(2.0).powf(T::from_f32(20.0).mul_add(distance, T::from_f32(-10.0)));
+    let sign = t.select_lt(half, half, T::from_f32(-0.5));
+    let base = t.select_lt(half, T::from_f32(0.0), one);
+    let value = select_zero(distance, base, sign.mul_add(power, base));
+    (value, T::from_f32(10.0) * T::ln_2() * power)
+}
+
+// -2^(10t - 10) sin((10t - 10.75) c4), sharing the power and the angle.
+fn in_elastic<T: EasingImplHelper>(t: T) -> (T, T) {
+    let f = T::from_f32;
+    let c4 = T::pi() * f(2.0 / 3.0);
+    let power = f(2.0).powf(f(10.0).mul_add(t, f(-10.0)));
+    let angle = f(10.0).mul_add(t, f(-10.75)) * c4;
+    let (sin, cos) = (angle.sin(), angle.cos());
+
+    let value = select_zero(t, f(0.0), f(0.0) - power * sin);
+    let value = select_zero(f(1.0) - t, f(1.0), value);
+    let derivative = f(-10.0) * power * T::ln_2().mul_add(sin, c4 * cos);
+    (value, derivative)
+}
+
+fn out_elastic<T: EasingImplHelper>(t: T) -> (T, T) {
+    mirror(t, in_elastic)
+}
+
+// The halves are 2^(20t - 10) and 2^(10 - 20t) weighted sines around their ends.
+fn in_out_elastic<T: EasingImplHelper>(t: T) -> (T, T) {
+    let f = T::from_f32;
+    let half = f(0.5);
+    let c5 = T::pi() * f(4.0 / 9.0);
+    let distance = t.select_lt(half, t, f(1.0) - t);
+    let power = f(2.0).powf(f(20.0).mul_add(distance, f(-10.0)));
+    let angle = f(20.0).mul_add(t, f(-11.125)) * c5;
+    let (sin, cos) = (angle.sin(), angle.cos());
+
+    let sign = t.select_lt(half, f(-1.0), f(1.0));
+    let base = t.select_lt(half, f(0.0), f(1.0));
+    let value = (sign * power * sin).mul_add(half, base);
+    let value = select_zero(distance, base, value);
+    let derivative = f(10.0) * power * (sign * c5).mul_add(cos, f(0.0) - T::ln_2() * sin);
+    (value, derivative)
+}
+
+fn in_curve<T: EasingImplHelper>(t: T, curve: f32) -> (T, T) {
+    if curve.abs() < T::curve_threshold() {
+        // The derivative of `curve_series`.
+        let (f, c) = (T::from_f32, T::from_f32(curve));
+        let (u, slope) = (t * (t - f(1.0)), t.double() - f(1.0));
+        let higher = (c * (f(6.0) * u + f(1.0)) + c * c * u * slope) / f(6.0);
+        return (t.curve_series(c), f(1.0) + c * f(0.5) * (slope + higher));
+    }
+    let grow = T::from_f32(curve).exp();
+    let scale = T::from_f32(1.0) / (T::from_f32(1.0) - grow);
+    let power = grow.powf(t);
+    let value = scale - scale * power;
+    (value, T::from_f32(-curve) * scale * power)
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use crate::easing::tests::EASINGS;
+    use crate::{Easing, EasingArgument};
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn values_match_apply() {
+        for easing in EASINGS {
+            for i in 0..=40 {
+                let t = i as f64 / 40.0;
+                let (value, _) = easing.apply_derivative_pair(t);
+                assert_relative_eq!(value, easing.apply(t), epsilon = 1e-6);
+            }
+            assert_eq!(easing.apply_derivative_pair(0.0f32).0, easing.apply(0.0f32));
+            assert_relative_eq!(easing.apply_derivative_pair(1.0f32).0, 1.0);
+        }
+    }
+
+    #[test]
+    fn derivatives_match_finite_differences() {
+        const H: f64 = 1e-6;
+        for easing in EASINGS {
+            // Irregular steps, to stay clear of the kinks of `Bounce`.
+            for i in 0..37 {
+                let t = 0.013 + i as f64 * 0.0268;
+                let (_, derivative) = easing.apply_derivative_pair(t);
+                let numeric = (easing.apply(t + H) - easing.apply(t - H)) / (2.0 * H);
+                assert_relative_eq!(derivative, numeric, epsilon = 1e-4, max_relative = 1e-5);
+            }
+        }
+    }
+
+    #[test]
+    fn small_curves() {
+        for curve in [-0.15f32, -1e-3, 0.0, 0.004, 0.1] {
+            let c = curve as f64;
+            for i in 0..=10 {
+                let t = i as f64 / 10.0;
+                let (value, derivative) = Easing::InCurve(curve).apply_derivative_pair(t);
+                let (exact, slope) = if c == 0.0 {
+                    (t, 1.0)
+                } else {
+                    (
+                        (c * t).exp_m1() / c.exp_m1(),
+                        c * (c * t).exp() / c.exp_m1(),
+                    )
+                };
+                assert_relative_eq!(value, exact, epsilon = 1e-13);
+                assert_relative_eq!(derivative, slope, epsilon = 1e-12);
+            }
+        }
+    }
+
+    #[test]
+    fn derivative_methods() {
+        let t = 0.3f64;
+        let pairs = [
+            (t.ease_in_quad_derivative(), Easing::InQuad),
+            (t.ease_out_cubic_derivative(), Easing::OutCubic),
+            (t.ease_in_out_sine_derivative(), Easing::InOutSine),
+            (t.ease_out_bounce_derivative(), Easing::OutBounce),
+            (t.ease_in_out_elastic_derivative(), Easing::InOutElastic),
+            (
+                t.ease_in_out_curve_derivative(-2.5),
+                Easing::InOutCurve(-2.5),
+            ),
+            (t.ease_out_pow_derivative(1.5), Easing::OutPow(1.5)),
+            (
+                t.ease_in_expo_scaled_derivative(4.0),
+                Easing::InExpoScaled(4.0),
+            ),
+            (t.smootherstep_derivative(), Easing::Smootherstep),
+        ];
+        for (derivative, easing) in pairs {
+            assert_eq!(derivative, easing.derivative(t), "{easing}");
+        }
+        assert_eq!(0.5f32.ease_in_quad_derivative(), 1.0);
+    }
+
+    #[test]
+    fn endpoint_slopes() {
+        assert_eq!(Easing::OutQuad.apply_derivative_pair(0.0f32), (0.0, 2.0));
+        assert_eq!(Easing::InOutCubic.apply_derivative_pair(0.5f64), (0.5, 3.0));
+        assert_eq!(
+            Easing::InCirc.apply_derivative_pair(1.0f32).1,
+            f32::INFINITY
+        );
+        assert_relative_eq!(
+            Easing::InOutSine.apply_derivative_pair(0.5f64).1,
+            core::f64::consts::FRAC_PI_2,
+            epsilon = 1e-6
+        );
+        assert_relative_eq!(Easing::OutBack.apply_derivative_pair(1.0f32).1, 0.0);
+    }
+
+    #[cfg(feature = "nightly")]
+    #[test]
+    fn simd() {
+        use core::simd::f32x8;
+
+        let t = f32x8::from_array([0.0, 0.1, 0.3, 0.45, 0.5, 0.62, 0.9, 1.0]);
+        for easing in EASINGS {
+            let (values, derivatives) = easing.apply_derivative_pair(t);
+            for lane in 0..8 {
+                let (value, derivative) = easing.apply_derivative_pair(t[lane]);
+                assert_relative_eq!(values[lane], value, epsilon = 1e-5, max_relative = 1e-5);
+                assert_relative_eq!(
+                    derivatives[lane],
+                    derivative,
+                    epsilon = 1e-5,
+                    max_relative = 1e-5
+                );
+            }
+        }
+    }
+}
@@ -0,0 +1,284 @@
+// Copyright (C) 2025 Tim Blechmann
+// SPDX-License-Identifier: MIT
+
+//! Bit-exact easings on fixed-point numbers, for lockstep simulations.
+//!
+//! Floating point results may differ between platforms (fused multiply-add, `libm`
+//! implementations, x87 precision). This module evaluates the polynomial easings (`Quad` to
+//! `Quint`, `Back` and the smoothsteps) with integer arithmetic only, so every platform,
+//! including WASM, computes identical bits.
+//!
+//! ```
+//! # use nova_easing::{Easing, deterministic::{Fixed, ease}};
+//! let t = Fixed::from_ratio(1, 4);
+//! let eased = ease(Easing::InOutCubic, t).unwrap();
+//! assert_eq!(eased, Fixed::from_ratio(1, 16));
+//!
+//! // Non-polynomial easings are not available.
+//! assert_eq!(ease(Easing::InSine, t), None);
+//! ```
+
+use core::ops::{Add, Mul, Neg, Sub};
+
+#[allow(unused_imports)]
+use num_traits::Float;
+
+use crate::Easing;
+
+/// A signed Q31.32 fixed-point number: 32 integer bits (including sign) and 32 fraction bits.
+///
+/// Products are rounded to the nearest representable value, with ties towards positive infinity.
+/// Arithmetic wraps on overflow, which is far outside of the range used by easings.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct Fixed(i64);
+
+impl Fixed {
+    const FRACTION_BITS: u32 = 32;
+
+    /// 0.
+    pub const ZERO: Self = Self(0);
+    /// 0.5.
+    pub const HALF: Self = Self(1 << (Self::FRACTION_BITS - 1));
+    /// 1.
+    pub const ONE: Self = Self(1 << Self::FRACTION_BITS);
+
+    /// Creates a number from its raw representation, `value * 2^32`.
+    pub const fn from_bits(bits: i64) -> Self {
+        Self(bits)
+    }
+
+    /// The raw representation, `self * 2^32`.
+    pub const fn to_bits(self) -> i64 {
+        self.0
+    }
+
+    /// Creates an integer.
+    pub const fn from_int(value: i32) -> Self {
+        Self((value as i64) << Self::FRACTION_BITS)
+    }
+
+    /// Creates `numerator / denominator`, rounded to the nearest representable value.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `denominator` is zero.
+    pub const fn from_ratio(numerator: i32, denominator: i32) -> Self {
+        assert!(denominator != 0, "denominator must not be zero");
+        let scaled = (numerator as i128) << (Self::FRACTION_BITS + 1);
+        let quotient = scaled / denominator as i128;
+        // Round half away from zero on the doubled quotient.
+        Self(((quotient + quotient.signum()) / 2) as i64)
+    }
+
+    /// Converts from `f64`, rounded to the nearest representable value.
+    ///
+    /// The conversion itself is exact IEEE arithmetic, so it is deterministic as well; only the
+    /// inputs need to be identical.
+    pub fn from_f64(value: f64) -> Self {
+        Self((value * (1u64 << Self::FRACTION_BITS) as f64).round() as i64)
+    }
+
+    /// Converts to `f64`, rounded to the nearest `f64` if there are more than 53 significant bits.
+    pub fn to_f64(self) -> f64 {
+        self.0 as f64 / (1u64 << Self::FRACTION_BITS) as f64
+    }
+
+    /// Linearly interpolates between `self` (at `t = 0`) and `other` (at `t = 1`).
+    pub fn lerp(self, other: Self, t: Self) -> Self {
+        self + (other - self) * t
+    }
+
+    fn powi(self, n: u32) -> Self {
+        (1..n).fold(self, |product, _| product * self)
+    }
+
+    fn double(self) -> Self {
+        self + self
+    }
+}
+
+impl Add for Fixed {
+    type Output = Self;
+    fn add(self, rhs: Self) -> Self {
+        Self(self.0.wrapping_add(rhs.0))
+    }
+}
+
+impl Sub for Fixed {
+    type Output = Self;
+    fn sub(self, rhs: Self) -> Self {
+        Self(self.0.wrapping_sub(rhs.0))
+    }
+}
+
+impl Mul for Fixed {
+    type Output = Self;
+    fn mul(self, rhs: Self) -> Self {
+        let product = self.0 as i128 * rhs.0 as i128;
+        let rounding = 1i128 << (Self::FRACTION_BITS - 1);
+        Self(((product + rounding) >> Self::FRACTION_BITS) as i64)
+    }
+}
+
+impl Neg for Fixed {
+    type Output = Self;
+    fn neg(self) -> Self {
+        Self(self.0.wrapping_neg())
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+// The constants of the back easings, rounded from their decimal definition.
+const BACK_C1: Fixed = Fixed::from_bits(7_308_230_452); // 1.70158
+const BACK_C3: Fixed = Fixed::from_bits(11_603_197_748); // 2.70158
+const BACK_C2: Fixed = Fixed::from_bits(11_145_051_439); // 1.70158 * 1.525
+const BACK_C2_PLUS_1: Fixed = Fixed::from_bits(15_440_018_735);
+
+/// Applies `easing` to `t` with integer arithmetic only.
+///
+/// Returns `None` for easings which are not polynomials (`Sine`, `Circ`, `Expo`, `Elastic`,
+/// `Bounce` and the `Curve` variants).
+pub fn ease(easing: Easing, t: Fixed) -> Option<Fixed> {
+    let eased = match easing {
+        Easing::Linear => t,
+        Easing::InQuad => ease_in_pow(t, 2),
+        Easing::OutQuad => ease_out_pow(t, 2),
+        Easing::InOutQuad => ease_in_out_pow(t, 2),
+        Easing::InCubic => ease_in_pow(t, 3),
+        Easing::OutCubic => ease_out_pow(t, 3),
+        Easing::InOutCubic => ease_in_out_pow(t, 3),
+        Easing::InQuart => ease_in_pow(t, 4),
+        Easing::OutQuart => ease_out_pow(t, 4),
+        Easing::InOutQuart => ease_in_out_pow(t, 4),
+        Easing::InQuint => ease_in_pow(t, 5),
+        Easing::OutQuint => ease_out_pow(t, 5),
+        Easing::InOutQuint => ease_in_out_pow(t, 5),
+        Easing::InBack => ease_in_back(t),
+        Easing::OutBack => Fixed::ONE - ease_in_back(Fixed::ONE - t),
+        Easing::InOutBack => ease_in_out_back(t),
+        Easing::Smoothstep => t * t * polynomial(t, &[-2, 3]),
+        Easing::Smootherstep => t * t * t * polynomial(t, &[6, -15, 10]),
+        Easing::Smootheststep => t.powi(4) * polynomial(t, &[-20, 70, -84, 35]),
+        _ => return None,
+    };
+    Some(eased)
+}
+
+fn ease_in_pow(t: Fixed, n: u32) -> Fixed {
+    t.powi(n)
+}
+
+fn ease_out_pow(t: Fixed, n: u32) -> Fixed {
+    Fixed::ONE - (Fixed::ONE - t).powi(n)
+}
+
+// 2^(n-1) t^n in the first half, mirrored in the second one. Halving rounds down.
+fn ease_in_out_pow(t: Fixed, n: u32) -> Fixed {
+    if t < Fixed::HALF {
+        Fixed(t.double().powi(n).0 >> 1)
+    } else {
+        Fixed::ONE - Fixed((Fixed::ONE - t).double().powi(n).0 >> 1)
+    }
+}
+
+// Horner's scheme, with the coefficients of the highest power first.
+fn polynomial(t: Fixed, coefficients: &[i32]) -> Fixed {
+    coefficients.iter().fold(Fixed::ZERO, |sum, &coefficient| {
+        sum * t + Fixed::from_int(coefficient)
+    })
+}
+
+fn ease_in_back(t: Fixed) -> Fixed {
+    let squared = t * t;
+    BACK_C3 * squared * t - BACK_C1 * squared
+}
+
+fn ease_in_out_back(t: Fixed) -> Fixed {
+    let two_x = t.double();
+    if t < Fixed::HALF {
+        Fixed((two_x * two_x * (BACK_C2_PLUS_1 * two_x - BACK_C2)).0 >> 1)
+    } else {
+        let shifted = two_x - Fixed::from_int(2);
+        let inner = BACK_C2_PLUS_1 * shifted + BACK_C2;
+        Fixed((shifted * shifted * inner + Fixed::from_int(2)).0 >> 1)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::{Fixed, ease};
+    use crate::Easing;
+    use approx::assert_relative_eq;
+
+    const POLYNOMIALS: [Easing; 19] = [
+        Easing::Linear,
+        Easing::InQuad,
+        Easing::OutQuad,
+        Easing::InOutQuad,
+        Easing::InCubic,
+        Easing::OutCubic,
+        Easing::InOutCubic,
+        Easing::InQuart,
+        Easing::OutQuart,
+        Easing::InOutQuart,
+        Easing::InQuint,
+        Easing::OutQuint,
+        Easing::InOutQuint,
+        Easing::InBack,
+        Easing::OutBack,
+        Easing::InOutBack,
+        Easing::Smoothstep,
+        Easing::Smootherstep,
+        Easing::Smootheststep,
+    ];
+
+    #[test]
+    fn fixed_point_arithmetic() {
+        assert_eq!(Fixed::from_ratio(1, 2), Fixed::HALF);
+        assert_eq!(Fixed::from_ratio(-3, 2).to_f64(), -1.5);
+        assert_eq!(Fixed::from_f64(0.25), Fixed::from_ratio(1, 4));
+        assert_eq!(Fixed::HALF * Fixed::HALF, Fixed::from_ratio(1, 4));
+        assert_eq!(-Fixed::ONE * Fixed::HALF, Fixed::from_ratio(-1, 2));
+        assert_eq!(
+            Fixed::from_int(2).lerp(Fixed::from_int(4), Fixed::HALF),
+            Fixed::from_int(3)
+        );
+        // Ties round up: 2^-32 * 0.5.
+        assert_eq!(Fixed::from_bits(1) * Fixed::HALF, Fixed::from_bits(1));
+    }
+
+    #[test]
+    fn matches_floating_point() {
+        for easing in POLYNOMIALS {
+            for i in 0..=32 {
+                let t = Fixed::from_ratio(i, 32);
+                let eased = ease(easing, t).unwrap();
+                assert_relative_eq!(eased.to_f64(), easing.apply(t.to_f64()), epsilon = 1e-7);
+            }
+            assert_eq!(ease(easing, Fixed::ZERO), Some(Fixed::ZERO));
+            assert_eq!(ease(easing, Fixed::ONE), Some(Fixed::ONE));
+        }
+        assert_eq!(ease(Easing::OutBounce, Fixed::HALF), None);
+    }
+
+    // Golden values: any change here breaks lockstep compatibility with previous versions.
+    #[test]
+    fn bit_exact() {
+        let t = Fixed::from_ratio(1, 3);
+        let bits = [
+            Easing::InQuad,
+            Easing::OutCubic,
+            Easing::InOutQuint,
+            Easing::InBack,
+            Easing::InOutBack,
+        ]
+        .map(|easing| ease(easing, t).unwrap().to_bits());
+        assert_eq!(
+            bits,
+            [477218588, 3022384393, 282796200, -382277541, -189267915]
+        );
+    }
+}
@@ -0,0 +1,86 @@
+// Copyright (C) 2025 Tim Blechmann
+// SPDX-License-Identifier: MIT
+
+//! Object-safe easing curves, to store curves of different types behind one trait object.
+//!
+//! [`Curve`] is generic over the argument type, so it cannot be used as `dyn Curve`. [`EasingFn`]
+//! is its object-safe `f32` counterpart. It lives in its own module because every [`Curve`]
+//! implements it: if a glob import of [`curve`](crate::curve) brought both traits into scope,
+//! `curve.eval(t)` would be ambiguous.
+
+use crate::curve::Curve;
+
+/// An easing curve for `f32`, which unlike [`Curve`] is object safe, so curves of different types
+/// can be stored as `Box<dyn EasingFn>`, e.g. in the pipelines of an animation system.
+///
+/// Implemented for every [`Curve`], i.e. [`Easing`](crate::Easing), the parametric curves and the
+/// combinators, and for closures wrapped into a [`Closure`]. Types implementing both traits have
+/// two `eval` methods, so with both traits in scope, `EasingFn::eval(&curve, t)` selects this one.
+///
+/// ```
+/// # use nova_easing::{Easing, cubic_bezier::CubicBezier, curve::{Curve, OutSine}};
+/// # use nova_easing::dynamic::{Closure, EasingFn};
+/// let pipeline: Vec<Box<dyn EasingFn>> = vec![
+///     Box::new(Easing::InOutQuad),
+///     Box::new(CubicBezier::EASE_OUT),
+///     Box::new(OutSine.yoyo()),
+///     Box::new(Closure(|t: f32| t * t * (3.0 - 2.0 * t))),
+/// ];
+/// let values: Vec<f32> = pipeline.iter().map(|easing| easing.eval(1.0)).collect();
+/// assert_eq!(values, [1.0, 1.0, 0.0, 1.0]);
+/// ```
+pub trait EasingFn {
+    /// Evaluates the curve at `t`.
+    fn eval(&self, t: f32) -> f32;
+}
+
+impl<C: Curve> EasingFn for C {
+    #[inline]
+    fn eval(&self, t: f32) -> f32 {
+        Curve::eval(self, t)
+    }
+}
+
+/// A closure as [`EasingFn`]. Closures cannot implement the trait directly, as they could also
+/// implement [`Curve`].
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct Closure<F>(pub F);
+
+impl<F: Fn(f32) -> f32> EasingFn for Closure<F> {
+    #[inline]
+    fn eval(&self, t: f32) -> f32 {
+        (self.0)(t)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::{Closure, EasingFn};
+    use crate::cubic_bezier::CubicBezier;
+    use crate::curve::{Curve, InQuad};
+    use crate::{Easing, EasingArgument};
+    use std::{boxed::Box, vec, vec::Vec};
+
+    #[test]
+    fn easing_fn_objects() {
+        let objects: Vec<Box<dyn EasingFn>> = vec![
+            Box::new(Easing::OutBack),
+            Box::new(InQuad.mirrored().scaled(0.5)),
+            Box::new(CubicBezier::EASE_IN_OUT),
+            Box::new(Closure(|t: f32| t.sqrt())),
+        ];
+        for t in [0.0f32, 0.25, 0.5, 0.9, 1.0] {
+            let values: Vec<f32> = objects.iter().map(|easing| easing.eval(t)).collect();
+            let expected = [
+                t.ease_out_back(),
+                0.5 * t.ease_out_quad(),
+                Curve::eval(&CubicBezier::EASE_IN_OUT, t),
+                t.sqrt(),
+            ];
+            assert_eq!(values, expected);
+        }
+        assert_eq!(EasingFn::eval(&Easing::InOutCubic, 0.5), 0.5);
+    }
+}
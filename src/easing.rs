@@ -0,0 +1,868 @@
+// Copyright (C) 2025 Tim Blechmann
+// SPDX-License-Identifier: MIT
+
+#[cfg(feature = "alloc")]
+use alloc::{borrow::ToOwned, string::String};
+use core::fmt;
+#[cfg(feature = "alloc")]
+use core::str::FromStr;
+
+use crate::internal::CurveParam;
+use crate::{EasingArgument, EasingImplHelper};
+
+/// A runtime representation of the easing functions.
+///
+/// Each variant corresponds to one method of [`EasingArgument`], which allows choosing a curve at
+/// runtime (e.g. from a configuration file or a UI element) and passing it around as a value.
+/// The parametric curves carry their `curve` factor, the power easings their exponent.
+///
+/// ```
+/// # use nova_easing::{Easing, EasingArgument};
+/// let easing = Easing::InOutPow(2.0);
+/// assert_eq!(easing.apply(0.25f32), 0.25f32.ease_in_out_quad());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Easing {
+    /// Identity, returning `t` unchanged.
+    Linear,
+    /// See [`EasingArgument::ease_in_quad`].
+    InQuad,
+    /// See [`EasingArgument::ease_out_quad`].
+    OutQuad,
+    /// See [`EasingArgument::ease_in_out_quad`].
+    InOutQuad,
+    /// See [`EasingArgument::ease_in_cubic`].
+    InCubic,
+    /// See [`EasingArgument::ease_out_cubic`].
+    OutCubic,
+    /// See [`EasingArgument::ease_in_out_cubic`].
+    InOutCubic,
+    /// See [`EasingArgument::ease_in_quart`].
+    InQuart,
+    /// See [`EasingArgument::ease_out_quart`].
+    OutQuart,
+    /// See [`EasingArgument::ease_in_out_quart`].
+    InOutQuart,
+    /// See [`EasingArgument::ease_in_quint`].
+    InQuint,
+    /// See [`EasingArgument::ease_out_quint`].
+    OutQuint,
+    /// See [`EasingArgument::ease_in_out_quint`].
+    InOutQuint,
+    /// See [`EasingArgument::ease_in_sine`].
+    InSine,
+    /// See [`EasingArgument::ease_out_sine`].
+    OutSine,
+    /// See [`EasingArgument::ease_in_out_sine`].
+    InOutSine,
+    /// See [`EasingArgument::ease_in_circ`].
+    InCirc,
+    /// See [`EasingArgument::ease_out_circ`].
+    OutCirc,
+    /// See [`EasingArgument::ease_in_out_circ`].
+    InOutCirc,
+    /// See [`EasingArgument::ease_in_back`].
+    InBack,
+    /// See [`EasingArgument::ease_out_back`].
+    OutBack,
+    /// See [`EasingArgument::ease_in_out_back`].
+    InOutBack,
+    /// See [`EasingArgument::ease_in_bounce`].
+    InBounce,
+    /// See [`EasingArgument::ease_out_bounce`].
+    OutBounce,
+    /// See [`EasingArgument::ease_in_out_bounce`].
+    InOutBounce,
+    /// See [`EasingArgument::ease_in_expo`].
+    InExpo,
+    /// See [`EasingArgument::ease_out_expo`].
+    OutExpo,
+    /// See [`EasingArgument::ease_in_out_expo`].
+    InOutExpo,
+    /// See [`EasingArgument::ease_in_elastic`].
+    InElastic,
+    /// See [`EasingArgument::ease_out_elastic`].
+    OutElastic,
+    /// See [`EasingArgument::ease_in_out_elastic`].
+    InOutElastic,
+    /// See [`EasingArgument::ease_in_curve`].
+    InCurve(f32),
+    /// See [`EasingArgument::ease_out_curve`].
+    OutCurve(f32),
+    /// See [`EasingArgument::ease_in_out_curve`].
+    InOutCurve(f32),
+    /// See [`EasingArgument::ease_in_pow`].
+    InPow(f32),
+    /// See [`EasingArgument::ease_out_pow`].
+    OutPow(f32),
+    /// See [`EasingArgument::ease_in_out_pow`].
+    InOutPow(f32),
+    /// See [`EasingArgument::ease_in_expo_scaled`].
+    InExpoScaled(f32),
+    /// See [`EasingArgument::ease_out_expo_scaled`].
+    OutExpoScaled(f32),
+    /// See [`EasingArgument::ease_in_out_expo_scaled`].
+    InOutExpoScaled(f32),
+    /// See [`EasingArgument::smoothstep`].
+    Smoothstep,
+    /// See [`EasingArgument::smootherstep`].
+    Smootherstep,
+    /// See [`EasingArgument::smootheststep`].
+    Smootheststep,
+}
+
+/// How [`Easing::apply_with`] treats arguments outside of [0, 1].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum RangePolicy {
+    /// Evaluates the formula of the easing as is, like [`Easing::apply`]. Outside of [0, 1], the
+    /// result depends on the implementation: it may jump (`InExpo`), turn back (`OutBounce`) or be
+    /// NaN (`InCirc`).
+    #[default]
+    Unchecked,
+    /// Clamps the argument to [0, 1].
+    Clamp,
+    /// Continues the easing smoothly, with a continuous value and slope at 0 and 1.
+    ///
+    /// Easings whose formula keeps rising outside of [0, 1] continue their formula: `Linear`,
+    /// the odd powers `Cubic` and `Quint`, `Back` and the `Curve` and `ExpoScaled` variants. All
+    /// others are point-reflected at the endpoints, so `f(-d) == -f(d)` and
+    /// `f(1 + d) == 2 - f(1 - d)`, repeating every 2 units: the even powers `Quad` and `Quart`,
+    /// `Sine` and the smoothsteps turn back, `Circ` and `Pow` are NaN and `Expo`, `Elastic` and
+    /// `Bounce` jump or oscillate. Either way, easings which are monotonic on [0, 1] stay
+    /// monotonic.
+    Extend,
+}
+
+/// How [`Easing::apply_timed`] maps times outside of [0, 1], e.g. for looping animations.
+///
+/// Unlike [`RangePolicy`], which keeps the easing meaningful near its ends, a time policy describes
+/// what happens after the animation: times within [0, 1] are passed through unchanged.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum TimePolicy {
+    /// Holds the values at the ends.
+    #[default]
+    Clamp,
+    /// Plays the easing forwards and backwards in turns (ping-pong): forwards on [0, 1], backwards
+    /// on [1, 2], forwards on [2, 3] and so on.
+    Mirror,
+    /// Restarts the easing after every unit of time: times outside of [0, 1] wrap to their
+    /// fractional part, so `1.25` and `-0.75` both become `0.25`.
+    Repeat,
+    /// Continues along the tangent at the nearest end, i.e. the value changes linearly with the
+    /// velocity the easing ends with. Easings with an infinite slope at an end, like `InCirc` at
+    /// 1, are infinite beyond it.
+    Extend,
+}
+
+impl TimePolicy {
+    /// Maps `t` to the time the easing is evaluated at. `Extend` clamps `t`, as the linear
+    /// continuation is added by [`Easing::apply_timed`].
+    ///
+    /// This is the time mapping alone, e.g. for curves which are not an [`Easing`]:
+    ///
+    /// ```
+    /// # use nova_easing::TimePolicy;
+    /// assert_eq!(TimePolicy::Mirror.wrap(1.25f32), 0.75);
+    /// assert_eq!(TimePolicy::Repeat.wrap(1.25f32), 0.25);
+    /// assert_eq!(TimePolicy::Repeat.wrap(1.0f32), 1.0);
+    /// ```
+    #[allow(private_bounds)]
+    #[inline]
+    pub fn wrap<T>(self, t: T) -> T
+    where
+        T: EasingImplHelper,
+    {
+        let zero = T::from_f32(0.0);
+        let one = T::from_f32(1.0);
+        let two = T::from_f32(2.0);
+        match self {
+            TimePolicy::Clamp | TimePolicy::Extend => {
+                t.select_lt(zero, zero, one.select_lt(t, one, t))
+            }
+            TimePolicy::Mirror => {
+                // The remainder of a period of 2, counted down in its second half.
+                let u = t - (t * T::from_f32(0.5)).floor() * two;
+                one.select_lt(u, two - u, u)
+            }
+            TimePolicy::Repeat => {
+                let fraction = t - t.floor();
+                t.select_lt(zero, fraction, one.select_lt(t, fraction, t))
+            }
+        }
+    }
+}
+
+impl Easing {
+    /// Applies the easing function to `t`.
+    ///
+    /// `t` can be any type implementing [`EasingArgument`], i.e. `f32`, `f64` or (with the
+    /// `nightly` feature) a SIMD vector of those.
+    ///
+    /// With the `validate` feature, debug builds panic if `t` is NaN or outside of [0, 1], or if a
+    /// parameter is out of bounds, see the `validate` module.
+    #[allow(private_bounds)]
+    #[inline]
+    #[cfg_attr(all(feature = "validate", debug_assertions), track_caller)]
+    pub fn apply<T>(self, t: T) -> T
+    where
+        T: EasingImplHelper + CurveParam<T>,
+    {
+        self.apply_inlined(t)
+    }
+
+    // `apply`, always inlined into the caller, so the batch kernels compiled for each instruction
+    // set contain the easing math instead of calling the baseline version.
+    #[inline(always)]
+    #[cfg_attr(all(feature = "validate", debug_assertions), track_caller)]
+    pub(crate) fn apply_inlined<T>(self, t: T) -> T
+    where
+        T: EasingImplHelper + CurveParam<T>,
+    {
+        #[cfg(all(feature = "validate", debug_assertions))]
+        crate::validate::assert_valid(self, t, true);
+        self.evaluate(t)
+    }
+
+    #[inline(always)]
+    fn evaluate<T>(self, t: T) -> T
+    where
+        T: EasingImplHelper + CurveParam<T>,
+    {
+        match self {
+            Easing::Linear => t,
+            Easing::InQuad => EasingArgument::ease_in_quad(t),
+            Easing::OutQuad => EasingArgument::ease_out_quad(t),
+            Easing::InOutQuad => EasingArgument::ease_in_out_quad(t),
+            Easing::InCubic => EasingArgument::ease_in_cubic(t),
+            Easing::OutCubic => EasingArgument::ease_out_cubic(t),
+            Easing::InOutCubic => EasingArgument::ease_in_out_cubic(t),
+            Easing::InQuart => EasingArgument::ease_in_quart(t),
+            Easing::OutQuart => EasingArgument::ease_out_quart(t),
+            Easing::InOutQuart => EasingArgument::ease_in_out_quart(t),
+            Easing::InQuint => EasingArgument::ease_in_quint(t),
+            Easing::OutQuint => EasingArgument::ease_out_quint(t),
+            Easing::InOutQuint => EasingArgument::ease_in_out_quint(t),
+            Easing::InSine => EasingArgument::ease_in_sine(t),
+            Easing::OutSine => EasingArgument::ease_out_sine(t),
+            Easing::InOutSine => EasingArgument::ease_in_out_sine(t),
+            Easing::InCirc => EasingArgument::ease_in_circ(t),
+            Easing::OutCirc => EasingArgument::ease_out_circ(t),
+            Easing::InOutCirc => EasingArgument::ease_in_out_circ(t),
+            Easing::InBack => EasingArgument::ease_in_back(t),
+            Easing::OutBack => EasingArgument::ease_out_back(t),
+            Easing::InOutBack => EasingArgument::ease_in_out_back(t),
+            Easing::InBounce => EasingArgument::ease_in_bounce(t),
+            Easing::OutBounce => EasingArgument::ease_out_bounce(t),
+            Easing::InOutBounce => EasingArgument::ease_in_out_bounce(t),
+            Easing::InExpo => EasingArgument::ease_in_expo(t),
+            Easing::OutExpo => EasingArgument::ease_out_expo(t),
+            Easing::InOutExpo => EasingArgument::ease_in_out_expo(t),
+            Easing::InElastic => EasingArgument::ease_in_elastic(t),
+            Easing::OutElastic => EasingArgument::ease_out_elastic(t),
+            Easing::InOutElastic => EasingArgument::ease_in_out_elastic(t),
+            Easing::InCurve(curve) => EasingArgument::ease_in_curve(t, T::from_f32(curve)),
+            Easing::OutCurve(curve) => EasingArgument::ease_out_curve(t, T::from_f32(curve)),
+            Easing::InOutCurve(curve) => EasingArgument::ease_in_out_curve(t, T::from_f32(curve)),
+            Easing::InPow(exponent) => EasingArgument::ease_in_pow(t, T::from_f32(exponent)),
+            Easing::OutPow(exponent) => EasingArgument::ease_out_pow(t, T::from_f32(exponent)),
+            Easing::InOutPow(exponent) => EasingArgument::ease_in_out_pow(t, T::from_f32(exponent)),
+            Easing::InExpoScaled(scale) => {
+                EasingArgument::ease_in_expo_scaled(t, T::from_f32(scale))
+            }
+            Easing::OutExpoScaled(scale) => {
+                EasingArgument::ease_out_expo_scaled(t, T::from_f32(scale))
+            }
+            Easing::InOutExpoScaled(scale) => {
+                EasingArgument::ease_in_out_expo_scaled(t, T::from_f32(scale))
+            }
+            Easing::Smoothstep => EasingArgument::smoothstep(t),
+            Easing::Smootherstep => EasingArgument::smootherstep(t),
+            Easing::Smootheststep => EasingArgument::smootheststep(t),
+        }
+    }
+
+    /// Applies the easing function to `t`, treating arguments outside of [0, 1] according to
+    /// `policy`.
+    ///
+    /// With the `validate` feature, debug builds only reject NaN arguments and invalid parameters
+    /// here, as the policy states how to treat the range.
+    ///
+    /// ```
+    /// # use nova_easing::{Easing, RangePolicy};
+    /// assert!(Easing::InCirc.apply_with(1.1f32, RangePolicy::Unchecked).is_nan());
+    /// assert_eq!(Easing::InCirc.apply_with(1.1f32, RangePolicy::Clamp), 1.0);
+    /// assert!(Easing::InCirc.apply_with(1.1f32, RangePolicy::Extend) > 1.0);
+    /// ```
+    #[allow(private_bounds)]
+    #[inline]
+    #[cfg_attr(all(feature = "validate", debug_assertions), track_caller)]
+    pub fn apply_with<T>(self, t: T, policy: RangePolicy) -> T
+    where
+        T: EasingImplHelper + CurveParam<T>,
+    {
+        #[cfg(all(feature = "validate", debug_assertions))]
+        crate::validate::assert_valid(self, t, false);
+        let zero = T::from_f32(0.0);
+        let one = T::from_f32(1.0);
+        match policy {
+            RangePolicy::Unchecked => self.evaluate(t),
+            RangePolicy::Clamp => self.evaluate(t.select_lt(zero, zero, one.select_lt(t, one, t))),
+            RangePolicy::Extend if self.continues_formula() => self.evaluate(t),
+            RangePolicy::Extend => self.apply_reflected(t),
+        }
+    }
+
+    /// Applies the easing to `t`, mapping times outside of [0, 1] according to `policy`.
+    ///
+    /// Within [0, 1], the result is exactly [`apply`](Self::apply).
+    ///
+    /// ```
+    /// # use nova_easing::{Easing, TimePolicy};
+    /// // A pulsing glow, fading in and out every 2 seconds.
+    /// let glow = |seconds: f32| Easing::InOutSine.apply_timed(seconds, TimePolicy::Mirror);
+    /// assert_eq!(glow(0.5), 0.5);
+    /// assert_eq!(glow(3.0), 1.0);
+    /// assert_eq!(glow(4.0), 0.0);
+    ///
+    /// assert_eq!(Easing::Linear.apply_timed(1.5f32, TimePolicy::Extend), 1.5);
+    /// assert_eq!(Easing::InQuad.apply_timed(2.0f32, TimePolicy::Extend), 3.0);
+    /// ```
+    #[allow(private_bounds)]
+    #[inline]
+    #[cfg_attr(all(feature = "validate", debug_assertions), track_caller)]
+    pub fn apply_timed<T>(self, t: T, policy: TimePolicy) -> T
+    where
+        T: EasingImplHelper + CurveParam<T>,
+    {
+        #[cfg(all(feature = "validate", debug_assertions))]
+        crate::validate::assert_valid(self, t, false);
+        let value = self.evaluate(policy.wrap(t));
+        if policy != TimePolicy::Extend {
+            return value;
+        }
+        let zero = T::from_f32(0.0);
+        let one = T::from_f32(1.0);
+        // The values at the ends are the ones of the easing, the slopes the one-sided limits.
+        let start = crate::derivative::pair(self, zero)
+            .1
+            .mul_add(t, self.evaluate(zero));
+        let end = crate::derivative::pair(self, one)
+            .1
+            .mul_add(t - one, self.evaluate(one));
+        t.select_lt(zero, start, one.select_lt(t, end, value))
+    }
+
+    // Whether the formula is smooth and keeps rising outside of [0, 1]. The piecewise in-out
+    // polynomials qualify, as each half continues its own odd power, and `Back` rises beyond its
+    // dip. The even powers, `Sine` and the smoothsteps turn back past their flat ends, and
+    // fractional powers of negative bases are NaN.
+    fn continues_formula(self) -> bool {
+        matches!(
+            self,
+            Easing::Linear
+                | Easing::InCubic
+                | Easing::OutCubic
+                | Easing::InOutCubic
+                | Easing::InQuint
+                | Easing::OutQuint
+                | Easing::InOutQuint
+                | Easing::InBack
+                | Easing::OutBack
+                | Easing::InOutBack
+                | Easing::InCurve(_)
+                | Easing::OutCurve(_)
+                | Easing::InOutCurve(_)
+                | Easing::InExpoScaled(_)
+                | Easing::OutExpoScaled(_)
+                | Easing::InOutExpoScaled(_)
+        )
+    }
+
+    // Point reflection at the endpoints: `t` is split into whole periods of 2 and a remainder `u`
+    // in [0, 2), which is evaluated directly in [0, 1] and reflected at (1, 1) beyond. Relies on
+    // `f(0) == 0` and `f(1) == 1`; within [0, 1], the result is exactly `self.apply(t)`.
+    fn apply_reflected<T>(self, t: T) -> T
+    where
+        T: EasingImplHelper + CurveParam<T>,
+    {
+        let zero = T::from_f32(0.0);
+        let one = T::from_f32(1.0);
+        let two = T::from_f32(2.0);
+
+        let periods = (t * T::from_f32(0.5)).floor();
+        let u = t - periods * two;
+        let argument = one.select_lt(u, two - u, u);
+        let sign = one.select_lt(u, zero - one, one);
+        let offset = periods.mul_add(two, one.select_lt(u, two, zero));
+        sign.mul_add(self.evaluate(argument), offset)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+// The names of the easings without parameters, as used by `FromStr` and `Display`.
+const NAMES: [(&str, Easing); 34] = [
+    ("linear", Easing::Linear),
+    ("ease_in_quad", Easing::InQuad),
+    ("ease_out_quad", Easing::OutQuad),
+    ("ease_in_out_quad", Easing::InOutQuad),
+    ("ease_in_cubic", Easing::InCubic),
+    ("ease_out_cubic", Easing::OutCubic),
+    ("ease_in_out_cubic", Easing::InOutCubic),
+    ("ease_in_quart", Easing::InQuart),
+    ("ease_out_quart", Easing::OutQuart),
+    ("ease_in_out_quart", Easing::InOutQuart),
+    ("ease_in_quint", Easing::InQuint),
+    ("ease_out_quint", Easing::OutQuint),
+    ("ease_in_out_quint", Easing::InOutQuint),
+    ("ease_in_sine", Easing::InSine),
+    ("ease_out_sine", Easing::OutSine),
+    ("ease_in_out_sine", Easing::InOutSine),
+    ("ease_in_circ", Easing::InCirc),
+    ("ease_out_circ", Easing::OutCirc),
+    ("ease_in_out_circ", Easing::InOutCirc),
+    ("ease_in_back", Easing::InBack),
+    ("ease_out_back", Easing::OutBack),
+    ("ease_in_out_back", Easing::InOutBack),
+    ("ease_in_bounce", Easing::InBounce),
+    ("ease_out_bounce", Easing::OutBounce),
+    ("ease_in_out_bounce", Easing::InOutBounce),
+    ("ease_in_expo", Easing::InExpo),
+    ("ease_out_expo", Easing::OutExpo),
+    ("ease_in_out_expo", Easing::InOutExpo),
+    ("ease_in_elastic", Easing::InElastic),
+    ("ease_out_elastic", Easing::OutElastic),
+    ("ease_in_out_elastic", Easing::InOutElastic),
+    ("smoothstep", Easing::Smoothstep),
+    ("smootherstep", Easing::Smootherstep),
+    ("smootheststep", Easing::Smootheststep),
+];
+
+// The constructors of the parametric easings, by name.
+#[cfg(feature = "alloc")]
+fn parametric(name: &str) -> Option<fn(f32) -> Easing> {
+    let constructor: fn(f32) -> Easing = match name {
+        "ease_in_curve" => Easing::InCurve,
+        "ease_out_curve" => Easing::OutCurve,
+        "ease_in_out_curve" => Easing::InOutCurve,
+        "ease_in_pow" => Easing::InPow,
+        "ease_out_pow" => Easing::OutPow,
+        "ease_in_out_pow" => Easing::InOutPow,
+        "ease_in_expo_scaled" => Easing::InExpoScaled,
+        "ease_out_expo_scaled" => Easing::OutExpoScaled,
+        "ease_in_out_expo_scaled" => Easing::InOutExpoScaled,
+        _ => return None,
+    };
+    Some(constructor)
+}
+
+impl Easing {
+    /// The name of the easing, matching the method of [`EasingArgument`], e.g. `"ease_in_cubic"`,
+    /// or `"linear"`.
+    ///
+    /// Parametric easings return the name without their parameter.
+    pub fn name(self) -> &'static str {
+        match self {
+            Easing::InCurve(_) => "ease_in_curve",
+            Easing::OutCurve(_) => "ease_out_curve",
+            Easing::InOutCurve(_) => "ease_in_out_curve",
+            Easing::InPow(_) => "ease_in_pow",
+            Easing::OutPow(_) => "ease_out_pow",
+            Easing::InOutPow(_) => "ease_in_out_pow",
+            Easing::InExpoScaled(_) => "ease_in_expo_scaled",
+            Easing::OutExpoScaled(_) => "ease_out_expo_scaled",
+            Easing::InOutExpoScaled(_) => "ease_in_out_expo_scaled",
+            easing => {
+                NAMES
+                    .iter()
+                    .find(|(_, candidate)| *candidate == easing)
+                    .expect("every easing without parameter has a name")
+                    .0
+            }
+        }
+    }
+
+    pub(crate) fn parameter(self) -> Option<f32> {
+        match self {
+            Easing::InCurve(p)
+            | Easing::OutCurve(p)
+            | Easing::InOutCurve(p)
+            | Easing::InPow(p)
+            | Easing::OutPow(p)
+            | Easing::InOutPow(p)
+            | Easing::InExpoScaled(p)
+            | Easing::OutExpoScaled(p)
+            | Easing::InOutExpoScaled(p) => Some(p),
+            _ => None,
+        }
+    }
+}
+
+/// Formats the easing by its [`name`](Easing::name), with the parameter of parametric easings in
+/// parentheses, e.g. `ease_in_out_cubic` or `ease_in_curve(-2.5)`.
+impl fmt::Display for Easing {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.parameter() {
+            Some(parameter) => write!(f, "{}({parameter})", self.name()),
+            None => f.write_str(self.name()),
+        }
+    }
+}
+
+/// Parses the format of [`Display`](fmt::Display), ignoring surrounding whitespace.
+///
+/// ```
+/// # use nova_easing::Easing;
+/// assert_eq!("ease_in_out_cubic".parse(), Ok(Easing::InOutCubic));
+/// assert_eq!("ease_out_curve( 4 )".parse(), Ok(Easing::OutCurve(4.0)));
+/// assert!("ease_sideways".parse::<Easing>().is_err());
+/// ```
+#[cfg(feature = "alloc")]
+impl FromStr for Easing {
+    type Err = ParseEasingError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        let (name, argument) = match s.split_once('(') {
+            Some((name, rest)) => {
+                let argument = rest
+                    .strip_suffix(')')
+                    .ok_or_else(|| ParseEasingError::InvalidParameter(s.to_owned()))?;
+                (name.trim_end(), Some(argument.trim()))
+            }
+            None => (s, None),
+        };
+
+        if let Some(&(_, easing)) = NAMES.iter().find(|(candidate, _)| *candidate == name) {
+            return match argument {
+                None => Ok(easing),
+                Some(_) => Err(ParseEasingError::InvalidParameter(s.to_owned())),
+            };
+        }
+        if let Some(easing) = parametric(name) {
+            return argument
+                .and_then(|argument| argument.parse().ok())
+                .map(easing)
+                .ok_or_else(|| ParseEasingError::InvalidParameter(s.to_owned()));
+        }
+        Err(ParseEasingError::UnknownName(name.to_owned()))
+    }
+}
+
+/// The error of parsing an [`Easing`] from a string.
+#[cfg(feature = "alloc")]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseEasingError {
+    /// No easing has this name.
+    UnknownName(String),
+    /// The parameter is missing, malformed or not expected, in the given input.
+    InvalidParameter(String),
+}
+
+#[cfg(feature = "alloc")]
+impl fmt::Display for ParseEasingError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseEasingError::UnknownName(name) => write!(f, "unknown easing `{name}`"),
+            ParseEasingError::InvalidParameter(input) => {
+                write!(f, "invalid or missing easing parameter in `{input}`")
+            }
+        }
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl core::error::Error for ParseEasingError {}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::{Easing, ParseEasingError, RangePolicy, TimePolicy};
+    use crate::EasingArgument;
+    use approx::assert_relative_eq;
+
+    pub(crate) const EASINGS: [Easing; 43] = [
+        Easing::Linear,
+        Easing::InQuad,
+        Easing::OutQuad,
+        Easing::InOutQuad,
+        Easing::InCubic,
+        Easing::OutCubic,
+        Easing::InOutCubic,
+        Easing::InQuart,
+        Easing::OutQuart,
+        Easing::InOutQuart,
+        Easing::InQuint,
+        Easing::OutQuint,
+        Easing::InOutQuint,
+        Easing::InSine,
+        Easing::OutSine,
+        Easing::InOutSine,
+        Easing::InCirc,
+        Easing::OutCirc,
+        Easing::InOutCirc,
+        Easing::InBack,
+        Easing::OutBack,
+        Easing::InOutBack,
+        Easing::InBounce,
+        Easing::OutBounce,
+        Easing::InOutBounce,
+        Easing::InExpo,
+        Easing::OutExpo,
+        Easing::InOutExpo,
+        Easing::InElastic,
+        Easing::OutElastic,
+        Easing::InOutElastic,
+        Easing::InCurve(2.0),
+        Easing::OutCurve(-3.0),
+        Easing::InOutCurve(4.0),
+        Easing::InPow(2.5),
+        Easing::OutPow(1.5),
+        Easing::InOutPow(3.5),
+        Easing::InExpoScaled(6.0),
+        Easing::OutExpoScaled(-2.0),
+        Easing::InOutExpoScaled(12.0),
+        Easing::Smoothstep,
+        Easing::Smootherstep,
+        Easing::Smootheststep,
+    ];
+
+    #[test]
+    fn apply_matches_trait_methods() {
+        let points = [0.0f32, 0.1, 0.25, 0.5, 0.75, 0.9, 1.0];
+        for &t in &points {
+            assert_eq!(Easing::Linear.apply(t), t);
+            assert_eq!(Easing::InQuad.apply(t), t.ease_in_quad());
+            assert_eq!(Easing::OutCubic.apply(t), t.ease_out_cubic());
+            assert_eq!(Easing::InOutSine.apply(t), t.ease_in_out_sine());
+            assert_eq!(Easing::OutBounce.apply(t), t.ease_out_bounce());
+            assert_eq!(Easing::InOutElastic.apply(t), t.ease_in_out_elastic());
+            assert_eq!(Easing::InCurve(2.0).apply(t), t.ease_in_curve(2.0));
+            assert_eq!(Easing::InOutCurve(-3.0).apply(t), t.ease_in_out_curve(-3.0));
+
+            let t = t as f64;
+            assert_eq!(Easing::InOutBack.apply(t), t.ease_in_out_back());
+            assert_eq!(Easing::OutCurve(1.5).apply(t), t.ease_out_curve(1.5));
+        }
+    }
+
+    #[test]
+    fn range_policies() {
+        for easing in EASINGS {
+            for t in [0.0f64, 0.3, 0.5, 1.0] {
+                assert_eq!(
+                    easing.apply_with(t, RangePolicy::Unchecked),
+                    easing.apply(t)
+                );
+                assert_eq!(easing.apply_with(t, RangePolicy::Clamp), easing.apply(t));
+                assert_eq!(easing.apply_with(t, RangePolicy::Extend), easing.apply(t));
+            }
+            assert_eq!(easing.apply_with(-0.5f32, RangePolicy::Clamp), 0.0);
+            assert_eq!(easing.apply_with(1.5f32, RangePolicy::Clamp), 1.0);
+        }
+    }
+
+    #[test]
+    fn time_policies() {
+        let policies = [
+            TimePolicy::Clamp,
+            TimePolicy::Mirror,
+            TimePolicy::Repeat,
+            TimePolicy::Extend,
+        ];
+        for easing in EASINGS {
+            for policy in policies {
+                for t in [0.0f64, 0.3, 0.5, 1.0] {
+                    assert_eq!(easing.apply_timed(t, policy), easing.apply(t));
+                }
+            }
+            let clamped = |t: f32| easing.apply_timed(t, TimePolicy::Clamp);
+            assert_eq!((clamped(-0.5), clamped(1.5)), (0.0, 1.0));
+
+            for t in [0.1f32, 0.3, 0.75] {
+                let mirrored = |t: f32| easing.apply_timed(t, TimePolicy::Mirror);
+                assert_relative_eq!(mirrored(2.0 - t), easing.apply(t), epsilon = 1e-5);
+                assert_relative_eq!(mirrored(2.0 + t), easing.apply(t), epsilon = 1e-5);
+                assert_relative_eq!(mirrored(-t), easing.apply(t), epsilon = 1e-5);
+
+                let repeated = |t: f32| easing.apply_timed(t, TimePolicy::Repeat);
+                assert_relative_eq!(repeated(3.0 + t), easing.apply(t), epsilon = 1e-5);
+                assert_relative_eq!(repeated(t - 1.0), easing.apply(t), epsilon = 1e-5);
+            }
+        }
+
+        let wrap = |policy: TimePolicy, t: f32| policy.wrap(t);
+        assert_eq!(wrap(TimePolicy::Mirror, 3.0), 1.0);
+        assert_eq!(wrap(TimePolicy::Mirror, 4.0), 0.0);
+        assert_eq!(wrap(TimePolicy::Repeat, 2.0), 0.0);
+        assert_eq!(wrap(TimePolicy::Repeat, -0.75), 0.25);
+        assert_eq!(wrap(TimePolicy::Extend, 7.0), 1.0);
+    }
+
+    #[test]
+    fn linear_time_extension() {
+        let extend = |easing: Easing, t: f64| easing.apply_timed(t, TimePolicy::Extend);
+        assert_eq!(extend(Easing::InCubic, -0.5), 0.0);
+        assert_eq!(extend(Easing::InCubic, 1.5), 2.5);
+        assert_eq!(extend(Easing::OutQuad, -0.25), -0.5);
+        // `InExpo` continues from its value 0, with the slope of its formula, `10 ln 2 / 1024` at 0
+        // and `10 ln 2` at 1.
+        let ln_2 = std::f64::consts::LN_2;
+        assert_relative_eq!(extend(Easing::InExpo, -1.0), -10.0 * ln_2 / 1024.0);
+        assert_relative_eq!(extend(Easing::InExpo, 1.1), 1.0 + ln_2, epsilon = 1e-12);
+        assert_eq!(extend(Easing::OutBack, 2.0), 1.0);
+        assert_eq!(extend(Easing::InCirc, 1.5), f64::INFINITY);
+
+        for easing in EASINGS {
+            let (start, end) = (easing.derivative(0.0f64), easing.derivative(1.0f64));
+            if start.is_finite() && end.is_finite() {
+                assert_relative_eq!(
+                    extend(easing, 3.0) - extend(easing, 2.0),
+                    end,
+                    epsilon = 1e-9
+                );
+                assert_relative_eq!(
+                    extend(easing, -2.0) - extend(easing, -3.0),
+                    start,
+                    epsilon = 1e-9
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn extension_is_smooth() {
+        let h = 1e-6;
+        for easing in EASINGS {
+            let f = |t: f64| easing.apply_with(t, RangePolicy::Extend);
+            for t in [-3.5, -1.0, -0.2, 1.2, 2.0, 4.7] {
+                assert!(f(t).is_finite(), "{easing:?} at {t}");
+            }
+
+            // Continuous value and slope at the endpoints, compared with one-sided differences.
+            // The slope of `Circ` is infinite at its endpoints.
+            let circ = matches!(easing, Easing::InCirc | Easing::OutCirc | Easing::InOutCirc);
+            for edge in [0.0, 1.0] {
+                assert_relative_eq!(f(edge - h), f(edge), epsilon = 1e-2);
+                assert_relative_eq!(f(edge + h), f(edge), epsilon = 1e-2);
+                if circ {
+                    assert!(f(edge - h) < f(edge) && f(edge) < f(edge + h));
+                    continue;
+                }
+                let left = (f(edge - h) - f(edge - 2.0 * h)) / h;
+                let right = (f(edge + 2.0 * h) - f(edge + h)) / h;
+                assert_relative_eq!(left, right, epsilon = 1e-2, max_relative = 1e-2);
+            }
+        }
+
+        let extend = |easing: Easing, t: f64| easing.apply_with(t, RangePolicy::Extend);
+        assert_eq!(extend(Easing::InCubic, -0.5), -0.125);
+        assert_relative_eq!(extend(Easing::InCirc, 1.2), 2.0 - 0.8f64.ease_in_circ());
+        assert_relative_eq!(extend(Easing::OutExpo, -0.3), -0.3f64.ease_out_expo());
+        assert_relative_eq!(extend(Easing::InExpo, 3.2), 4.0 - 0.8f64.ease_in_expo());
+
+        let points: Vec<f64> = (-40..=40)
+            .map(|i| extend(Easing::OutBounce, i as f64 * 0.1))
+            .collect();
+        assert!(points.iter().all(|p| p.is_finite()));
+    }
+
+    #[test]
+    fn extension_is_monotonic() {
+        let samples = |f: &dyn Fn(f64) -> f64, from: i32, to: i32| -> Vec<f64> {
+            (from..=to).map(|i| f(i as f64 / 64.0)).collect()
+        };
+        let rising = |values: &[f64]| values.windows(2).all(|pair| pair[0] <= pair[1]);
+        for easing in EASINGS {
+            if !rising(&samples(&|t| easing.apply(t), 0, 64)) {
+                continue;
+            }
+            let extended = samples(&|t| easing.apply_with(t, RangePolicy::Extend), -64, 128);
+            assert!(rising(&extended), "{easing:?}");
+        }
+
+        // Overshoots keep their direction instead of turning back.
+        let extend = |easing: Easing, t: f64| easing.apply_with(t, RangePolicy::Extend);
+        assert_eq!(extend(Easing::InQuad, -0.5), -0.25);
+        assert_eq!(extend(Easing::OutQuad, 1.5), 1.25);
+        assert_eq!(extend(Easing::InOutQuad, 1.5), 1.5);
+        assert_eq!(extend(Easing::InOutQuad, -0.5), -0.5);
+        assert_relative_eq!(extend(Easing::InSine, 3.0), 3.0, epsilon = 1e-6);
+    }
+
+    #[test]
+    fn names_round_trip() {
+        for easing in EASINGS {
+            assert_eq!(easing.to_string().parse(), Ok(easing));
+        }
+        assert_eq!(Easing::InOutCubic.to_string(), "ease_in_out_cubic");
+        assert_eq!(Easing::InCurve(-2.5).to_string(), "ease_in_curve(-2.5)");
+        assert_eq!(Easing::InOutPow(3.0).name(), "ease_in_out_pow");
+        assert_eq!(" linear\n".parse(), Ok(Easing::Linear));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde_round_trip() {
+        for easing in EASINGS {
+            let json = serde_json::to_string(&easing).unwrap();
+            assert_eq!(serde_json::from_str::<Easing>(&json).unwrap(), easing);
+        }
+        assert_eq!(
+            serde_json::to_string(&Easing::InQuad).unwrap(),
+            r#""InQuad""#
+        );
+        assert_eq!(
+            serde_json::from_str::<Easing>(r#"{"InCurve":-2.5}"#).unwrap(),
+            Easing::InCurve(-2.5)
+        );
+        assert_eq!(
+            serde_json::from_str::<RangePolicy>(r#""Extend""#).unwrap(),
+            RangePolicy::Extend
+        );
+    }
+
+    #[test]
+    fn parse_errors() {
+        let parse = |s: &str| s.parse::<Easing>().unwrap_err();
+        assert_eq!(
+            parse("ease_in_out_cubi"),
+            ParseEasingError::UnknownName("ease_in_out_cubi".into())
+        );
+        assert_eq!(parse("InCubic").to_string(), "unknown easing `InCubic`");
+        for input in [
+            "ease_in_curve",
+            "ease_in_curve()",
+            "ease_in_curve(x)",
+            "ease_in_curve(2",
+            "ease_in_quad(2)",
+        ] {
+            assert_eq!(
+                parse(input),
+                ParseEasingError::InvalidParameter(input.into())
+            );
+        }
+    }
+
+    #[cfg(feature = "nightly")]
+    #[test]
+    fn apply_simd() {
+        use core::simd::f32x4;
+
+        let t = f32x4::from_array([0.1, 0.3, 0.6, 0.9]);
+        assert_eq!(Easing::InOutExpo.apply(t), t.ease_in_out_expo());
+        assert_eq!(Easing::InCurve(2.0).apply(t), t.ease_in_curve(2.0f32));
+
+        let outside = [-1.3f32, -0.2, 1.1, 2.6];
+        for policy in [RangePolicy::Clamp, RangePolicy::Extend] {
+            let simd = Easing::OutCirc.apply_with(f32x4::from_array(outside), policy);
+            for (t, value) in outside.into_iter().zip(simd.to_array()) {
+                assert_eq!(value, Easing::OutCirc.apply_with(t, policy));
+            }
+        }
+    }
+}
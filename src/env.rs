@@ -0,0 +1,1028 @@
+// Copyright (C) 2025 Tim Blechmann
+// SPDX-License-Identifier: MIT
+
+//! Breakpoint envelopes in the style of SuperCollider's `Env`, and their OSC encoding.
+//!
+//! [`Env::to_osc`] flattens an envelope into the layout of SuperCollider's `Env.asArray`, the
+//! format `EnvGen` reads its envelope from. A live-coding rig can edit envelopes in Rust and send
+//! them as an array control, e.g. `/n_setn <node> env <size> <values...>` to a synth declared with
+//! `EnvGen.kr(\env.kr(Env.newClear(8).asArray), gate)`. All values are plain floats, so the same
+//! list can be unpacked in Max from a `[udpreceive]`.
+//!
+//! ```text
+//! [initial level, segment count, release node, loop node,
+//!  level 1, time 1, shape number 1, curve 1,
+//!  level 2, time 2, shape number 2, curve 2, ...]
+//! ```
+//!
+//! Times are in seconds, missing nodes are encoded as `-99`, and the curve value is only used by
+//! [`Shape::Curve`]. [`Env::from_osc`] decodes the same layout.
+//!
+//! ```
+//! # use nova_easing::env::{Env, Shape};
+//! let adsr = Env::new(0.0)
+//!     .segment(1.0, 0.01, Shape::Curve(-4.0))
+//!     .segment(0.5, 0.3, Shape::Exponential)
+//!     .segment(0.0, 1.0, Shape::Curve(-4.0))
+//!     .with_release_node(2);
+//!
+//! let message = adsr.to_osc();
+//! assert_eq!(message[..8], [0.0, 3.0, 2.0, -99.0, 1.0, 0.01, 5.0, -4.0]);
+//! assert_eq!(Env::from_osc(&message), Ok(adsr));
+//! ```
+
+use alloc::vec::Vec;
+use core::fmt;
+#[allow(unused_imports)]
+use num_traits::Float;
+
+use crate::track::Track;
+use crate::{Easing, EasingArgument, batch};
+
+// The node index SuperCollider uses for "no node".
+const NO_NODE: f32 = -99.0;
+
+/// The shape of an envelope segment, with the shape numbers of SuperCollider's `Env`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Shape {
+    /// Jumps to the end level at the start of the segment (`\step`, 0).
+    Step,
+    /// Linear (`\lin`, 1).
+    Linear,
+    /// Exponential, for levels of the same sign and non-zero (`\exp`, 2).
+    Exponential,
+    /// Sinusoidal S-shape, like [`EasingArgument::ease_in_out_sine`] (`\sin`, 3).
+    Sine,
+    /// Quarter sine, steep near the lower level (`\wel`, 4).
+    Welch,
+    /// Exponential curvature, like [`EasingArgument::ease_in_curve`] (a number, 5).
+    Curve(f32),
+    /// Linear on the square roots of the levels, for non-negative levels (`\sqr`, 6).
+    Squared,
+    /// Linear on the cube roots of the levels (`\cub`, 7).
+    Cubed,
+    /// Holds the start level and jumps to the end level at the end of the segment (`\hold`, 8).
+    Hold,
+    /// Any built-in easing, e.g. [`Easing::OutBounce`] for a bouncing release.
+    ///
+    /// `EnvGen` has no equivalent, so [`to_sc`](Self::to_sc) encodes it as [`Linear`](Self::Linear).
+    Eased(Easing),
+}
+
+impl Shape {
+    /// The shape number and curve value of SuperCollider's `Env.asArray`.
+    pub fn to_sc(self) -> (f32, f32) {
+        match self {
+            Shape::Step => (0.0, 0.0),
+            Shape::Linear => (1.0, 0.0),
+            Shape::Exponential => (2.0, 0.0),
+            Shape::Sine => (3.0, 0.0),
+            Shape::Welch => (4.0, 0.0),
+            Shape::Curve(curve) => (5.0, curve),
+            Shape::Squared => (6.0, 0.0),
+            Shape::Cubed => (7.0, 0.0),
+            Shape::Hold => (8.0, 0.0),
+            Shape::Eased(_) => (1.0, 0.0),
+        }
+    }
+
+    /// The shape of a SuperCollider shape number and curve value, or `None` for unknown numbers.
+    pub fn from_sc(number: f32, curve: f32) -> Option<Self> {
+        Some(match number {
+            0.0 => Shape::Step,
+            1.0 => Shape::Linear,
+            2.0 => Shape::Exponential,
+            3.0 => Shape::Sine,
+            4.0 => Shape::Welch,
+            5.0 => Shape::Curve(curve),
+            6.0 => Shape::Squared,
+            7.0 => Shape::Cubed,
+            8.0 => Shape::Hold,
+            _ => return None,
+        })
+    }
+
+    /// Interpolates from `start` to `end` at the fraction `pos` of the segment, like `EnvGen`.
+    pub fn interpolate(self, start: f32, end: f32, pos: f32) -> f32 {
+        let pos = pos.clamp(0.0, 1.0);
+        match self {
+            Shape::Step => end,
+            Shape::Hold if pos < 1.0 => start,
+            Shape::Hold => end,
+            Shape::Linear => start + (end - start) * pos,
+            Shape::Exponential => start * (end / start).powf(pos),
+            Shape::Sine => start + (end - start) * pos.ease_in_out_sine(),
+            Shape::Welch if start < end => start + (end - start) * pos.ease_out_sine(),
+            Shape::Welch => start + (end - start) * pos.ease_in_sine(),
+            Shape::Curve(curve) => start + (end - start) * pos.ease_in_curve(curve),
+            Shape::Squared => {
+                let (start, end) = (start.sqrt(), end.sqrt());
+                (start + (end - start) * pos).powi(2)
+            }
+            Shape::Cubed => {
+                let (start, end) = (start.cbrt(), end.cbrt());
+                (start + (end - start) * pos).powi(3)
+            }
+            Shape::Eased(easing) => start + (end - start) * easing.apply(pos),
+        }
+    }
+
+    // The easing of the shapes which are a plain `start + (end - start) * easing(pos)`.
+    fn easing(self, start: f32, end: f32) -> Option<Easing> {
+        match self {
+            Shape::Linear => Some(Easing::Linear),
+            Shape::Sine => Some(Easing::InOutSine),
+            Shape::Welch if start < end => Some(Easing::OutSine),
+            Shape::Welch => Some(Easing::InSine),
+            Shape::Curve(curve) => Some(Easing::InCurve(curve)),
+            Shape::Eased(easing) => Some(easing),
+            _ => None,
+        }
+    }
+}
+
+/// A segment of an [`Env`], moving from the previous level to `level`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Segment {
+    /// The level at the end of the segment.
+    pub level: f32,
+    /// The duration of the segment, in seconds.
+    pub time: f32,
+    /// The shape of the transition.
+    pub shape: Shape,
+}
+
+/// A breakpoint envelope: an initial level, followed by shaped segments.
+///
+/// Like in SuperCollider, the release and loop nodes are indices of levels, where `0` is the
+/// initial level and `i` the end of the `i`-th segment. They are carried along for `EnvGen`, but
+/// [`level_at`](Self::level_at) plays the envelope straight through.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Env {
+    initial_level: f32,
+    segments: Vec<Segment>,
+    release_node: Option<usize>,
+    loop_node: Option<usize>,
+}
+
+impl Env {
+    /// Creates an envelope holding `initial_level`, without segments.
+    pub fn new(initial_level: f32) -> Self {
+        Self {
+            initial_level,
+            segments: Vec::new(),
+            release_node: None,
+            loop_node: None,
+        }
+    }
+
+    /// Appends a segment moving to `level` within `time` seconds.
+    pub fn segment(mut self, level: f32, time: f32, shape: Shape) -> Self {
+        self.segments.push(Segment { level, time, shape });
+        self
+    }
+
+    /// Sets the level index the envelope sustains at until released.
+    pub fn with_release_node(mut self, node: usize) -> Self {
+        self.release_node = Some(node);
+        self
+    }
+
+    /// Sets the level index the envelope loops back to from its release node.
+    pub fn with_loop_node(mut self, node: usize) -> Self {
+        self.loop_node = Some(node);
+        self
+    }
+
+    /// The level at the start of the envelope.
+    pub fn initial_level(&self) -> f32 {
+        self.initial_level
+    }
+
+    /// The segments of the envelope.
+    pub fn segments(&self) -> &[Segment] {
+        &self.segments
+    }
+
+    /// The release node, if any.
+    pub fn release_node(&self) -> Option<usize> {
+        self.release_node
+    }
+
+    /// The loop node, if any.
+    pub fn loop_node(&self) -> Option<usize> {
+        self.loop_node
+    }
+
+    /// The total duration of all segments, in seconds.
+    pub fn duration(&self) -> f32 {
+        self.segments.iter().map(|segment| segment.time).sum()
+    }
+
+    /// The level at `time` seconds, holding the initial and final levels outside of the envelope.
+    pub fn level_at(&self, time: f32) -> f32 {
+        let mut start_time = 0.0;
+        let mut start_level = self.initial_level;
+        for segment in &self.segments {
+            let end_time = start_time + segment.time;
+            if time < end_time {
+                if time < start_time {
+                    break;
+                }
+                let pos = (time - start_time) / segment.time;
+                return segment.shape.interpolate(start_level, segment.level, pos);
+            }
+            start_time = end_time;
+            start_level = segment.level;
+        }
+        if time < 0.0 {
+            self.initial_level
+        } else {
+            start_level
+        }
+    }
+
+    /// Renders the envelope into `dst`, with the first element at `start` seconds and
+    /// `sample_rate` elements per second.
+    ///
+    /// The elements are the levels of [`level_at`](Self::level_at) up to rounding. Segments with a
+    /// shape based on an easing, e.g. [`Shape::Curve`] or [`Shape::Eased`], are computed with the
+    /// SIMD kernels of the [`batch`] module.
+    ///
+    /// Time passes at the global [time scale](crate::tween::set_time_scale) like for tweens, so at
+    /// a scale of 0.5 the elements are `0.5 / sample_rate` seconds apart, and at 0 all elements
+    /// hold the level at `start`.
+    ///
+    /// ```
+    /// # use nova_easing::{Easing, env::{Env, Shape}};
+    /// let env = Env::new(0.0)
+    ///     .segment(1.0, 0.01, Shape::Eased(Easing::OutElastic))
+    ///     .segment(0.0, 0.5, Shape::Eased(Easing::OutBounce));
+    /// let mut block = [0.0f32; 64];
+    /// env.render(&mut block, 0.0, 1000.0);
+    /// assert_eq!(block[0], 0.0);
+    /// assert!((block[40] - env.level_at(0.04)).abs() < 1e-5);
+    /// ```
+    pub fn render(&self, dst: &mut [f32], start: f32, sample_rate: f32) {
+        self.render_scaled(dst, start, sample_rate, crate::tween::time_scale());
+    }
+
+    fn render_scaled(&self, dst: &mut [f32], start: f32, sample_rate: f32, scale: f32) {
+        let len = dst.len();
+        let time = |index: usize| start + index as f32 * scale / sample_rate;
+        // The first index from `index` on whose time is not before `limit`.
+        let boundary = |index: usize, limit: f32| {
+            // NaN, for a limit at `start` with a scale of 0, falls back to `index`.
+            let estimate = ((limit - start) * sample_rate / scale).ceil();
+            let mut end = estimate.max(index as f32).min(len as f32) as usize;
+            while end > index && time(end - 1) >= limit {
+                end -= 1;
+            }
+            while end < len && time(end) < limit {
+                end += 1;
+            }
+            end
+        };
+
+        let mut index = boundary(0, 0.0);
+        dst[..index].fill(self.initial_level);
+        let mut start_time = 0.0;
+        let mut start_level = self.initial_level;
+        for segment in &self.segments {
+            let end_time = start_time + segment.time;
+            let end = boundary(index, end_time);
+            if end > index {
+                let run = &mut dst[index..end];
+                let position = |index: usize| (time(index) - start_time) / segment.time;
+                match segment.shape.easing(start_level, segment.level) {
+                    Some(easing) => {
+                        batch::fill_eased_segment(
+                            run,
+                            0,
+                            position(index),
+                            scale / (segment.time * sample_rate),
+                            start_level,
+                            segment.level,
+                            easing,
+                        )
+                    }
+                    None => {
+                        for (offset, level) in run.iter_mut().enumerate() {
+                            let pos = position(index + offset);
+                            *level = segment.shape.interpolate(start_level, segment.level, pos);
+                        }
+                    }
+                }
+            }
+            index = end;
+            start_time = end_time;
+            start_level = segment.level;
+        }
+        dst[index..].fill(start_level);
+    }
+
+    /// Encodes the envelope in the layout of SuperCollider's `Env.asArray`, see the
+    /// [module documentation](self).
+    pub fn to_osc(&self) -> Vec<f32> {
+        let node = |node: Option<usize>| node.map_or(NO_NODE, |node| node as f32);
+        let mut message = Vec::with_capacity(4 + 4 * self.segments.len());
+        message.extend([
+            self.initial_level,
+            self.segments.len() as f32,
+            node(self.release_node),
+            node(self.loop_node),
+        ]);
+        for segment in &self.segments {
+            let (number, curve) = segment.shape.to_sc();
+            message.extend([segment.level, segment.time, number, curve]);
+        }
+        message
+    }
+
+    /// Decodes an envelope from the layout of SuperCollider's `Env.asArray`.
+    pub fn from_osc(message: &[f32]) -> Result<Self, DecodeEnvError> {
+        let [initial_level, count, release_node, loop_node, rest @ ..] = message else {
+            return Err(DecodeEnvError::InvalidLength(message.len()));
+        };
+        if *count < 0.0 || count.fract() != 0.0 {
+            return Err(DecodeEnvError::InvalidSegmentCount(*count));
+        }
+        let count = *count as usize;
+        // `Env.newClear` pads unused segments, so trailing values are allowed. Dividing instead of
+        // multiplying keeps huge counts from overflowing.
+        if rest.len() / 4 < count {
+            return Err(DecodeEnvError::InvalidLength(message.len()));
+        }
+
+        let node = |node: f32| {
+            match node {
+                NO_NODE => Ok(None),
+                node if node >= 0.0 && node <= count as f32 && node.fract() == 0.0 => {
+                    Ok(Some(node as usize))
+                }
+                node => Err(DecodeEnvError::InvalidNode(node)),
+            }
+        };
+        let segments = rest
+            .chunks_exact(4)
+            .take(count)
+            .map(|chunk| {
+                let &[level, time, number, curve] = chunk else {
+                    unreachable!()
+                };
+                let shape =
+                    Shape::from_sc(number, curve).ok_or(DecodeEnvError::UnknownShape(number))?;
+                Ok(Segment { level, time, shape })
+            })
+            .collect::<Result<_, _>>()?;
+
+        Ok(Self {
+            initial_level: *initial_level,
+            segments,
+            release_node: node(*release_node)?,
+            loop_node: node(*loop_node)?,
+        })
+    }
+}
+
+/// Converts automation keyframes into linear segments, holding the first value until its key.
+impl From<&Track> for Env {
+    fn from(track: &Track) -> Self {
+        let keyframes = track.keyframes();
+        let Some(first) = keyframes.first() else {
+            return Env::new(0.0);
+        };
+        let mut env = Env::new(first.value);
+        if first.time > 0.0 {
+            env = env.segment(first.value, first.time, Shape::Linear);
+        }
+        for pair in keyframes.windows(2) {
+            env = env.segment(pair[1].value, pair[1].time - pair[0].time, Shape::Linear);
+        }
+        env
+    }
+}
+
+/// Renders an [`Env`] block by block, e.g. in an audio callback, keeping its position as an exact
+/// sample count, plus a fixed-point fraction of a sample at time scales other than 1.
+///
+/// Positions are computed from the sample index within the segment, so rounding errors do not
+/// accumulate across blocks. The position can be saved with [`state`](Self::state) and restored
+/// with [`restore`](Self::restore), e.g. to checkpoint an offline bounce or to continue rendering
+/// in another process: a restored renderer continues bit-exactly like the renderer the state was
+/// taken from.
+///
+/// ```
+/// # use nova_easing::env::{Env, EnvRenderer, Shape};
+/// let env = Env::new(0.0)
+///     .segment(1.0, 0.01, Shape::Curve(-4.0))
+///     .segment(0.0, 0.05, Shape::Sine);
+/// let mut renderer = EnvRenderer::new(&env, 1000.0);
+/// let mut first = [0.0f32; 32];
+/// renderer.render(&mut first);
+///
+/// // Resume from a checkpoint, e.g. after a restart.
+/// let checkpoint = renderer.state();
+/// let mut resumed = EnvRenderer::new(&env, 1000.0);
+/// resumed.restore(checkpoint);
+/// let (mut a, mut b) = ([0.0f32; 64], [0.0f32; 64]);
+/// renderer.render(&mut a);
+/// resumed.render(&mut b);
+/// assert_eq!(a, b);
+/// assert!(resumed.is_finished());
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct EnvRenderer {
+    runs: Vec<Run>,
+    final_level: f32,
+    state: RenderState,
+}
+
+/// The position of an [`EnvRenderer`], see [`EnvRenderer::state`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct RenderState {
+    segment: usize,
+    offset: u64,
+    // The part of a sample past `offset` in units of 2^-32, reached at time scales other than 1.
+    #[cfg_attr(feature = "serde", serde(default))]
+    fraction: u32,
+}
+
+impl RenderState {
+    /// The index of the current segment, or the segment count once the envelope is finished.
+    pub fn segment(&self) -> usize {
+        self.segment
+    }
+
+    /// The number of whole samples of the current segment rendered so far.
+    pub fn offset(&self) -> u64 {
+        self.offset
+    }
+
+    // The position in the current segment in samples.
+    fn position(&self) -> f64 {
+        self.offset as f64 + self.fraction as f64 / FRACTION_UNIT
+    }
+
+    fn at(segment: usize, position: f64) -> Self {
+        let offset = position.floor();
+        Self {
+            segment,
+            offset: offset as u64,
+            fraction: ((position - offset) * FRACTION_UNIT) as u32,
+        }
+    }
+}
+
+const FRACTION_UNIT: f64 = (1u64 << 32) as f64;
+
+// A segment in samples: `samples` levels with the positions `position + i * step`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Run {
+    samples: u64,
+    position: f32,
+    step: f32,
+    from: f32,
+    to: f32,
+    shape: Shape,
+}
+
+impl Run {
+    // The position at `offset` samples into the run, which is 1 at the end of the segment.
+    fn position_at(&self, offset: f64) -> f64 {
+        offset.mul_add(self.step as f64, self.position as f64)
+    }
+}
+
+impl EnvRenderer {
+    /// Creates a renderer for `env` at `sample_rate`, positioned at its start.
+    ///
+    /// The samples are assigned to the segments like in [`Env::render`], with the sample index `i`
+    /// at `i / sample_rate` seconds.
+    pub fn new(env: &Env, sample_rate: f32) -> Self {
+        let time = |index: u64| index as f32 / sample_rate;
+        // The first sample whose time is not before `limit`.
+        let boundary = |limit: f32| {
+            let mut end = (limit * sample_rate).ceil().max(0.0) as u64;
+            while end > 0 && time(end - 1) >= limit {
+                end -= 1;
+            }
+            while time(end) < limit {
+                end += 1;
+            }
+            end
+        };
+
+        let mut runs = Vec::with_capacity(env.segments.len());
+        let mut start_time = 0.0;
+        let mut start = 0;
+        let mut from = env.initial_level;
+        for segment in &env.segments {
+            let end_time = start_time + segment.time;
+            let end = boundary(end_time).max(start);
+            runs.push(Run {
+                samples: end - start,
+                position: (time(start) - start_time) / segment.time,
+                step: 1.0 / (segment.time * sample_rate),
+                from,
+                to: segment.level,
+                shape: segment.shape,
+            });
+            start_time = end_time;
+            start = end;
+            from = segment.level;
+        }
+        Self {
+            runs,
+            final_level: from,
+            state: RenderState::default(),
+        }
+    }
+
+    /// Renders the next `dst.len()` levels and advances, holding the final level at the end.
+    ///
+    /// Time passes at the global [time scale](crate::tween::set_time_scale) like for tweens, so at
+    /// a scale of 0.5 each level advances the envelope by half a sample, and at 0 the current level
+    /// is held.
+    pub fn render(&mut self, dst: &mut [f32]) {
+        let scale = crate::tween::time_scale();
+        if scale == 1.0 && self.state.fraction == 0 {
+            self.render_samples(dst);
+        } else {
+            self.render_scaled(dst, scale as f64);
+        }
+    }
+
+    // Renders at a time scale of 1 from a whole sample offset.
+    fn render_samples(&mut self, dst: &mut [f32]) {
+        let mut index = 0;
+        while let Some(run) = self.runs.get(self.state.segment) {
+            let remaining = run.samples - self.state.offset;
+            let end = dst
+                .len()
+                .min(index + remaining.min(usize::MAX as u64) as usize);
+            let first = self.state.offset as usize;
+            match run.shape.easing(run.from, run.to) {
+                Some(easing) => {
+                    batch::fill_eased_segment(
+                        &mut dst[index..end],
+                        first,
+                        run.position,
+                        run.step,
+                        run.from,
+                        run.to,
+                        easing,
+                    )
+                }
+                None => {
+                    for (offset, level) in dst[index..end].iter_mut().enumerate() {
+                        let pos = ((first + offset) as f32).mul_add(run.step, run.position);
+                        *level = run.shape.interpolate(run.from, run.to, pos);
+                    }
+                }
+            }
+            self.state.offset += (end - index) as u64;
+            if self.state.offset == run.samples {
+                self.state = RenderState {
+                    segment: self.state.segment + 1,
+                    ..RenderState::default()
+                };
+            }
+            index = end;
+            if index == dst.len() {
+                return;
+            }
+        }
+        dst[index..].fill(self.final_level);
+    }
+
+    // Renders with the envelope advancing by `scale` samples per level.
+    fn render_scaled(&mut self, dst: &mut [f32], scale: f64) {
+        let mut index = 0;
+        let mut segment = self.state.segment;
+        let mut offset = self.state.position();
+        while let Some(run) = self.runs.get(segment) {
+            if offset >= run.samples as f64 {
+                offset -= run.samples as f64;
+                segment += 1;
+                continue;
+            }
+            if index == dst.len() {
+                break;
+            }
+            let count = if scale == 0.0 {
+                usize::MAX
+            } else {
+                ((run.samples as f64 - offset) / scale).ceil().max(1.0) as usize
+            };
+            let end = dst.len().min(index.saturating_add(count));
+            let offset_of = |index: usize| (index as f64).mul_add(scale, offset);
+
+            // Between the end of the segment and the next whole sample, the levels belong to the
+            // following segments.
+            let mut inside = end;
+            while inside > index && run.position_at(offset_of(inside - 1 - index)) >= 1.0 {
+                inside -= 1;
+            }
+            match run.shape.easing(run.from, run.to) {
+                Some(easing) => {
+                    batch::fill_eased_segment(
+                        &mut dst[index..inside],
+                        0,
+                        run.position_at(offset) as f32,
+                        (scale * run.step as f64) as f32,
+                        run.from,
+                        run.to,
+                        easing,
+                    )
+                }
+                None => {
+                    for (i, level) in dst[index..inside].iter_mut().enumerate() {
+                        let pos = run.position_at(offset_of(i)) as f32;
+                        *level = run.shape.interpolate(run.from, run.to, pos);
+                    }
+                }
+            }
+            for (i, level) in dst[inside..end].iter_mut().enumerate() {
+                *level = self.level_after(segment, offset_of(inside - index + i));
+            }
+            offset = offset_of(end - index);
+            index = end;
+        }
+        if segment == self.runs.len() {
+            offset = 0.0;
+        }
+        self.state = RenderState::at(segment, offset);
+        dst[index..].fill(self.final_level);
+    }
+
+    // The level `offset` samples into `segment`, past the end of the segment.
+    fn level_after(&self, segment: usize, mut offset: f64) -> f32 {
+        for run in &self.runs[segment..] {
+            // Not taken for the NaN and infinite positions of segments without duration.
+            let pos = run.position_at(offset);
+            if pos < 1.0 {
+                return run.shape.interpolate(run.from, run.to, pos as f32);
+            }
+            offset -= run.samples as f64;
+        }
+        self.final_level
+    }
+
+    /// Whether all segments have been rendered, so only the final level follows.
+    pub fn is_finished(&self) -> bool {
+        self.state.segment == self.runs.len()
+    }
+
+    /// The current position, to be passed to [`restore`](Self::restore).
+    pub fn state(&self) -> RenderState {
+        self.state
+    }
+
+    /// Continues from `state`, taken from a renderer of the same envelope and sample rate.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `state` is beyond the end of the envelope.
+    pub fn restore(&mut self, state: RenderState) {
+        let valid = match self.runs.get(state.segment) {
+            Some(run) => state.offset < run.samples,
+            None => state.segment == self.runs.len() && state.position() == 0.0,
+        };
+        assert!(valid, "render state is beyond the end of the envelope");
+        self.state = state;
+    }
+
+    /// Rewinds to the start of the envelope.
+    pub fn reset(&mut self) {
+        self.state = RenderState::default();
+    }
+}
+
+/// The error of decoding an [`Env`] with [`Env::from_osc`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum DecodeEnvError {
+    /// The message of the given length is too short for its segment count.
+    InvalidLength(usize),
+    /// The segment count is negative or not an integer.
+    InvalidSegmentCount(f32),
+    /// The shape number is not one of SuperCollider's.
+    UnknownShape(f32),
+    /// The release or loop node is neither `-99` nor the index of a level.
+    InvalidNode(f32),
+}
+
+impl fmt::Display for DecodeEnvError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DecodeEnvError::InvalidLength(length) => {
+                write!(f, "envelope message of length {length} is truncated")
+            }
+            DecodeEnvError::InvalidSegmentCount(count) => {
+                write!(f, "invalid envelope segment count {count}")
+            }
+            DecodeEnvError::UnknownShape(number) => write!(f, "unknown envelope shape {number}"),
+            DecodeEnvError::InvalidNode(node) => write!(f, "invalid envelope node {node}"),
+        }
+    }
+}
+
+impl core::error::Error for DecodeEnvError {}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::{DecodeEnvError, Env, EnvRenderer, Shape};
+    use crate::track::{Keyframe, Track};
+    use crate::{Easing, EasingArgument};
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn shapes() {
+        assert_eq!(Shape::Step.interpolate(0.0, 1.0, 0.0), 1.0);
+        assert_eq!(Shape::Hold.interpolate(0.0, 1.0, 0.99), 0.0);
+        assert_eq!(Shape::Hold.interpolate(0.0, 1.0, 1.0), 1.0);
+        assert_eq!(Shape::Linear.interpolate(2.0, 4.0, 0.25), 2.5);
+        assert_relative_eq!(Shape::Exponential.interpolate(1.0, 100.0, 0.5), 10.0);
+        assert_relative_eq!(
+            Shape::Sine.interpolate(0.0, 2.0, 0.25),
+            2.0 * 0.25.ease_in_out_sine()
+        );
+        assert_relative_eq!(Shape::Squared.interpolate(0.0, 4.0, 0.5), 1.0);
+        assert_relative_eq!(Shape::Cubed.interpolate(-8.0, 8.0, 0.25), -1.0);
+        assert_relative_eq!(
+            Shape::Curve(-4.0).interpolate(1.0, 0.0, 0.3),
+            1.0 - 0.3.ease_in_curve(-4.0)
+        );
+
+        // Welch is steep near the lower level in both directions.
+        assert_relative_eq!(Shape::Welch.interpolate(0.0, 1.0, 0.5), 0.5.ease_out_sine());
+        assert_relative_eq!(
+            Shape::Welch.interpolate(1.0, 0.0, 0.5),
+            1.0 - 0.5.ease_in_sine()
+        );
+
+        for number in 0..=8 {
+            let shape = Shape::from_sc(number as f32, 2.0).unwrap();
+            assert_eq!(
+                Shape::from_sc(shape.to_sc().0, shape.to_sc().1),
+                Some(shape)
+            );
+        }
+        assert_relative_eq!(
+            Shape::Eased(Easing::OutBounce).interpolate(1.0, 0.0, 0.6),
+            1.0 - 0.6.ease_out_bounce()
+        );
+        assert_eq!(Shape::Eased(Easing::InBack).to_sc(), Shape::Linear.to_sc());
+        assert_eq!(Shape::from_sc(9.0, 0.0), None);
+        assert_eq!(Shape::from_sc(1.5, 0.0), None);
+    }
+
+    #[test]
+    fn level_at() {
+        let env = Env::new(0.0)
+            .segment(1.0, 1.0, Shape::Linear)
+            .segment(0.5, 2.0, Shape::Linear);
+        assert_eq!(env.duration(), 3.0);
+        assert_eq!(env.level_at(-1.0), 0.0);
+        assert_eq!(env.level_at(0.5), 0.5);
+        assert_eq!(env.level_at(1.0), 1.0);
+        assert_eq!(env.level_at(2.0), 0.75);
+        assert_eq!(env.level_at(5.0), 0.5);
+        assert_eq!(Env::new(0.25).level_at(1.0), 0.25);
+    }
+
+    #[test]
+    fn render() {
+        let env = Env::new(0.2)
+            .segment(1.0, 0.013, Shape::Eased(Easing::OutElastic))
+            .segment(0.6, 0.0, Shape::Linear)
+            .segment(0.5, 0.02, Shape::Welch)
+            .segment(0.5, 0.005, Shape::Hold)
+            .segment(0.1, 0.03, Shape::Exponential)
+            .segment(0.0, 0.05, Shape::Eased(Easing::OutBounce));
+        let sample_rate = 1000.0;
+        let mut block = [0.0f32; 131];
+        for start in [-0.0105, 0.0, 0.0317] {
+            env.render(&mut block, start, sample_rate);
+            for (index, level) in block.into_iter().enumerate() {
+                let time = start + index as f32 / sample_rate;
+                assert_relative_eq!(level, env.level_at(time), epsilon = 1e-5);
+            }
+        }
+
+        // Empty envelopes and blocks.
+        let mut block = [1.0f32; 4];
+        Env::new(0.5).render(&mut block, 0.0, sample_rate);
+        assert_eq!(block, [0.5; 4]);
+        env.render(&mut [], 0.0, sample_rate);
+    }
+
+    #[test]
+    fn renderer() {
+        let env = Env::new(0.2)
+            .segment(1.0, 0.013, Shape::Eased(Easing::OutBack))
+            .segment(0.5, 0.0, Shape::Step)
+            .segment(0.05, 0.0211, Shape::Exponential)
+            .segment(0.3, 0.0005, Shape::Linear)
+            .segment(0.0, 0.04, Shape::Curve(-4.0));
+        let mut expected = [0.0f32; 100];
+        env.render(&mut expected, 0.0, 1000.0);
+
+        let mut renderer = EnvRenderer::new(&env, 1000.0);
+        let mut rendered = [0.0f32; 100];
+        let mut blocks = [1, 7, 13, 2, 30, 47].into_iter().cycle();
+        let mut index = 0;
+        while index < rendered.len() {
+            let end = (index + blocks.next().unwrap()).min(rendered.len());
+            renderer.render(&mut rendered[index..end]);
+            index = end;
+        }
+        for (rendered, expected) in rendered.iter().zip(expected) {
+            assert_relative_eq!(*rendered, expected, epsilon = 1e-5);
+        }
+        assert!(renderer.is_finished());
+        assert_eq!(renderer.state().segment(), 5);
+
+        renderer.reset();
+        let mut block = [0.0f32; 20];
+        renderer.render(&mut block);
+        assert_eq!(block, rendered[..20]);
+        assert_eq!(renderer.state().segment(), 2);
+    }
+
+    #[test]
+    fn renderer_checkpoints() {
+        let env = Env::new(0.0)
+            .segment(1.0, 0.5, Shape::Eased(Easing::InOutElastic))
+            .segment(0.25, 1.0, Shape::Welch);
+        let mut renderer = EnvRenderer::new(&env, 44100.0);
+        let mut block = [0.0f32; 4000];
+        for _ in 0..8 {
+            renderer.render(&mut block);
+        }
+        let checkpoint = renderer.state();
+        assert_eq!((checkpoint.segment(), checkpoint.offset()), (1, 9950));
+
+        let mut resumed = EnvRenderer::new(&env, 44100.0);
+        resumed.restore(checkpoint);
+        let mut expected = [0.0f32; 4000];
+        while !renderer.is_finished() {
+            renderer.render(&mut expected);
+            resumed.render(&mut block);
+            assert_eq!(block, expected);
+        }
+        assert_eq!(resumed.state(), renderer.state());
+    }
+
+    // The global time scale is shared with the tests running in parallel, so the scale is passed
+    // to the private render functions instead.
+    #[test]
+    fn time_scale() {
+        let env = Env::new(0.2)
+            .segment(1.0, 0.013, Shape::Eased(Easing::OutBack))
+            .segment(0.5, 0.0, Shape::Step)
+            .segment(0.05, 0.0211, Shape::Exponential)
+            .segment(0.0, 0.04, Shape::Curve(-4.0));
+        let sample_rate = 1000.0;
+        let mut block = [0.0f32; 200];
+        for scale in [0.0, 0.37, 1.0, 2.5] {
+            env.render_scaled(&mut block, 0.004, sample_rate, scale);
+            for (index, level) in block.into_iter().enumerate() {
+                let time = 0.004 + index as f32 * scale / sample_rate;
+                assert_relative_eq!(level, env.level_at(time), epsilon = 1e-5);
+            }
+        }
+
+        for scale in [0.37, 0.5, 2.5] {
+            let mut expected = [0.0f32; 300];
+            env.render_scaled(&mut expected, 0.0, sample_rate, scale);
+            let mut renderer = EnvRenderer::new(&env, sample_rate);
+            let mut rendered = [0.0f32; 300];
+            let mut blocks = [1, 7, 13, 2, 30, 47].into_iter().cycle();
+            let mut index = 0;
+            while index < rendered.len() {
+                let end = (index + blocks.next().unwrap()).min(rendered.len());
+                renderer.render_scaled(&mut rendered[index..end], scale as f64);
+                // Pausing holds the level without advancing.
+                let state = renderer.state();
+                let mut paused = [0.0f32; 3];
+                renderer.render_scaled(&mut paused, 0.0);
+                assert_eq!(renderer.state(), state);
+                index = end;
+            }
+            for (rendered, expected) in rendered.iter().zip(expected) {
+                assert_relative_eq!(*rendered, expected, epsilon = 1e-5);
+            }
+            assert!(renderer.is_finished());
+        }
+
+        // Fractional positions are restored and continued at a scale of 1.
+        let mut renderer = EnvRenderer::new(&env, sample_rate);
+        renderer.render_scaled(&mut [0.0; 15], 0.5);
+        let checkpoint = renderer.state();
+        assert_eq!((checkpoint.segment(), checkpoint.offset()), (0, 7));
+        let mut resumed = EnvRenderer::new(&env, sample_rate);
+        resumed.restore(checkpoint);
+        let (mut a, mut b) = ([0.0f32; 40], [0.0f32; 40]);
+        renderer.render_scaled(&mut a, 1.0);
+        resumed.render_scaled(&mut b, 1.0);
+        assert_eq!(a, b);
+        let mut expected = [0.0f32; 40];
+        env.render_scaled(&mut expected, 0.0075, sample_rate, 1.0);
+        for (rendered, expected) in a.iter().zip(expected) {
+            assert_relative_eq!(*rendered, expected, epsilon = 1e-5);
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "render state is beyond the end of the envelope")]
+    fn renderer_invalid_state() {
+        let env = Env::new(0.0).segment(1.0, 0.01, Shape::Linear);
+        let mut renderer = EnvRenderer::new(&env, 1000.0);
+        let mut other = EnvRenderer::new(&env.clone().segment(0.0, 1.0, Shape::Linear), 1000.0);
+        other.render(&mut [0.0; 20]);
+        renderer.restore(other.state());
+    }
+
+    #[test]
+    fn osc_round_trip() {
+        let env = Env::new(0.1)
+            .segment(1.0, 0.5, Shape::Curve(3.0))
+            .segment(0.2, 0.25, Shape::Welch)
+            .segment(0.0, 1.5, Shape::Hold)
+            .with_release_node(2)
+            .with_loop_node(1);
+        let message = env.to_osc();
+        assert_eq!(
+            message,
+            [
+                0.1, 3.0, 2.0, 1.0, 1.0, 0.5, 5.0, 3.0, 0.2, 0.25, 4.0, 0.0, 0.0, 1.5, 8.0, 0.0
+            ]
+        );
+        assert_eq!(Env::from_osc(&message), Ok(env));
+
+        // Padding of `Env.newClear` is ignored.
+        let mut padded = Env::new(0.0).segment(1.0, 1.0, Shape::Linear).to_osc();
+        padded.extend([0.0; 8]);
+        assert_eq!(Env::from_osc(&padded).unwrap().segments().len(), 1);
+    }
+
+    #[test]
+    fn decode_errors() {
+        let decode = |message: &[f32]| Env::from_osc(message).unwrap_err();
+        assert_eq!(decode(&[0.0, 0.0, -99.0]), DecodeEnvError::InvalidLength(3));
+        assert_eq!(
+            decode(&[0.0, 1.0, -99.0, -99.0, 1.0, 1.0, 1.0]),
+            DecodeEnvError::InvalidLength(7)
+        );
+        assert_eq!(
+            decode(&[0.0, 1e20, -99.0, -99.0]),
+            DecodeEnvError::InvalidLength(4)
+        );
+        assert_eq!(
+            decode(&[0.0, (1u64 << 62) as f32, -99.0, -99.0, 1.0, 1.0, 1.0, 0.0]),
+            DecodeEnvError::InvalidLength(8)
+        );
+        assert_eq!(
+            decode(&[0.0, 0.5, -99.0, -99.0]),
+            DecodeEnvError::InvalidSegmentCount(0.5)
+        );
+        assert_eq!(
+            decode(&[0.0, 1.0, -99.0, -99.0, 1.0, 1.0, 12.0, 0.0]),
+            DecodeEnvError::UnknownShape(12.0)
+        );
+        assert_eq!(
+            decode(&[0.0, 1.0, 2.0, -99.0, 1.0, 1.0, 1.0, 0.0]),
+            DecodeEnvError::InvalidNode(2.0)
+        );
+        assert_eq!(
+            DecodeEnvError::UnknownShape(12.0).to_string(),
+            "unknown envelope shape 12"
+        );
+    }
+
+    #[test]
+    fn from_track() {
+        let track = Track::new(vec![
+            Keyframe {
+                time: 0.5,
+                value: 1.0,
+            },
+            Keyframe {
+                time: 1.5,
+                value: 3.0,
+            },
+        ]);
+        let env = Env::from(&track);
+        for time in [0.0, 0.25, 0.75, 1.0, 1.5, 2.0] {
+            assert_relative_eq!(env.level_at(time), track.sample(time));
+        }
+        assert_eq!(Env::from(&Track::default()), Env::new(0.0));
+    }
+}
@@ -0,0 +1,224 @@
+// Copyright (C) 2025 Tim Blechmann
+// SPDX-License-Identifier: MIT
+
+//! Audio fades in decibels: attenuation along a fade and the fade lengths to reach it.
+
+#[allow(unused_imports)]
+use num_traits::Float;
+
+use crate::Easing;
+use crate::scale::solve;
+
+/// Whether a fade raises the gain from silence to full level or lowers it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum FadeDirection {
+    /// The gain follows `easing(t)`, rising from 0 to 1.
+    In,
+    /// The gain follows `1 - easing(t)`, falling from 1 to 0.
+    Out,
+}
+
+/// The gain curve of an eased fade, measured in decibels relative to full level.
+///
+/// Fade positions are fractions in [0, 1] of the whole fade, so the results apply to fades of any
+/// length and in any unit (seconds, samples, beats).
+///
+/// ```
+/// # use nova_easing::{Easing, fade::Fade};
+/// // A linear fade-out is 6 dB down halfway through ...
+/// let fade = Fade::fade_out(Easing::Linear);
+/// assert!((fade.attenuation_db(0.5) + 6.0206).abs() < 1e-4);
+///
+/// // ... and reaches -60 dB after 99.9% of its length.
+/// assert!((fade.fraction_at_attenuation(-60.0) - 0.999).abs() < 1e-9);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Fade {
+    easing: Easing,
+    direction: FadeDirection,
+}
+
+impl Fade {
+    /// Creates a fade following `easing` in `direction`.
+    pub fn new(easing: Easing, direction: FadeDirection) -> Self {
+        Self { easing, direction }
+    }
+
+    /// Creates a fade-in following `easing`.
+    pub fn fade_in(easing: Easing) -> Self {
+        Self::new(easing, FadeDirection::In)
+    }
+
+    /// Creates a fade-out following `easing`.
+    pub fn fade_out(easing: Easing) -> Self {
+        Self::new(easing, FadeDirection::Out)
+    }
+
+    /// The easing.
+    pub fn easing(&self) -> Easing {
+        self.easing
+    }
+
+    /// The direction.
+    pub fn direction(&self) -> FadeDirection {
+        self.direction
+    }
+
+    /// The linear gain at `fraction` of the fade.
+    ///
+    /// Overshooting easings may yield gains above 1 or below 0 (a phase inversion).
+    pub fn gain(&self, fraction: f64) -> f64 {
+        let eased = self.easing.apply(fraction);
+        match self.direction {
+            FadeDirection::In => eased,
+            FadeDirection::Out => 1.0 - eased,
+        }
+    }
+
+    /// The attenuation in dB at `fraction` of the fade, e.g. `-6.02` for half the gain.
+    ///
+    /// Silence yields negative infinity.
+    pub fn attenuation_db(&self, fraction: f64) -> f64 {
+        gain_to_db(self.gain(fraction))
+    }
+
+    /// The fraction of the fade at which it passes `attenuation_db`, e.g. `-60.0`.
+    ///
+    /// Attenuations of 0 dB or above are clamped to full level and negative infinity to silence.
+    /// The easing is inverted numerically, which is exact for monotonic easings; overshooting
+    /// easings yield one of the crossings.
+    pub fn fraction_at_attenuation(&self, attenuation_db: f64) -> f64 {
+        let gain = db_to_gain(attenuation_db);
+        match self.direction {
+            FadeDirection::In if gain >= 1.0 => 1.0,
+            FadeDirection::Out if gain >= 1.0 => 0.0,
+            FadeDirection::In => solve(self.easing, gain),
+            FadeDirection::Out => solve(self.easing, 1.0 - gain),
+        }
+    }
+
+    /// The total fade length at which the fade passes `attenuation_db` after `elapsed`.
+    ///
+    /// E.g. a fade-out which should be 60 dB down after 2 seconds needs to be
+    /// `fade_length(-60.0, 2.0)` seconds long. Returns infinity if the attenuation is only reached
+    /// at the very start of the fade.
+    pub fn fade_length(&self, attenuation_db: f64, elapsed: f64) -> f64 {
+        elapsed / self.fraction_at_attenuation(attenuation_db)
+    }
+
+    /// The time after which a fade of `length` passes `attenuation_db`.
+    pub fn time_at_attenuation(&self, attenuation_db: f64, length: f64) -> f64 {
+        length * self.fraction_at_attenuation(attenuation_db)
+    }
+
+    /// The average power over the whole fade in dB, i.e. the level of a constant gain carrying the
+    /// same energy, e.g. `-4.77` for a linear fade.
+    pub fn average_attenuation_db(&self) -> f64 {
+        // Simpson's rule on the squared gain.
+        const INTERVALS: usize = 1024;
+        let power = |i: usize| self.gain(i as f64 / INTERVALS as f64).powi(2);
+        let inner: f64 = (1..INTERVALS)
+            .map(|i| if i % 2 == 1 { 4.0 } else { 2.0 } * power(i))
+            .sum();
+        let energy = (power(0) + inner + power(INTERVALS)) / (3.0 * INTERVALS as f64);
+        10.0 * energy.log10()
+    }
+}
+
+/// Converts a linear gain to decibels. The sign of the gain is ignored.
+pub fn gain_to_db(gain: f64) -> f64 {
+    20.0 * gain.abs().log10()
+}
+
+/// Converts decibels to a linear gain.
+pub fn db_to_gain(db: f64) -> f64 {
+    10.0f64.powf(db / 20.0)
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::{Fade, db_to_gain, gain_to_db};
+    use crate::Easing;
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn decibels() {
+        assert_relative_eq!(gain_to_db(0.5), -6.0206, epsilon = 1e-4);
+        assert_relative_eq!(db_to_gain(-60.0), 0.001, epsilon = 1e-15);
+        assert_eq!(gain_to_db(0.0), f64::NEG_INFINITY);
+        assert_relative_eq!(db_to_gain(gain_to_db(0.3)), 0.3, epsilon = 1e-12);
+    }
+
+    #[test]
+    fn attenuation() {
+        let fade_in = Fade::fade_in(Easing::InQuad);
+        assert_relative_eq!(fade_in.attenuation_db(0.5), -12.0412, epsilon = 1e-4);
+        assert_eq!(fade_in.attenuation_db(0.0), f64::NEG_INFINITY);
+        assert_eq!(fade_in.attenuation_db(1.0), 0.0);
+
+        let fade_out = Fade::fade_out(Easing::InQuad);
+        assert_relative_eq!(
+            fade_out.attenuation_db(0.5),
+            gain_to_db(0.75),
+            epsilon = 1e-12
+        );
+        assert_eq!(fade_out.attenuation_db(0.0), 0.0);
+    }
+
+    #[test]
+    fn fraction_at_attenuation() {
+        // 2^-10t == 10^-3
+        let fade_out = Fade::fade_out(Easing::OutExpo);
+        let expected = 1000.0f64.log2() / 10.0;
+        assert_relative_eq!(
+            fade_out.fraction_at_attenuation(-60.0),
+            expected,
+            epsilon = 1e-12
+        );
+
+        // t^2 == 10^-3
+        let fade_in = Fade::fade_in(Easing::InQuad);
+        let expected = 0.001f64.sqrt();
+        assert_relative_eq!(
+            fade_in.fraction_at_attenuation(-60.0),
+            expected,
+            epsilon = 1e-12
+        );
+
+        for fade in [fade_in, fade_out, Fade::fade_out(Easing::InOutSine)] {
+            let fraction = fade.fraction_at_attenuation(-24.0);
+            assert_relative_eq!(fade.attenuation_db(fraction), -24.0, epsilon = 1e-9);
+        }
+
+        assert_eq!(fade_out.fraction_at_attenuation(6.0), 0.0);
+        assert_eq!(fade_in.fraction_at_attenuation(6.0), 1.0);
+    }
+
+    #[test]
+    fn fade_lengths() {
+        let fade = Fade::fade_out(Easing::Linear);
+        assert_relative_eq!(fade.fade_length(-60.0, 2.0), 2.0 / 0.999, epsilon = 1e-9);
+        assert_relative_eq!(fade.time_at_attenuation(-6.0206, 4.0), 2.0, epsilon = 1e-4);
+        assert_eq!(fade.fade_length(0.0, 1.0), f64::INFINITY);
+    }
+
+    #[test]
+    fn average_attenuation() {
+        // The mean of t^2 over [0, 1] is 1/3.
+        let linear = Fade::fade_in(Easing::Linear).average_attenuation_db();
+        assert_relative_eq!(linear, 10.0 * (1.0f64 / 3.0).log10(), epsilon = 1e-9);
+        assert_relative_eq!(
+            Fade::fade_out(Easing::Linear).average_attenuation_db(),
+            linear,
+            epsilon = 1e-9
+        );
+
+        // Fast fade-outs carry less energy.
+        let fast = Fade::fade_out(Easing::OutQuart).average_attenuation_db();
+        assert!(fast < linear);
+    }
+}
@@ -0,0 +1,164 @@
+// Copyright (C) 2025 Tim Blechmann
+// SPDX-License-Identifier: MIT
+
+//! Approximate circular easings for `f32` SIMD vectors.
+//!
+//! The square root dominates the cost of the `Circ` easings. [`FastCirc`] replaces it by the
+//! hardware reciprocal square root estimate (`rsqrtps` on x86-64), refined by one Newton step, and
+//! a multiplication: `sqrt(x) = x * rsqrt(x)`. The result is within `1e-6` of the exact easing,
+//! which is below what 8-, 16- or 24-bit output can resolve. The endpoints are not exact, e.g.
+//! `ease_in_circ_fast(0)` may be `6e-8`.
+//!
+//! On other architectures, the exact reciprocal square root is used, with the same results as
+//! the exact easings up to rounding.
+//!
+//! ```
+//! #![feature(portable_simd)]
+//! use core::simd::f32x8;
+//! use nova_easing::EasingArgument;
+//! use nova_easing::fast_circ::FastCirc;
+//!
+//! let t = f32x8::splat(0.3);
+//! let difference = t.ease_out_circ_fast() - t.ease_out_circ();
+//! assert!(difference.to_array().iter().all(|d| d.abs() < 1e-6));
+//! ```
+
+use core::simd::cmp::{SimdPartialEq, SimdPartialOrd};
+use core::simd::{LaneCount, Simd, SupportedLaneCount};
+
+/// Circular easings computed with a reciprocal square root estimate. See the
+/// [module documentation](self).
+pub trait FastCirc {
+    /// Approximates [`ease_in_circ`](crate::EasingArgument::ease_in_circ).
+    fn ease_in_circ_fast(self) -> Self;
+    /// Approximates [`ease_out_circ`](crate::EasingArgument::ease_out_circ).
+    fn ease_out_circ_fast(self) -> Self;
+    /// Approximates [`ease_in_out_circ`](crate::EasingArgument::ease_in_out_circ).
+    fn ease_in_out_circ_fast(self) -> Self;
+}
+
+impl<const N: usize> FastCirc for Simd<f32, N>
+where
+    LaneCount<N>: SupportedLaneCount,
+{
+    #[inline]
+    fn ease_in_circ_fast(self) -> Self {
+        let one = Self::splat(1.0);
+        one - sqrt(one - self * self)
+    }
+
+    #[inline]
+    fn ease_out_circ_fast(self) -> Self {
+        let one = Self::splat(1.0);
+        let u = self - one;
+        sqrt(one - u * u)
+    }
+
+    #[inline]
+    fn ease_in_out_circ_fast(self) -> Self {
+        // Both halves take the root of `1 - u^2`, so a single root serves all lanes.
+        let (one, half) = (Self::splat(1.0), Self::splat(0.5));
+        let lower = self.simd_lt(half);
+        let double = self + self;
+        let u = lower.select(double, Self::splat(2.0) - double);
+        let root = sqrt(one - u * u);
+        lower.select(one - root, one + root) * half
+    }
+}
+
+// `x * rsqrt(x)`, with one Newton step `y (3 - x y^2) / 2` on the estimate. Zero would be
+// `0 * inf`, so it is selected explicitly.
+#[inline]
+fn sqrt<const N: usize>(x: Simd<f32, N>) -> Simd<f32, N>
+where
+    LaneCount<N>: SupportedLaneCount,
+{
+    let y = rsqrt_estimate(x);
+    let y = y * (Simd::splat(1.5) - Simd::splat(0.5) * x * y * y);
+    let zero = Simd::splat(0.0);
+    x.simd_eq(zero).select(zero, x * y)
+}
+
+// About 12 bits of `1 / sqrt(x)`, four lanes per `rsqrtps`.
+#[cfg(target_arch = "x86_64")]
+#[inline]
+fn rsqrt_estimate<const N: usize>(x: Simd<f32, N>) -> Simd<f32, N>
+where
+    LaneCount<N>: SupportedLaneCount,
+{
+    use core::arch::x86_64::_mm_rsqrt_ps;
+    use core::simd::f32x4;
+
+    let mut lanes = x.to_array();
+    for chunk in lanes.chunks_mut(4) {
+        let mut quad = [1.0f32; 4];
+        quad[..chunk.len()].copy_from_slice(chunk);
+        // SAFETY: SSE is enabled on every x86-64 target.
+        let estimate = f32x4::from(unsafe { _mm_rsqrt_ps(f32x4::from_array(quad).into()) });
+        chunk.copy_from_slice(&estimate[..chunk.len()]);
+    }
+    Simd::from_array(lanes)
+}
+
+#[cfg(not(target_arch = "x86_64"))]
+#[inline]
+fn rsqrt_estimate<const N: usize>(x: Simd<f32, N>) -> Simd<f32, N>
+where
+    LaneCount<N>: SupportedLaneCount,
+{
+    use core::simd::StdFloat;
+
+    Simd::splat(1.0) / x.sqrt()
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::FastCirc;
+    use crate::EasingArgument;
+    use core::simd::{f32x4, f32x8, f32x16};
+
+    // The largest deviation from the `f64` easing over a dense grid of [0, 1], in all lanes.
+    fn max_error(fast: fn(f32x8) -> f32x8, exact: fn(f64) -> f64) -> f64 {
+        let steps = 1 << 16;
+        (0..=steps)
+            .step_by(8)
+            .flat_map(|start| {
+                let t =
+                    core::array::from_fn(|lane| (start + lane).min(steps) as f32 / steps as f32);
+                let eased = fast(f32x8::from_array(t)).to_array();
+                (0..8).map(move |lane| (eased[lane] as f64 - exact(t[lane] as f64)).abs())
+            })
+            .fold(0.0, f64::max)
+    }
+
+    #[test]
+    fn error_bounds() {
+        let errors = [
+            max_error(f32x8::ease_in_circ_fast, f64::ease_in_circ),
+            max_error(f32x8::ease_out_circ_fast, f64::ease_out_circ),
+            max_error(f32x8::ease_in_out_circ_fast, f64::ease_in_out_circ),
+        ];
+        for error in errors {
+            assert!(error < 1e-6, "{errors:?}");
+        }
+    }
+
+    #[test]
+    fn lane_counts() {
+        let t = [0.0f32, 0.2, 0.5, 1.0];
+        let quad = f32x4::from_array(t).ease_in_out_circ_fast().to_array();
+        let wide = f32x16::splat(0.2).ease_in_circ_fast().to_array();
+        for (t, value) in t.into_iter().zip(quad) {
+            assert!((value - t.ease_in_out_circ()).abs() < 1e-6);
+        }
+        assert!(
+            wide.iter()
+                .all(|&value| (value - 0.2f32.ease_in_circ()).abs() < 1e-6)
+        );
+
+        // Outside of [0, 1], the root of a negative number is NaN, like the exact easing.
+        assert!(f32x4::splat(1.5).ease_in_circ_fast()[0].is_nan());
+    }
+}
@@ -0,0 +1,246 @@
+// Copyright (C) 2025 Tim Blechmann
+// SPDX-License-Identifier: MIT
+
+//! Color gradients with eased transitions between stops, and LUT export.
+
+use alloc::{vec, vec::Vec};
+#[allow(unused_imports)]
+use num_traits::Float;
+
+use crate::Easing;
+
+/// An sRGB color with alpha, each component in [0, 1].
+pub type Rgba = [f32; 4];
+
+/// The color space colors are interpolated in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ColorSpace {
+    /// Component-wise on the sRGB encoded values.
+    #[default]
+    Srgb,
+    /// In the perceptually uniform OKLab space, avoiding the muddy midpoints of sRGB blends.
+    Oklab,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Stop {
+    position: f32,
+    color: Rgba,
+    // Easing of the transition from the previous stop to this one.
+    easing: Easing,
+}
+
+/// A gradient over [0, 1] through color stops, easing the transition between each pair of stops.
+///
+/// ```
+/// # use nova_easing::{Easing, gradient::{ColorSpace, Gradient}};
+/// let heatmap = Gradient::new([0.0, 0.0, 0.5, 1.0])
+///     .stop(0.5, [1.0, 1.0, 0.0, 1.0], Easing::InOutSine)
+///     .stop(1.0, [1.0, 0.0, 0.0, 1.0], Easing::OutQuad)
+///     .with_color_space(ColorSpace::Oklab);
+///
+/// let lut = heatmap.lut_rgba8(256);
+/// assert_eq!(lut[0], [0, 0, 128, 255]);
+/// assert_eq!(lut[255], [255, 0, 0, 255]);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct Gradient {
+    stops: Vec<Stop>,
+    color_space: ColorSpace,
+}
+
+impl Gradient {
+    /// Creates a gradient starting with `color` at position 0.
+    pub fn new(color: Rgba) -> Self {
+        Self {
+            stops: vec![Stop {
+                position: 0.0,
+                color,
+                easing: Easing::Linear,
+            }],
+            color_space: ColorSpace::Srgb,
+        }
+    }
+
+    /// Appends a stop at `position`, reached from the previous stop following `easing`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `position` is smaller than the position of the previous stop.
+    pub fn stop(mut self, position: f32, color: Rgba, easing: Easing) -> Self {
+        let previous = self.stops.last().unwrap().position;
+        assert!(
+            position >= previous,
+            "gradient stops must be sorted by position"
+        );
+        self.stops.push(Stop {
+            position,
+            color,
+            easing,
+        });
+        self
+    }
+
+    /// Sets the color space used for interpolation.
+    pub fn with_color_space(mut self, color_space: ColorSpace) -> Self {
+        self.color_space = color_space;
+        self
+    }
+
+    /// Returns the color at `t`, holding the first and last stop outside of them.
+    pub fn sample(&self, t: f32) -> Rgba {
+        let index = self.stops.partition_point(|stop| stop.position <= t);
+        if index == 0 {
+            return self.stops[0].color;
+        }
+        let Some(to) = self.stops.get(index) else {
+            return self.stops[index - 1].color;
+        };
+        let from = &self.stops[index - 1];
+
+        let local = (t - from.position) / (to.position - from.position);
+        let weight = to.easing.apply(local);
+        match self.color_space {
+            // Keep stop colors exact, without a round trip through OKLab.
+            _ if weight == 0.0 => from.color,
+            _ if weight == 1.0 => to.color,
+            ColorSpace::Srgb => mix(from.color, to.color, weight),
+            ColorSpace::Oklab => {
+                oklab_to_srgb(mix(
+                    srgb_to_oklab(from.color),
+                    srgb_to_oklab(to.color),
+                    weight,
+                ))
+            }
+        }
+    }
+
+    /// Samples the gradient at `size` evenly spaced positions, including both ends.
+    pub fn lut(&self, size: usize) -> Vec<Rgba> {
+        let denominator = size.saturating_sub(1).max(1) as f32;
+        (0..size)
+            .map(|i| self.sample(i as f32 / denominator))
+            .collect()
+    }
+
+    /// Like [`lut`](Self::lut), quantized to 8 bits per component.
+    pub fn lut_rgba8(&self, size: usize) -> Vec<[u8; 4]> {
+        self.lut(size)
+            .into_iter()
+            .map(|color| color.map(|c| (c.clamp(0.0, 1.0) * 255.0).round() as u8))
+            .collect()
+    }
+}
+
+fn mix(a: Rgba, b: Rgba, weight: f32) -> Rgba {
+    core::array::from_fn(|i| a[i] + (b[i] - a[i]) * weight)
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+// OKLab conversion, see https://bottosson.github.io/posts/oklab/. Computed in double precision,
+// alpha is passed through.
+
+fn srgb_to_linear(c: f32) -> f64 {
+    let c = c as f64;
+    if c <= 0.04045 {
+        c / 12.92
+    } else {
+        ((c + 0.055) / 1.055).powf(2.4)
+    }
+}
+
+fn linear_to_srgb(c: f64) -> f32 {
+    let c = if c <= 0.0031308 {
+        c * 12.92
+    } else {
+        1.055 * c.powf(1.0 / 2.4) - 0.055
+    };
+    c as f32
+}
+
+fn srgb_to_oklab([r, g, b, alpha]: Rgba) -> [f32; 4] {
+    let (r, g, b) = (srgb_to_linear(r), srgb_to_linear(g), srgb_to_linear(b));
+
+    let l = (0.4122214708 * r + 0.5363325363 * g + 0.0514459929 * b).cbrt();
+    let m = (0.2119034982 * r + 0.6806995451 * g + 0.1073969566 * b).cbrt();
+    let s = (0.0883024619 * r + 0.2817188376 * g + 0.6299787005 * b).cbrt();
+
+    [
+        (0.2104542553 * l + 0.7936177850 * m - 0.0040720468 * s) as f32,
+        (1.9779984951 * l - 2.4285922050 * m + 0.4505937099 * s) as f32,
+        (0.0259040371 * l + 0.7827717662 * m - 0.8086757660 * s) as f32,
+        alpha,
+    ]
+}
+
+fn oklab_to_srgb([lightness, a, b, alpha]: [f32; 4]) -> Rgba {
+    let (lightness, a, b) = (lightness as f64, a as f64, b as f64);
+
+    let l = (lightness + 0.3963377774 * a + 0.2158037573 * b).powi(3);
+    let m = (lightness - 0.1055613458 * a - 0.0638541728 * b).powi(3);
+    let s = (lightness - 0.0894841775 * a - 1.2914855480 * b).powi(3);
+
+    [
+        linear_to_srgb(4.0767416621 * l - 3.3077115913 * m + 0.2309699292 * s),
+        linear_to_srgb(-1.2684380046 * l + 2.6097574011 * m - 0.3413193965 * s),
+        linear_to_srgb(-0.0041960863 * l - 0.7034186147 * m + 1.7076147010 * s),
+        alpha,
+    ]
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::{ColorSpace, Gradient, oklab_to_srgb, srgb_to_oklab};
+    use crate::{Easing, EasingArgument};
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn eased_segments() {
+        let gradient = Gradient::new([0.0, 0.0, 0.0, 1.0])
+            .stop(0.5, [1.0, 1.0, 1.0, 1.0], Easing::InQuad)
+            .stop(1.0, [1.0, 0.0, 0.0, 0.0], Easing::Linear);
+
+        assert_eq!(gradient.sample(-1.0), [0.0, 0.0, 0.0, 1.0]);
+        assert_relative_eq!(gradient.sample(0.25)[0], 0.5f32.ease_in_quad());
+        assert_eq!(gradient.sample(0.5), [1.0, 1.0, 1.0, 1.0]);
+        assert_eq!(gradient.sample(0.75), [1.0, 0.5, 0.5, 0.5]);
+        assert_eq!(gradient.sample(2.0), [1.0, 0.0, 0.0, 0.0]);
+    }
+
+    #[test]
+    fn oklab_round_trip() {
+        for color in [
+            [0.2, 0.4, 0.6, 0.5],
+            [1.0, 0.0, 0.0, 1.0],
+            [0.0, 0.0, 0.0, 0.0],
+        ] {
+            let round_trip = oklab_to_srgb(srgb_to_oklab(color));
+            for (a, b) in color.into_iter().zip(round_trip) {
+                assert_relative_eq!(a, b, epsilon = 1e-4);
+            }
+        }
+    }
+
+    #[test]
+    fn lut() {
+        let gradient = Gradient::new([0.0, 0.0, 1.0, 1.0])
+            .stop(1.0, [1.0, 1.0, 0.0, 1.0], Easing::Linear)
+            .with_color_space(ColorSpace::Oklab);
+        let lut = gradient.lut(16);
+        assert_eq!(lut.len(), 16);
+        assert_relative_eq!(lut[0][2], 1.0, epsilon = 1e-4);
+        assert_relative_eq!(lut[15][0], 1.0, epsilon = 1e-4);
+
+        // Blue to yellow in OKLab stays brighter in the middle than the gray of an sRGB blend.
+        let middle = gradient.sample(0.5);
+        assert!(middle.iter().take(3).sum::<f32>() > 1.5);
+
+        assert_eq!(
+            gradient.lut_rgba8(2),
+            [[0, 0, 255, 255], [255, 255, 0, 255]]
+        );
+    }
+}
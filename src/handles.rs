@@ -0,0 +1,394 @@
+// Copyright (C) 2025 Tim Blechmann
+// SPDX-License-Identifier: MIT
+
+//! Interchange with GUI curve editors via points and normalized Bezier handles.
+//!
+//! Curve editors typically draw a segment as a cubic Bezier between two points, with its control
+//! points relative to the bounding box of the segment, like CSS `cubic-bezier(x1, y1, x2, y2)`.
+//! [`HandleCurve`] holds that representation and converts from and to [`Env`] and [`Track`].
+//!
+//! Handles are derived from the slopes of a shape at both ends, which reproduces polynomial shapes
+//! like [`Shape::Squared`] and [`Shape::Cubed`] exactly. [`Shape::Curve`] segments round-trip
+//! through their handles up to rounding, other handles are mapped to the closest curve parameter.
+//!
+//! ```
+//! # use nova_easing::env::{Env, Shape};
+//! # use nova_easing::handles::HandleCurve;
+//! let env = Env::new(0.0)
+//!     .segment(1.0, 0.5, Shape::Curve(-4.0))
+//!     .segment(0.0, 1.0, Shape::Linear);
+//!
+//! let mut handles = HandleCurve::from(&env);
+//! assert_eq!(handles.points[1].handles, [1.0 / 3.0, 1.0 / 3.0, 2.0 / 3.0, 2.0 / 3.0]);
+//!
+//! // The user drags the release point, the curve of the attack keeps its shape.
+//! handles.points[1].time = 2.0;
+//! let edited = Env::from(&handles);
+//! let Shape::Curve(curve) = edited.segments()[0].shape else { unreachable!() };
+//! assert!((curve + 4.0).abs() < 1e-4);
+//! ```
+
+use alloc::vec::Vec;
+#[allow(unused_imports)]
+use num_traits::Float;
+
+use crate::env::{Env, Shape};
+use crate::track::{Track, bake};
+
+const THIRD: f32 = 1.0 / 3.0;
+const LINEAR: [f32; 4] = [THIRD, THIRD, 2.0 * THIRD, 2.0 * THIRD];
+const STEP: [f32; 4] = [0.0, 1.0, 0.0, 1.0];
+const HOLD: [f32; 4] = [1.0, 0.0, 1.0, 0.0];
+
+/// A point of a [`HandleCurve`], ending the segment from the previous point.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct HandlePoint {
+    /// Time of the point, in seconds.
+    pub time: f32,
+    /// Level at [`time`](Self::time).
+    pub level: f32,
+    /// The control points `[x1, y1, x2, y2]` of the segment ending at this point, normalized to
+    /// the box between the previous point and this one. `y` may leave [0, 1] for overshoots and
+    /// steep curves.
+    pub handles: [f32; 4],
+}
+
+/// A curve of points connected by cubic Bezier segments, as edited by GUI curve editors.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct HandleCurve {
+    /// The level at time 0.
+    pub initial_level: f32,
+    /// The points, sorted by time.
+    pub points: Vec<HandlePoint>,
+    /// The release node of the envelope, see [`Env`].
+    pub release_node: Option<usize>,
+    /// The loop node of the envelope, see [`Env`].
+    pub loop_node: Option<usize>,
+}
+
+impl HandleCurve {
+    /// The time of the last point.
+    pub fn duration(&self) -> f32 {
+        self.points.last().map_or(0.0, |point| point.time)
+    }
+
+    /// Samples the curve at `time`, holding the first and last levels outside of the points.
+    pub fn sample(&self, time: f32) -> f32 {
+        let mut start_time = 0.0;
+        let mut start_level = self.initial_level;
+        for point in &self.points {
+            if time < point.time {
+                if time < start_time {
+                    break;
+                }
+                let u = (time - start_time) / (point.time - start_time);
+                return start_level + (point.level - start_level) * bezier(point.handles, u);
+            }
+            start_time = point.time;
+            start_level = point.level;
+        }
+        if time < 0.0 {
+            self.initial_level
+        } else {
+            start_level
+        }
+    }
+
+    /// Bakes the curve into a linearly interpolated keyframe track, see [`bake`].
+    pub fn to_track(&self, tolerance: f32) -> Track {
+        let duration = self.duration();
+        bake(|u| self.sample(u * duration), duration, tolerance)
+    }
+}
+
+impl From<&Env> for HandleCurve {
+    fn from(env: &Env) -> Self {
+        let mut time = 0.0;
+        let mut level = env.initial_level();
+        let points = env
+            .segments()
+            .iter()
+            .map(|segment| {
+                let handles = handles(segment.shape, level, segment.level);
+                time += segment.time;
+                level = segment.level;
+                HandlePoint {
+                    time,
+                    level,
+                    handles,
+                }
+            })
+            .collect();
+        Self {
+            initial_level: env.initial_level(),
+            points,
+            release_node: env.release_node(),
+            loop_node: env.loop_node(),
+        }
+    }
+}
+
+/// Converts each Bezier segment to the closest [`Shape`].
+impl From<&HandleCurve> for Env {
+    fn from(curve: &HandleCurve) -> Self {
+        let mut env = Env::new(curve.initial_level);
+        let mut time = 0.0;
+        for point in &curve.points {
+            env = env.segment(point.level, point.time - time, shape(point.handles));
+            time = point.time;
+        }
+        if let Some(node) = curve.release_node {
+            env = env.with_release_node(node);
+        }
+        if let Some(node) = curve.loop_node {
+            env = env.with_loop_node(node);
+        }
+        env
+    }
+}
+
+impl From<&Track> for HandleCurve {
+    fn from(track: &Track) -> Self {
+        Self::from(&Env::from(track))
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+// Handles of the cubic Hermite segment with the normalized slopes `d0` and `d1` at its ends.
+fn hermite(d0: f64, d1: f64) -> [f32; 4] {
+    [
+        THIRD,
+        (d0 / 3.0) as f32,
+        2.0 * THIRD,
+        (1.0 - d1 / 3.0) as f32,
+    ]
+}
+
+// Normalized slopes of `Shape::Curve(c)`, i.e. of `(1 - e^(c t)) / (1 - e^c)`.
+fn curve_slopes(c: f64) -> (f64, f64) {
+    let d0 = c / c.exp_m1();
+    (d0, d0 * c.exp())
+}
+
+// Handles of `Shape::Curve(c)` on its end tangents, at the fraction `k` of the way to their
+// intersection `(x, y)`. `k` is chosen such that the middle of the Bezier lies on the curve, which
+// stays within 0.6% of the curve for `|c| <= 4`.
+fn curve_handles(c: f64) -> [f32; 4] {
+    if c.abs() < 0.001 {
+        return LINEAR;
+    }
+    let (d0, d1) = curve_slopes(c);
+    let x = (1.0 - d1) / (d0 - d1);
+    let y = d0 * x;
+    let f = |t: f64| (c * t).exp_m1() / c.exp_m1();
+    let miss = |k: f64| {
+        let middle = |p: f64| (4.0 - 3.0 * k + 6.0 * k * p) / 8.0;
+        f(middle(x)) - middle(y)
+    };
+
+    let (mut low, mut high) = (0.0, 1.0);
+    if miss(low).signum() != miss(high).signum() {
+        for _ in 0..48 {
+            let k = (low + high) * 0.5;
+            if miss(k).signum() == miss(low).signum() {
+                low = k;
+            } else {
+                high = k;
+            }
+        }
+    }
+    let k = (low + high) * 0.5;
+    [k * x, k * y, 1.0 + k * (x - 1.0), 1.0 + k * (y - 1.0)].map(|v| v as f32)
+}
+
+fn handles(shape: Shape, start: f32, end: f32) -> [f32; 4] {
+    if start == end {
+        return LINEAR;
+    }
+    let (a, b) = (start as f64, end as f64);
+    match shape {
+        Shape::Step => STEP,
+        Shape::Hold => HOLD,
+        Shape::Linear => LINEAR,
+        Shape::Curve(c) => curve_handles(c as f64),
+        Shape::Exponential => curve_handles((b / a).ln()),
+        Shape::Sine => hermite(0.0, 0.0),
+        Shape::Welch if start < end => hermite(core::f64::consts::FRAC_PI_2, 0.0),
+        Shape::Welch => hermite(0.0, core::f64::consts::FRAC_PI_2),
+        Shape::Squared => {
+            let (a, b) = (a.sqrt(), b.sqrt());
+            hermite(2.0 * a / (a + b), 2.0 * b / (a + b))
+        }
+        Shape::Cubed => {
+            let (a, b) = (a.cbrt(), b.cbrt());
+            let scale = 3.0 * (b - a) / (b * b * b - a * a * a);
+            hermite(scale * a * a, scale * b * b)
+        }
+        Shape::Eased(easing) => {
+            let (_, d0) = easing.apply_derivative_pair(0.0f64);
+            let (_, d1) = easing.apply_derivative_pair(1.0f64);
+            hermite(d0, d1)
+        }
+    }
+}
+
+fn shape(handles: [f32; 4]) -> Shape {
+    match handles {
+        STEP => return Shape::Step,
+        HOLD => return Shape::Hold,
+        LINEAR => return Shape::Linear,
+        _ => {}
+    }
+    let [x1, y1, x2, y2] = handles.map(f64::from);
+
+    // Handles of a `Curve`: the ratio of the end slopes is `e^c`.
+    if x1 > 0.0 && x2 < 1.0 && y1 > 0.0 && y2 < 1.0 {
+        let c = ((1.0 - y2) / (1.0 - x2) / (y1 / x1)).ln();
+        let expected = curve_handles(c);
+        if expected
+            .iter()
+            .zip(handles)
+            .all(|(a, b)| (a - b).abs() < 1e-4)
+        {
+            return Shape::Curve(c as f32);
+        }
+    }
+
+    // Otherwise match the middle, where `Curve(c)` is `1 / (1 + e^(c / 2))`.
+    let middle = (bezier(handles, 0.5) as f64).clamp(1e-6, 1.0 - 1e-6);
+    let c = 2.0 * (1.0 / middle - 1.0).ln();
+    if c.abs() < 0.001 {
+        Shape::Linear
+    } else {
+        Shape::Curve(c as f32)
+    }
+}
+
+// The `y` of the normalized Bezier at `x = u`, solving for the curve parameter by bisection.
+fn bezier(handles: [f32; 4], u: f32) -> f32 {
+    let [x1, y1, x2, y2] = handles;
+    let (x1, x2) = (x1.clamp(0.0, 1.0), x2.clamp(0.0, 1.0));
+    let eval = |p1: f32, p2: f32, s: f32| {
+        let r = 1.0 - s;
+        3.0 * r * r * s * p1 + 3.0 * r * s * s * p2 + s * s * s
+    };
+
+    let (mut low, mut high) = (0.0f32, 1.0f32);
+    for _ in 0..32 {
+        let s = (low + high) * 0.5;
+        if eval(x1, x2, s) < u {
+            low = s;
+        } else {
+            high = s;
+        }
+    }
+    eval(y1, y2, (low + high) * 0.5)
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::{HandleCurve, HandlePoint, LINEAR};
+    use crate::env::{Env, Shape};
+    use crate::track::{Keyframe, Track};
+    use approx::assert_relative_eq;
+
+    fn round_trip(shape: Shape, start: f32, end: f32) -> Shape {
+        let env = Env::new(start).segment(end, 1.0, shape);
+        Env::from(&HandleCurve::from(&env)).segments()[0].shape
+    }
+
+    #[test]
+    fn exact_round_trips() {
+        for shape in [Shape::Linear, Shape::Step, Shape::Hold] {
+            assert_eq!(round_trip(shape, 0.0, 1.0), shape);
+        }
+        for c in [-8.0, -2.0, -0.5, 0.25, 3.0, 8.0] {
+            let Shape::Curve(result) = round_trip(Shape::Curve(c), 1.0, -1.0) else {
+                panic!("curve {c} lost its shape");
+            };
+            assert_relative_eq!(result, c, max_relative = 1e-4);
+        }
+        // Exponential segments are curves in normalized form.
+        let Shape::Curve(c) = round_trip(Shape::Exponential, 1.0, 100.0) else {
+            panic!("exponential lost its shape");
+        };
+        assert_relative_eq!(c, 100.0f32.ln(), max_relative = 1e-4);
+    }
+
+    #[test]
+    fn bezier_matches_shapes() {
+        let shapes = [
+            (Shape::Squared, 0.0, 4.0, 1e-5),
+            (Shape::Cubed, -8.0, 8.0, 1e-4),
+            (Shape::Curve(-4.0), 0.0, 1.0, 0.006),
+            (Shape::Curve(1.0), 0.0, 1.0, 1e-4),
+            (Shape::Exponential, 0.1, 1.0, 0.006),
+            (Shape::Sine, 0.0, 1.0, 0.02),
+            (Shape::Welch, 1.0, 0.0, 0.03),
+        ];
+        for (shape, start, end, tolerance) in shapes {
+            let env = Env::new(start).segment(end, 2.0, shape);
+            let handles = HandleCurve::from(&env);
+            for i in 0..=20 {
+                let time = i as f32 / 10.0;
+                let range = (end - start).abs();
+                assert!(
+                    (handles.sample(time) - env.level_at(time)).abs() <= tolerance * range,
+                    "{shape:?} at {time}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn fitted_handles() {
+        // An ease-out drawn by hand becomes a concave curve.
+        let curve = HandleCurve {
+            initial_level: 0.0,
+            points: vec![HandlePoint {
+                time: 1.0,
+                level: 1.0,
+                handles: [0.0, 0.6, 0.4, 1.0],
+            }],
+            release_node: Some(1),
+            loop_node: None,
+        };
+        let env = Env::from(&curve);
+        assert_eq!(env.release_node(), Some(1));
+        let Shape::Curve(c) = env.segments()[0].shape else {
+            panic!("expected a curve");
+        };
+        assert!(c < 0.0);
+        assert_relative_eq!(env.level_at(0.5), curve.sample(0.5), epsilon = 1e-5);
+    }
+
+    #[test]
+    fn tracks() {
+        let track = Track::new(vec![
+            Keyframe {
+                time: 0.0,
+                value: 1.0,
+            },
+            Keyframe {
+                time: 2.0,
+                value: 3.0,
+            },
+        ]);
+        let curve = HandleCurve::from(&track);
+        assert_eq!(curve.points.len(), 1);
+        assert_eq!(curve.points[0].handles, LINEAR);
+        assert_eq!(curve.to_track(1e-4), track);
+
+        let env = Env::new(0.0).segment(1.0, 1.0, Shape::Curve(4.0));
+        let baked = HandleCurve::from(&env).to_track(1e-3);
+        for i in 0..=10 {
+            let time = i as f32 / 10.0;
+            assert!((baked.sample(time) - env.level_at(time)).abs() < 0.02);
+        }
+    }
+}
@@ -0,0 +1,108 @@
+// Copyright (C) 2025 Tim Blechmann
+// SPDX-License-Identifier: MIT
+
+//! Eased crossfades of 8-bit pixel buffers.
+//!
+//! With the `nightly` feature, pixels are widened to `f32x8`, blended, and narrowed back in SIMD
+//! chunks, with the remainder handled by the scalar path. Both paths round identically.
+
+#[allow(unused_imports)]
+use num_traits::Float;
+
+use crate::Easing;
+
+#[cfg(feature = "nightly")]
+use core::simd::{f32x8, u8x8};
+
+#[cfg(feature = "nightly")]
+use std::simd::StdFloat;
+
+#[cfg(feature = "nightly")]
+use std::simd::num::{SimdFloat, SimdUint};
+
+#[cfg(feature = "nightly")]
+const LANES: usize = 8;
+
+/// Crossfades from image `a` to image `b`, writing the result to `dst`.
+///
+/// The buffers hold 8-bit channels, typically RGBA rows; every channel, including alpha, is
+/// blended. `t` is the progress of the transition, eased once per frame: the weight of `b` is
+/// `easing(t)`. Results are rounded to the nearest value and saturate at 0 and 255 for
+/// overshooting easings.
+///
+/// # Panics
+///
+/// Panics if `a` or `b` have a different length than `dst`.
+pub fn blend_images(dst: &mut [u8], a: &[u8], b: &[u8], t: f32, easing: Easing) {
+    assert_eq!(dst.len(), a.len(), "buffer length mismatch");
+    assert_eq!(dst.len(), b.len(), "buffer length mismatch");
+    let weight = easing.apply(t);
+
+    #[cfg(feature = "nightly")]
+    let (dst, a, b) = {
+        let bulk = dst.len() - dst.len() % LANES;
+        let (dst_bulk, dst_tail) = dst.split_at_mut(bulk);
+        let weight = f32x8::splat(weight);
+        for ((d, a), b) in dst_bulk
+            .chunks_exact_mut(LANES)
+            .zip(a.chunks_exact(LANES))
+            .zip(b.chunks_exact(LANES))
+        {
+            let a: f32x8 = u8x8::from_slice(a).cast();
+            let b: f32x8 = u8x8::from_slice(b).cast();
+            let blended = a + (b - a) * weight;
+            blended.round().cast::<u8>().copy_to_slice(d);
+        }
+        (dst_tail, &a[bulk..], &b[bulk..])
+    };
+
+    for ((d, &a), &b) in dst.iter_mut().zip(a).zip(b) {
+        let (a, b) = (a as f32, b as f32);
+        *d = (a + (b - a) * weight).round() as u8;
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::blend_images;
+    use crate::{Easing, EasingArgument};
+
+    fn images() -> (Vec<u8>, Vec<u8>) {
+        // 5 RGBA pixels, covering a SIMD chunk and a scalar tail.
+        let a = (0..20).map(|i| (i * 12) as u8).collect();
+        let b = (0..20).map(|i| 255 - (i * 12) as u8).collect();
+        (a, b)
+    }
+
+    #[test]
+    fn endpoints() {
+        let (a, b) = images();
+        let mut dst = vec![0; 20];
+        blend_images(&mut dst, &a, &b, 0.0, Easing::InOutSine);
+        assert_eq!(dst, a);
+        blend_images(&mut dst, &a, &b, 1.0, Easing::InOutSine);
+        assert_eq!(dst, b);
+    }
+
+    #[test]
+    fn eased_weight() {
+        let (a, b) = images();
+        let mut dst = vec![0; 20];
+        blend_images(&mut dst, &a, &b, 0.3, Easing::InQuad);
+
+        let weight = 0.3f32.ease_in_quad();
+        for ((&d, &a), &b) in dst.iter().zip(&a).zip(&b) {
+            let expected = a as f32 + (b as f32 - a as f32) * weight;
+            assert_eq!(d, expected.round() as u8);
+        }
+    }
+
+    #[test]
+    fn overshoot_saturates() {
+        let mut dst = [0; 9];
+        blend_images(&mut dst, &[0; 9], &[255; 9], 0.7, Easing::OutBack);
+        assert_eq!(dst, [255; 9]);
+    }
+}
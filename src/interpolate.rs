@@ -0,0 +1,534 @@
+// Copyright (C) 2025 Tim Blechmann
+// SPDX-License-Identifier: MIT
+
+use core::time::Duration;
+
+use crate::internal::CurveParam;
+use crate::{Easing, EasingImplHelper, Scalar};
+
+#[cfg(feature = "nalgebra")]
+mod nalgebra;
+
+/// Types that can be linearly interpolated, and hence be driven by a [`Tween`](crate::tween::Tween).
+///
+/// With the `derive` feature, `#[derive(Ease)]` implements this trait for structs, field by field.
+/// Single fields can follow their own easing via `#[ease(...)]`:
+///
+/// ```
+/// # #[cfg(feature = "derive")] {
+/// use nova_easing::{Ease, Easing, Interpolatable};
+///
+/// #[derive(Clone, Copy, Ease)]
+/// struct Style {
+///     opacity: f32,
+///     #[ease(OutBack)]
+///     scale: f32,
+/// }
+///
+/// let from = Style { opacity: 0.0, scale: 0.5 };
+/// let to = Style { opacity: 1.0, scale: 1.0 };
+/// let style = from.ease(to, 0.5, Easing::Linear);
+/// assert_eq!(style.opacity, 0.5);
+/// assert!(style.scale > 0.75);
+/// # }
+/// ```
+pub trait Interpolatable: Copy {
+    /// Linearly interpolates between `self` (at `t = 0`) and `other` (at `t = 1`).
+    fn lerp(self, other: Self, t: f32) -> Self;
+
+    /// Interpolates between `self` and `other`, following `easing`.
+    ///
+    /// Composite types may override this to ease parts of the value differently.
+    fn ease(self, other: Self, t: f32, easing: Easing) -> Self {
+        self.lerp(other, easing.apply(t))
+    }
+
+    /// Eases from `self` towards `target`, following `easing`: the same as [`ease`](Self::ease),
+    /// reading as a transition.
+    ///
+    /// ```
+    /// # use nova_easing::{Easing, Interpolatable};
+    /// let position = (0.0f32, [10.0f64, 20.0]).ease_to((100.0, [20.0, 0.0]), 0.5, Easing::InQuad);
+    /// assert_eq!(position, (25.0, [12.5, 15.0]));
+    /// ```
+    fn ease_to(self, target: Self, t: f32, easing: Easing) -> Self {
+        self.ease(target, t, easing)
+    }
+}
+
+impl Interpolatable for f32 {
+    fn lerp(self, other: Self, t: f32) -> Self {
+        self + (other - self) * t
+    }
+}
+
+impl Interpolatable for f64 {
+    fn lerp(self, other: Self, t: f32) -> Self {
+        self + (other - self) * t as f64
+    }
+}
+
+/// Interpolates element-wise, e.g. points, vectors or colors such as
+/// [`gradient::Rgba`](crate::gradient::Rgba).
+impl<T: Interpolatable, const N: usize> Interpolatable for [T; N] {
+    fn lerp(self, other: Self, t: f32) -> Self {
+        core::array::from_fn(|i| self[i].lerp(other[i], t))
+    }
+
+    fn ease(self, other: Self, t: f32, easing: Easing) -> Self {
+        core::array::from_fn(|i| self[i].ease(other[i], t, easing))
+    }
+}
+
+// Interpolates tuples element-wise, forwarding `ease` so elements keep their own overrides.
+macro_rules! impl_interpolatable_for_tuple {
+    ($($name:ident: $index:tt),+) => {
+        impl<$($name: Interpolatable),+> Interpolatable for ($($name,)+) {
+            fn lerp(self, other: Self, t: f32) -> Self {
+                ($(self.$index.lerp(other.$index, t),)+)
+            }
+
+            fn ease(self, other: Self, t: f32, easing: Easing) -> Self {
+                ($(self.$index.ease(other.$index, t, easing),)+)
+            }
+        }
+    };
+}
+
+impl_interpolatable_for_tuple!(A: 0);
+impl_interpolatable_for_tuple!(A: 0, B: 1);
+impl_interpolatable_for_tuple!(A: 0, B: 1, C: 2);
+impl_interpolatable_for_tuple!(A: 0, B: 1, C: 2, D: 3);
+impl_interpolatable_for_tuple!(A: 0, B: 1, C: 2, D: 3, E: 4);
+impl_interpolatable_for_tuple!(A: 0, B: 1, C: 2, D: 3, E: 4, F: 5);
+
+/// Interpolates with nanosecond precision, saturating at zero and [`Duration::MAX`] when an
+/// overshooting easing leaves the representable range.
+///
+/// ```
+/// # use nova_easing::{Easing, Interpolatable};
+/// # use std::time::Duration;
+/// // Backoff delays which stay short for the first retries, then grow quickly.
+/// let (first, last) = (Duration::from_millis(100), Duration::from_secs(30));
+/// let delays: Vec<Duration> = (0..5)
+///     .map(|attempt| first.ease(last, attempt as f32 / 4.0, Easing::InExpo))
+///     .collect();
+/// assert_eq!(delays[0], first);
+/// assert_eq!(delays[2], Duration::from_nanos(1_034_375_000));
+/// assert_eq!(delays[4], last);
+/// ```
+impl Interpolatable for Duration {
+    fn lerp(self, other: Self, t: f32) -> Self {
+        let from = self.as_nanos() as i128;
+        let nanos = from.saturating_add(scale(other.as_nanos() as i128 - from, t));
+        let nanos = nanos.clamp(0, Duration::MAX.as_nanos() as i128) as u128;
+        Duration::new(
+            (nanos / 1_000_000_000) as u64,
+            (nanos % 1_000_000_000) as u32,
+        )
+    }
+}
+
+/// Interpolates integer timestamps, e.g. in nanoseconds, saturating at the bounds of the type.
+impl Interpolatable for u64 {
+    fn lerp(self, other: Self, t: f32) -> Self {
+        let value = (self as i128).saturating_add(scale(other as i128 - self as i128, t));
+        value.clamp(0, u64::MAX as i128) as u64
+    }
+}
+
+/// Interpolates integer timestamps, e.g. in nanoseconds, saturating at the bounds of the type.
+impl Interpolatable for i64 {
+    fn lerp(self, other: Self, t: f32) -> Self {
+        let value = (self as i128).saturating_add(scale(other as i128 - self as i128, t));
+        value.clamp(i64::MIN as i128, i64::MAX as i128) as i64
+    }
+}
+
+// `delta * t`, rounded to the nearest integer and saturating. `t` is split into its exact integer
+// mantissa and binary exponent, so large values keep every bit, unlike a product in `f64`.
+fn scale(delta: i128, t: f32) -> i128 {
+    if t.is_nan() {
+        return 0;
+    }
+    if t.is_infinite() {
+        let sign = delta.signum() * if t > 0.0 { 1 } else { -1 };
+        return sign.saturating_mul(i128::MAX);
+    }
+
+    let bits = t.to_bits();
+    let biased = ((bits >> 23) & 0xff) as i32;
+    let (mantissa, exponent) = if biased == 0 {
+        ((bits & 0x7f_ffff) as i128, -149)
+    } else {
+        ((bits & 0x7f_ffff | 0x80_0000) as i128, biased - 150)
+    };
+    let mantissa = if t < 0.0 { -mantissa } else { mantissa };
+
+    let product = delta.saturating_mul(mantissa);
+    if exponent >= 0 {
+        let factor = 1i128.checked_shl(exponent as u32).filter(|f| *f > 0);
+        factor.map_or(product.signum().saturating_mul(i128::MAX), |factor| {
+            product.saturating_mul(factor)
+        })
+    } else if exponent > -127 {
+        // Rounds half up, without overflowing near `i128::MAX`.
+        ((product >> (-exponent - 1)) + 1) >> 1
+    } else {
+        0
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Eases from `a` (at `t = 0`) to `b` (at `t = 1`), i.e. `a + (b - a) * easing.apply(t)`.
+///
+/// Like [`Easing::apply`], it takes `f32`, `f64` or (with the `nightly` feature) SIMD vectors, so
+/// one call eases a whole batch of values:
+///
+/// ```
+/// # use nova_easing::{Easing, ease_lerp};
+/// assert_eq!(ease_lerp(10.0f32, 20.0, 0.5, Easing::InQuad), 12.5);
+/// assert_eq!(ease_lerp(20.0f64, 10.0, 1.0, Easing::OutBounce), 10.0);
+/// ```
+#[allow(private_bounds)]
+#[inline]
+pub fn ease_lerp<T>(a: T, b: T, t: T, easing: Easing) -> T
+where
+    T: EasingImplHelper + CurveParam<T>,
+{
+    a + (b - a) * easing.apply(t)
+}
+
+/// Eases from `a` to `b` on a circle with the given `period`, such as hue (360), phase (1) or a
+/// heading in radians (`TAU`).
+///
+/// The transition takes the shorter way around, crossing the wrap point if that is shorter, and
+/// the result is wrapped into [0, `period`). If both ways are equally long, it moves towards
+/// increasing values.
+///
+/// ```
+/// # use nova_easing::{Easing, ease_wrapped};
+/// // From 350° to 10° via 0°, not backwards through 180°.
+/// assert_eq!(ease_wrapped(350.0f32, 10.0, 0.5, 360.0, Easing::Linear), 0.0);
+/// assert_eq!(ease_wrapped(350.0f32, 10.0, 0.75, 360.0, Easing::Linear), 5.0);
+/// ```
+#[allow(private_bounds)]
+pub fn ease_wrapped<T>(a: T, b: T, t: T, period: T, easing: Easing) -> T
+where
+    T: Scalar + CurveParam<T>,
+{
+    let half = period / T::from_f32(2.0).unwrap();
+    let mut delta = wrap(b - a, period);
+    if delta > half {
+        delta = delta - period;
+    }
+    wrap(a + delta * easing.apply(t), period)
+}
+
+/// Eases from the angle `from` to `to`, in radians, along the shorter arc.
+///
+/// Unlike easing the plain numbers, a transition from `170°` to `-170°` turns by `20°` across
+/// `±180°`, instead of by `340°` the long way around. The result is normalized to (-π, π].
+///
+/// ```
+/// # use nova_easing::{Easing, ease_angle};
+/// # use std::f64::consts::PI;
+/// // From 162° to -162° via 180°.
+/// let heading = ease_angle(0.9 * PI, -0.9 * PI, 0.25, Easing::Linear);
+/// assert!((heading - 0.95 * PI).abs() < 1e-12);
+/// ```
+#[allow(private_bounds)]
+pub fn ease_angle<T>(from: T, to: T, t: T, easing: Easing) -> T
+where
+    T: Scalar + CurveParam<T>,
+{
+    signed(
+        from,
+        to,
+        t,
+        T::from_f64(core::f64::consts::TAU).unwrap(),
+        easing,
+    )
+}
+
+/// Eases from the angle `from` to `to`, in degrees, along the shorter arc, like [`ease_angle`].
+///
+/// The result is normalized to (-180, 180].
+///
+/// ```
+/// # use nova_easing::{Easing, ease_angle_degrees};
+/// assert_eq!(ease_angle_degrees(170.0f32, -170.0, 0.25, Easing::Linear), 175.0);
+/// assert_eq!(ease_angle_degrees(170.0f32, -170.0, 0.75, Easing::Linear), -175.0);
+/// ```
+#[allow(private_bounds)]
+pub fn ease_angle_degrees<T>(from: T, to: T, t: T, easing: Easing) -> T
+where
+    T: Scalar + CurveParam<T>,
+{
+    signed(from, to, t, T::from_f32(360.0).unwrap(), easing)
+}
+
+fn signed<T>(from: T, to: T, t: T, period: T, easing: Easing) -> T
+where
+    T: Scalar + CurveParam<T>,
+{
+    let angle = ease_wrapped(from, to, t, period, easing);
+    if angle > period / T::from_f32(2.0).unwrap() {
+        angle - period
+    } else {
+        angle
+    }
+}
+
+fn wrap<T: Scalar>(value: T, period: T) -> T {
+    let wrapped = value - (value / period).floor() * period;
+    // Rounding can yield `period` itself for tiny negative values.
+    if wrapped >= period {
+        T::zero()
+    } else {
+        wrapped
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::{ease_angle, ease_angle_degrees, ease_lerp, ease_wrapped};
+    use crate::{Easing, EasingArgument, Interpolatable};
+    use approx::assert_relative_eq;
+    use core::f64::consts::{PI, TAU};
+    use core::time::Duration;
+
+    #[test]
+    fn durations() {
+        let (a, b) = (Duration::from_secs(1), Duration::from_secs(3));
+        assert_eq!(a.lerp(b, 0.25), Duration::from_millis(1500));
+        assert_eq!(b.lerp(a, 0.5), Duration::from_secs(2));
+        assert_eq!(a.ease(b, 0.5, Easing::InQuad), Duration::from_millis(1500));
+
+        // Exact endpoints and nanosecond steps far beyond the precision of `f64`.
+        let (a, b) = (
+            Duration::from_secs(1 << 40),
+            Duration::new(1 << 41, 999_999_999),
+        );
+        assert_eq!(a.lerp(b, 0.0), a);
+        assert_eq!(a.lerp(b, 1.0), b);
+        assert_eq!(a.lerp(b, 0.5), Duration::new(3 << 39, 500_000_000));
+
+        // Overshoot saturates instead of panicking.
+        let short = Duration::from_millis(10);
+        assert_eq!(short.lerp(Duration::ZERO, 1.5), Duration::ZERO);
+        assert_eq!(Duration::ZERO.lerp(Duration::MAX, 2.0), Duration::MAX);
+        let backoff = Duration::ZERO.ease(short, 0.1, Easing::InBack);
+        assert_eq!(backoff, Duration::ZERO);
+    }
+
+    #[test]
+    fn timestamps() {
+        assert_eq!(100u64.lerp(200, 0.5), 150);
+        assert_eq!(200u64.lerp(100, 0.75), 125);
+        assert_eq!(0u64.lerp(10, 0.15), 2);
+        assert_eq!(10u64.lerp(0, 1.5), 0);
+        assert_eq!(0u64.lerp(u64::MAX, 1.0), u64::MAX);
+        assert_eq!((u64::MAX - 1).lerp(u64::MAX, 3.0), u64::MAX);
+        assert_eq!(i64::MIN.lerp(i64::MAX, 0.5), 0);
+        assert_eq!(i64::MIN.lerp(i64::MAX, 1.0), i64::MAX);
+        assert_eq!((-10i64).lerp(10, -1.0), -30);
+        assert_eq!(0i64.lerp(1, f32::INFINITY), i64::MAX);
+        assert_eq!(5i64.lerp(1, f32::NAN), 5);
+    }
+
+    #[test]
+    fn composites() {
+        assert_eq!([0.0f32, 10.0].lerp([1.0, 20.0], 0.5), [0.5, 15.0]);
+        assert_eq!((1.0f32, 100u64).lerp((3.0, 200), 0.25), (1.5, 125));
+        assert_eq!((2.0f64,).ease_to((4.0,), 0.5, Easing::InCubic), (2.25,));
+
+        let eased = ([0.0f32; 3], Duration::ZERO).ease_to(
+            ([1.0, 2.0, 4.0], Duration::from_secs(1)),
+            0.5,
+            Easing::InQuad,
+        );
+        assert_eq!(eased, ([0.25, 0.5, 1.0], Duration::from_millis(250)));
+        assert_eq!([[1.0f32]; 2].lerp([[3.0]; 2], 0.5), [[2.0]; 2]);
+    }
+
+    #[test]
+    fn eased_lerp() {
+        for easing in [Easing::InOutCubic, Easing::OutBack, Easing::InCurve(-3.0)] {
+            for t in [0.0, 0.3, 0.5, 0.8, 1.0] {
+                let expected = 4.0f32.lerp(-2.0, easing.apply(t));
+                assert_relative_eq!(ease_lerp(4.0f32, -2.0, t, easing), expected);
+                assert_relative_eq!(
+                    ease_lerp(4.0f64, -2.0, t as f64, easing),
+                    expected as f64,
+                    epsilon = 1e-6
+                );
+            }
+        }
+        assert_eq!(ease_lerp(1.0f32, 1.0, 0.7, Easing::OutElastic), 1.0);
+    }
+
+    #[cfg(feature = "nightly")]
+    #[test]
+    fn eased_lerp_simd() {
+        use core::simd::f32x4;
+
+        let t = f32x4::from_array([0.0, 0.25, 0.5, 1.0]);
+        let (a, b) = (f32x4::splat(1.0), f32x4::from_array([2.0, 3.0, 5.0, 9.0]));
+        let eased = ease_lerp(a, b, t, Easing::OutQuad);
+        for lane in 0..4 {
+            assert_relative_eq!(
+                eased[lane],
+                ease_lerp(a[lane], b[lane], t[lane], Easing::OutQuad)
+            );
+        }
+    }
+
+    #[test]
+    fn shortest_path() {
+        assert_eq!(
+            ease_wrapped(10.0f32, 350.0, 0.5, 360.0, Easing::Linear),
+            0.0
+        );
+        assert_eq!(
+            ease_wrapped(10.0f32, 350.0, 0.25, 360.0, Easing::Linear),
+            5.0
+        );
+        assert_eq!(
+            ease_wrapped(10.0f32, 50.0, 0.5, 360.0, Easing::Linear),
+            30.0
+        );
+        assert_eq!(
+            ease_wrapped(0.0f32, 180.0, 0.5, 360.0, Easing::Linear),
+            90.0
+        );
+
+        let eased = ease_wrapped(0.9, 0.1, 0.3, 1.0, Easing::InQuad);
+        assert_relative_eq!(eased, 0.9 + 0.2 * 0.3f64.ease_in_quad());
+    }
+
+    #[test]
+    fn angles() {
+        // Straddling ±180°, and the plain case.
+        assert_eq!(
+            ease_angle_degrees(-170.0, 170.0, 0.5, Easing::Linear),
+            180.0
+        );
+        assert_eq!(
+            ease_angle_degrees(-170.0, 170.0, 1.0, Easing::Linear),
+            170.0
+        );
+        assert_eq!(
+            ease_angle_degrees(-30.0f64, 60.0, 0.5, Easing::Linear),
+            15.0
+        );
+        assert_eq!(
+            ease_angle_degrees(350.0f32, 10.0, 0.25, Easing::Linear),
+            -5.0
+        );
+
+        let eased = ease_angle_degrees(170.0, -170.0, 0.3, Easing::OutBack);
+        assert_relative_eq!(
+            eased,
+            20.0 * 0.3f64.ease_out_back() - 190.0,
+            epsilon = 1e-12
+        );
+
+        assert_relative_eq!(ease_angle(-3.0, 3.0, 0.0, Easing::InQuad), -3.0);
+        assert_relative_eq!(
+            ease_angle(-3.0, 3.0, 1.0, Easing::InQuad),
+            3.0,
+            epsilon = 1e-12
+        );
+        let crossing = ease_angle(-3.0f32, 3.0, 0.5, Easing::Linear);
+        assert_relative_eq!(crossing.abs(), core::f32::consts::PI, epsilon = 1e-6);
+        for t in [0.0, 0.2, 0.5, 0.9, 1.0] {
+            let angle = ease_angle(2.5, -2.5, t, Easing::InOutElastic);
+            assert!(angle > -PI && angle <= PI);
+        }
+    }
+
+    #[test]
+    fn wraps_inputs_and_output() {
+        let heading = ease_wrapped(-PI / 2.0, 5.0 * PI, 1.0, TAU, Easing::Linear);
+        assert_relative_eq!(heading, PI, epsilon = 1e-12);
+        assert_relative_eq!(
+            ease_wrapped(-PI / 2.0, 5.0 * PI, 0.0, TAU, Easing::Linear),
+            1.5 * PI
+        );
+
+        for t in [0.0, 0.25, 0.5, 0.75, 1.0] {
+            let hue = ease_wrapped(300.0, 60.0, t, 360.0, Easing::OutBack);
+            assert!((0.0..360.0).contains(&hue));
+        }
+    }
+}
+
+#[cfg(all(test, feature = "derive"))]
+mod derive_tests {
+    use crate::{Ease, Easing, EasingArgument, Interpolatable};
+    use approx::assert_relative_eq;
+
+    #[derive(Debug, Clone, Copy, PartialEq, Ease)]
+    struct Transform {
+        x: f32,
+        #[ease(OutBounce)]
+        y: f64,
+        #[ease(InCurve(2.0))]
+        rotation: f32,
+    }
+
+    #[derive(Debug, Clone, Copy, PartialEq, Ease)]
+    struct Pair<T>(T, #[ease(Easing::Linear)] T);
+
+    #[test]
+    fn derived_lerp() {
+        let from = Transform {
+            x: 0.0,
+            y: 0.0,
+            rotation: 0.0,
+        };
+        let to = Transform {
+            x: 10.0,
+            y: 20.0,
+            rotation: 1.0,
+        };
+        assert_eq!(
+            from.lerp(to, 0.5),
+            Transform {
+                x: 5.0,
+                y: 10.0,
+                rotation: 0.5
+            }
+        );
+    }
+
+    #[test]
+    fn derived_ease_with_overrides() {
+        let from = Transform {
+            x: 0.0,
+            y: 0.0,
+            rotation: 0.0,
+        };
+        let to = Transform {
+            x: 10.0,
+            y: 20.0,
+            rotation: 1.0,
+        };
+        let eased = from.ease(to, 0.25, Easing::InQuad);
+        assert_relative_eq!(eased.x, 10.0 * 0.25f32.ease_in_quad());
+        assert_relative_eq!(eased.y, 20.0 * 0.25f32.ease_out_bounce() as f64);
+        assert_relative_eq!(eased.rotation, 0.25f32.ease_in_curve(2.0));
+
+        let pair = Pair(0.0f32, 0.0).ease(Pair(1.0, 1.0), 0.5, Easing::InCubic);
+        assert_eq!(pair, Pair(0.125, 0.5));
+
+        // Tuples and arrays keep the overrides of their elements.
+        let nested = [(from, 0.0f32)].ease_to([(to, 1.0)], 0.25, Easing::InQuad);
+        assert_eq!(nested[0].0, eased);
+        assert_relative_eq!(nested[0].1, 0.25f32.ease_in_quad());
+    }
+}
@@ -0,0 +1,107 @@
+// Copyright (C) 2025 Tim Blechmann
+// SPDX-License-Identifier: MIT
+
+//! [`Interpolatable`] for the vectors, points and rotations of `nalgebra`.
+
+use nalgebra::{Point, SVector, UnitQuaternion};
+
+use crate::{Easing, Interpolatable};
+
+/// Interpolates component-wise, forwarding `ease` to the components.
+impl<T, const D: usize> Interpolatable for SVector<T, D>
+where
+    T: nalgebra::Scalar + Interpolatable,
+{
+    fn lerp(self, other: Self, t: f32) -> Self {
+        self.zip_map(&other, |a, b| a.lerp(b, t))
+    }
+
+    fn ease(self, other: Self, t: f32, easing: Easing) -> Self {
+        self.zip_map(&other, |a, b| a.ease(b, t, easing))
+    }
+}
+
+/// Interpolates the coordinates.
+impl<T, const D: usize> Interpolatable for Point<T, D>
+where
+    T: nalgebra::Scalar + Interpolatable,
+{
+    fn lerp(self, other: Self, t: f32) -> Self {
+        Point::from(self.coords.lerp(other.coords, t))
+    }
+
+    fn ease(self, other: Self, t: f32, easing: Easing) -> Self {
+        Point::from(self.coords.ease(other.coords, t, easing))
+    }
+}
+
+/// Interpolates along the shortest arc with constant angular velocity (slerp). Overshooting
+/// easings continue to rotate past either end.
+impl Interpolatable for UnitQuaternion<f32> {
+    fn lerp(self, other: Self, t: f32) -> Self {
+        self.slerp(&other, t)
+    }
+}
+
+/// Interpolates along the shortest arc with constant angular velocity (slerp). Overshooting
+/// easings continue to rotate past either end.
+impl Interpolatable for UnitQuaternion<f64> {
+    fn lerp(self, other: Self, t: f32) -> Self {
+        self.slerp(&other, t as f64)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use crate::{Easing, EasingArgument, Interpolatable};
+    use approx::assert_relative_eq;
+    use core::f64::consts::FRAC_PI_2;
+    use nalgebra::{Point2, UnitQuaternion, Vector3, Vector4};
+
+    #[test]
+    fn vectors_and_points() {
+        let (a, b) = (
+            Vector3::new(0.0f32, 10.0, -4.0),
+            Vector3::new(1.0, 20.0, 4.0),
+        );
+        assert_eq!(a.lerp(b, 0.5), Vector3::new(0.5, 15.0, 0.0));
+        let eased = a.ease_to(b, 0.5, Easing::InQuad);
+        assert_eq!(eased, Vector3::new(0.25, 12.5, -2.0));
+
+        let (a, b) = (Point2::new(1.0f64, 1.0), Point2::new(3.0, -1.0));
+        assert_eq!(a.lerp(b, 0.25), Point2::new(1.5, 0.5));
+        let eased = a.ease_to(b, 0.3, Easing::OutBack);
+        assert_relative_eq!(eased.x, 1.0 + 2.0 * 0.3f64.ease_out_back(), epsilon = 1e-6);
+
+        let colors = Vector4::repeat([0.0f32, 1.0]);
+        assert_eq!(
+            colors.lerp(Vector4::repeat([1.0, 0.0]), 0.25),
+            Vector4::repeat([0.25, 0.75])
+        );
+    }
+
+    #[test]
+    fn rotations() {
+        let from = UnitQuaternion::identity();
+        let to = UnitQuaternion::from_euler_angles(0.0, 0.0, FRAC_PI_2);
+        let halfway = from.ease_to(to, 0.5, Easing::InOutSine);
+        assert_relative_eq!(halfway.angle(), FRAC_PI_2 / 2.0, epsilon = 1e-12);
+
+        // Past the end with an overshooting easing.
+        let overshoot = from.lerp(to, 1.25);
+        assert_relative_eq!(overshoot.angle(), 1.25 * FRAC_PI_2, epsilon = 1e-12);
+
+        // The shortest arc, although the quaternions have opposite signs.
+        let flipped = UnitQuaternion::new_unchecked(-to.into_inner());
+        assert_relative_eq!(
+            from.lerp(flipped, 0.5).angle(),
+            FRAC_PI_2 / 2.0,
+            epsilon = 1e-12
+        );
+
+        let single = UnitQuaternion::<f32>::identity().lerp(to.cast(), 1.0);
+        assert_relative_eq!(single.angle(), core::f32::consts::FRAC_PI_2, epsilon = 1e-6);
+    }
+}
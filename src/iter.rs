@@ -0,0 +1,168 @@
+// Copyright (C) 2025 Tim Blechmann
+// SPDX-License-Identifier: MIT
+
+//! Easing of iterators, e.g. for animation frames or plots without intermediate vectors.
+
+use core::iter::FusedIterator;
+
+use crate::Easing;
+use crate::custom::EasingValue;
+
+macro_rules! eased_methods {
+    ($($method:ident => $variant:ident),+ $(,)?) => {
+        $(
+            #[doc = concat!("Applies [`Easing::", stringify!($variant), "`] to every item.")]
+            #[inline]
+            fn $method(self) -> Eased<Self> {
+                self.eased(Easing::$variant)
+            }
+        )+
+    };
+}
+
+/// Extension of iterators over [`EasingValue`]s, applying an easing to every item.
+///
+/// ```
+/// use nova_easing::{Easing, EasedIterator};
+///
+/// let frames: Vec<f32> = (0..=4).map(|i| i as f32 / 4.0).eased_in_out_quad().collect();
+/// assert_eq!(frames, [0.0, 0.125, 0.5, 0.875, 1.0]);
+///
+/// let curve = (0..=2).map(|i| i as f64 / 2.0).eased(Easing::InPow(3.0));
+/// assert_eq!(curve.collect::<Vec<_>>(), [0.0, 0.125, 1.0]);
+/// ```
+pub trait EasedIterator: Iterator + Sized
+where
+    Self::Item: EasingValue,
+{
+    /// Applies `easing` to every item.
+    #[inline]
+    fn eased(self, easing: Easing) -> Eased<Self> {
+        Eased { iter: self, easing }
+    }
+
+    eased_methods!(
+        eased_in_quad => InQuad,
+        eased_out_quad => OutQuad,
+        eased_in_out_quad => InOutQuad,
+        eased_in_cubic => InCubic,
+        eased_out_cubic => OutCubic,
+        eased_in_out_cubic => InOutCubic,
+        eased_in_quart => InQuart,
+        eased_out_quart => OutQuart,
+        eased_in_out_quart => InOutQuart,
+        eased_in_quint => InQuint,
+        eased_out_quint => OutQuint,
+        eased_in_out_quint => InOutQuint,
+        eased_in_sine => InSine,
+        eased_out_sine => OutSine,
+        eased_in_out_sine => InOutSine,
+        eased_in_circ => InCirc,
+        eased_out_circ => OutCirc,
+        eased_in_out_circ => InOutCirc,
+        eased_in_back => InBack,
+        eased_out_back => OutBack,
+        eased_in_out_back => InOutBack,
+        eased_in_bounce => InBounce,
+        eased_out_bounce => OutBounce,
+        eased_in_out_bounce => InOutBounce,
+        eased_in_expo => InExpo,
+        eased_out_expo => OutExpo,
+        eased_in_out_expo => InOutExpo,
+        eased_in_elastic => InElastic,
+        eased_out_elastic => OutElastic,
+        eased_in_out_elastic => InOutElastic,
+    );
+}
+
+impl<I> EasedIterator for I
+where
+    I: Iterator,
+    I::Item: EasingValue,
+{
+}
+
+/// An iterator applying an easing to the items of another, see [`EasedIterator`].
+#[derive(Debug, Clone)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct Eased<I> {
+    iter: I,
+    easing: Easing,
+}
+
+impl<I> Eased<I> {
+    /// The applied easing.
+    pub fn easing(&self) -> Easing {
+        self.easing
+    }
+}
+
+impl<I> Iterator for Eased<I>
+where
+    I: Iterator,
+    I::Item: EasingValue,
+{
+    type Item = I::Item;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter.next().map(|t| t.eased(self.easing))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<I> DoubleEndedIterator for Eased<I>
+where
+    I: DoubleEndedIterator,
+    I::Item: EasingValue,
+{
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.iter.next_back().map(|t| t.eased(self.easing))
+    }
+}
+
+impl<I> ExactSizeIterator for Eased<I>
+where
+    I: ExactSizeIterator,
+    I::Item: EasingValue,
+{
+}
+
+impl<I> FusedIterator for Eased<I>
+where
+    I: FusedIterator,
+    I::Item: EasingValue,
+{
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::EasedIterator;
+    use crate::{Easing, EasingArgument};
+
+    #[test]
+    fn matches_scalar() {
+        let t = (0..=10).map(|i| i as f32 / 10.0);
+        for (eased, t) in t.clone().eased_out_bounce().zip(t.clone()) {
+            assert_eq!(eased, t.ease_out_bounce());
+        }
+        for (eased, t) in t.clone().eased(Easing::InOutCurve(-2.0)).zip(t) {
+            assert_eq!(eased, t.ease_in_out_curve(-2.0));
+        }
+    }
+
+    #[test]
+    fn adapters() {
+        let eased = [0.0f64, 0.5, 1.0].into_iter().eased_in_quad();
+        assert_eq!(eased.easing(), Easing::InQuad);
+        assert_eq!(eased.len(), 3);
+        assert_eq!(eased.rev().collect::<Vec<_>>(), [1.0, 0.25, 0.0]);
+    }
+}
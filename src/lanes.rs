@@ -0,0 +1,990 @@
+// Copyright (C) 2025 Tim Blechmann
+// SPDX-License-Identifier: MIT
+
+//! The vector backends of the easings.
+//!
+//! [`Batch`] abstracts over vectors of `N` lanes of `f32` or `f64`: comparisons producing masks,
+//! per-lane selection and the math functions the easings need. The branch-free kernels of the
+//! easings are written once against it, and every backend implements `EasingImplHelper` by
+//! forwarding to them via `batch_easing_helper!`:
+//!
+//! * `core::simd::Simd` with the `nightly` feature,
+//! * the vectors of the `wide` crate with the `wide` feature,
+//! * [`Lanes`], plain arrays evaluated lane by lane, which need neither.
+//!
+//! Scalars keep their own implementation in `lib.rs`, since they can branch instead of blending.
+
+use core::ops::{Add, Div, Index, Mul, Neg, Sub};
+
+use crate::internal::{CurveParam, LaneScalar};
+use crate::{BACK_C2, BACK_C2_PLUS_1, EasingImplHelper, Scalar};
+
+#[cfg(feature = "nightly")]
+use core::simd::{LaneCount, Mask, Simd, SupportedLaneCount};
+#[cfg(feature = "nightly")]
+use std::simd::StdFloat;
+#[cfg(feature = "nightly")]
+use std::simd::cmp::{SimdPartialEq, SimdPartialOrd};
+#[cfg(feature = "nightly")]
+use std::simd::num::SimdFloat;
+
+/// A vector of `N` lanes of type `T`.
+pub(crate) trait Batch<T: LaneScalar, const N: usize>:
+    Add<Output = Self>
+    + Sub<Output = Self>
+    + Mul<Output = Self>
+    + Div<Output = Self>
+    + Neg<Output = Self>
+    + Copy
+{
+    /// The result of a lane-wise comparison.
+    type Mask: Copy;
+
+    fn splat(value: T) -> Self;
+    #[cfg(feature = "validate")]
+    fn lane(self, index: usize) -> T;
+
+    fn lanes_lt(self, other: Self) -> Self::Mask;
+    fn lanes_eq(self, other: Self) -> Self::Mask;
+    /// `if_true` in the lanes set in `mask`, `if_false` in the others.
+    fn select(mask: Self::Mask, if_true: Self, if_false: Self) -> Self;
+    fn all(mask: Self::Mask) -> bool;
+    fn any(mask: Self::Mask) -> bool;
+
+    fn sin(self) -> Self;
+    fn cos(self) -> Self;
+    fn exp(self) -> Self;
+    fn ln(self) -> Self;
+    fn sqrt(self) -> Self;
+    fn floor(self) -> Self;
+    fn abs(self) -> Self;
+    fn min(self, other: Self) -> Self;
+    fn mul_add(self, a: Self, b: Self) -> Self;
+
+    #[inline(always)]
+    fn constant(value: f32) -> Self {
+        Self::splat(T::from_f32_scalar(value))
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+// Selects per lane between two branches, evaluating only one of them if all lanes agree. Ramps
+// over contiguous buffers almost always have lane-coherent inputs, so evaluating both branches
+// would be wasted work.
+macro_rules! select_uniform {
+    ($batch:ty, $mask:expr, $if_true:expr, $if_false:expr) => {{
+        let mask = $mask;
+        if <$batch>::all(mask) {
+            $if_true
+        } else if !<$batch>::any(mask) {
+            $if_false
+        } else {
+            <$batch>::select(mask, $if_true, $if_false)
+        }
+    }};
+}
+
+// The kernels shared by all backends. They take the same bounds, so they are generated with them.
+macro_rules! kernels {
+    ($(fn $name:ident($($arg:ident: $ty:ty),*) -> $ret:ty $body:block)*) => {$(
+        #[inline(always)]
+        pub(crate) fn $name<T, const N: usize, B>($($arg: $ty),*) -> $ret
+        where
+            T: LaneScalar,
+            B: Batch<T, N> + EasingImplHelper,
+        $body
+    )*};
+}
+
+kernels! {
+    fn powi(x: B, n: i32) -> B {
+        // Square and multiply, as a loop rather than recursion, so it is inlined and unrolled for
+        // the constant exponents of the easings.
+        let (mut result, mut base, mut n) = (B::constant(1.0), x, n);
+        while n > 0 {
+            if n % 2 == 1 {
+                result = result * base;
+            }
+            n /= 2;
+            if n > 0 {
+                base = base * base;
+            }
+        }
+        result
+    }
+
+    fn powf(x: B, exponent: B) -> B {
+        Batch::exp(exponent * Batch::ln(x))
+    }
+
+    fn ease_in_out_quad(x: B) -> B {
+        let half = B::constant(0.5);
+        let mask = x.lanes_lt(half);
+
+        let lower_half = || x.powi(2).double();
+        let upper_half = || B::constant(1.0) - (x.double() - B::constant(2.0)).powi(2) * half;
+
+        select_uniform!(B, mask, lower_half(), upper_half())
+    }
+
+    fn ease_in_out_cubic(x: B) -> B {
+        let half = B::constant(0.5);
+        let mask = x.lanes_lt(half);
+
+        let lower_half = || {
+            let cubed = x.powi(3);
+            let doubled = cubed.double();
+            doubled + doubled
+        };
+
+        let upper_half = || {
+            let one = B::constant(1.0);
+            let two = B::constant(2.0);
+            one - (two - x.double()).powi(3) * half
+        };
+
+        select_uniform!(B, mask, lower_half(), upper_half())
+    }
+
+    fn ease_in_out_quart(x: B) -> B {
+        let half = B::constant(0.5);
+        let mask = x.lanes_lt(half);
+
+        let lower_half = || B::constant(8.0) * x.powi(4);
+        let upper_half = || {
+            let one = B::constant(1.0);
+            let two = B::constant(2.0);
+            one - (two - x.double()).powi(4) * half
+        };
+        select_uniform!(B, mask, lower_half(), upper_half())
+    }
+
+    fn ease_in_out_quint(x: B) -> B {
+        let half = B::constant(0.5);
+        let mask = x.lanes_lt(half);
+
+        let lower_half = || B::constant(16.0) * x.powi(5);
+        let upper_half = || {
+            let one = B::constant(1.0);
+            let two = B::constant(2.0);
+            one - (two - x.double()).powi(5) * half
+        };
+        select_uniform!(B, mask, lower_half(), upper_half())
+    }
+
+    // Both halves are `u^2 ((c2 + 1) u ∓ c2) (+ 2)`, so the terms which differ are blended and a
+    // single polynomial is evaluated, with the same operations as the scalar version.
+    fn ease_in_out_back(x: B) -> B {
+        let c2 = B::splat(T::from_f64_scalar(BACK_C2));
+        let c2_plus_1 = B::splat(T::from_f64_scalar(BACK_C2_PLUS_1));
+        let (half, two) = (B::constant(0.5), B::constant(2.0));
+        let mask = x.lanes_lt(half);
+
+        let two_x = x.double();
+        let u = B::select(mask, two_x, two_x - two);
+        let sign = B::select(mask, -c2, c2);
+        let offset = B::select(mask, B::constant(0.0), two);
+        let inner = Batch::mul_add(c2_plus_1, u, sign);
+        Batch::mul_add(u * u, inner, offset) * half
+    }
+
+    fn ease_out_bounce(x: B) -> B {
+        let n1 = B::constant(7.5625);
+        let mask1 = x.lanes_lt(B::constant(1.0 / 2.75));
+        let mask2 = x.lanes_lt(B::constant(2.0 / 2.75));
+        let mask3 = x.lanes_lt(B::constant(2.5 / 2.75));
+
+        // All segments are parabolas `n1 * (x - offset)^2 + constant`: blend offset and
+        // constant per lane, then evaluate once.
+        let zero = B::constant(0.0);
+        let offset = B::select(
+            mask1,
+            zero,
+            B::select(
+                mask2,
+                B::constant(1.5 / 2.75),
+                B::select(mask3, B::constant(2.25 / 2.75), B::constant(2.625 / 2.75)),
+            ),
+        );
+        let constant = B::select(
+            mask1,
+            zero,
+            B::select(
+                mask2,
+                B::constant(0.75),
+                B::select(mask3, B::constant(0.9375), B::constant(0.984375)),
+            ),
+        );
+        let adjusted = x - offset;
+        Batch::mul_add(adjusted * adjusted, n1, constant)
+    }
+
+    fn ease_in_out_bounce(x: B) -> B {
+        let half = B::constant(0.5);
+        let one = B::constant(1.0);
+        let mask = x.lanes_lt(half);
+        let lower_half = || one - ease_out_bounce(one - x.double());
+        let upper_half = || one + ease_out_bounce(x.double() - one);
+        select_uniform!(B, mask, lower_half(), upper_half()) * half
+    }
+
+    fn ease_in_expo(x: B) -> B {
+        let zero = B::constant(0.0);
+        let ln2 = B::splat(T::ln_2());
+        let ten = B::constant(10.0);
+        let mask_zero = x.lanes_eq(zero);
+        let exponent = Batch::mul_add(ten, x, -ten);
+        let normal = Batch::exp(exponent * ln2);
+        B::select(mask_zero, zero, normal)
+    }
+
+    fn ease_out_expo(x: B) -> B {
+        let one = B::constant(1.0);
+        let ln2 = B::splat(T::ln_2());
+        let neg_ten = B::constant(-10.0);
+        let mask_one = x.lanes_eq(one);
+        let exponent = neg_ten * x;
+        let normal = Batch::mul_add(Batch::exp(exponent * ln2), -one, one);
+        B::select(mask_one, one, normal)
+    }
+
+    fn ease_in_out_expo(x: B) -> B {
+        let zero = B::constant(0.0);
+        let one = B::constant(1.0);
+        let half = B::constant(0.5);
+        let ln2 = B::splat(T::ln_2());
+        let twenty = B::constant(20.0);
+        let ten = B::constant(10.0);
+        let mask_zero = x.lanes_eq(zero);
+        let mask_one = x.lanes_eq(one);
+        let mask_half = x.lanes_lt(half);
+        let branch_lower = || {
+            let exponent_lower = Batch::mul_add(twenty, x, -ten);
+            Batch::exp(exponent_lower * ln2) * half
+        };
+        let branch_upper = || {
+            let exponent_upper = Batch::mul_add(-twenty, x, ten);
+            Batch::mul_add(Batch::exp(exponent_upper * ln2), -half, one)
+        };
+        let temp = select_uniform!(B, mask_half, branch_lower(), branch_upper());
+        let temp2 = B::select(mask_one, one, temp);
+        B::select(mask_zero, zero, temp2)
+    }
+
+    fn ease_in_elastic(x: B) -> B {
+        let zero = B::constant(0.0);
+        let one = B::constant(1.0);
+        let ln2 = B::splat(T::ln_2());
+        let c4 = B::constant(2.094_395_2);
+        let ten = B::constant(10.0);
+        let mask_zero = x.lanes_eq(zero);
+        let mask_one = x.lanes_eq(one);
+        let exponent = Batch::mul_add(ten, x, -ten);
+        let sin_arg = Batch::mul_add(ten, x, B::constant(-10.75)) * c4;
+        let normal = -Batch::exp(exponent * ln2) * Batch::sin(sin_arg);
+        let temp = B::select(mask_one, one, normal);
+        B::select(mask_zero, zero, temp)
+    }
+
+    fn ease_out_elastic(x: B) -> B {
+        let zero = B::constant(0.0);
+        let one = B::constant(1.0);
+        let ln2 = B::splat(T::ln_2());
+        let c4 = B::constant(2.094_395_2);
+        let ten = B::constant(10.0);
+        let mask_zero = x.lanes_eq(zero);
+        let mask_one = x.lanes_eq(one);
+        let exponent = -ten * x;
+        let sin_arg = Batch::mul_add(ten, x, B::constant(-0.75)) * c4;
+        let normal = Batch::mul_add(Batch::exp(exponent * ln2), Batch::sin(sin_arg), one);
+        let temp = B::select(mask_one, one, normal);
+        B::select(mask_zero, zero, temp)
+    }
+
+    fn ease_in_out_elastic(x: B) -> B {
+        let zero = B::constant(0.0);
+        let one = B::constant(1.0);
+        let half = B::constant(0.5);
+        let ln2 = B::splat(T::ln_2());
+        let c5 = B::constant(1.396_263_4);
+        let twenty = B::constant(20.0);
+        let ten = B::constant(10.0);
+        let mask_zero = x.lanes_eq(zero);
+        let mask_one = x.lanes_eq(one);
+        let mask_half = x.lanes_lt(half);
+
+        // The exponent of the upper half is the negated exponent of the lower half, so a single
+        // `exp` of the blended exponent serves both halves.
+        let exponent = Batch::mul_add(twenty, x, -ten) * B::select(mask_half, one, -one);
+        let sin_arg = Batch::mul_add(twenty, x, B::constant(-11.125)) * c5;
+        let scaled = Batch::exp(exponent * ln2) * Batch::sin(sin_arg);
+        let temp = Batch::mul_add(
+            scaled,
+            B::select(mask_half, -half, half),
+            B::select(mask_half, zero, one),
+        );
+        let temp2 = B::select(mask_one, one, temp);
+        B::select(mask_zero, zero, temp2)
+    }
+
+    fn ease_in_out_circ(x: B) -> B {
+        let half = B::constant(0.5);
+        let mask = x.lanes_lt(half);
+
+        let one = B::constant(1.0);
+        let two = B::constant(2.0);
+        let double = x.double();
+
+        let lower_half = || one - Batch::sqrt(one - double.powi(2));
+        let upper_half = || Batch::sqrt(one - (two - double).powi(2)) + one;
+        select_uniform!(B, mask, lower_half(), upper_half()) * half
+    }
+
+    fn ease_in_curve(x: B, c: B, threshold: B) -> B {
+        let one = B::constant(1.0);
+        let mask = Batch::abs(c).lanes_lt(threshold);
+        let series = x.curve_series(c);
+
+        // Lanes share one evaluation, so the series lanes would divide by zero and extreme lanes
+        // overflow `exp`. Both get safe curves, and the series lanes are selected afterwards.
+        let c = B::select(mask, one, Batch::min(c, B::splat(T::max_curve())));
+        let grow = Batch::exp(c);
+        let a = one / (one - grow);
+        let normal = a - (a * Batch::exp(c * x));
+        B::select(mask, series, normal)
+    }
+
+    fn ease_out_curve(x: B, c: B, threshold: B) -> B {
+        let one = B::constant(1.0);
+        one - ease_in_curve(one - x, c, threshold)
+    }
+
+    fn ease_in_out_curve(x: B, c: B, threshold: B) -> B {
+        let half = B::constant(0.5);
+        let mask = x.lanes_lt(half);
+        let lower_half = || ease_in_curve(x.double(), c, threshold) * half;
+        let upper_half = || half + ease_out_curve((x - half).double(), c, threshold) * half;
+        select_uniform!(B, mask, lower_half(), upper_half())
+    }
+}
+
+// The body of `EasingImplHelper` for a backend, forwarding to `Batch` and the kernels.
+macro_rules! batch_easing_helper {
+    ($scalar:ty, $lanes:tt) => {
+        #[inline(always)]
+        fn from_f32(arg: f32) -> Self {
+            <Self as Batch<$scalar, $lanes>>::constant(arg)
+        }
+        #[inline(always)]
+        fn ln_2() -> Self {
+            <Self as Batch<$scalar, $lanes>>::splat(<$scalar as LaneScalar>::ln_2())
+        }
+        #[cfg(feature = "validate")]
+        #[inline(always)]
+        fn lane_count() -> usize {
+            $lanes
+        }
+        #[cfg(feature = "validate")]
+        #[inline(always)]
+        fn lane(self, index: usize) -> f64 {
+            Batch::lane(self, index).to_f64_scalar()
+        }
+        #[cfg(feature = "validate")]
+        #[inline(always)]
+        fn max_curve_f64() -> f64 {
+            <$scalar as LaneScalar>::max_curve().to_f64_scalar()
+        }
+        #[inline(always)]
+        fn curve_threshold() -> f32 {
+            <$scalar as LaneScalar>::curve_threshold()
+        }
+
+        #[inline(always)]
+        fn sin(self) -> Self {
+            Batch::sin(self)
+        }
+        #[inline(always)]
+        fn cos(self) -> Self {
+            Batch::cos(self)
+        }
+        #[inline(always)]
+        fn powi(self, n: i32) -> Self {
+            powi(self, n)
+        }
+        #[inline(always)]
+        fn powf(self, other: Self) -> Self {
+            powf(self, other)
+        }
+        #[inline(always)]
+        fn sqrt(self) -> Self {
+            Batch::sqrt(self)
+        }
+        #[inline(always)]
+        fn floor(self) -> Self {
+            Batch::floor(self)
+        }
+        #[inline(always)]
+        fn exp(self) -> Self {
+            Batch::exp(self)
+        }
+        #[inline(always)]
+        fn mul_add(self, a: Self, b: Self) -> Self {
+            Batch::mul_add(self, a, b)
+        }
+        #[inline(always)]
+        fn select_lt(self, threshold: Self, if_less: Self, otherwise: Self) -> Self {
+            Self::select(self.lanes_lt(threshold), if_less, otherwise)
+        }
+
+        #[inline(always)]
+        fn ease_in_out_quad(self) -> Self {
+            ease_in_out_quad(self)
+        }
+        #[inline(always)]
+        fn ease_in_out_cubic(self) -> Self {
+            ease_in_out_cubic(self)
+        }
+        #[inline(always)]
+        fn ease_in_out_quart(self) -> Self {
+            ease_in_out_quart(self)
+        }
+        #[inline(always)]
+        fn ease_in_out_quint(self) -> Self {
+            ease_in_out_quint(self)
+        }
+        #[inline(always)]
+        fn ease_in_out_back(self) -> Self {
+            ease_in_out_back(self)
+        }
+        #[inline(always)]
+        fn ease_out_bounce(self) -> Self {
+            ease_out_bounce(self)
+        }
+        #[inline(always)]
+        fn ease_in_out_bounce(self) -> Self {
+            ease_in_out_bounce(self)
+        }
+        #[inline(always)]
+        fn ease_in_expo(self) -> Self {
+            ease_in_expo(self)
+        }
+        #[inline(always)]
+        fn ease_out_expo(self) -> Self {
+            ease_out_expo(self)
+        }
+        #[inline(always)]
+        fn ease_in_out_expo(self) -> Self {
+            ease_in_out_expo(self)
+        }
+        #[inline(always)]
+        fn ease_in_elastic(self) -> Self {
+            ease_in_elastic(self)
+        }
+        #[inline(always)]
+        fn ease_out_elastic(self) -> Self {
+            ease_out_elastic(self)
+        }
+        #[inline(always)]
+        fn ease_in_out_elastic(self) -> Self {
+            ease_in_out_elastic(self)
+        }
+        #[inline(always)]
+        fn ease_in_out_circ(self) -> Self {
+            ease_in_out_circ(self)
+        }
+        #[inline(always)]
+        fn ease_in_curve<C>(self, curve: C, threshold: Self) -> Self
+        where
+            C: CurveParam<Self>,
+        {
+            ease_in_curve(self, curve.to_curve(), threshold)
+        }
+        #[inline(always)]
+        fn ease_out_curve<C>(self, curve: C, threshold: Self) -> Self
+        where
+            C: CurveParam<Self>,
+        {
+            ease_out_curve(self, curve.to_curve(), threshold)
+        }
+        #[inline(always)]
+        fn ease_in_out_curve<C>(self, curve: C, threshold: Self) -> Self
+        where
+            C: CurveParam<Self>,
+        {
+            ease_in_out_curve(self, curve.to_curve(), threshold)
+        }
+    };
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// `N` lanes of `f32` or `f64`, evaluated one by one with the vector algorithms of the easings.
+///
+/// `Lanes` is an [`EasingArgument`](crate::EasingArgument) on stable Rust and without any SIMD
+/// dependency: it computes the same branch-free formulas as the SIMD vectors of the `nightly` and
+/// `wide` features, lane by lane, which the compiler is free to auto-vectorize. It also serves as
+/// the reference the SIMD backends are compared to.
+///
+/// ```
+/// use nova_easing::{EasingArgument, Lanes};
+///
+/// let t = Lanes([0.0f32, 0.25, 0.75, 1.0]);
+/// let eased = t.ease_in_out_cubic();
+/// assert_eq!(eased.0[1], 0.25f32.ease_in_out_cubic());
+/// assert_eq!(eased[3], 1.0);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Lanes<T, const N: usize>(pub [T; N]);
+
+impl<T: Copy, const N: usize> Lanes<T, N> {
+    /// Sets all lanes to `value`.
+    pub fn splat(value: T) -> Self {
+        Self([value; N])
+    }
+
+    /// The values of the lanes.
+    pub fn to_array(self) -> [T; N] {
+        self.0
+    }
+
+    fn map(self, f: impl Fn(T) -> T) -> Self {
+        Self(self.0.map(f))
+    }
+
+    fn zip(self, other: Self, f: impl Fn(T, T) -> T) -> Self {
+        Self(core::array::from_fn(|lane| f(self.0[lane], other.0[lane])))
+    }
+
+    fn mask(self, other: Self, f: impl Fn(T, T) -> bool) -> [bool; N] {
+        core::array::from_fn(|lane| f(self.0[lane], other.0[lane]))
+    }
+}
+
+impl<T, const N: usize> From<[T; N]> for Lanes<T, N> {
+    fn from(lanes: [T; N]) -> Self {
+        Self(lanes)
+    }
+}
+
+impl<T, const N: usize> Index<usize> for Lanes<T, N> {
+    type Output = T;
+
+    fn index(&self, lane: usize) -> &T {
+        &self.0[lane]
+    }
+}
+
+macro_rules! lanes_operators {
+    ($($trait:ident $method:ident),+) => {$(
+        impl<T: Scalar, const N: usize> $trait for Lanes<T, N> {
+            type Output = Self;
+
+            fn $method(self, rhs: Self) -> Self {
+                self.zip(rhs, T::$method)
+            }
+        }
+    )+};
+}
+
+lanes_operators!(Add add, Sub sub, Mul mul, Div div);
+
+impl<T: Scalar, const N: usize> Neg for Lanes<T, N> {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        self.map(T::neg)
+    }
+}
+
+impl<T: Scalar + LaneScalar, const N: usize> Batch<T, N> for Lanes<T, N> {
+    type Mask = [bool; N];
+
+    #[inline(always)]
+    fn splat(value: T) -> Self {
+        Self([value; N])
+    }
+    #[cfg(feature = "validate")]
+    #[inline(always)]
+    fn lane(self, index: usize) -> T {
+        self.0[index]
+    }
+
+    #[inline(always)]
+    fn lanes_lt(self, other: Self) -> [bool; N] {
+        self.mask(other, |a, b| a < b)
+    }
+    #[inline(always)]
+    fn lanes_eq(self, other: Self) -> [bool; N] {
+        self.mask(other, |a, b| a == b)
+    }
+    #[inline(always)]
+    fn select(mask: [bool; N], if_true: Self, if_false: Self) -> Self {
+        Self(core::array::from_fn(|lane| {
+            if mask[lane] {
+                if_true.0[lane]
+            } else {
+                if_false.0[lane]
+            }
+        }))
+    }
+    #[inline(always)]
+    fn all(mask: [bool; N]) -> bool {
+        mask.iter().all(|&lane| lane)
+    }
+    #[inline(always)]
+    fn any(mask: [bool; N]) -> bool {
+        mask.iter().any(|&lane| lane)
+    }
+
+    #[inline(always)]
+    fn sin(self) -> Self {
+        self.map(T::sin)
+    }
+    #[inline(always)]
+    fn cos(self) -> Self {
+        self.map(T::cos)
+    }
+    #[inline(always)]
+    fn exp(self) -> Self {
+        self.map(T::exp)
+    }
+    #[inline(always)]
+    fn ln(self) -> Self {
+        self.map(T::ln)
+    }
+    #[inline(always)]
+    fn sqrt(self) -> Self {
+        self.map(T::sqrt)
+    }
+    #[inline(always)]
+    fn floor(self) -> Self {
+        self.map(T::floor)
+    }
+    #[inline(always)]
+    fn abs(self) -> Self {
+        self.map(T::abs)
+    }
+    #[inline(always)]
+    fn min(self, other: Self) -> Self {
+        self.zip(other, T::min)
+    }
+    #[inline(always)]
+    fn mul_add(self, a: Self, b: Self) -> Self {
+        Self(core::array::from_fn(|lane| {
+            self.0[lane].mul_add(a.0[lane], b.0[lane])
+        }))
+    }
+}
+
+impl<T: Scalar + LaneScalar, const N: usize> EasingImplHelper for Lanes<T, N> {
+    batch_easing_helper!(T, N);
+}
+
+impl<T: Scalar + LaneScalar, const N: usize> CurveParam<Lanes<T, N>> for T {
+    fn to_curve(self) -> Lanes<T, N> {
+        Lanes::splat(self)
+    }
+}
+
+impl<T: Scalar + LaneScalar, const N: usize> CurveParam<Lanes<T, N>> for Lanes<T, N> {
+    fn to_curve(self) -> Lanes<T, N> {
+        self
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(feature = "nightly")]
+impl<T, const N: usize> Batch<T, N> for Simd<T, N>
+where
+    T: LaneScalar + core::simd::SimdElement,
+    T::Mask: core::simd::MaskElement,
+    LaneCount<N>: SupportedLaneCount,
+    Simd<T, N>: StdFloat
+        + SimdFloat
+        + SimdPartialEq<Mask = Mask<T::Mask, N>>
+        + SimdPartialOrd
+        + Add<Output = Simd<T, N>>
+        + Sub<Output = Simd<T, N>>
+        + Mul<Output = Simd<T, N>>
+        + Div<Output = Simd<T, N>>
+        + Neg<Output = Simd<T, N>>,
+{
+    type Mask = Mask<T::Mask, N>;
+
+    #[inline(always)]
+    fn splat(value: T) -> Self {
+        Simd::splat(value)
+    }
+    #[cfg(feature = "validate")]
+    #[inline(always)]
+    fn lane(self, index: usize) -> T {
+        self[index]
+    }
+
+    #[inline(always)]
+    fn lanes_lt(self, other: Self) -> Self::Mask {
+        self.simd_lt(other)
+    }
+    #[inline(always)]
+    fn lanes_eq(self, other: Self) -> Self::Mask {
+        self.simd_eq(other)
+    }
+    #[inline(always)]
+    fn select(mask: Self::Mask, if_true: Self, if_false: Self) -> Self {
+        mask.select(if_true, if_false)
+    }
+    #[inline(always)]
+    fn all(mask: Self::Mask) -> bool {
+        mask.all()
+    }
+    #[inline(always)]
+    fn any(mask: Self::Mask) -> bool {
+        mask.any()
+    }
+
+    #[inline(always)]
+    fn sin(self) -> Self {
+        StdFloat::sin(self)
+    }
+    #[inline(always)]
+    fn cos(self) -> Self {
+        StdFloat::cos(self)
+    }
+    #[inline(always)]
+    fn exp(self) -> Self {
+        StdFloat::exp(self)
+    }
+    #[inline(always)]
+    fn ln(self) -> Self {
+        StdFloat::ln(self)
+    }
+    #[inline(always)]
+    fn sqrt(self) -> Self {
+        StdFloat::sqrt(self)
+    }
+    #[inline(always)]
+    fn floor(self) -> Self {
+        StdFloat::floor(self)
+    }
+    #[inline(always)]
+    fn abs(self) -> Self {
+        SimdFloat::abs(self)
+    }
+    #[inline(always)]
+    fn min(self, other: Self) -> Self {
+        self.simd_min(other)
+    }
+    #[inline(always)]
+    fn mul_add(self, a: Self, b: Self) -> Self {
+        StdFloat::mul_add(self, a, b)
+    }
+}
+
+#[cfg(feature = "nightly")]
+impl<T, const N: usize> EasingImplHelper for Simd<T, N>
+where
+    T: LaneScalar + core::simd::SimdElement,
+    LaneCount<N>: SupportedLaneCount,
+    Simd<T, N>: Batch<T, N>,
+{
+    batch_easing_helper!(T, N);
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(feature = "wide")]
+macro_rules! wide_backend {
+    ($($vector:ident: $scalar:ident x $lanes:tt),+) => {$(
+        impl Batch<$scalar, $lanes> for wide::$vector {
+            type Mask = Self;
+
+            #[inline(always)]
+            fn splat(value: $scalar) -> Self {
+                wide::$vector::splat(value)
+            }
+            #[cfg(feature = "validate")]
+            #[inline(always)]
+            fn lane(self, index: usize) -> $scalar {
+                self.to_array()[index]
+            }
+
+            #[inline(always)]
+            fn lanes_lt(self, other: Self) -> Self {
+                wide::CmpLt::cmp_lt(self, other)
+            }
+            #[inline(always)]
+            fn lanes_eq(self, other: Self) -> Self {
+                wide::CmpEq::cmp_eq(self, other)
+            }
+            #[inline(always)]
+            fn select(mask: Self, if_true: Self, if_false: Self) -> Self {
+                mask.blend(if_true, if_false)
+            }
+            #[inline(always)]
+            fn all(mask: Self) -> bool {
+                mask.all()
+            }
+            #[inline(always)]
+            fn any(mask: Self) -> bool {
+                mask.any()
+            }
+
+            #[inline(always)]
+            fn sin(self) -> Self {
+                wide::$vector::sin(self)
+            }
+            #[inline(always)]
+            fn cos(self) -> Self {
+                wide::$vector::cos(self)
+            }
+            #[inline(always)]
+            fn exp(self) -> Self {
+                wide::$vector::exp(self)
+            }
+            #[inline(always)]
+            fn ln(self) -> Self {
+                wide::$vector::ln(self)
+            }
+            #[inline(always)]
+            fn sqrt(self) -> Self {
+                wide::$vector::sqrt(self)
+            }
+            #[inline(always)]
+            fn floor(self) -> Self {
+                wide::$vector::floor(self)
+            }
+            #[inline(always)]
+            fn abs(self) -> Self {
+                wide::$vector::abs(self)
+            }
+            #[inline(always)]
+            fn min(self, other: Self) -> Self {
+                wide::$vector::min(self, other)
+            }
+            #[inline(always)]
+            fn mul_add(self, a: Self, b: Self) -> Self {
+                wide::$vector::mul_add(self, a, b)
+            }
+        }
+
+        impl EasingImplHelper for wide::$vector {
+            batch_easing_helper!($scalar, $lanes);
+        }
+
+        impl CurveParam<wide::$vector> for $scalar {
+            #[inline(always)]
+            fn to_curve(self) -> wide::$vector {
+                wide::$vector::splat(self)
+            }
+        }
+
+        impl CurveParam<wide::$vector> for wide::$vector {
+            #[inline(always)]
+            fn to_curve(self) -> wide::$vector {
+                self
+            }
+        }
+    )+};
+}
+
+#[cfg(feature = "wide")]
+wide_backend!(f32x4: f32 x 4, f32x8: f32 x 8, f64x2: f64 x 2, f64x4: f64 x 4);
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::Lanes;
+    use crate::EasingArgument;
+    use crate::easing::tests::EASINGS;
+    use approx::assert_relative_eq;
+
+    // Both halves of the split points, and the endpoints handled by masks. The `f64` lanes share
+    // the `f32` constants of the SIMD formulas, so they are compared with the `f32` tolerance.
+    const POINTS: [f32; 8] = [0.0, 0.1, 0.3, 0.45, 0.5, 0.62, 0.9, 1.0];
+
+    #[test]
+    fn lanes_match_scalar() {
+        let wide = POINTS.map(|t| t as f64);
+        for easing in EASINGS {
+            let narrow = easing.apply(Lanes(POINTS));
+            let wide_lanes = easing.apply(Lanes(wide));
+            for lane in 0..POINTS.len() {
+                let (t, t64) = (POINTS[lane], wide[lane]);
+                assert_relative_eq!(narrow[lane], easing.apply(t), epsilon = 1e-6);
+                assert_relative_eq!(wide_lanes[lane], easing.apply(t64), epsilon = 1e-7);
+            }
+        }
+
+        // Uniform lanes take the single-branch paths.
+        for t in POINTS {
+            let lanes = Lanes::<f32, 4>::splat(t).ease_in_out_bounce();
+            assert_eq!(lanes.to_array(), [t.ease_in_out_bounce(); 4]);
+        }
+    }
+
+    #[test]
+    fn lanes_curves() {
+        // Curves beyond the overflow limit of `f32` behave like the limit itself.
+        let curves = Lanes([1e-5f32, 100.0, -100.0, 2.0]);
+        let limited = [1e-5f64, 80.0, -100.0, 2.0];
+        for t in [0.0f32, 0.01, 0.5, 0.99, 1.0] {
+            let eased = Lanes::splat(t).ease_in_curve(curves);
+            let mirrored = Lanes::splat(t).ease_in_out_curve(curves);
+            for lane in 0..4 {
+                let curve = limited[lane];
+                let expected = (t as f64).ease_in_curve(curve) as f32;
+                assert_relative_eq!(eased[lane], expected, epsilon = 1e-5);
+                let expected = (t as f64).ease_in_out_curve(curve) as f32;
+                assert_relative_eq!(mirrored[lane], expected, epsilon = 1e-5);
+            }
+        }
+    }
+
+    // The SIMD vectors and plain arrays share the kernels, so they agree lane by lane.
+    #[cfg(feature = "nightly")]
+    #[test]
+    fn simd_matches_lanes() {
+        use core::simd::{f32x8, f64x8};
+
+        let wide = POINTS.map(|t| t as f64);
+        for easing in EASINGS {
+            let simd = easing.apply(f32x8::from_array(POINTS)).to_array();
+            let simd64 = easing.apply(f64x8::from_array(wide)).to_array();
+            for lane in 0..POINTS.len() {
+                assert_relative_eq!(
+                    simd[lane],
+                    easing.apply(Lanes(POINTS))[lane],
+                    epsilon = 1e-7
+                );
+                assert_relative_eq!(
+                    simd64[lane],
+                    easing.apply(Lanes(wide))[lane],
+                    epsilon = 1e-15
+                );
+            }
+        }
+    }
+
+    // `wide` brings its own approximations of `sin`, `exp` and `ln`, and shares the `f32` constants
+    // of the `f64` lanes.
+    #[cfg(feature = "wide")]
+    #[test]
+    fn wide_matches_scalar() {
+        use wide::{f32x8, f64x4};
+
+        for easing in EASINGS {
+            let narrow = easing.apply(f32x8::new(POINTS)).to_array();
+            for (t, value) in POINTS.into_iter().zip(narrow) {
+                assert_relative_eq!(value, easing.apply(t), epsilon = 1e-5);
+            }
+            let wide = [0.1f64, 0.45, 0.55, 0.9];
+            let values = easing.apply(f64x4::new(wide)).to_array();
+            for (t, value) in wide.into_iter().zip(values) {
+                assert_relative_eq!(value, easing.apply(t), epsilon = 1e-7);
+            }
+        }
+    }
+}
@@ -0,0 +1,230 @@
+// Copyright (C) 2025 Tim Blechmann
+// SPDX-License-Identifier: MIT
+
+//! Eased color ramps for 8-bit LED strips, rendered with integer arithmetic only.
+//!
+//! Easings are tabulated once when a ramp is created, so rendering a frame needs neither floating
+//! point nor allocation, which suits microcontrollers without an FPU. Colors are computed with
+//! 8 fraction bits per channel and narrowed to 8 bits with temporal dithering: the fraction decides
+//! how often a channel rounds up, so the average over successive frames matches the exact color.
+//! Low-brightness fades, where each 8-bit step is a visible jump, thereby become smooth.
+//!
+//! ```
+//! # use nova_easing::{Easing, led::{FixedCurve, LedRamp}};
+//! let ramp = LedRamp::new([255, 0, 0], [0, 0, 255], Easing::InOutSine);
+//! let fade_in = FixedCurve::new(Easing::InQuad);
+//!
+//! let mut pixels = [[0u8; 3]; 30];
+//! for frame in 0..100u32 {
+//!     let level = fade_in.level((frame * 65535 / 99) as u16);
+//!     ramp.render(&mut pixels, level, frame);
+//!     // ... write `pixels` to the strip.
+//! }
+//! assert_eq!(pixels[0], [255, 0, 0]);
+//! assert_eq!(pixels[29], [0, 0, 255]);
+//! ```
+
+#[allow(unused_imports)]
+use num_traits::Float;
+
+use crate::Easing;
+
+/// An 8-bit RGB color, in the channel order of the strip.
+pub type Rgb8 = [u8; 3];
+
+/// An easing tabulated as fixed point numbers, evaluated with integer arithmetic.
+///
+/// Positions are `u16`, mapping [0, 65535] to [0, 1]. Values have 16 fraction bits, i.e. 65536 is
+/// 1, and may leave [0, 65536] for overshooting easings. The curve is linearly interpolated between
+/// 65 samples, which is accurate to about `1e-3` for all easings except the steep parts of
+/// `Elastic` and `Expo`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FixedCurve {
+    table: [i32; Self::INTERVALS + 1],
+}
+
+impl FixedCurve {
+    const INTERVALS: usize = 64;
+    const ONE: i32 = 1 << 16;
+
+    /// Tabulates `easing`.
+    pub fn new(easing: Easing) -> Self {
+        let mut table = [0; Self::INTERVALS + 1];
+        for (i, entry) in table.iter_mut().enumerate() {
+            let eased = easing.apply(i as f32 / Self::INTERVALS as f32);
+            *entry = (eased * Self::ONE as f32 + 0.5).floor() as i32;
+        }
+        Self { table }
+    }
+
+    /// The eased value at `t`, with 16 fraction bits.
+    pub fn eval(&self, t: u16) -> i32 {
+        // Maps 65535 to 65536, so both ends are exact.
+        let position = t as u32 + (t as u32 >> 15);
+        let index = (position >> 10) as usize;
+        let fraction = (position & 0x3ff) as i32;
+        if index == Self::INTERVALS {
+            return self.table[index];
+        }
+        let (low, high) = (self.table[index], self.table[index + 1]);
+        low + (((high - low) * fraction + 0x200) >> 10)
+    }
+
+    /// The eased value at `t`, clamped to [0, 1] and scaled to [0, 65535], e.g. as a brightness
+    /// level for [`LedRamp::render`].
+    pub fn level(&self, t: u16) -> u16 {
+        let value = self.eval(t).clamp(0, Self::ONE);
+        (value - (value >> 16)) as u16
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// An eased color transition along a strip, from its first to its last pixel.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LedRamp {
+    from: Rgb8,
+    to: Rgb8,
+    curve: FixedCurve,
+}
+
+impl LedRamp {
+    /// Creates a ramp from `from` to `to`, following `easing`.
+    pub fn new(from: Rgb8, to: Rgb8, easing: Easing) -> Self {
+        Self {
+            from,
+            to,
+            curve: FixedCurve::new(easing),
+        }
+    }
+
+    /// A ramp of the single color `color`, e.g. to fade a whole strip in or out.
+    pub fn solid(color: Rgb8) -> Self {
+        Self::new(color, color, Easing::Linear)
+    }
+
+    /// The color at position `t` of the ramp, scaled by `level` (65535 is full brightness), with
+    /// 8 fraction bits per channel.
+    ///
+    /// Overshooting easings are clamped to the 8-bit range of each channel.
+    pub fn color(&self, t: u16, level: u16) -> [u16; 3] {
+        let eased = self.curve.eval(t) as i64;
+        let level = level as i64 + (level as i64 >> 15);
+        core::array::from_fn(|channel| {
+            let (from, to) = (self.from[channel] as i64, self.to[channel] as i64);
+            let value = (from << 8) + (((to - from) * eased + 0x80) >> 8);
+            let value = value.clamp(0, 255 << 8);
+            ((value * level + 0x8000) >> 16) as u16
+        })
+    }
+
+    /// Renders the ramp across `pixels` at brightness `level` (65535 is full brightness), dithered
+    /// for the given `frame`.
+    ///
+    /// `frame` should advance by one for every frame which is sent to the strip, including frames
+    /// where nothing else changes, so the dithering keeps averaging.
+    pub fn render(&self, pixels: &mut [Rgb8], level: u16, frame: u32) {
+        let last = pixels.len().saturating_sub(1).max(1) as u32;
+        for (index, pixel) in pixels.iter_mut().enumerate() {
+            let t = (index as u32 * 65535 / last) as u16;
+            let color = self.color(t, level);
+            *pixel = color.map(|channel| dither(channel, frame, index));
+        }
+    }
+}
+
+/// Narrows `value`, an 8-bit value with 8 fraction bits, to 8 bits for the given `frame`.
+///
+/// Over any 256 consecutive frames, the output sums to exactly `value`, and shorter windows stay
+/// close to it, since the rounding thresholds follow a bit-reversed counter. The `pixel` index
+/// offsets the sequence, so neighbouring pixels of the same color do not flicker in sync.
+pub fn dither(value: u16, frame: u32, pixel: usize) -> u8 {
+    let step = (frame as u8).wrapping_add((pixel as u8).wrapping_mul(157));
+    let threshold = step.reverse_bits() as u32;
+    ((value as u32 + threshold) >> 8).min(255) as u8
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::{FixedCurve, LedRamp, dither};
+    use crate::Easing;
+
+    #[test]
+    fn fixed_curves() {
+        for easing in [
+            Easing::Linear,
+            Easing::InOutCubic,
+            Easing::OutBack,
+            Easing::InSine,
+        ] {
+            let curve = FixedCurve::new(easing);
+            assert_eq!(curve.eval(0), 0);
+            assert_eq!(curve.eval(u16::MAX), 65536);
+            for t in (0..=u16::MAX).step_by(997) {
+                let expected = easing.apply(t as f64 / 65535.0);
+                let actual = curve.eval(t) as f64 / 65536.0;
+                assert!((actual - expected).abs() < 1e-3, "{easing} at {t}");
+            }
+        }
+
+        let back = FixedCurve::new(Easing::InBack);
+        assert!(back.eval(16384) < 0);
+        assert_eq!(back.level(16384), 0);
+        assert_eq!(back.level(u16::MAX), u16::MAX);
+    }
+
+    #[test]
+    fn dithering_averages_exactly() {
+        for value in [0, 1, 100, 0x1280, 0xfe7f, 0xff00] {
+            for pixel in [0, 1, 7] {
+                let sum: u32 = (0..256)
+                    .map(|frame| dither(value, frame, pixel) as u32)
+                    .sum();
+                assert_eq!(sum, value as u32);
+            }
+        }
+        // Short windows are close to the average as well.
+        let sum: u32 = (0..16).map(|frame| dither(0x0140, frame, 3) as u32).sum();
+        assert_eq!(sum, 20);
+    }
+
+    #[test]
+    fn ramps() {
+        let ramp = LedRamp::new([0, 128, 255], [255, 128, 0], Easing::Linear);
+        assert_eq!(ramp.color(0, u16::MAX), [0, 128 << 8, 255 << 8]);
+        assert_eq!(ramp.color(u16::MAX, u16::MAX), [255 << 8, 128 << 8, 0]);
+        assert_eq!(ramp.color(32768, u16::MAX), [0x7f81, 0x8000, 0x7f7f]);
+        assert_eq!(ramp.color(u16::MAX, 32768), [0x7f81, 0x4001, 0]);
+
+        // Overshoot is clamped.
+        let pop = LedRamp::new([0; 3], [255; 3], Easing::OutBack);
+        assert_eq!(pop.color(45000, u16::MAX), [255 << 8; 3]);
+
+        let mut pixels = [[0; 3]; 5];
+        ramp.render(&mut pixels, u16::MAX, 0);
+        assert_eq!(pixels[0], [0, 128, 255]);
+        assert_eq!(pixels[2], [127, 128, 127]);
+        assert_eq!(pixels[4], [255, 128, 0]);
+
+        let mut single = [[0; 3]];
+        LedRamp::solid([10, 20, 30]).render(&mut single, u16::MAX, 5);
+        assert_eq!(single, [[10, 20, 30]]);
+        LedRamp::solid([10, 20, 30]).render(&mut [], u16::MAX, 5);
+    }
+
+    #[test]
+    fn dim_fades_are_smooth() {
+        // At 1/16 brightness, 20 becomes 1.25: a quarter of the frames show 2.
+        let ramp = LedRamp::solid([20, 0, 0]);
+        let mut pixels = [[0; 3]];
+        let lit = (0..256)
+            .filter(|&frame| {
+                ramp.render(&mut pixels, 4096, frame);
+                pixels[0][0] == 2
+            })
+            .count();
+        assert_eq!(lit, 64);
+    }
+}
@@ -1,29 +1,118 @@
 // Copyright (C) 2025 Tim Blechmann
 // SPDX-License-Identifier: MIT
 
+#![cfg_attr(not(any(feature = "std", test)), no_std)]
 #![cfg_attr(feature = "nightly", feature(portable_simd))]
+#![cfg_attr(feature = "f128", feature(f128))]
 
-use core::ops::*;
-use num_traits::{Float, FromPrimitive};
+#[cfg(not(any(feature = "std", feature = "libm")))]
+compile_error!("nova-easing requires either the `std` or the `libm` feature");
 
-#[cfg(feature = "nightly")]
-use core::simd::{LaneCount, Mask, Simd, SupportedLaneCount};
+#[cfg(feature = "alloc")]
+extern crate alloc;
 
-#[cfg(feature = "nightly")]
-use std::simd::cmp::{SimdPartialEq, SimdPartialOrd};
+use core::ops::*;
+use num_traits::{Float, FromPrimitive};
 
 #[cfg(feature = "nightly")]
-use std::simd::num::SimdFloat;
-
+use core::simd::{LaneCount, Simd, SupportedLaneCount};
+
+#[cfg(feature = "alloc")]
+pub mod arc_length;
+pub mod auto_ease;
+#[cfg(feature = "baked")]
+pub mod baked;
+pub mod batch;
+#[cfg(feature = "nalgebra")]
+pub mod camera;
+mod cost;
+pub mod css;
+pub mod cubic_bezier;
+pub mod curve;
+pub mod custom;
+mod derivative;
+#[cfg(feature = "deterministic")]
+pub mod deterministic;
+pub mod dynamic;
+mod easing;
+#[cfg(feature = "alloc")]
+pub mod env;
+pub mod fade;
 #[cfg(feature = "nightly")]
-use std::simd::StdFloat;
+pub mod fast_circ;
+#[cfg(feature = "alloc")]
+pub mod gradient;
+#[cfg(feature = "alloc")]
+pub mod handles;
+pub mod image;
+mod interpolate;
+pub mod iter;
+mod lanes;
+pub mod led;
+pub mod lut;
+pub mod metrics;
+pub mod motion_profile;
+#[cfg(feature = "alloc")]
+pub mod motion_spec;
+pub mod penner;
+#[cfg(feature = "plot-export")]
+pub mod plot_export;
+pub mod presets;
+#[cfg(feature = "f128")]
+mod quad_precision;
+mod range;
+#[cfg(feature = "alloc")]
+pub mod sampler;
+pub mod scale;
+pub mod smoothing;
+#[cfg(feature = "alloc")]
+pub mod spline;
+pub mod spring;
+pub mod stagger;
+#[cfg(feature = "alloc")]
+pub mod track;
+pub mod tween;
+#[cfg(feature = "validate")]
+pub mod validate;
+#[cfg(feature = "alloc")]
+pub mod velocity;
+#[cfg(feature = "alloc")]
+pub mod warp;
+
+pub use cost::Cost;
+pub use css::StepPosition;
+#[cfg(feature = "alloc")]
+pub use easing::ParseEasingError;
+pub use easing::{Easing, RangePolicy, TimePolicy};
+pub use interpolate::{Interpolatable, ease_angle, ease_angle_degrees, ease_lerp, ease_wrapped};
+pub use iter::EasedIterator;
+pub use lanes::Lanes;
+
+#[cfg(feature = "derive")]
+pub use nova_easing_derive::Ease;
+
+// Lets `::nova_easing` paths emitted by the derive macros resolve inside this crate.
+#[cfg(feature = "derive")]
+extern crate self as nova_easing;
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////
 
 // Marker trait for scalar float types we support.
-trait Scalar: Float + FromPrimitive {}
-impl Scalar for f32 {}
-impl Scalar for f64 {}
+trait Scalar: Float + FromPrimitive {
+    // Default threshold of the series expansion of the `curve` easings, where its truncation error
+    // falls below the rounding error of the closed form.
+    const CURVE_THRESHOLD: f32;
+    // Largest curve whose `exp` keeps `1 / (1 - exp(curve))` a normal number.
+    const MAX_CURVE: f32;
+}
+impl Scalar for f32 {
+    const CURVE_THRESHOLD: f32 = 0.2;
+    const MAX_CURVE: f32 = 80.0;
+}
+impl Scalar for f64 {
+    const CURVE_THRESHOLD: f32 = 0.005;
+    const MAX_CURVE: f32 = 700.0;
+}
 
 mod internal {
     pub trait Sealed {}
@@ -32,10 +121,16 @@ mod internal {
         fn to_curve(self) -> T;
     }
 
-    #[cfg(feature = "nightly")]
-    pub trait SimdScalar: core::simd::SimdElement + Copy {
+    // The lane type of the vector backends.
+    pub trait LaneScalar: Copy {
         fn from_f32_scalar(val: f32) -> Self;
+        fn from_f64_scalar(val: f64) -> Self;
+        #[cfg(feature = "validate")]
+        fn to_f64_scalar(self) -> f64;
         fn ln_2() -> Self;
+        // Largest curve whose `exp` keeps `1 / (1 - exp(curve))` a normal number.
+        fn max_curve() -> Self;
+        fn curve_threshold() -> f32;
     }
 }
 
@@ -51,24 +146,48 @@ impl internal::CurveParam<f64> for f64 {
     }
 }
 
-#[cfg(feature = "nightly")]
-impl internal::SimdScalar for f32 {
+impl internal::LaneScalar for f32 {
     fn from_f32_scalar(val: f32) -> Self {
         val
     }
+    fn from_f64_scalar(val: f64) -> Self {
+        val as f32
+    }
+    #[cfg(feature = "validate")]
+    fn to_f64_scalar(self) -> f64 {
+        self as f64
+    }
     fn ln_2() -> Self {
         2.0f32.ln()
     }
+    fn max_curve() -> Self {
+        <f32 as Scalar>::MAX_CURVE
+    }
+    fn curve_threshold() -> f32 {
+        <f32 as Scalar>::CURVE_THRESHOLD
+    }
 }
 
-#[cfg(feature = "nightly")]
-impl internal::SimdScalar for f64 {
+impl internal::LaneScalar for f64 {
     fn from_f32_scalar(val: f32) -> Self {
         val as f64
     }
+    fn from_f64_scalar(val: f64) -> Self {
+        val
+    }
+    #[cfg(feature = "validate")]
+    fn to_f64_scalar(self) -> f64 {
+        self
+    }
     fn ln_2() -> Self {
         2.0f64.ln()
     }
+    fn max_curve() -> Self {
+        <f64 as Scalar>::MAX_CURVE as f64
+    }
+    fn curve_threshold() -> f32 {
+        <f64 as Scalar>::CURVE_THRESHOLD
+    }
 }
 
 #[cfg(feature = "nightly")]
@@ -115,6 +234,29 @@ where
     }
 }
 
+// The `*_derivative` methods of `EasingArgument`, evaluating the derivative of the easing method
+// they are named after, with an optional scalar parameter.
+macro_rules! derivative_methods {
+    ($($method:ident $(($param:ident))? => $easing:ident, $derivative:ident;)*) => {$(
+        #[doc = concat!(
+            "Returns the derivative `d/dt` of [`", stringify!($method), "`](Self::",
+            stringify!($method), ") at `self`, i.e. its velocity."
+        )]
+        $(
+            #[doc = ""]
+            #[doc = concat!("`", stringify!($param), "` is a scalar, also for SIMD arguments.")]
+        )?
+        #[allow(private_bounds)]
+        #[inline]
+        fn $derivative(self $(, $param: f32)?) -> Self
+        where
+            Self: EasingImplHelper,
+        {
+            derivative::pair(Easing::$easing $(($param))?, self).1
+        }
+    )*};
+}
+
 /// A trait providing easing functions for smooth interpolation.
 ///
 /// Easing functions take a value `t` in the range [0, 1] and return an eased value
@@ -127,28 +269,31 @@ pub trait EasingArgument: internal::Sealed + Sized + Copy {
     ///
     /// See [easings.net](https://easings.net/#easeInQuad) for visualization.
     #[allow(private_bounds)]
+    #[inline(always)]
     fn ease_in_quad(self) -> Self
     where
         Self: EasingImplHelper,
     {
-        self.ease_in_pow(2)
+        self.ease_in_powi(2)
     }
 
     /// Applies quadratic easing out. Starts fast and decelerates.
     ///
     /// See [easings.net](https://easings.net/#easeOutQuad) for visualization.
     #[allow(private_bounds)]
+    #[inline(always)]
     fn ease_out_quad(self) -> Self
     where
         Self: EasingImplHelper,
     {
-        self.ease_out_pow(2)
+        self.ease_out_powi(2)
     }
 
     /// Applies quadratic easing in-out. Accelerates then decelerates.
     ///
     /// See [easings.net](https://easings.net/#easeInOutQuad) for visualization.
     #[allow(private_bounds)]
+    #[inline(always)]
     fn ease_in_out_quad(self) -> Self
     where
         Self: EasingImplHelper,
@@ -160,28 +305,31 @@ pub trait EasingArgument: internal::Sealed + Sized + Copy {
     ///
     /// See [easings.net](https://easings.net/#easeInCubic) for visualization.
     #[allow(private_bounds)]
+    #[inline(always)]
     fn ease_in_cubic(self) -> Self
     where
         Self: EasingImplHelper,
     {
-        self.ease_in_pow(3)
+        self.ease_in_powi(3)
     }
 
     /// Applies cubic easing out. Starts fast and decelerates more gradually.
     ///
     /// See [easings.net](https://easings.net/#easeOutCubic) for visualization.
     #[allow(private_bounds)]
+    #[inline(always)]
     fn ease_out_cubic(self) -> Self
     where
         Self: EasingImplHelper,
     {
-        self.ease_out_pow(3)
+        self.ease_out_powi(3)
     }
 
     /// Applies cubic easing in-out. Accelerates then decelerates more gradually.
     ///
     /// See [easings.net](https://easings.net/#easeInOutCubic) for visualization.
     #[allow(private_bounds)]
+    #[inline(always)]
     fn ease_in_out_cubic(self) -> Self
     where
         Self: EasingImplHelper,
@@ -193,28 +341,31 @@ pub trait EasingArgument: internal::Sealed + Sized + Copy {
     ///
     /// See [easings.net](https://easings.net/#easeInQuart) for visualization.
     #[allow(private_bounds)]
+    #[inline(always)]
     fn ease_in_quart(self) -> Self
     where
         Self: EasingImplHelper,
     {
-        self.ease_in_pow(4)
+        self.ease_in_powi(4)
     }
 
     /// Applies quartic easing out. Starts very fast and decelerates sharply.
     ///
     /// See [easings.net](https://easings.net/#easeOutQuart) for visualization.
     #[allow(private_bounds)]
+    #[inline(always)]
     fn ease_out_quart(self) -> Self
     where
         Self: EasingImplHelper,
     {
-        self.ease_out_pow(4)
+        self.ease_out_powi(4)
     }
 
     /// Applies quartic easing in-out. Accelerates sharply then decelerates sharply.
     ///
     /// See [easings.net](https://easings.net/#easeInOutQuart) for visualization.
     #[allow(private_bounds)]
+    #[inline(always)]
     fn ease_in_out_quart(self) -> Self
     where
         Self: EasingImplHelper,
@@ -226,28 +377,31 @@ pub trait EasingArgument: internal::Sealed + Sized + Copy {
     ///
     /// See [easings.net](https://easings.net/#easeInQuint) for visualization.
     #[allow(private_bounds)]
+    #[inline(always)]
     fn ease_in_quint(self) -> Self
     where
         Self: EasingImplHelper,
     {
-        self.ease_in_pow(5)
+        self.ease_in_powi(5)
     }
 
     /// Applies quintic easing out. Starts extremely fast and decelerates very sharply.
     ///
     /// See [easings.net](https://easings.net/#easeOutQuint) for visualization.
     #[allow(private_bounds)]
+    #[inline(always)]
     fn ease_out_quint(self) -> Self
     where
         Self: EasingImplHelper,
     {
-        self.ease_out_pow(5)
+        self.ease_out_powi(5)
     }
 
     /// Applies quintic easing in-out. Accelerates very sharply then decelerates very sharply.
     ///
     /// See [easings.net](https://easings.net/#easeInOutQuint) for visualization.
     #[allow(private_bounds)]
+    #[inline(always)]
     fn ease_in_out_quint(self) -> Self
     where
         Self: EasingImplHelper,
@@ -259,6 +413,7 @@ pub trait EasingArgument: internal::Sealed + Sized + Copy {
     ///
     /// See [easings.net](https://easings.net/#easeInOutBack) for visualization.
     #[allow(private_bounds)]
+    #[inline(always)]
     fn ease_in_out_back(self) -> Self
     where
         Self: EasingImplHelper,
@@ -270,6 +425,7 @@ pub trait EasingArgument: internal::Sealed + Sized + Copy {
     ///
     /// See [easings.net](https://easings.net/#easeInBounce) for visualization.
     #[allow(private_bounds)]
+    #[inline(always)]
     fn ease_in_bounce(self) -> Self
     where
         Self: EasingImplHelper,
@@ -282,6 +438,7 @@ pub trait EasingArgument: internal::Sealed + Sized + Copy {
     ///
     /// See [easings.net](https://easings.net/#easeOutBounce) for visualization.
     #[allow(private_bounds)]
+    #[inline(always)]
     fn ease_out_bounce(self) -> Self
     where
         Self: EasingImplHelper,
@@ -293,6 +450,7 @@ pub trait EasingArgument: internal::Sealed + Sized + Copy {
     ///
     /// See [easings.net](https://easings.net/#easeInOutBounce) for visualization.
     #[allow(private_bounds)]
+    #[inline(always)]
     fn ease_in_out_bounce(self) -> Self
     where
         Self: EasingImplHelper,
@@ -304,6 +462,7 @@ pub trait EasingArgument: internal::Sealed + Sized + Copy {
     ///
     /// See [easings.net](https://easings.net/#easeInExpo) for visualization.
     #[allow(private_bounds)]
+    #[inline(always)]
     fn ease_in_expo(self) -> Self
     where
         Self: EasingImplHelper,
@@ -315,6 +474,7 @@ pub trait EasingArgument: internal::Sealed + Sized + Copy {
     ///
     /// See [easings.net](https://easings.net/#easeOutExpo) for visualization.
     #[allow(private_bounds)]
+    #[inline(always)]
     fn ease_out_expo(self) -> Self
     where
         Self: EasingImplHelper,
@@ -326,6 +486,7 @@ pub trait EasingArgument: internal::Sealed + Sized + Copy {
     ///
     /// See [easings.net](https://easings.net/#easeInOutExpo) for visualization.
     #[allow(private_bounds)]
+    #[inline(always)]
     fn ease_in_out_expo(self) -> Self
     where
         Self: EasingImplHelper,
@@ -333,10 +494,59 @@ pub trait EasingArgument: internal::Sealed + Sized + Copy {
         <Self as EasingImplHelper>::ease_in_out_expo(self)
     }
 
+    /// Applies exponential easing in with an adjustable steepness: `(2^(scale t) - 1) /
+    /// (2^scale - 1)`.
+    ///
+    /// `ease_in_expo` is `2^(10 t - 10)`, snapped to 0 at `t = 0`. This variant is normalized
+    /// instead, so it passes through both endpoints for any `scale`: gentle ramps for small scales,
+    /// steeper ones for large scales, with `scale = 10` within `0.001` of `ease_in_expo`. A base
+    /// `b` with the exponent `k t` is the scale `k log2(b)`, e.g. `e^(5 t)` is `5 / ln(2)`.
+    ///
+    /// ```
+    /// # use nova_easing::EasingArgument;
+    /// assert!((0.7f32.ease_in_expo_scaled(10.0) - 0.7f32.ease_in_expo()).abs() < 1e-3);
+    /// assert_eq!(0.0f64.ease_in_expo_scaled(4.0), 0.0);
+    /// ```
+    #[allow(private_bounds)]
+    #[inline(always)]
+    fn ease_in_expo_scaled<C>(self, scale: C) -> Self
+    where
+        Self: EasingImplHelper + internal::CurveParam<Self>,
+        C: internal::CurveParam<Self>,
+    {
+        EasingArgument::ease_in_curve(self, scale.to_curve() * Self::ln_2())
+    }
+
+    /// Applies exponential easing out with an adjustable steepness, the mirror image of
+    /// [`ease_in_expo_scaled`](Self::ease_in_expo_scaled).
+    #[allow(private_bounds)]
+    #[inline(always)]
+    fn ease_out_expo_scaled<C>(self, scale: C) -> Self
+    where
+        Self: EasingImplHelper + internal::CurveParam<Self>,
+        C: internal::CurveParam<Self>,
+    {
+        EasingArgument::ease_out_curve(self, scale.to_curve() * Self::ln_2())
+    }
+
+    /// Applies exponential easing in-out with an adjustable steepness, joining
+    /// [`ease_in_expo_scaled`](Self::ease_in_expo_scaled) and
+    /// [`ease_out_expo_scaled`](Self::ease_out_expo_scaled) at `t = 0.5`.
+    #[allow(private_bounds)]
+    #[inline(always)]
+    fn ease_in_out_expo_scaled<C>(self, scale: C) -> Self
+    where
+        Self: EasingImplHelper + internal::CurveParam<Self>,
+        C: internal::CurveParam<Self>,
+    {
+        EasingArgument::ease_in_out_curve(self, scale.to_curve() * Self::ln_2())
+    }
+
     /// Applies elastic easing in. Starts with oscillation and settles.
     ///
     /// See [easings.net](https://easings.net/#easeInElastic) for visualization.
     #[allow(private_bounds)]
+    #[inline(always)]
     fn ease_in_elastic(self) -> Self
     where
         Self: EasingImplHelper,
@@ -348,6 +558,7 @@ pub trait EasingArgument: internal::Sealed + Sized + Copy {
     ///
     /// See [easings.net](https://easings.net/#easeOutElastic) for visualization.
     #[allow(private_bounds)]
+    #[inline(always)]
     fn ease_out_elastic(self) -> Self
     where
         Self: EasingImplHelper,
@@ -359,6 +570,7 @@ pub trait EasingArgument: internal::Sealed + Sized + Copy {
     ///
     /// See [easings.net](https://easings.net/#easeInOutElastic) for visualization.
     #[allow(private_bounds)]
+    #[inline(always)]
     fn ease_in_out_elastic(self) -> Self
     where
         Self: EasingImplHelper,
@@ -370,37 +582,37 @@ pub trait EasingArgument: internal::Sealed + Sized + Copy {
     ///
     /// See [easings.net](https://easings.net/#easeInSine) for visualization.
     #[allow(private_bounds)]
+    #[inline(always)]
     fn ease_in_sine(self) -> Self
     where
         Self: EasingImplHelper,
     {
         let one = Self::from_f32(1.0);
-        let pi_half = Self::from_f32(std::f32::consts::FRAC_PI_2);
-        one - (self * pi_half).cos()
+        one - (self * Self::frac_pi_2()).cos()
     }
 
     /// Applies sine easing out. Ends slow with a smooth curve.
     ///
     /// See [easings.net](https://easings.net/#easeOutSine) for visualization.
     #[allow(private_bounds)]
+    #[inline(always)]
     fn ease_out_sine(self) -> Self
     where
         Self: EasingImplHelper,
     {
-        let pi_half = Self::from_f32(std::f32::consts::FRAC_PI_2);
-        (self * pi_half).sin()
+        (self * Self::frac_pi_2()).sin()
     }
 
     /// Applies sine easing in-out. Smooth acceleration and deceleration.
     ///
     /// See [easings.net](https://easings.net/#easeInOutSine) for visualization.
     #[allow(private_bounds)]
+    #[inline(always)]
     fn ease_in_out_sine(self) -> Self
     where
         Self: EasingImplHelper,
     {
-        use std::f32::consts::PI;
-        let cos_val = (self * Self::from_f32(PI)).cos();
+        let cos_val = (self * Self::pi()).cos();
         cos_val.mul_add(Self::from_f32(-0.5), Self::from_f32(0.5))
     }
 
@@ -408,6 +620,7 @@ pub trait EasingArgument: internal::Sealed + Sized + Copy {
     ///
     /// See [easings.net](https://easings.net/#easeInCirc) for visualization.
     #[allow(private_bounds)]
+    #[inline(always)]
     fn ease_in_circ(self) -> Self
     where
         Self: EasingImplHelper,
@@ -420,6 +633,7 @@ pub trait EasingArgument: internal::Sealed + Sized + Copy {
     ///
     /// See [easings.net](https://easings.net/#easeOutCirc) for visualization.
     #[allow(private_bounds)]
+    #[inline(always)]
     fn ease_out_circ(self) -> Self
     where
         Self: EasingImplHelper,
@@ -432,6 +646,7 @@ pub trait EasingArgument: internal::Sealed + Sized + Copy {
     ///
     /// See [easings.net](https://easings.net/#easeInOutCirc) for visualization.
     #[allow(private_bounds)]
+    #[inline(always)]
     fn ease_in_out_circ(self) -> Self
     where
         Self: EasingImplHelper,
@@ -443,12 +658,13 @@ pub trait EasingArgument: internal::Sealed + Sized + Copy {
     ///
     /// See [easings.net](https://easings.net/#easeInBack) for visualization.
     #[allow(private_bounds)]
+    #[inline(always)]
     fn ease_in_back(self) -> Self
     where
         Self: EasingImplHelper,
     {
-        let c1 = Self::from_f32(1.70158);
-        let c3 = Self::from_f32(2.70158);
+        let c1 = Self::back_c1();
+        let c3 = Self::back_c3();
 
         c3 * self.powi(3) - c1 * self.powi(2)
     }
@@ -457,12 +673,13 @@ pub trait EasingArgument: internal::Sealed + Sized + Copy {
     ///
     /// See [easings.net](https://easings.net/#easeOutBack) for visualization.
     #[allow(private_bounds)]
+    #[inline(always)]
     fn ease_out_back(self) -> Self
     where
         Self: EasingImplHelper,
     {
-        let c1 = Self::from_f32(1.70158);
-        let c3 = Self::from_f32(2.70158);
+        let c1 = Self::back_c1();
+        let c3 = Self::back_c3();
         let one = Self::from_f32(1.0);
 
         one + c3 * (self - one).powi(3) + c1 * (self - one).powi(2)
@@ -479,12 +696,31 @@ pub trait EasingArgument: internal::Sealed + Sized + Copy {
     /// Inspired by SuperCollider's `Env` curve parameter for envelope shaping.
     /// See [SuperCollider Env documentation](https://doc.sccode.org/Classes/Env.html) for more on curve values.
     #[allow(private_bounds)]
+    #[inline(always)]
     fn ease_in_curve<C>(self, curve: C) -> Self
     where
         Self: EasingImplHelper,
         C: internal::CurveParam<Self>,
     {
-        <Self as EasingImplHelper>::ease_in_curve(self, curve)
+        let threshold = Self::from_f32(Self::curve_threshold());
+        <Self as EasingImplHelper>::ease_in_curve(self, curve, threshold)
+    }
+
+    /// Like `ease_in_curve`, with a custom `threshold` below which `|curve|` is evaluated by a
+    /// series expansion.
+    ///
+    /// The closed form `(1 - e^(curve t)) / (1 - e^curve)` loses precision as `curve` approaches 0
+    /// and is undefined at 0, so small curves use its expansion up to `curve^3` instead. The
+    /// default thresholds (`0.2` for `f32`, `0.005` for `f64`) switch where both are equally
+    /// accurate, so a curve parameter can be animated through zero without a visible kink.
+    #[allow(private_bounds)]
+    #[inline(always)]
+    fn ease_in_curve_with_threshold<C>(self, curve: C, threshold: C) -> Self
+    where
+        Self: EasingImplHelper,
+        C: internal::CurveParam<Self>,
+    {
+        <Self as EasingImplHelper>::ease_in_curve(self, curve, threshold.to_curve())
     }
 
     /// Applies custom exponential easing out with a curve parameter.
@@ -498,12 +734,26 @@ pub trait EasingArgument: internal::Sealed + Sized + Copy {
     /// Mirrors `ease_in_curve` but in reverse. Inspired by SuperCollider's `Env` curve parameter.
     /// See [SuperCollider Env documentation](https://doc.sccode.org/Classes/Env.html).
     #[allow(private_bounds)]
+    #[inline(always)]
     fn ease_out_curve<C>(self, curve: C) -> Self
     where
         Self: EasingImplHelper,
         C: internal::CurveParam<Self>,
     {
-        <Self as EasingImplHelper>::ease_out_curve(self, curve)
+        let threshold = Self::from_f32(Self::curve_threshold());
+        <Self as EasingImplHelper>::ease_out_curve(self, curve, threshold)
+    }
+
+    /// Like `ease_out_curve`, with a custom `threshold` below which `|curve|` is evaluated by a
+    /// series expansion, see `ease_in_curve_with_threshold`.
+    #[allow(private_bounds)]
+    #[inline(always)]
+    fn ease_out_curve_with_threshold<C>(self, curve: C, threshold: C) -> Self
+    where
+        Self: EasingImplHelper,
+        C: internal::CurveParam<Self>,
+    {
+        <Self as EasingImplHelper>::ease_out_curve(self, curve, threshold.to_curve())
     }
 
     /// Applies custom exponential easing in-out with a curve parameter.
@@ -518,17 +768,193 @@ pub trait EasingArgument: internal::Sealed + Sized + Copy {
     /// Inspired by SuperCollider's `Env` curve parameter for envelope shaping.
     /// See [SuperCollider Env documentation](https://doc.sccode.org/Classes/Env.html).
     #[allow(private_bounds)]
+    #[inline(always)]
     fn ease_in_out_curve<C>(self, curve: C) -> Self
     where
         Self: EasingImplHelper,
         C: internal::CurveParam<Self>,
     {
-        <Self as EasingImplHelper>::ease_in_out_curve(self, curve)
+        let threshold = Self::from_f32(Self::curve_threshold());
+        <Self as EasingImplHelper>::ease_in_out_curve(self, curve, threshold)
+    }
+
+    /// Like `ease_in_out_curve`, with a custom `threshold` below which `|curve|` is evaluated by a
+    /// series expansion, see `ease_in_curve_with_threshold`.
+    #[allow(private_bounds)]
+    #[inline(always)]
+    fn ease_in_out_curve_with_threshold<C>(self, curve: C, threshold: C) -> Self
+    where
+        Self: EasingImplHelper,
+        C: internal::CurveParam<Self>,
+    {
+        <Self as EasingImplHelper>::ease_in_out_curve(self, curve, threshold.to_curve())
+    }
+
+    /// Applies power easing in with an arbitrary, non-negative `exponent`: `t^exponent`.
+    ///
+    /// Integer exponents match the named easings, e.g. `2.0` is `ease_in_quad`. The `exponent`
+    /// can be a scalar or SIMD vector matching the easing argument type.
+    #[allow(private_bounds)]
+    #[inline(always)]
+    fn ease_in_pow<C>(self, exponent: C) -> Self
+    where
+        Self: EasingImplHelper,
+        C: internal::CurveParam<Self>,
+    {
+        self.powf(exponent.to_curve())
+    }
+
+    /// Applies power easing out with an arbitrary, non-negative `exponent`, mirroring
+    /// `ease_in_pow`.
+    #[allow(private_bounds)]
+    #[inline(always)]
+    fn ease_out_pow<C>(self, exponent: C) -> Self
+    where
+        Self: EasingImplHelper,
+        C: internal::CurveParam<Self>,
+    {
+        let one = Self::from_f32(1.0);
+        one - (one - self).powf(exponent.to_curve())
+    }
+
+    /// Applies power easing in-out with an arbitrary, non-negative `exponent`: `ease_in_pow` on the
+    /// first half, `ease_out_pow` on the second one.
+    ///
+    /// Tunes the sharpness of a symmetric curve continuously, with `2.0` matching
+    /// `ease_in_out_quad`. Both halves share a single `powf`, selected without branches on SIMD.
+    #[allow(private_bounds)]
+    #[inline(always)]
+    fn ease_in_out_pow<C>(self, exponent: C) -> Self
+    where
+        Self: EasingImplHelper,
+        C: internal::CurveParam<Self>,
+    {
+        let half = Self::from_f32(0.5);
+        let one = Self::from_f32(1.0);
+        // Distance to the nearer end, scaled to [0, 1].
+        let distance = self.select_lt(half, self, one - self).double();
+        let sign = self.select_lt(half, one, Self::from_f32(-1.0));
+        let base = self.select_lt(half, Self::from_f32(0.0), one);
+        sign.mul_add(distance.powf(exponent.to_curve()) * half, base)
+    }
+
+    /// Applies the cubic smoothstep `3t² - 2t³` of shader languages, with zero slope at both ends.
+    ///
+    /// Unlike GLSL's `smoothstep(0.0, 1.0, t)`, the argument is not clamped; see
+    /// [`Easing::apply_with`] for arguments outside of [0, 1].
+    #[allow(private_bounds)]
+    #[inline(always)]
+    fn smoothstep(self) -> Self
+    where
+        Self: EasingImplHelper,
+    {
+        let slope = Self::from_f32(-2.0).mul_add(self, Self::from_f32(3.0));
+        self * self * slope
+    }
+
+    /// Applies Ken Perlin's quintic smootherstep `6t⁵ - 15t⁴ + 10t³`, whose first and second
+    /// derivatives vanish at both ends.
+    #[allow(private_bounds)]
+    #[inline(always)]
+    fn smootherstep(self) -> Self
+    where
+        Self: EasingImplHelper,
+    {
+        let inner = Self::from_f32(6.0)
+            .mul_add(self, Self::from_f32(-15.0))
+            .mul_add(self, Self::from_f32(10.0));
+        self * self * self * inner
+    }
+
+    /// Applies the smoothstep of order 7, `-20t⁷ + 70t⁶ - 84t⁵ + 35t⁴`, which is flat up to the
+    /// third derivative at both ends.
+    #[allow(private_bounds)]
+    #[inline(always)]
+    fn smootheststep(self) -> Self
+    where
+        Self: EasingImplHelper,
+    {
+        let inner = Self::from_f32(-20.0)
+            .mul_add(self, Self::from_f32(70.0))
+            .mul_add(self, Self::from_f32(-84.0))
+            .mul_add(self, Self::from_f32(35.0));
+        let square = self * self;
+        square * square * inner
+    }
+
+    /// Applies stepped easing, holding the value in `count` discrete steps like CSS
+    /// `steps(count, position)`, e.g. for sprite animation.
+    ///
+    /// `position` selects where the jumps happen, see [`StepPosition`]. `count` is raised to its
+    /// minimum of 1, or 2 for [`StepPosition::JumpNone`]. Outside of [0, 1], the steps continue.
+    ///
+    /// ```
+    /// # use nova_easing::{EasingArgument, StepPosition};
+    /// assert_eq!(0.3f32.ease_steps(4, StepPosition::JumpEnd), 0.25);
+    /// assert_eq!(0.3f64.ease_steps(4, StepPosition::JumpStart), 0.5);
+    /// assert_eq!(0.0f32.ease_steps(3, StepPosition::JumpBoth), 0.25);
+    /// ```
+    #[allow(private_bounds)]
+    #[inline(always)]
+    fn ease_steps(self, count: u32, position: StepPosition) -> Self
+    where
+        Self: EasingImplHelper + custom::EasingValue,
+    {
+        css::steps(self, count, position)
+    }
+
+    derivative_methods! {
+        ease_in_quad => InQuad, ease_in_quad_derivative;
+        ease_out_quad => OutQuad, ease_out_quad_derivative;
+        ease_in_out_quad => InOutQuad, ease_in_out_quad_derivative;
+        ease_in_cubic => InCubic, ease_in_cubic_derivative;
+        ease_out_cubic => OutCubic, ease_out_cubic_derivative;
+        ease_in_out_cubic => InOutCubic, ease_in_out_cubic_derivative;
+        ease_in_quart => InQuart, ease_in_quart_derivative;
+        ease_out_quart => OutQuart, ease_out_quart_derivative;
+        ease_in_out_quart => InOutQuart, ease_in_out_quart_derivative;
+        ease_in_quint => InQuint, ease_in_quint_derivative;
+        ease_out_quint => OutQuint, ease_out_quint_derivative;
+        ease_in_out_quint => InOutQuint, ease_in_out_quint_derivative;
+        ease_in_sine => InSine, ease_in_sine_derivative;
+        ease_out_sine => OutSine, ease_out_sine_derivative;
+        ease_in_out_sine => InOutSine, ease_in_out_sine_derivative;
+        ease_in_circ => InCirc, ease_in_circ_derivative;
+        ease_out_circ => OutCirc, ease_out_circ_derivative;
+        ease_in_out_circ => InOutCirc, ease_in_out_circ_derivative;
+        ease_in_back => InBack, ease_in_back_derivative;
+        ease_out_back => OutBack, ease_out_back_derivative;
+        ease_in_out_back => InOutBack, ease_in_out_back_derivative;
+        ease_in_bounce => InBounce, ease_in_bounce_derivative;
+        ease_out_bounce => OutBounce, ease_out_bounce_derivative;
+        ease_in_out_bounce => InOutBounce, ease_in_out_bounce_derivative;
+        ease_in_expo => InExpo, ease_in_expo_derivative;
+        ease_out_expo => OutExpo, ease_out_expo_derivative;
+        ease_in_out_expo => InOutExpo, ease_in_out_expo_derivative;
+        ease_in_elastic => InElastic, ease_in_elastic_derivative;
+        ease_out_elastic => OutElastic, ease_out_elastic_derivative;
+        ease_in_out_elastic => InOutElastic, ease_in_out_elastic_derivative;
+        ease_in_curve(curve) => InCurve, ease_in_curve_derivative;
+        ease_out_curve(curve) => OutCurve, ease_out_curve_derivative;
+        ease_in_out_curve(curve) => InOutCurve, ease_in_out_curve_derivative;
+        ease_in_pow(exponent) => InPow, ease_in_pow_derivative;
+        ease_out_pow(exponent) => OutPow, ease_out_pow_derivative;
+        ease_in_out_pow(exponent) => InOutPow, ease_in_out_pow_derivative;
+        ease_in_expo_scaled(scale) => InExpoScaled, ease_in_expo_scaled_derivative;
+        ease_out_expo_scaled(scale) => OutExpoScaled, ease_out_expo_scaled_derivative;
+        ease_in_out_expo_scaled(scale) => InOutExpoScaled, ease_in_out_expo_scaled_derivative;
+        smoothstep => Smoothstep, smoothstep_derivative;
+        smootherstep => Smootherstep, smootherstep_derivative;
+        smootheststep => Smootheststep, smootheststep_derivative;
     }
 }
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////
 
+// The constants of `ease_in_out_back`, evaluated in `f64` and rounded once for each type.
+const BACK_C2: f64 = 1.70158 * 1.525;
+const BACK_C2_PLUS_1: f64 = BACK_C2 + 1.0;
+
 trait EasingImplHelper:
     Sub<Self, Output = Self>
     + Add<Self, Output = Self>
@@ -541,21 +967,59 @@ trait EasingImplHelper:
     fn sin(self) -> Self;
     fn cos(self) -> Self;
     fn powi(self, n: i32) -> Self;
-    #[allow(unused)]
     fn powf(self, other: Self) -> Self;
+    #[inline(always)]
     fn double(self) -> Self {
         self + self
     }
     fn sqrt(self) -> Self;
-    #[allow(unused)]
+    fn floor(self) -> Self;
     fn exp(self) -> Self;
     fn mul_add(self, a: Self, b: Self) -> Self;
+    fn select_lt(self, threshold: Self, if_less: Self, otherwise: Self) -> Self;
+
+    // Constants of the formulas shared by all types. Types with more precision than the `f32`
+    // literals override them.
+    #[inline(always)]
+    fn frac_pi_2() -> Self {
+        Self::from_f32(core::f32::consts::FRAC_PI_2)
+    }
+    #[inline(always)]
+    fn pi() -> Self {
+        Self::from_f32(core::f32::consts::PI)
+    }
+    #[inline(always)]
+    fn back_c1() -> Self {
+        Self::from_f32(1.70158)
+    }
+    #[inline(always)]
+    fn back_c3() -> Self {
+        Self::from_f32(2.70158)
+    }
+    #[inline(always)]
+    fn ln_2() -> Self {
+        Self::from_f32(core::f32::consts::LN_2)
+    }
 
-    fn ease_in_pow(self, n: i32) -> Self {
+    // The lanes as `f64`, for the diagnostics of the `validate` feature, and the largest curve
+    // evaluated without overflowing `exp`.
+    #[cfg(feature = "validate")]
+    #[inline(always)]
+    fn lane_count() -> usize {
+        1
+    }
+    #[cfg(feature = "validate")]
+    fn lane(self, index: usize) -> f64;
+    #[cfg(feature = "validate")]
+    fn max_curve_f64() -> f64;
+
+    #[inline(always)]
+    fn ease_in_powi(self, n: i32) -> Self {
         self.powi(n)
     }
 
-    fn ease_out_pow(self, n: i32) -> Self {
+    #[inline(always)]
+    fn ease_out_powi(self, n: i32) -> Self {
         let one = Self::from_f32(1.0);
         one - (one - self).powi(n)
     }
@@ -575,15 +1039,30 @@ trait EasingImplHelper:
     fn ease_in_out_elastic(self) -> Self;
     fn ease_in_out_circ(self) -> Self;
 
-    fn ease_in_curve<C>(self, curve: C) -> Self
+    // Curves with `|curve| < threshold` are evaluated by `curve_series`.
+    fn curve_threshold() -> f32;
+    fn ease_in_curve<C>(self, curve: C, threshold: Self) -> Self
     where
         C: internal::CurveParam<Self>;
-    fn ease_out_curve<C>(self, curve: C) -> Self
+    fn ease_out_curve<C>(self, curve: C, threshold: Self) -> Self
     where
         C: internal::CurveParam<Self>;
-    fn ease_in_out_curve<C>(self, curve: C) -> Self
+    fn ease_in_out_curve<C>(self, curve: C, threshold: Self) -> Self
     where
         C: internal::CurveParam<Self>;
+
+    // `(1 - e^(c t)) / (1 - e^c)` expanded up to `c^3` via Bernoulli polynomials, which avoids the
+    // cancellation of the closed form for small curves. With `u = t (t - 1)`, it is
+    // `t + c u / 2 * (1 + c (2 t - 1) / 6 + c^2 u / 12)`.
+    #[inline(always)]
+    fn curve_series(self, c: Self) -> Self {
+        let one = Self::from_f32(1.0);
+        let u = self * (self - one);
+        // Divisions, since `1 / 6` is not exact in `f32`.
+        let higher =
+            c * (self.double() - one) / Self::from_f32(6.0) + c * c * u / Self::from_f32(12.0);
+        self + c * u * Self::from_f32(0.5) * (one + higher)
+    }
 }
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -595,31 +1074,66 @@ impl<T> EasingImplHelper for T
 where
     T: Scalar,
 {
+    #[inline(always)]
     fn from_f32(arg: f32) -> Self {
         T::from(arg).unwrap()
     }
+    #[inline(always)]
+    fn curve_threshold() -> f32 {
+        T::CURVE_THRESHOLD
+    }
+    #[inline(always)]
+    fn ln_2() -> Self {
+        T::from(core::f64::consts::LN_2).unwrap()
+    }
+    #[cfg(feature = "validate")]
+    #[inline(always)]
+    fn lane(self, _index: usize) -> f64 {
+        self.to_f64().unwrap()
+    }
+    #[cfg(feature = "validate")]
+    #[inline(always)]
+    fn max_curve_f64() -> f64 {
+        T::MAX_CURVE as f64
+    }
+    #[inline(always)]
     fn sin(self) -> Self {
         self.sin()
     }
+    #[inline(always)]
     fn cos(self) -> Self {
         self.cos()
     }
+    #[inline(always)]
     fn powi(self, n: i32) -> Self {
         self.powi(n)
     }
+    #[inline(always)]
     fn powf(self, other: Self) -> Self {
         self.powf(other)
     }
+    #[inline(always)]
     fn sqrt(self) -> Self {
         self.sqrt()
     }
+    #[inline(always)]
+    fn floor(self) -> Self {
+        self.floor()
+    }
+    #[inline(always)]
     fn exp(self) -> Self {
         self.exp()
     }
+    #[inline(always)]
     fn mul_add(self, a: Self, b: Self) -> Self {
         self.mul_add(a, b)
     }
+    #[inline(always)]
+    fn select_lt(self, threshold: Self, if_less: Self, otherwise: Self) -> Self {
+        if self < threshold { if_less } else { otherwise }
+    }
 
+    #[inline(always)]
     fn ease_in_out_quad(self) -> Self {
         let half = T::from(0.5).unwrap();
         let one = T::one();
@@ -630,6 +1144,7 @@ where
             one - ((two * self - two).powi(2) * half)
         }
     }
+    #[inline(always)]
     fn ease_in_out_cubic(self) -> Self {
         let half = T::from(0.5).unwrap();
         if self < half {
@@ -642,6 +1157,7 @@ where
             one - (two - self.double()).powi(3) * half
         }
     }
+    #[inline(always)]
     fn ease_in_out_quart(self) -> Self {
         let half = T::from(0.5).unwrap();
         if self < half {
@@ -652,6 +1168,7 @@ where
             one - (two - self.double()).powi(4) * half
         }
     }
+    #[inline(always)]
     fn ease_in_out_quint(self) -> Self {
         let half = T::from(0.5).unwrap();
         if self < half {
@@ -662,22 +1179,21 @@ where
             one - (two - self.double()).powi(5) * half
         }
     }
+    #[inline(always)]
     fn ease_in_out_back(self) -> Self {
-        let c2 = T::from(1.70158 * 1.525).unwrap();
+        let c2 = T::from(BACK_C2).unwrap();
+        let c2_plus_1 = T::from(BACK_C2_PLUS_1).unwrap();
         let half = T::from(0.5).unwrap();
-        let two = T::from(2.0).unwrap();
-        if self < half {
-            let two_x = self.double();
-            let pow_two_x_2 = two_x.powi(2);
-            let inner = (c2 + T::one()).mul_add(two_x, -c2);
-            pow_two_x_2 * inner * half
+        let two_x = self.double();
+        let (u, sign, offset) = if self < half {
+            (two_x, -c2, T::zero())
         } else {
-            let two_x_minus_2 = self.double() - two;
-            let pow_two_x_minus_2_2 = two_x_minus_2.powi(2);
-            let inner = (c2 + T::one()).mul_add(self.double() - two, c2);
-            pow_two_x_minus_2_2.mul_add(inner, two) * half
-        }
+            let two = T::from(2.0).unwrap();
+            (two_x - two, c2, two)
+        };
+        (u * u).mul_add(c2_plus_1.mul_add(u, sign), offset) * half
     }
+    #[inline(always)]
     fn ease_out_bounce(self) -> Self {
         let n1 = T::from(7.5625).unwrap();
         let one_over_d1 = T::from(1.0 / 2.75).unwrap();
@@ -696,6 +1212,7 @@ where
             (adjusted * adjusted).mul_add(n1, T::from(0.984375).unwrap())
         }
     }
+    #[inline(always)]
     fn ease_in_out_bounce(self) -> Self {
         let half = T::from(0.5).unwrap();
         let one = T::one();
@@ -705,6 +1222,7 @@ where
             (one + EasingArgument::ease_out_bounce(self.double() - one)) * half
         }
     }
+    #[inline(always)]
     fn ease_in_expo(self) -> Self {
         if self == T::zero() {
             T::zero()
@@ -716,6 +1234,7 @@ where
             )
         }
     }
+    #[inline(always)]
     fn ease_out_expo(self) -> Self {
         if self == T::one() {
             T::one()
@@ -726,6 +1245,7 @@ where
                 .mul_add(-T::one(), T::one())
         }
     }
+    #[inline(always)]
     fn ease_in_out_expo(self) -> Self {
         if self == T::zero() {
             T::zero()
@@ -751,6 +1271,7 @@ where
                 .mul_add(-T::from(0.5).unwrap(), T::one())
         }
     }
+    #[inline(always)]
     fn ease_in_elastic(self) -> Self {
         if self == T::zero() {
             T::zero()
@@ -764,6 +1285,7 @@ where
                 * (self.mul_add(T::from(10.0).unwrap(), -T::from(10.75).unwrap()) * c4).sin()
         }
     }
+    #[inline(always)]
     fn ease_out_elastic(self) -> Self {
         if self == T::zero() {
             T::zero()
@@ -780,6 +1302,7 @@ where
                 )
         }
     }
+    #[inline(always)]
     fn ease_in_out_elastic(self) -> Self {
         if self == T::zero() {
             T::zero()
@@ -804,6 +1327,7 @@ where
                 )
         }
     }
+    #[inline(always)]
     fn ease_in_out_circ(self) -> Self {
         let half = T::from(0.5).unwrap();
         let one = T::one();
@@ -816,13 +1340,14 @@ where
         }
     }
 
-    fn ease_in_curve<C>(self, curve: C) -> Self
+    #[inline(always)]
+    fn ease_in_curve<C>(self, curve: C, threshold: Self) -> Self
     where
         C: internal::CurveParam<Self>,
     {
         let c = curve.to_curve();
-        if c.abs() < T::from(0.001).unwrap() {
-            self
+        if c.abs() < threshold {
+            self.curve_series(c)
         } else {
             let grow = c.exp();
             let one = T::one();
@@ -831,350 +1356,32 @@ where
         }
     }
 
-    fn ease_out_curve<C>(self, curve: C) -> Self
+    #[inline(always)]
+    fn ease_out_curve<C>(self, curve: C, threshold: Self) -> Self
     where
         C: internal::CurveParam<Self>,
     {
         let one = T::one();
-        one - <Self as EasingImplHelper>::ease_in_curve(one - self, curve)
+        one - <Self as EasingImplHelper>::ease_in_curve(one - self, curve, threshold)
     }
 
-    fn ease_in_out_curve<C>(self, curve: C) -> Self
+    #[inline(always)]
+    fn ease_in_out_curve<C>(self, curve: C, threshold: Self) -> Self
     where
         C: internal::CurveParam<Self>,
     {
         let half = T::from(0.5).unwrap();
         if self < half {
-            <Self as EasingImplHelper>::ease_in_curve(self.double(), curve) * half
+            <Self as EasingImplHelper>::ease_in_curve(self.double(), curve, threshold) * half
         } else {
-            half + <Self as EasingImplHelper>::ease_out_curve((self - half).double(), curve) * half
+            let upper = (self - half).double();
+            half + <Self as EasingImplHelper>::ease_out_curve(upper, curve, threshold) * half
         }
     }
 }
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////
 
-#[cfg(feature = "nightly")]
-impl<T, const N: usize> EasingImplHelper for Simd<T, N>
-where
-    T: internal::SimdScalar + core::simd::SimdElement,
-    T::Mask: core::simd::MaskElement,
-    LaneCount<N>: SupportedLaneCount,
-    Simd<T, N>: StdFloat
-        + SimdFloat
-        + SimdPartialEq<Mask = Mask<T::Mask, N>>
-        + SimdPartialOrd
-        + Add<Output = Simd<T, N>>
-        + Sub<Output = Simd<T, N>>
-        + Mul<Output = Simd<T, N>>
-        + Div<Output = Simd<T, N>>
-        + Neg<Output = Simd<T, N>>,
-{
-    fn from_f32(arg: f32) -> Self {
-        Simd::splat(T::from_f32_scalar(arg))
-    }
-
-    fn sin(self) -> Self {
-        <Self as StdFloat>::sin(self)
-    }
-
-    fn cos(self) -> Self {
-        <Self as StdFloat>::cos(self)
-    }
-
-    fn powi(self, n: i32) -> Self {
-        if n == 1 {
-            self
-        } else if n % 2 == 0 {
-            let tmp = self.powi(n / 2);
-            tmp * tmp
-        } else {
-            self * self.powi(n - 1)
-        }
-    }
-
-    fn powf(self, other: Self) -> Self {
-        <Self as StdFloat>::exp(other * <Self as StdFloat>::ln(self))
-    }
-
-    fn sqrt(self) -> Self {
-        <Self as StdFloat>::sqrt(self)
-    }
-
-    fn exp(self) -> Self {
-        <Self as StdFloat>::exp(self)
-    }
-
-    fn mul_add(self, a: Self, b: Self) -> Self {
-        <Self as StdFloat>::mul_add(self, a, b)
-    }
-
-    fn ease_in_out_quad(self) -> Self {
-        let half = Self::from_f32(0.5);
-        let mask = self.simd_lt(half);
-
-        let lower_half = self.powi(2).double();
-        let upper_half = Self::from_f32(1.0) - (self.double() - Self::from_f32(2.0)).powi(2) * half;
-
-        mask.select(lower_half, upper_half)
-    }
-
-    fn ease_in_out_cubic(self) -> Self {
-        let half = Self::from_f32(0.5);
-        let mask = self.simd_lt(half);
-
-        let lower_half = {
-            let cubed = self.powi(3);
-            let doubled = cubed.double();
-            doubled + doubled
-        };
-
-        let upper_half = {
-            let one = Self::from_f32(1.0);
-            let two = Self::from_f32(2.0);
-            one - (two - self.double()).powi(3) * half
-        };
-
-        mask.select(lower_half, upper_half)
-    }
-
-    fn ease_in_out_quart(self) -> Self {
-        let half = Self::from_f32(0.5);
-        let mask = self.simd_lt(half);
-
-        let lower_half = { Self::from_f32(8.0) * self.powi(4) };
-        let upper_half = {
-            let one = Self::from_f32(1.0);
-            let two = Self::from_f32(2.0);
-            one - (two - self.double()).powi(4) * half
-        };
-        mask.select(lower_half, upper_half)
-    }
-
-    fn ease_in_out_quint(self) -> Self {
-        let half = Self::from_f32(0.5);
-        let mask = self.simd_lt(half);
-
-        let lower_half = { Self::from_f32(16.0) * self.powi(5) };
-        let upper_half = {
-            let one = Self::from_f32(1.0);
-            let two = Self::from_f32(2.0);
-            one - (two - self.double()).powi(5) * half
-        };
-        mask.select(lower_half, upper_half)
-    }
-
-    fn ease_in_out_back(self) -> Self {
-        let c2 = Self::from_f32(1.70158 * 1.525);
-        let half = Self::from_f32(0.5);
-        let mask = self.simd_lt(half);
-
-        let lower_half = {
-            let two_x = self.double();
-            let pow_two_x_2 = two_x.powi(2);
-            let inner = StdFloat::mul_add(c2 + Self::from_f32(1.0), two_x, -c2);
-            pow_two_x_2 * inner
-        };
-        let upper_half = {
-            let two_x_minus_2 = self.double() - Self::from_f32(2.0);
-            let pow_two_x_minus_2_2 = two_x_minus_2.powi(2);
-            let inner = StdFloat::mul_add(
-                c2 + Self::from_f32(1.0),
-                self.double() - Self::from_f32(2.0),
-                c2,
-            );
-            StdFloat::mul_add(pow_two_x_minus_2_2, inner, Self::from_f32(2.0))
-        };
-        mask.select(lower_half, upper_half) * half
-    }
-
-    fn ease_out_bounce(self) -> Self {
-        let n1 = Self::from_f32(7.5625);
-        let one_over_d1 = Self::from_f32(1.0 / 2.75);
-        let two_over_d1 = Self::from_f32(2.0 / 2.75);
-        let two_point_five_over_d1 = Self::from_f32(2.5 / 2.75);
-        let mask1 = self.simd_lt(one_over_d1);
-        let mask2 = self.simd_lt(two_over_d1);
-        let mask3 = self.simd_lt(two_point_five_over_d1);
-        let branch1 = n1 * self * self;
-        let adjusted2 = self - Self::from_f32(1.5 / 2.75);
-        let branch2 = StdFloat::mul_add(adjusted2 * adjusted2, n1, Self::from_f32(0.75));
-        let adjusted3 = self - Self::from_f32(2.25 / 2.75);
-        let branch3 = StdFloat::mul_add(adjusted3 * adjusted3, n1, Self::from_f32(0.9375));
-        let adjusted4 = self - Self::from_f32(2.625 / 2.75);
-        let branch4 = StdFloat::mul_add(adjusted4 * adjusted4, n1, Self::from_f32(0.984375));
-        mask1.select(
-            branch1,
-            mask2.select(branch2, mask3.select(branch3, branch4)),
-        )
-    }
-
-    fn ease_in_out_bounce(self) -> Self {
-        let half = Self::from_f32(0.5);
-        let one = Self::from_f32(1.0);
-        let mask = self.simd_lt(half);
-        let lower_half = one - EasingArgument::ease_out_bounce(one - self.double());
-        let upper_half = one + EasingArgument::ease_out_bounce(self.double() - one);
-        mask.select(lower_half, upper_half) * half
-    }
-
-    fn ease_in_expo(self) -> Self {
-        let zero = Self::from_f32(0.0);
-        let ln2 = Simd::splat(T::ln_2());
-        let ten = Self::from_f32(10.0);
-        let mask_zero = self.simd_eq(zero);
-        let exponent = StdFloat::mul_add(ten, self, -ten);
-        let normal = <Self as StdFloat>::exp(exponent * ln2);
-        mask_zero.select(zero, normal)
-    }
-
-    fn ease_out_expo(self) -> Self {
-        let one = Self::from_f32(1.0);
-        let ln2 = Simd::splat(T::ln_2());
-        let neg_ten = Self::from_f32(-10.0);
-        let mask_one = self.simd_eq(one);
-        let exponent = neg_ten * self;
-        let normal = StdFloat::mul_add(
-            <Self as StdFloat>::exp(exponent * ln2),
-            -Self::from_f32(1.0),
-            one,
-        );
-        mask_one.select(one, normal)
-    }
-
-    fn ease_in_out_expo(self) -> Self {
-        let zero = Self::from_f32(0.0);
-        let one = Self::from_f32(1.0);
-        let half = Self::from_f32(0.5);
-        let ln2 = Simd::splat(T::ln_2());
-        let twenty = Self::from_f32(20.0);
-        let ten = Self::from_f32(10.0);
-        let mask_zero = self.simd_eq(zero);
-        let mask_one = self.simd_eq(one);
-        let mask_half = self.simd_lt(half);
-        let exponent_lower = StdFloat::mul_add(twenty, self, -ten);
-        let branch_lower = <Self as StdFloat>::exp(exponent_lower * ln2) * half;
-        let exponent_upper = StdFloat::mul_add(-twenty, self, ten);
-        let branch_upper =
-            StdFloat::mul_add(<Self as StdFloat>::exp(exponent_upper * ln2), -half, one);
-        let temp = mask_half.select(branch_lower, branch_upper);
-        let temp2 = mask_one.select(one, temp);
-        mask_zero.select(zero, temp2)
-    }
-
-    fn ease_in_elastic(self) -> Self {
-        let zero = Self::from_f32(0.0);
-        let one = Self::from_f32(1.0);
-        let ln2 = Simd::splat(T::ln_2());
-        let c4 = Self::from_f32(2.094_395_2);
-        let ten = Self::from_f32(10.0);
-        let minus_ten_point_75 = Self::from_f32(-10.75);
-        let mask_zero = self.simd_eq(zero);
-        let mask_one = self.simd_eq(one);
-        let exponent = StdFloat::mul_add(ten, self, -ten);
-        let sin_arg = StdFloat::mul_add(ten, self, minus_ten_point_75) * c4;
-        let normal = -<Self as StdFloat>::exp(exponent * ln2) * <Self as StdFloat>::sin(sin_arg);
-        let temp = mask_one.select(one, normal);
-        mask_zero.select(zero, temp)
-    }
-
-    fn ease_out_elastic(self) -> Self {
-        let zero = Self::from_f32(0.0);
-        let one = Self::from_f32(1.0);
-        let ln2 = Simd::splat(T::ln_2());
-        let c4 = Self::from_f32(2.094_395_2);
-        let ten = Self::from_f32(10.0);
-        let minus_zero_point_75 = Self::from_f32(-0.75);
-        let mask_zero = self.simd_eq(zero);
-        let mask_one = self.simd_eq(one);
-        let exponent = -ten * self;
-        let sin_arg = StdFloat::mul_add(ten, self, minus_zero_point_75) * c4;
-        let normal = StdFloat::mul_add(
-            <Self as StdFloat>::exp(exponent * ln2),
-            <Self as StdFloat>::sin(sin_arg),
-            one,
-        );
-        let temp = mask_one.select(one, normal);
-        mask_zero.select(zero, temp)
-    }
-
-    fn ease_in_out_elastic(self) -> Self {
-        let zero = Self::from_f32(0.0);
-        let one = Self::from_f32(1.0);
-        let half = Self::from_f32(0.5);
-        let ln2 = Simd::splat(T::ln_2());
-        let c5 = Self::from_f32(1.396_263_4);
-        let twenty = Self::from_f32(20.0);
-        let ten = Self::from_f32(10.0);
-        let minus_eleven_point_125 = Self::from_f32(-11.125);
-        let mask_zero = self.simd_eq(zero);
-        let mask_one = self.simd_eq(one);
-        let mask_half = self.simd_lt(half);
-        let exponent_lower = StdFloat::mul_add(twenty, self, -ten);
-        let sin_arg = StdFloat::mul_add(twenty, self, minus_eleven_point_125) * c5;
-        let branch_lower = -<Self as StdFloat>::exp(exponent_lower * ln2)
-            * <Self as StdFloat>::sin(sin_arg)
-            * half;
-        let exponent_upper = StdFloat::mul_add(-twenty, self, ten);
-        let branch_upper = StdFloat::mul_add(
-            <Self as StdFloat>::exp(exponent_upper * ln2),
-            <Self as StdFloat>::sin(sin_arg) * half,
-            one,
-        );
-        let temp = mask_half.select(branch_lower, branch_upper);
-        let temp2 = mask_one.select(one, temp);
-        mask_zero.select(zero, temp2)
-    }
-
-    fn ease_in_out_circ(self) -> Self {
-        let half = Self::from_f32(0.5);
-        let mask = self.simd_lt(half);
-
-        let one = Self::from_f32(1.0);
-        let two = Self::from_f32(2.0);
-        let double = self.double();
-
-        let lower_half = one - StdFloat::sqrt(one - double.powi(2));
-        let upper_half = StdFloat::sqrt(one - (two - double).powi(2)) + one;
-        mask.select(lower_half, upper_half) * half
-    }
-
-    fn ease_in_curve<C>(self, curve: C) -> Self
-    where
-        C: internal::CurveParam<Self>,
-    {
-        let c = curve.to_curve();
-        let abs_curve = SimdFloat::abs(c);
-        let mask = abs_curve.simd_lt(Self::from_f32(0.001));
-        let grow = <Self as StdFloat>::exp(c);
-        let a = Self::from_f32(1.0) / (Self::from_f32(1.0) - grow);
-        let normal = a - (a * grow.powf(self));
-        mask.select(self, normal)
-    }
-
-    fn ease_out_curve<C>(self, curve: C) -> Self
-    where
-        C: internal::CurveParam<Self>,
-    {
-        let one = Self::from_f32(1.0);
-        one - <Self as EasingImplHelper>::ease_in_curve(one - self, curve)
-    }
-
-    fn ease_in_out_curve<C>(self, curve: C) -> Self
-    where
-        C: internal::CurveParam<Self>,
-    {
-        let half = Self::from_f32(0.5);
-        let mask = self.simd_lt(half);
-        let lower_half = <Self as EasingImplHelper>::ease_in_curve(self.double(), curve) * half;
-        let upper_half =
-            half + <Self as EasingImplHelper>::ease_out_curve((self - half).double(), curve) * half;
-        mask.select(lower_half, upper_half)
-    }
-}
-
-////////////////////////////////////////////////////////////////////////////////////////////////////
-
 #[cfg(test)]
 mod tests {
     use super::EasingArgument;
@@ -1199,6 +1406,17 @@ mod tests {
                             assert_relative_eq!(scalar, vector, epsilon = 1e-6);
                         }
                     }
+
+                    // Lanes on both sides of the split points, bypassing the uniform fast paths.
+                    #[test]
+                    fn [<$func _f32_vs_f32x4_mixed_lanes>]() {
+                        use super::EasingArgument;
+                        let points = [0.1, 0.45, 0.55, 0.9];
+                        let vector = EasingArgument::$func(core::simd::f32x4::from_array(points));
+                        for (&x, &v) in points.iter().zip(vector.as_array()) {
+                            assert_relative_eq!(EasingArgument::$func(x), v, epsilon = 1e-6);
+                        }
+                    }
                 }
             };
         }
@@ -1267,6 +1485,222 @@ mod tests {
                 assert_relative_eq!(scalar, vector, epsilon = 1e-6);
             }
         }
+
+        #[test]
+        fn ease_in_curve_mixed_lanes() {
+            use super::EasingArgument;
+            use core::simd::{f32x4, f64x4};
+
+            // Curves beyond the overflow limit of `f32` behave like the limit itself.
+            let curves = f32x4::from_array([1e-5, 100.0, -100.0, 2.0]);
+            let limited = [1e-5f64, 80.0, -100.0, 2.0];
+            for x in [0.0, 0.01, 0.5, 0.99, 1.0] {
+                let vector = EasingArgument::ease_in_curve(f32x4::splat(x), curves).to_array();
+                for (&curve, vector) in limited.iter().zip(vector) {
+                    let scalar = EasingArgument::ease_in_curve(x as f64, curve);
+                    assert_relative_eq!(scalar as f32, vector, epsilon = 1e-5);
+                }
+
+                let vector = EasingArgument::ease_out_curve(f32x4::splat(x), curves).to_array();
+                for (&curve, vector) in limited.iter().zip(vector) {
+                    let scalar = EasingArgument::ease_out_curve(x as f64, curve);
+                    assert_relative_eq!(scalar as f32, vector, epsilon = 1e-5);
+                }
+            }
+
+            let curves = f64x4::from_array([0.0, 1000.0, -1000.0, -3.0]);
+            let vector = EasingArgument::ease_in_curve(f64x4::splat(0.5), curves).to_array();
+            assert!(vector.iter().all(|v| (0.0..=1.0).contains(v)));
+            assert_eq!(vector[0], 0.5);
+            assert_relative_eq!(vector[3], EasingArgument::ease_in_curve(0.5, -3.0));
+        }
+
+        #[test]
+        fn ease_steps_f32_vs_f32x4() {
+            use crate::{EasingArgument, StepPosition};
+            let t = core::simd::f32x4::from_array([-0.3, 0.1, 0.5, 1.2]);
+            for position in [
+                StepPosition::JumpStart,
+                StepPosition::JumpEnd,
+                StepPosition::JumpNone,
+                StepPosition::JumpBoth,
+            ] {
+                let vector = t.ease_steps(5, position).to_array();
+                assert_eq!(vector, t.to_array().map(|t| t.ease_steps(5, position)));
+            }
+        }
+
+        // Scalar and SIMD evaluate the same fused operations, so they agree to the bit.
+        #[test]
+        fn ease_in_out_back_bit_exact() {
+            use crate::EasingArgument;
+            use core::simd::{f32x8, f64x4};
+
+            let points: [f32; 64] = core::array::from_fn(|i| i as f32 / 56.0 - 0.0625);
+            for chunk in points.chunks_exact(8) {
+                let vector = f32x8::from_slice(chunk).ease_in_out_back().to_array();
+                for (&x, vector) in chunk.iter().zip(vector) {
+                    assert_eq!(x.ease_in_out_back(), vector);
+                }
+            }
+            for chunk in points.chunks_exact(4) {
+                let lanes = core::array::from_fn(|i| chunk[i] as f64);
+                let vector = f64x4::from_array(lanes).ease_in_out_back().to_array();
+                for (x, vector) in lanes.into_iter().zip(vector) {
+                    assert_eq!(x.ease_in_out_back(), vector);
+                }
+            }
+        }
+
+        #[test]
+        fn smoothsteps_f32_vs_f32x8() {
+            use crate::EasingArgument;
+            let t = core::simd::f32x8::from_array([0.0, 0.1, 0.3, 0.5, 0.7, 0.95, 1.0, 1.5]);
+            let scalar = t.to_array();
+            assert_eq!(t.smoothstep().to_array(), scalar.map(f32::smoothstep));
+            assert_eq!(t.smootherstep().to_array(), scalar.map(f32::smootherstep));
+            assert_eq!(t.smootheststep().to_array(), scalar.map(f32::smootheststep));
+        }
+
+        #[test]
+        fn expo_scaled_f32_vs_f32x4() {
+            use super::EasingArgument;
+            let t = core::simd::f32x4::from_array([0.0, 0.3, 0.6, 1.0]);
+            let in_out = EasingArgument::ease_in_out_expo_scaled(t, 6.0f32).to_array();
+            for (&x, vector) in t.to_array().iter().zip(in_out) {
+                let scalar = EasingArgument::ease_in_out_expo_scaled(x, 6.0f32);
+                assert_relative_eq!(scalar, vector, epsilon = 1e-6);
+            }
+        }
+
+        #[test]
+        fn ease_in_out_pow_f32_vs_f32x4() {
+            use super::EasingArgument;
+            let points = [0.0, 0.1, 0.2, 0.3, 0.4, 0.5, 0.6, 0.7, 0.8, 0.9, 1.0];
+            for chunk in points.chunks(4) {
+                let mut lanes = [0.0; 4];
+                lanes[..chunk.len()].copy_from_slice(chunk);
+                let vector =
+                    EasingArgument::ease_in_out_pow(core::simd::f32x4::from_array(lanes), 2.7f32);
+                for (&x, vector) in chunk.iter().zip(vector.to_array()) {
+                    let scalar = EasingArgument::ease_in_out_pow(x, 2.7f32);
+                    assert_relative_eq!(scalar, vector, epsilon = 1e-6);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn steps() {
+        use super::StepPosition::{JumpBoth, JumpEnd, JumpNone, JumpStart};
+
+        let sprite =
+            [0.0f32, 0.1, 0.2, 0.25, 0.5, 0.74, 0.75, 0.99, 1.0].map(|t| t.ease_steps(4, JumpEnd));
+        assert_eq!(sprite, [0.0, 0.0, 0.0, 0.25, 0.5, 0.5, 0.75, 0.75, 1.0]);
+        assert_eq!(0.0f64.ease_steps(2, JumpStart), 0.5);
+        assert_eq!(0.5f64.ease_steps(2, JumpNone), 1.0);
+        assert_eq!(0.99f64.ease_steps(2, JumpBoth), 2.0 / 3.0);
+        assert_eq!(1.0f64.ease_steps(2, JumpBoth), 1.0);
+
+        // Invalid counts are raised to the minimum.
+        assert_eq!(0.7f32.ease_steps(0, JumpEnd), 0.7f32.ease_steps(1, JumpEnd));
+        assert_eq!(
+            0.7f32.ease_steps(1, JumpNone),
+            0.7f32.ease_steps(2, JumpNone)
+        );
+    }
+
+    #[test]
+    fn expo_scaled() {
+        use approx::assert_relative_eq;
+
+        for scale in [-4.0f64, 0.5, 6.0, 10.0] {
+            assert_eq!(0.0.ease_in_expo_scaled(scale), 0.0);
+            assert_relative_eq!(1.0.ease_in_expo_scaled(scale), 1.0, epsilon = 1e-15);
+            assert_relative_eq!(1.0.ease_out_expo_scaled(scale), 1.0, epsilon = 1e-15);
+            assert_relative_eq!(0.5.ease_in_out_expo_scaled(scale), 0.5, epsilon = 1e-15);
+        }
+        for i in 0..=20 {
+            let t = i as f64 / 20.0;
+            // A scale of 10 is the normalized form of the fixed exponential easing.
+            assert_relative_eq!(
+                t.ease_in_expo_scaled(10.0),
+                t.ease_in_expo(),
+                epsilon = 1e-3
+            );
+            assert_relative_eq!(
+                t.ease_out_expo_scaled(7.0),
+                1.0 - (1.0 - t).ease_in_expo_scaled(7.0),
+                epsilon = 1e-14
+            );
+            // Base 3 with exponent 5 is a scale of 5 * log2(3).
+            let based = (3f64.powf(5.0 * t) - 1.0) / (3f64.powf(5.0) - 1.0);
+            assert_relative_eq!(
+                t.ease_in_expo_scaled(5.0 * 3f64.log2()),
+                based,
+                epsilon = 1e-14
+            );
+        }
+        assert_relative_eq!(
+            0.25.ease_in_out_expo_scaled(8.0f64),
+            0.5 * 0.5.ease_in_expo_scaled(8.0),
+            epsilon = 1e-15
+        );
+    }
+
+    #[test]
+    fn smoothsteps() {
+        use approx::assert_relative_eq;
+
+        for t in [0.0f32, 1.0] {
+            assert_eq!(t.smoothstep(), t);
+            assert_eq!(t.smootherstep(), t);
+            assert_eq!(t.smootheststep(), t);
+        }
+        for i in 0..=20 {
+            let t = i as f64 / 20.0;
+            assert_relative_eq!(t.smoothstep(), t * t * (3.0 - 2.0 * t), epsilon = 1e-15);
+            let perlin = t * t * t * (t * (t * 6.0 - 15.0) + 10.0);
+            assert_relative_eq!(t.smootherstep(), perlin, epsilon = 1e-15);
+            // Symmetric about the midpoint, like the in-out easings.
+            assert_relative_eq!(
+                t.smootheststep(),
+                1.0 - (1.0 - t).smootheststep(),
+                epsilon = 1e-14
+            );
+        }
+        assert_eq!(0.5f64.smootheststep(), 0.5);
+    }
+
+    #[test]
+    fn curves_through_zero() {
+        use approx::assert_relative_eq;
+
+        // Sweeps the curve parameter through zero, as an animated parameter would.
+        for step in -400..=400 {
+            let curve = step as f64 / 1000.0;
+            for i in 0..=20 {
+                let t = i as f64 / 20.0;
+                let exact = if curve == 0.0 {
+                    t
+                } else {
+                    (curve * t).exp_m1() / curve.exp_m1()
+                };
+                let single = EasingArgument::ease_in_curve(t as f32, curve as f32);
+                assert_relative_eq!(single as f64, exact, epsilon = 1e-6);
+                let double = EasingArgument::ease_in_curve(t, curve);
+                assert_relative_eq!(double, exact, epsilon = 1e-13);
+            }
+        }
+
+        // A zero threshold evaluates the closed form, which loses precision near zero.
+        let closed = EasingArgument::ease_in_curve_with_threshold(0.5f32, 1e-3, 0.0);
+        let series = EasingArgument::ease_in_curve(0.5f32, 1e-3);
+        assert!((closed - series).abs() > 1e-6);
+        assert_relative_eq!(series, 0.5 - 1e-3 / 8.0);
+        // The series up to `c^3`, even for large curves.
+        let forced = EasingArgument::ease_out_curve_with_threshold(0.5f64, 3.0, 5.0);
+        assert_eq!(forced, 1.0 - (0.5 - 0.375 * (1.0 - 0.1875)));
     }
 
     mod boundary_and_symmetry_tests {
@@ -1280,8 +1714,8 @@ mod tests {
                 paste! {
                     #[test]
                     fn [<boundary_tests_ $type>]() {
-                        let zero: $type = 0.0.into();
-                        let one: $type = 1.0.into();
+                        let zero: $type = 0.0;
+                        let one: $type = 1.0;
 
                         assert_relative_eq!(zero.ease_in_quad(), zero, epsilon = $epsilon);
                         assert_relative_eq!(one.ease_in_quad(), one, epsilon = $epsilon);
@@ -1378,11 +1812,11 @@ mod tests {
                 paste! {
                     #[test]
                     fn [<mirror_symmetry_ $type>]() {
-                        let points = [0.1, 0.2, 0.3, 0.4, 0.5, 0.6, 0.7, 0.8, 0.9];
-                        let one: $type = 1.0.into();
+                        let points: [$type; 9] = [0.1, 0.2, 0.3, 0.4, 0.5, 0.6, 0.7, 0.8, 0.9];
+                        let one: $type = 1.0;
                         for &t in &points {
-                            let t_val: $type = t.into();
-                            let one_minus_t: $type = (1.0 - t).into();
+                            let t_val: $type = t;
+                            let one_minus_t: $type = 1.0 - t;
 
                             assert_relative_eq!(t_val.ease_out_quad(), one - one_minus_t.ease_in_quad(), epsilon = $epsilon);
                             assert_relative_eq!(t_val.ease_out_cubic(), one - one_minus_t.ease_in_cubic(), epsilon = $epsilon);
@@ -1395,6 +1829,7 @@ mod tests {
                             assert_relative_eq!(t_val.ease_out_expo(), one - one_minus_t.ease_in_expo(), epsilon = $epsilon);
                             assert_relative_eq!(t_val.ease_out_elastic(), one - one_minus_t.ease_in_elastic(), epsilon = $epsilon);
                             assert_relative_eq!(t_val.ease_out_curve(1.0), one - one_minus_t.ease_in_curve(1.0), epsilon = $epsilon);
+                            assert_relative_eq!(t_val.ease_out_pow(1.5), one - one_minus_t.ease_in_pow(1.5), epsilon = $epsilon);
                         }
                     }
                 }
@@ -1407,11 +1842,11 @@ mod tests {
                 paste! {
                     #[test]
                     fn [<in_out_symmetry_ $type>]() {
-                        let points = [0.1, 0.2, 0.3, 0.4, 0.5];
-                        let one: $type = 1.0.into();
+                        let points: [$type; 5] = [0.1, 0.2, 0.3, 0.4, 0.5];
+                        let one: $type = 1.0;
                         for &t in &points {
-                            let t_val: $type = t.into();
-                            let one_minus_t: $type = (1.0 - t).into();
+                            let t_val: $type = t;
+                            let one_minus_t: $type = 1.0 - t;
 
                             assert_relative_eq!(t_val.ease_in_out_quad(), one - one_minus_t.ease_in_out_quad(), epsilon = $epsilon);
                             assert_relative_eq!(t_val.ease_in_out_cubic(), one - one_minus_t.ease_in_out_cubic(), epsilon = $epsilon);
@@ -1424,6 +1859,7 @@ mod tests {
                             assert_relative_eq!(t_val.ease_in_out_expo(), one - one_minus_t.ease_in_out_expo(), epsilon = $epsilon);
                             assert_relative_eq!(t_val.ease_in_out_elastic(), one - one_minus_t.ease_in_out_elastic(), epsilon = $epsilon);
                             assert_relative_eq!(t_val.ease_in_out_curve(1.0), one - one_minus_t.ease_in_out_curve(1.0), epsilon = $epsilon);
+                            assert_relative_eq!(t_val.ease_in_out_pow(3.5), one - one_minus_t.ease_in_out_pow(3.5), epsilon = $epsilon);
                         }
                     }
                 }
@@ -1441,6 +1877,25 @@ mod tests {
         generate_in_out_symmetry_tests!(f64, 1e-7);
     }
 
+    #[test]
+    fn integer_powers_match_named_easings() {
+        for i in 0..=10 {
+            let t = i as f64 / 10.0;
+            approx::assert_relative_eq!(t.ease_in_pow(3.0), t.ease_in_cubic(), epsilon = 1e-12);
+            approx::assert_relative_eq!(t.ease_out_pow(4.0), t.ease_out_quart(), epsilon = 1e-12);
+            approx::assert_relative_eq!(
+                t.ease_in_out_pow(2.0),
+                t.ease_in_out_quad(),
+                epsilon = 1e-12
+            );
+            approx::assert_relative_eq!(
+                t.ease_in_out_pow(5.0),
+                t.ease_in_out_quint(),
+                epsilon = 1e-12
+            );
+        }
+    }
+
     #[cfg(feature = "nightly")]
     #[test]
     fn test_mixed_arguments() {
@@ -1622,4 +2077,19 @@ mod reference_value_tests {
         1.0,
         [0.143115, 0.356618, 0.500000, 0.643382, 0.856885]
     );
+    generate_reference_tests!(
+        ease_in_pow,
+        2.5,
+        [0.017889, 0.101193, 0.176777, 0.278855, 0.572433]
+    );
+    generate_reference_tests!(
+        ease_out_pow,
+        2.5,
+        [0.427567, 0.721145, 0.823223, 0.898807, 0.982111]
+    );
+    generate_reference_tests!(
+        ease_in_out_pow,
+        2.5,
+        [0.050596, 0.286217, 0.500000, 0.713783, 0.949404]
+    );
 }
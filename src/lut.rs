@@ -0,0 +1,310 @@
+// Copyright (C) 2025 Tim Blechmann
+// SPDX-License-Identifier: MIT
+
+//! Curve tables as compact binary blobs, e.g. for asset packs which are memory-mapped at runtime.
+//!
+//! A blob holds the samples of a curve, e.g. at `t = i / (n - 1)`, as 16-bit fixed point entries
+//! relative to the range of the samples. All fields are little-endian:
+//!
+//! | Offset | Type       | Content                          |
+//! |--------|------------|----------------------------------|
+//! | 0      | `[u8; 4]`  | [`MAGIC`]                        |
+//! | 4      | `u16`      | [`VERSION`]                      |
+//! | 6      | `u16`      | reserved, written as 0           |
+//! | 8      | `u32`      | entry count `n`, at least 1      |
+//! | 12     | `f32`      | `min`, the value of entry 0      |
+//! | 16     | `f32`      | `max`, the value of entry 65535  |
+//! | 20     | `[u16; n]` | entries                          |
+//!
+//! Entry `e` decodes to `min + (max - min) * e / 65535`. Overshooting easings widen the range,
+//! so the quantization step is `(max - min) / 65535`.
+//!
+//! ```
+//! # use nova_easing::{Easing, lut::{LutView, encode_easing}};
+//! let blob = encode_easing(Easing::OutBack, 256);
+//! assert_eq!(blob.len(), 20 + 2 * 256);
+//!
+//! let table = LutView::from_bytes(&blob).unwrap();
+//! assert!((table.lookup_linear(0.5) - Easing::OutBack.apply(0.5f32)).abs() < 1e-4);
+//! ```
+
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+use core::fmt;
+
+#[cfg(feature = "alloc")]
+use crate::Easing;
+
+/// The first bytes of every blob.
+pub const MAGIC: [u8; 4] = *b"NELT";
+
+/// The format version written by [`encode`].
+pub const VERSION: u16 = 1;
+
+/// The size of the header preceding the entries, in bytes.
+pub const HEADER_SIZE: usize = 20;
+
+const ENTRY_MAX: f32 = u16::MAX as f32;
+
+/// Encodes `samples` as a blob, see the [module documentation](self).
+///
+/// # Panics
+///
+/// Panics if `samples` is empty, has more than `u32::MAX` elements or contains non-finite values.
+#[cfg(feature = "alloc")]
+pub fn encode(samples: &[f32]) -> Vec<u8> {
+    assert!(!samples.is_empty(), "a table needs at least one sample");
+    let count = u32::try_from(samples.len()).expect("too many samples");
+    assert!(
+        samples.iter().all(|sample| sample.is_finite()),
+        "samples must be finite"
+    );
+
+    let min = samples.iter().copied().fold(f32::INFINITY, f32::min);
+    let max = samples.iter().copied().fold(f32::NEG_INFINITY, f32::max);
+    let scale = if max > min {
+        ENTRY_MAX / (max - min)
+    } else {
+        0.0
+    };
+
+    let mut blob = Vec::with_capacity(HEADER_SIZE + 2 * samples.len());
+    blob.extend(MAGIC);
+    blob.extend(VERSION.to_le_bytes());
+    blob.extend(0u16.to_le_bytes());
+    blob.extend(count.to_le_bytes());
+    blob.extend(min.to_le_bytes());
+    blob.extend(max.to_le_bytes());
+    for &sample in samples {
+        let entry = ((sample - min) * scale + 0.5).min(ENTRY_MAX) as u16;
+        blob.extend(entry.to_le_bytes());
+    }
+    blob
+}
+
+/// Encodes `len` samples of `easing`, at `t` evenly spaced over [0, 1], as a blob.
+///
+/// # Panics
+///
+/// Panics if `len` is zero or larger than `u32::MAX`.
+#[cfg(feature = "alloc")]
+pub fn encode_easing(easing: Easing, len: usize) -> Vec<u8> {
+    encode(&crate::batch::sample_n_vec(len, easing))
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// A table read in place from a blob, without copying or allocating.
+///
+/// The entries are decoded on access, so the blob may come straight from a memory-mapped file at
+/// any alignment.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct LutView<'a> {
+    min: f32,
+    max: f32,
+    entries: &'a [u8],
+}
+
+impl<'a> LutView<'a> {
+    /// Reads the blob at the start of `bytes`.
+    ///
+    /// Trailing bytes are ignored, so blobs can be concatenated in an asset pack: the next blob
+    /// starts at [`byte_len`](Self::byte_len).
+    pub fn from_bytes(bytes: &'a [u8]) -> Result<Self, DecodeLutError> {
+        let Some((header, rest)) = bytes.split_first_chunk::<HEADER_SIZE>() else {
+            return Err(DecodeLutError::InvalidLength(bytes.len()));
+        };
+        let word = |offset: usize| -> [u8; 4] { header[offset..offset + 4].try_into().unwrap() };
+
+        if header[..4] != MAGIC {
+            return Err(DecodeLutError::InvalidMagic);
+        }
+        let version = u16::from_le_bytes([header[4], header[5]]);
+        if version != VERSION {
+            return Err(DecodeLutError::UnsupportedVersion(version));
+        }
+        let count = u32::from_le_bytes(word(8)) as usize;
+        if count == 0 {
+            return Err(DecodeLutError::Empty);
+        }
+        let (min, max) = (f32::from_le_bytes(word(12)), f32::from_le_bytes(word(16)));
+        if !(min.is_finite() && max.is_finite() && min <= max) {
+            return Err(DecodeLutError::InvalidRange(min, max));
+        }
+        let entries = rest
+            .get(..count.saturating_mul(2))
+            .ok_or(DecodeLutError::InvalidLength(bytes.len()))?;
+        Ok(Self { min, max, entries })
+    }
+
+    /// The number of entries, at least 1.
+    pub fn entry_count(&self) -> usize {
+        self.entries.len() / 2
+    }
+
+    /// The size of the blob in bytes, including the header.
+    pub fn byte_len(&self) -> usize {
+        HEADER_SIZE + self.entries.len()
+    }
+
+    /// The range `(min, max)` of the values.
+    pub fn range(&self) -> (f32, f32) {
+        (self.min, self.max)
+    }
+
+    /// The fixed point entry at `index`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is out of bounds.
+    pub fn raw(&self, index: usize) -> u16 {
+        u16::from_le_bytes([self.entries[2 * index], self.entries[2 * index + 1]])
+    }
+
+    /// The value of the entry at `index`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is out of bounds.
+    pub fn get(&self, index: usize) -> f32 {
+        self.min + (self.max - self.min) * (self.raw(index) as f32 / ENTRY_MAX)
+    }
+
+    /// Looks up the entry nearest to `t`, which is clamped to [0, 1].
+    pub fn lookup(&self, t: f32) -> f32 {
+        let position = t.clamp(0.0, 1.0) * (self.entry_count() - 1) as f32;
+        self.get((position + 0.5) as usize)
+    }
+
+    /// Looks up `t` with linear interpolation between neighbouring entries. `t` is clamped to
+    /// [0, 1].
+    pub fn lookup_linear(&self, t: f32) -> f32 {
+        let last = self.entry_count() - 1;
+        let position = t.clamp(0.0, 1.0) * last as f32;
+        let index = (position as usize).min(last.saturating_sub(1));
+        let (low, high) = (self.get(index), self.get((index + 1).min(last)));
+        low + (high - low) * (position - index as f32)
+    }
+}
+
+/// The error of reading a [`LutView`] from a blob.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum DecodeLutError {
+    /// The blob of the given length is too short for its header or entries.
+    InvalidLength(usize),
+    /// The blob does not start with [`MAGIC`].
+    InvalidMagic,
+    /// The blob has a format version other than [`VERSION`].
+    UnsupportedVersion(u16),
+    /// The blob has no entries.
+    Empty,
+    /// The range `(min, max)` is not finite or inverted.
+    InvalidRange(f32, f32),
+}
+
+impl fmt::Display for DecodeLutError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DecodeLutError::InvalidLength(length) => {
+                write!(f, "table blob of length {length} is truncated")
+            }
+            DecodeLutError::InvalidMagic => f.write_str("not a table blob"),
+            DecodeLutError::UnsupportedVersion(version) => {
+                write!(f, "unsupported table blob version {version}")
+            }
+            DecodeLutError::Empty => f.write_str("table blob has no entries"),
+            DecodeLutError::InvalidRange(min, max) => {
+                write!(f, "invalid table blob range [{min}, {max}]")
+            }
+        }
+    }
+}
+
+impl core::error::Error for DecodeLutError {}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::{DecodeLutError, HEADER_SIZE, LutView, encode, encode_easing};
+    use crate::Easing;
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn round_trip() {
+        for easing in [Easing::InOutSine, Easing::OutElastic, Easing::InBack] {
+            let blob = encode_easing(easing, 129);
+            let table = LutView::from_bytes(&blob).unwrap();
+            assert_eq!(table.entry_count(), 129);
+            assert_eq!(table.byte_len(), blob.len());
+
+            let (min, max) = table.range();
+            let step = (max - min) / 65535.0;
+            for i in 0..129 {
+                let t = i as f32 / 128.0;
+                assert_relative_eq!(table.get(i), easing.apply(t), epsilon = step);
+                assert_relative_eq!(table.lookup(t), easing.apply(t), epsilon = step);
+            }
+        }
+    }
+
+    #[test]
+    fn lookups() {
+        let blob = encode(&[1.0, 3.0, 2.0]);
+        let table = LutView::from_bytes(&blob).unwrap();
+        assert_eq!(table.range(), (1.0, 3.0));
+        assert_eq!(table.raw(1), u16::MAX);
+        assert_eq!(table.lookup(0.3), 3.0);
+        assert_eq!(table.lookup_linear(0.25), 2.0);
+        assert_eq!(table.lookup_linear(-1.0), 1.0);
+        assert_relative_eq!(table.lookup_linear(2.0), 2.0, epsilon = 1e-4);
+
+        let blob = encode(&[0.5]);
+        let constant = LutView::from_bytes(&blob).unwrap();
+        assert_eq!(constant.lookup_linear(0.7), 0.5);
+        assert_eq!(constant.lookup(0.7), 0.5);
+    }
+
+    #[test]
+    fn concatenated_and_unaligned() {
+        let mut pack = vec![0u8];
+        pack.extend(encode(&[0.0, 1.0]));
+        pack.extend(encode_easing(Easing::InQuad, 3));
+
+        let first = LutView::from_bytes(&pack[1..]).unwrap();
+        assert_eq!(first.lookup_linear(0.5), 0.5);
+        let second = LutView::from_bytes(&pack[1 + first.byte_len()..]).unwrap();
+        assert_eq!(second.entry_count(), 3);
+        assert_relative_eq!(second.get(1), 0.25, epsilon = 1e-4);
+    }
+
+    #[test]
+    fn errors() {
+        let blob = encode(&[0.0, 1.0]);
+        let decode = |bytes: &[u8]| LutView::from_bytes(bytes).unwrap_err();
+
+        assert_eq!(decode(&blob[..10]), DecodeLutError::InvalidLength(10));
+        assert_eq!(decode(&blob[..23]), DecodeLutError::InvalidLength(23));
+
+        let mut corrupt = blob.clone();
+        corrupt[0] = b'X';
+        assert_eq!(decode(&corrupt), DecodeLutError::InvalidMagic);
+
+        let mut corrupt = blob.clone();
+        corrupt[4] = 2;
+        assert_eq!(decode(&corrupt), DecodeLutError::UnsupportedVersion(2));
+
+        let mut corrupt = blob.clone();
+        corrupt[8..12].copy_from_slice(&0u32.to_le_bytes());
+        assert_eq!(decode(&corrupt), DecodeLutError::Empty);
+
+        let mut corrupt = blob.clone();
+        corrupt[12..16].copy_from_slice(&f32::NAN.to_le_bytes());
+        assert!(matches!(decode(&corrupt), DecodeLutError::InvalidRange(..)));
+        assert_eq!(
+            DecodeLutError::InvalidLength(3).to_string(),
+            "table blob of length 3 is truncated"
+        );
+        assert_eq!(blob.len(), HEADER_SIZE + 4);
+    }
+}
@@ -0,0 +1,156 @@
+// Copyright (C) 2025 Tim Blechmann
+// SPDX-License-Identifier: MIT
+
+//! Error metrics between two curves over [0, 1].
+//!
+//! Curves are passed as functions, so easings, baked tables, fitted approximations and keyframe
+//! tracks can all be compared against a reference:
+//!
+//! ```
+//! # use nova_easing::{Easing, metrics::{max_error, rms_error}};
+//! let reference = |t: f64| Easing::InOutSine.apply(t);
+//! let approximation = |t: f64| Easing::InOutQuad.apply(t);
+//! assert!(max_error(reference, approximation) < 0.05);
+//! assert!(rms_error(reference, approximation) < max_error(reference, approximation));
+//! ```
+//!
+//! All metrics sample the curves at [`RESOLUTION`] evenly spaced intervals.
+
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+#[allow(unused_imports)]
+use num_traits::Float;
+
+/// The number of intervals at which the curves are sampled.
+pub const RESOLUTION: usize = 1024;
+
+/// The largest vertical distance `|a(t) - b(t)|` over [0, 1].
+///
+/// NaN if either curve is NaN at any sample, like all metrics of this module.
+pub fn max_error(a: impl Fn(f64) -> f64, b: impl Fn(f64) -> f64) -> f64 {
+    samples().map(|t| (a(t) - b(t)).abs()).fold(0.0, max_or_nan)
+}
+
+/// The root mean square of the vertical distance `a(t) - b(t)` over [0, 1].
+pub fn rms_error(a: impl Fn(f64) -> f64, b: impl Fn(f64) -> f64) -> f64 {
+    // Midpoint rule, which leaves out the endpoints where most curves agree exactly anyway.
+    let sum: f64 = (0..RESOLUTION)
+        .map(|i| {
+            let t = (i as f64 + 0.5) / RESOLUTION as f64;
+            (a(t) - b(t)).powi(2)
+        })
+        .sum();
+    (sum / RESOLUTION as f64).sqrt()
+}
+
+/// The Hausdorff distance between the graphs of `a` and `b` over [0, 1], approximated by
+/// polylines through the samples.
+///
+/// Unlike [`max_error`], it also measures horizontal distance, so a curve which is slightly
+/// shifted in time (e.g. a step, or a steep exponential) scores a small error where the vertical
+/// distance would be large.
+#[cfg(feature = "alloc")]
+pub fn hausdorff_like(a: impl Fn(f64) -> f64, b: impl Fn(f64) -> f64) -> f64 {
+    let a: Vec<(f64, f64)> = samples().map(|t| (t, a(t))).collect();
+    let b: Vec<(f64, f64)> = samples().map(|t| (t, b(t))).collect();
+    max_or_nan(directed_distance(&a, &b), directed_distance(&b, &a))
+}
+
+fn samples() -> impl Iterator<Item = f64> {
+    (0..=RESOLUTION).map(|i| i as f64 / RESOLUTION as f64)
+}
+
+// `f64::max` and `f64::min` ignore NaN, which would let a curve returning NaN pass as accurate.
+fn max_or_nan(a: f64, b: f64) -> f64 {
+    if a.is_nan() || b.is_nan() {
+        f64::NAN
+    } else {
+        a.max(b)
+    }
+}
+
+#[cfg(feature = "alloc")]
+fn min_or_nan(a: f64, b: f64) -> f64 {
+    if a.is_nan() || b.is_nan() {
+        f64::NAN
+    } else {
+        a.min(b)
+    }
+}
+
+// The largest distance from a point of `from` to the polyline `to`.
+#[cfg(feature = "alloc")]
+fn directed_distance(from: &[(f64, f64)], to: &[(f64, f64)]) -> f64 {
+    from.iter()
+        .map(|&point| {
+            to.windows(2)
+                .map(|segment| segment_distance(point, segment[0], segment[1]))
+                .fold(f64::INFINITY, min_or_nan)
+        })
+        .fold(0.0, max_or_nan)
+}
+
+#[cfg(feature = "alloc")]
+fn segment_distance((x, y): (f64, f64), (x0, y0): (f64, f64), (x1, y1): (f64, f64)) -> f64 {
+    let (dx, dy) = (x1 - x0, y1 - y0);
+    let length_squared = dx * dx + dy * dy;
+    let along = if length_squared > 0.0 {
+        (((x - x0) * dx + (y - y0) * dy) / length_squared).clamp(0.0, 1.0)
+    } else {
+        0.0
+    };
+    (x - x0 - along * dx).hypot(y - y0 - along * dy)
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::{hausdorff_like, max_error, rms_error};
+    use crate::Easing;
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn identical_curves() {
+        let curve = |t: f64| Easing::OutBounce.apply(t);
+        assert_eq!(max_error(curve, curve), 0.0);
+        assert_eq!(rms_error(curve, curve), 0.0);
+        assert_eq!(hausdorff_like(curve, curve), 0.0);
+    }
+
+    #[test]
+    fn quadratic_against_linear() {
+        let quad = |t: f64| t * t;
+        let linear = |t: f64| t;
+
+        // t - t^2 peaks at 0.5; its squared integral is 1/30.
+        assert_relative_eq!(max_error(quad, linear), 0.25);
+        assert_relative_eq!(
+            rms_error(quad, linear),
+            (1.0f64 / 30.0).sqrt(),
+            epsilon = 1e-6
+        );
+
+        // The closest point of the diagonal to (0.5, 0.25) is at distance 0.25 / sqrt(2).
+        let expected = 0.25 / 2.0f64.sqrt();
+        assert_relative_eq!(hausdorff_like(quad, linear), expected, epsilon = 1e-6);
+        assert_relative_eq!(hausdorff_like(linear, quad), expected, epsilon = 1e-6);
+    }
+
+    #[test]
+    fn shifted_step() {
+        let step = |at: f64| move |t: f64| if t < at { 0.0 } else { 1.0 };
+        assert_eq!(max_error(step(0.5), step(0.51)), 1.0);
+        assert!(hausdorff_like(step(0.5), step(0.51)) < 0.02);
+    }
+    #[test]
+    fn nan_propagates() {
+        let linear = |t: f64| t;
+        let broken = |t: f64| if t == 0.5 { f64::NAN } else { t };
+        assert!(max_error(linear, broken).is_nan());
+        assert!(max_error(broken, linear).is_nan());
+        assert!(rms_error(linear, |_| f64::NAN).is_nan());
+        assert!(hausdorff_like(linear, broken).is_nan());
+        assert!(hausdorff_like(broken, linear).is_nan());
+    }
+}
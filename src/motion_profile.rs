@@ -0,0 +1,428 @@
+// Copyright (C) 2025 Tim Blechmann
+// SPDX-License-Identifier: MIT
+
+//! Motion-control profiles: trapezoidal velocity and 7-segment S-curves.
+//!
+//! A [`MotionProfile`] plans a rest-to-rest move within velocity, acceleration and (for S-curves)
+//! jerk limits. It can be sampled in the time domain, like a motor or servo setpoint generator,
+//! and, via [`Curve`], as a normalized easing from 0 to 1:
+//!
+//! ```
+//! # use nova_easing::{curve::Curve, motion_profile::MotionProfile};
+//! let profile = MotionProfile::s_curve(10.0, 2.0, 1.0, 2.0);
+//! assert!((profile.duration() - 7.5).abs() < 1e-12);
+//! assert!((profile.position(profile.duration()) - 10.0).abs() < 1e-12);
+//! assert!((profile.velocity(3.75) - 2.0).abs() < 1e-12);
+//!
+//! // The same shape as an easing.
+//! assert!((profile.eval(0.5f32) - 0.5).abs() < 1e-6);
+//! ```
+
+use crate::curve::Curve;
+use crate::custom::EasingValue;
+
+/// A planned rest-to-rest move over a signed `distance`.
+///
+/// The move accelerates, cruises at the peak velocity and decelerates symmetrically. A trapezoidal
+/// profile switches the acceleration instantly; an S-curve ramps it with the jerk limit, which
+/// gives up to 7 segments: jerk up, constant acceleration, jerk down, cruise, and the same
+/// mirrored. Short moves which cannot reach the velocity or acceleration limits skip the
+/// respective constant segments.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct MotionProfile {
+    distance: f64,
+    cruise_time: f64,
+    phase: Phase<f64>,
+}
+
+impl MotionProfile {
+    /// Plans a move with a trapezoidal velocity profile.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a limit is not positive.
+    pub fn trapezoidal(distance: f64, max_velocity: f64, max_acceleration: f64) -> Self {
+        Self::plan(distance, max_velocity, max_acceleration, None)
+    }
+
+    /// Plans a move with a jerk-limited S-curve profile.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a limit is not positive.
+    pub fn s_curve(distance: f64, max_velocity: f64, max_acceleration: f64, max_jerk: f64) -> Self {
+        assert!(max_jerk > 0.0, "motion limits must be positive");
+        Self::plan(distance, max_velocity, max_acceleration, Some(max_jerk))
+    }
+
+    fn plan(distance: f64, max_velocity: f64, max_acceleration: f64, jerk: Option<f64>) -> Self {
+        assert!(
+            max_velocity > 0.0 && max_acceleration > 0.0,
+            "motion limits must be positive"
+        );
+        let length = distance.abs();
+        if length == 0.0 {
+            return Self {
+                distance,
+                cruise_time: 0.0,
+                phase: Phase::accelerate_to(0.0, max_acceleration, jerk),
+            };
+        }
+
+        let phase = Phase::accelerate_to(max_velocity, max_acceleration, jerk);
+        if 2.0 * phase.distance() <= length {
+            return Self {
+                distance,
+                cruise_time: (length - 2.0 * phase.distance()) / phase.peak_velocity,
+                phase,
+            };
+        }
+
+        // The peak velocity `v` of a move without cruise covers `v * accel_time(v)`.
+        let velocity = match jerk {
+            None => (length * max_acceleration).sqrt(),
+            Some(jerk) => {
+                let velocity = (length * jerk.sqrt() / 2.0).powf(2.0 / 3.0);
+                if velocity * jerk < max_acceleration * max_acceleration {
+                    velocity
+                } else {
+                    let q = max_acceleration / jerk;
+                    ((q * q + 4.0 * length / max_acceleration).sqrt() - q) * max_acceleration / 2.0
+                }
+            }
+        };
+        Self {
+            distance,
+            cruise_time: 0.0,
+            phase: Phase::accelerate_to(velocity, max_acceleration, jerk),
+        }
+    }
+
+    /// The signed distance of the move.
+    pub fn distance(&self) -> f64 {
+        self.distance
+    }
+
+    /// The total duration of the move.
+    pub fn duration(&self) -> f64 {
+        2.0 * self.phase.accel_time + self.cruise_time
+    }
+
+    /// The duration of the acceleration, and of the deceleration.
+    pub fn accel_time(&self) -> f64 {
+        self.phase.accel_time
+    }
+
+    /// The duration of the cruise at the peak velocity.
+    pub fn cruise_time(&self) -> f64 {
+        self.cruise_time
+    }
+
+    /// The largest speed reached, which is the velocity limit unless the move is too short.
+    pub fn peak_velocity(&self) -> f64 {
+        self.phase.peak_velocity
+    }
+
+    /// The position at `time`, from 0 to [`distance`](Self::distance), clamped outside of the move.
+    pub fn position(&self, time: f64) -> f64 {
+        let time = time.clamp(0.0, self.duration());
+        self.sign()
+            * self
+                .phase
+                .position(time, self.cruise_time, self.distance.abs())
+    }
+
+    /// The velocity at `time`.
+    pub fn velocity(&self, time: f64) -> f64 {
+        let (tau, _) = self.mirrored(time);
+        let phase = &self.phase;
+        let speed = if tau <= 0.0 {
+            0.0
+        } else if tau < phase.jerk_time {
+            phase.jerk * tau * tau / 2.0
+        } else if tau < phase.accel_time - phase.jerk_time {
+            phase.jerk_velocity() + phase.peak_acceleration * (tau - phase.jerk_time)
+        } else if tau < phase.accel_time {
+            let remaining = phase.accel_time - tau;
+            phase.peak_velocity - phase.jerk * remaining * remaining / 2.0
+        } else {
+            phase.peak_velocity
+        };
+        self.sign() * speed
+    }
+
+    /// The acceleration at `time`.
+    pub fn acceleration(&self, time: f64) -> f64 {
+        let (tau, decelerating) = self.mirrored(time);
+        let phase = &self.phase;
+        let magnitude = if tau <= 0.0 || tau >= phase.accel_time {
+            0.0
+        } else if tau < phase.jerk_time {
+            phase.jerk * tau
+        } else if tau < phase.accel_time - phase.jerk_time {
+            phase.peak_acceleration
+        } else {
+            phase.jerk * (phase.accel_time - tau)
+        };
+        let direction = if decelerating { -1.0 } else { 1.0 };
+        self.sign() * direction * magnitude
+    }
+
+    fn sign(&self) -> f64 {
+        if self.distance < 0.0 { -1.0 } else { 1.0 }
+    }
+
+    // The time since the start or until the end, whichever is closer, and whether it is the end.
+    fn mirrored(&self, time: f64) -> (f64, bool) {
+        let duration = self.duration();
+        let time = time.clamp(0.0, duration);
+        if time * 2.0 < duration {
+            (time, false)
+        } else {
+            (duration - time, true)
+        }
+    }
+}
+
+/// The normalized profile: progress from 0 to 1 over the normalized time [0, 1].
+impl Curve for MotionProfile {
+    #[inline]
+    fn eval<T: EasingValue>(&self, t: T) -> T {
+        let length = self.distance.abs();
+        if length == 0.0 {
+            return t;
+        }
+        // Rescaled to unit duration and distance, so jerk scales with `T^3 / D`.
+        let duration = self.duration();
+        let phase = Phase {
+            jerk_time: self.phase.jerk_time / duration,
+            accel_time: self.phase.accel_time / duration,
+            jerk: self.phase.jerk * duration.powi(3) / length,
+            peak_acceleration: self.phase.peak_acceleration * duration.powi(2) / length,
+            peak_velocity: self.phase.peak_velocity * duration / length,
+        }
+        .map(|value| T::splat(value as f32));
+        let zero = T::splat(0.0);
+        let one = T::splat(1.0);
+        let t = t.select_lt(zero, zero, one.select_lt(t, one, t));
+        phase.position(t, T::splat((self.cruise_time / duration) as f32), one)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+// The acceleration phase: jerk up for `jerk_time`, constant acceleration, jerk down for `jerk_time`,
+// reaching `peak_velocity` after `accel_time`. Trapezoidal profiles have no jerk segments.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+struct Phase<T> {
+    jerk_time: T,
+    accel_time: T,
+    jerk: T,
+    peak_acceleration: T,
+    peak_velocity: T,
+}
+
+impl Phase<f64> {
+    fn accelerate_to(velocity: f64, max_acceleration: f64, jerk: Option<f64>) -> Self {
+        match jerk {
+            None => {
+                Self {
+                    jerk_time: 0.0,
+                    accel_time: velocity / max_acceleration,
+                    jerk: 0.0,
+                    peak_acceleration: max_acceleration,
+                    peak_velocity: velocity,
+                }
+            }
+            // Too slow to reach the acceleration limit: jerk up and straight down again.
+            Some(jerk) if velocity * jerk < max_acceleration * max_acceleration => {
+                let jerk_time = (velocity / jerk).sqrt();
+                Self {
+                    jerk_time,
+                    accel_time: 2.0 * jerk_time,
+                    jerk,
+                    peak_acceleration: jerk * jerk_time,
+                    peak_velocity: velocity,
+                }
+            }
+            Some(jerk) => {
+                Self {
+                    jerk_time: max_acceleration / jerk,
+                    accel_time: max_acceleration / jerk + velocity / max_acceleration,
+                    jerk,
+                    peak_acceleration: max_acceleration,
+                    peak_velocity: velocity,
+                }
+            }
+        }
+    }
+
+    fn map<T>(self, f: impl Fn(f64) -> T) -> Phase<T> {
+        Phase {
+            jerk_time: f(self.jerk_time),
+            accel_time: f(self.accel_time),
+            jerk: f(self.jerk),
+            peak_acceleration: f(self.peak_acceleration),
+            peak_velocity: f(self.peak_velocity),
+        }
+    }
+}
+
+impl<T: EasingValue> Phase<T> {
+    // The phase is symmetric, so its average velocity is half the peak.
+    fn distance(&self) -> T {
+        self.peak_velocity * self.accel_time * T::splat(0.5)
+    }
+
+    fn jerk_velocity(&self) -> T {
+        self.jerk * self.jerk_time * self.jerk_time * T::splat(0.5)
+    }
+
+    // The distance covered after `time` of a whole move of `length`, with `time` in the move.
+    fn position(&self, time: T, cruise_time: T, length: T) -> T {
+        let sixth = T::splat(1.0 / 6.0);
+        let half_duration = self.accel_time + cruise_time * T::splat(0.5);
+        let duration = half_duration.double();
+        let tau = time.select_lt(half_duration, time, duration - time);
+
+        let jerk_up = self.jerk * tau * tau * tau * sixth;
+        let since = tau - self.jerk_time;
+        let jerk_distance = self.jerk * self.jerk_time * self.jerk_time * self.jerk_time * sixth;
+        let constant = jerk_distance
+            + self.jerk_velocity() * since
+            + self.peak_acceleration * since * since * T::splat(0.5);
+        let remaining = self.accel_time - tau;
+        let jerk_down = self.distance() - self.peak_velocity * remaining
+            + self.jerk * remaining * remaining * remaining * sixth;
+        let cruise = self.distance() + self.peak_velocity * (tau - self.accel_time);
+
+        let covered = tau.select_lt(
+            self.jerk_time,
+            jerk_up,
+            tau.select_lt(
+                self.accel_time - self.jerk_time,
+                constant,
+                tau.select_lt(self.accel_time, jerk_down, cruise),
+            ),
+        );
+        time.select_lt(half_duration, covered, length - covered)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::MotionProfile;
+    use crate::curve::Curve;
+    use approx::assert_relative_eq;
+
+    // Checks the end state, the limits, and that velocity and acceleration are the derivatives.
+    fn check(profile: &MotionProfile, max_velocity: f64, max_acceleration: f64, max_jerk: f64) {
+        let duration = profile.duration();
+        assert_eq!(profile.position(0.0), 0.0);
+        assert_relative_eq!(
+            profile.position(duration),
+            profile.distance(),
+            epsilon = 1e-9
+        );
+        assert_eq!(profile.velocity(duration), 0.0);
+
+        let h = duration * 1e-5;
+        for i in 1..200 {
+            let time = duration * i as f64 / 200.0 + h * 0.5;
+            let velocity = profile.velocity(time);
+            let acceleration = profile.acceleration(time);
+            assert!(velocity.abs() <= max_velocity * (1.0 + 1e-9));
+            assert!(acceleration.abs() <= max_acceleration * (1.0 + 1e-9));
+
+            let numeric_velocity =
+                (profile.position(time + h) - profile.position(time - h)) / (2.0 * h);
+            assert_relative_eq!(
+                velocity,
+                numeric_velocity,
+                epsilon = 1e-5,
+                max_relative = 1e-5
+            );
+            let numeric_acceleration =
+                (profile.velocity(time + h) - profile.velocity(time - h)) / (2.0 * h);
+            assert!((acceleration - numeric_acceleration).abs() <= max_jerk * 2.0 * h + 1e-6);
+        }
+    }
+
+    #[test]
+    fn trapezoidal() {
+        let profile = MotionProfile::trapezoidal(10.0, 2.0, 1.0);
+        assert_eq!(profile.duration(), 7.0);
+        assert_eq!(profile.accel_time(), 2.0);
+        assert_eq!(profile.position(2.0), 2.0);
+        assert_eq!(profile.position(3.5), 5.0);
+        assert_eq!(profile.velocity(3.0), 2.0);
+        assert_eq!(profile.acceleration(1.0), 1.0);
+        assert_eq!(profile.acceleration(6.0), -1.0);
+        check(&profile, 2.0, 1.0, f64::INFINITY);
+
+        // Too short to reach the velocity limit: a triangular profile.
+        let profile = MotionProfile::trapezoidal(-1.0, 10.0, 4.0);
+        assert_eq!(profile.peak_velocity(), 2.0);
+        assert_eq!(profile.cruise_time(), 0.0);
+        assert_eq!(profile.duration(), 1.0);
+        assert_eq!(profile.velocity(0.5), -2.0);
+        check(&profile, 10.0, 4.0, f64::INFINITY);
+    }
+
+    #[test]
+    fn s_curve() {
+        let profile = MotionProfile::s_curve(10.0, 2.0, 1.0, 2.0);
+        assert_relative_eq!(profile.accel_time(), 2.5);
+        assert_relative_eq!(profile.cruise_time(), 2.5);
+        assert_relative_eq!(profile.acceleration(0.25), 0.5);
+        assert_relative_eq!(profile.acceleration(1.0), 1.0);
+        check(&profile, 2.0, 1.0, 2.0);
+
+        // Reaches the acceleration limit, but not the velocity limit.
+        let profile = MotionProfile::s_curve(3.0, 5.0, 1.0, 4.0);
+        assert!(profile.peak_velocity() < 5.0);
+        assert_eq!(profile.cruise_time(), 0.0);
+        assert_relative_eq!(profile.acceleration(profile.accel_time() / 2.0), 1.0);
+        check(&profile, 5.0, 1.0, 4.0);
+
+        // Reaches neither.
+        let profile = MotionProfile::s_curve(-0.1, 5.0, 1.0, 4.0);
+        assert!(profile.acceleration(profile.accel_time() / 2.0) > -1.0);
+        check(&profile, 5.0, 1.0, 4.0);
+    }
+
+    #[test]
+    fn normalized_curve() {
+        let profiles = [
+            MotionProfile::trapezoidal(10.0, 2.0, 1.0),
+            MotionProfile::s_curve(-3.0, 5.0, 1.0, 4.0),
+            MotionProfile::s_curve(10.0, 2.0, 1.0, 2.0),
+        ];
+        for profile in profiles {
+            for i in 0..=20 {
+                let u = i as f64 / 20.0;
+                let expected = profile.position(u * profile.duration()) / profile.distance();
+                assert_relative_eq!(profile.eval(u), expected, epsilon = 1e-6);
+                assert_relative_eq!(profile.eval(u as f32), expected as f32, epsilon = 1e-5);
+            }
+        }
+        let zero = MotionProfile::trapezoidal(0.0, 1.0, 1.0);
+        assert_eq!(zero.duration(), 0.0);
+        assert_eq!(zero.eval(0.25f32), 0.25);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde_round_trip() {
+        let profile = MotionProfile::s_curve(3.0, 5.0, 1.0, 4.0);
+        let json = serde_json::to_string(&profile).unwrap();
+        assert_eq!(
+            serde_json::from_str::<MotionProfile>(&json).unwrap(),
+            profile
+        );
+    }
+}
@@ -0,0 +1,239 @@
+// Copyright (C) 2025 Tim Blechmann
+// SPDX-License-Identifier: MIT
+
+//! A small text format for motion, parsed into an [`Env`].
+//!
+//! Designers and tests can describe an animation as a string, e.g. in a fixture file, instead of
+//! building it in Rust. A spec is a sequence of statements, separated by `;` or line breaks:
+//!
+//! - `<from> -> <to> in <duration> [<easing>]` moves from `from` to `to`. `from` is optional and
+//!   defaults to the current level, which starts at 0. A `from` which differs from the current
+//!   level jumps there first.
+//! - `hold <duration>` keeps the current level.
+//!
+//! Durations are numbers with the unit `ms` or `s`. Easings are given by their
+//! [names](crate::Easing::name), as accepted by [`Easing`]'s `FromStr`, and default to `linear`.
+//!
+//! ```
+//! # use nova_easing::motion_spec;
+//! let env =
+//!     motion_spec::parse("0 -> 1 in 300ms ease_out_back; hold 100ms; -> 0 in 200ms ease_in_quad")
+//!         .unwrap();
+//! assert_eq!(env.segments().len(), 3);
+//! assert_eq!(env.level_at(0.35), 1.0);
+//! assert!((env.level_at(0.5) - 0.75).abs() < 1e-5);
+//! ```
+
+use alloc::vec::Vec;
+use core::fmt;
+
+use crate::env::{Env, Shape};
+use crate::{Easing, ParseEasingError};
+
+/// Parses a motion spec, see the [module documentation](self).
+pub fn parse(spec: &str) -> Result<Env, ParseMotionError> {
+    let statements = spec
+        .split([';', '\n'])
+        .map(str::trim)
+        .filter(|statement| !statement.is_empty());
+
+    let mut env: Option<Env> = None;
+    let mut level = 0.0;
+    for (index, statement) in statements.enumerate() {
+        if let Some(duration) = statement.strip_prefix("hold") {
+            let time =
+                parse_duration(duration.trim()).ok_or(ParseMotionError::InvalidDuration(index))?;
+            let current = env.unwrap_or_else(|| Env::new(level));
+            env = Some(current.segment(level, time, Shape::Hold));
+            continue;
+        }
+
+        let (from, rest) = statement
+            .split_once("->")
+            .ok_or(ParseMotionError::InvalidStatement(index))?;
+        let mut words = rest.split_whitespace();
+        let (Some(to), Some("in"), Some(duration)) = (words.next(), words.next(), words.next())
+        else {
+            return Err(ParseMotionError::InvalidStatement(index));
+        };
+
+        let parse_level = |level: &str| {
+            level
+                .parse::<f32>()
+                .ok()
+                .filter(|level| level.is_finite())
+                .ok_or(ParseMotionError::InvalidLevel(index))
+        };
+        let from = match from.trim() {
+            "" => level,
+            from => parse_level(from)?,
+        };
+        let to = parse_level(to)?;
+        let time = parse_duration(duration).ok_or(ParseMotionError::InvalidDuration(index))?;
+        let easing = words.collect::<Vec<_>>().join(" ");
+        let easing = match easing.as_str() {
+            "" => Easing::Linear,
+            easing => {
+                easing
+                    .parse()
+                    .map_err(|error| ParseMotionError::InvalidEasing(index, error))?
+            }
+        };
+
+        let mut current = match env {
+            Some(env) => env,
+            None => {
+                level = from;
+                Env::new(from)
+            }
+        };
+        if from != level {
+            current = current.segment(from, 0.0, Shape::Step);
+        }
+        env = Some(current.segment(to, time, shape(easing)));
+        level = to;
+    }
+    Ok(env.unwrap_or_else(|| Env::new(0.0)))
+}
+
+// The shape of an easing, preferring the shapes `EnvGen` knows.
+fn shape(easing: Easing) -> Shape {
+    match easing {
+        Easing::Linear => Shape::Linear,
+        Easing::InOutSine => Shape::Sine,
+        Easing::InCurve(curve) => Shape::Curve(curve),
+        easing => Shape::Eased(easing),
+    }
+}
+
+// A non-negative duration in seconds, from a number with the unit `ms` or `s`.
+fn parse_duration(duration: &str) -> Option<f32> {
+    let (value, scale) = match duration.strip_suffix("ms") {
+        Some(value) => (value, 1e-3),
+        None => (duration.strip_suffix('s')?, 1.0),
+    };
+    let value = value.trim_end().parse::<f32>().ok()? * scale;
+    (value.is_finite() && value >= 0.0).then_some(value)
+}
+
+/// The error of parsing a motion spec with [`parse`]. Statements are counted from 0, skipping
+/// empty ones.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ParseMotionError {
+    /// The statement at the index is neither a transition nor a hold.
+    InvalidStatement(usize),
+    /// A level of the statement at the index is not a finite number.
+    InvalidLevel(usize),
+    /// The duration of the statement at the index is not a non-negative number with `ms` or `s`.
+    InvalidDuration(usize),
+    /// The easing of the statement at the index does not parse.
+    InvalidEasing(usize, ParseEasingError),
+}
+
+impl fmt::Display for ParseMotionError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseMotionError::InvalidStatement(index) => {
+                write!(f, "invalid motion statement {index}")
+            }
+            ParseMotionError::InvalidLevel(index) => {
+                write!(f, "invalid level in motion statement {index}")
+            }
+            ParseMotionError::InvalidDuration(index) => {
+                write!(f, "invalid duration in motion statement {index}")
+            }
+            ParseMotionError::InvalidEasing(index, error) => {
+                write!(f, "{error} in motion statement {index}")
+            }
+        }
+    }
+}
+
+impl core::error::Error for ParseMotionError {}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::{ParseMotionError, parse};
+    use crate::env::{Env, Shape};
+    use crate::{Easing, ParseEasingError};
+
+    #[test]
+    fn statements() {
+        let env =
+            parse("0 -> 1 in 300ms ease_out_back; hold 100ms; -> 0 in 0.2s ease_in_quad;").unwrap();
+        let expected = Env::new(0.0)
+            .segment(1.0, 0.3, Shape::Eased(Easing::OutBack))
+            .segment(1.0, 0.1, Shape::Hold)
+            .segment(0.0, 0.2, Shape::Eased(Easing::InQuad));
+        assert_eq!(env, expected);
+
+        // Line breaks, default easings and shapes of `EnvGen`.
+        let env = parse(
+            "\n  0.5->1 in 1s\n-> 2 in 2s ease_in_curve( -3 )\n\n-> 0 in 1s ease_in_out_sine",
+        )
+        .unwrap();
+        let expected = Env::new(0.5)
+            .segment(1.0, 1.0, Shape::Linear)
+            .segment(2.0, 2.0, Shape::Curve(-3.0))
+            .segment(0.0, 1.0, Shape::Sine);
+        assert_eq!(env, expected);
+
+        // A differing start level jumps.
+        let env = parse("hold 1s; 1 -> 2 in 1s; 2 -> 3 in 1s").unwrap();
+        let expected = Env::new(0.0)
+            .segment(0.0, 1.0, Shape::Hold)
+            .segment(1.0, 0.0, Shape::Step)
+            .segment(2.0, 1.0, Shape::Linear)
+            .segment(3.0, 1.0, Shape::Linear);
+        assert_eq!(env, expected);
+        assert_eq!(env.level_at(1.0), 1.0);
+        assert_eq!(env.level_at(1.5), 1.5);
+
+        assert_eq!(parse(" ; "), Ok(Env::new(0.0)));
+    }
+
+    #[test]
+    fn errors() {
+        assert_eq!(
+            parse("0 to 1 in 1s"),
+            Err(ParseMotionError::InvalidStatement(0))
+        );
+        assert_eq!(
+            parse("-> 1 over 1s"),
+            Err(ParseMotionError::InvalidStatement(0))
+        );
+        assert_eq!(parse("-> 1"), Err(ParseMotionError::InvalidStatement(0)));
+        assert_eq!(
+            parse("-> 1 in 1s; x -> 1 in 1s"),
+            Err(ParseMotionError::InvalidLevel(1))
+        );
+        assert_eq!(
+            parse("-> inf in 1s"),
+            Err(ParseMotionError::InvalidLevel(0))
+        );
+        assert_eq!(
+            parse("-> 1 in 1"),
+            Err(ParseMotionError::InvalidDuration(0))
+        );
+        assert_eq!(
+            parse("-> 1 in -1ms"),
+            Err(ParseMotionError::InvalidDuration(0))
+        );
+        assert_eq!(
+            parse("hold; -> 1 in 1s"),
+            Err(ParseMotionError::InvalidDuration(0))
+        );
+
+        let error = parse(";; -> 1 in 1s bouncy").unwrap_err();
+        assert_eq!(
+            error,
+            ParseMotionError::InvalidEasing(0, ParseEasingError::UnknownName("bouncy".into()))
+        );
+        assert_eq!(
+            error.to_string(),
+            "unknown easing `bouncy` in motion statement 0"
+        );
+    }
+}
@@ -0,0 +1,105 @@
+// Copyright (C) 2025 Tim Blechmann
+// SPDX-License-Identifier: MIT
+
+//! Compatibility aliases using the classic Robert Penner / jQuery easing names.
+//!
+//! These functions forward to the corresponding [`EasingArgument`] methods and are meant to keep
+//! diffs small when porting ActionScript or JavaScript tweening code. Unlike the original
+//! `(t, b, c, d)` signatures, they take a normalized argument: `b + c * easeOutQuad(t / d)`.
+
+#![allow(non_snake_case)]
+
+use crate::{EasingArgument, EasingImplHelper};
+
+macro_rules! penner_alias {
+    ($($penner:ident => $method:ident),* $(,)?) => {
+        $(
+            #[doc = concat!("Alias for [`EasingArgument::", stringify!($method), "`].")]
+            #[allow(private_bounds)]
+            #[inline]
+            pub fn $penner<T>(t: T) -> T
+            where
+                T: EasingImplHelper,
+            {
+                EasingArgument::$method(t)
+            }
+        )*
+    };
+}
+
+/// Linear easing, returning its argument unchanged.
+#[allow(private_bounds)]
+#[inline]
+pub fn linear<T>(t: T) -> T
+where
+    T: EasingImplHelper,
+{
+    t
+}
+
+/// jQuery's default `swing` easing, equivalent to [`EasingArgument::ease_in_out_sine`].
+#[allow(private_bounds)]
+#[inline]
+pub fn swing<T>(t: T) -> T
+where
+    T: EasingImplHelper,
+{
+    EasingArgument::ease_in_out_sine(t)
+}
+
+penner_alias!(
+    easeInQuad => ease_in_quad,
+    easeOutQuad => ease_out_quad,
+    easeInOutQuad => ease_in_out_quad,
+    easeInCubic => ease_in_cubic,
+    easeOutCubic => ease_out_cubic,
+    easeInOutCubic => ease_in_out_cubic,
+    easeInQuart => ease_in_quart,
+    easeOutQuart => ease_out_quart,
+    easeInOutQuart => ease_in_out_quart,
+    easeInQuint => ease_in_quint,
+    easeOutQuint => ease_out_quint,
+    easeInOutQuint => ease_in_out_quint,
+    easeInSine => ease_in_sine,
+    easeOutSine => ease_out_sine,
+    easeInOutSine => ease_in_out_sine,
+    easeInCirc => ease_in_circ,
+    easeOutCirc => ease_out_circ,
+    easeInOutCirc => ease_in_out_circ,
+    easeInBack => ease_in_back,
+    easeOutBack => ease_out_back,
+    easeInOutBack => ease_in_out_back,
+    easeInBounce => ease_in_bounce,
+    easeOutBounce => ease_out_bounce,
+    easeInOutBounce => ease_in_out_bounce,
+    easeInExpo => ease_in_expo,
+    easeOutExpo => ease_out_expo,
+    easeInOutExpo => ease_in_out_expo,
+    easeInElastic => ease_in_elastic,
+    easeOutElastic => ease_out_elastic,
+    easeInOutElastic => ease_in_out_elastic,
+);
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        easeInOutElastic, easeInOutExpo, easeInQuad, easeOutBounce, easeOutCubic, linear, swing,
+    };
+    use crate::EasingArgument;
+
+    #[test]
+    fn aliases_forward_to_trait_methods() {
+        let points = [0.0f32, 0.1, 0.25, 0.5, 0.75, 0.9, 1.0];
+        for &t in &points {
+            assert_eq!(linear(t), t);
+            assert_eq!(swing(t), t.ease_in_out_sine());
+            assert_eq!(easeInQuad(t), t.ease_in_quad());
+            assert_eq!(easeOutCubic(t), t.ease_out_cubic());
+            assert_eq!(easeInOutExpo(t), t.ease_in_out_expo());
+            assert_eq!(easeOutBounce(t as f64), (t as f64).ease_out_bounce());
+            assert_eq!(easeInOutElastic(t as f64), (t as f64).ease_in_out_elastic());
+        }
+    }
+}
@@ -0,0 +1,108 @@
+// Copyright (C) 2025 Tim Blechmann
+// SPDX-License-Identifier: MIT
+
+//! Export of sampled curves for external plotting tools, without depending on a plotting crate.
+
+use std::fmt::Write as _;
+use std::io;
+
+use crate::Easing;
+
+// `samples` points evenly spaced over [0, 1], including both endpoints.
+fn sample(easing: Easing, samples: usize) -> impl Iterator<Item = (f64, f64)> {
+    let denominator = samples.saturating_sub(1).max(1) as f64;
+    (0..samples).map(move |i| {
+        let t = i as f64 / denominator;
+        (t, easing.apply(t))
+    })
+}
+
+/// Returns a Plotly scatter trace of `easing`, sampled at `samples` points over [0, 1], as JSON.
+///
+/// The trace is named after the easing and can be put into the `data` array of a figure. JSON has
+/// no NaN or infinity, so non-finite values are written as `null`, which Plotly draws as gaps:
+///
+/// ```
+/// # use nova_easing::{Easing, plot_export::to_plotly_json};
+/// let trace = to_plotly_json(Easing::InQuad, 3);
+/// assert_eq!(
+///     trace,
+///     r#"{"type":"scatter","mode":"lines","name":"InQuad","x":[0,0.5,1],"y":[0,0.25,1]}"#
+/// );
+/// ```
+pub fn to_plotly_json(easing: Easing, samples: usize) -> String {
+    plotly_trace(&format!("{easing:?}"), sample(easing, samples))
+}
+
+fn plotly_trace(name: &str, points: impl Iterator<Item = (f64, f64)>) -> String {
+    let mut x = String::new();
+    let mut y = String::new();
+    for (i, (t, value)) in points.enumerate() {
+        let separator = if i == 0 { "" } else { "," };
+        write!(x, "{separator}{t}").unwrap();
+        if value.is_finite() {
+            write!(y, "{separator}{value}").unwrap();
+        } else {
+            write!(y, "{separator}null").unwrap();
+        }
+    }
+    format!(r#"{{"type":"scatter","mode":"lines","name":"{name}","x":[{x}],"y":[{y}]}}"#)
+}
+
+/// Writes `easings`, sampled at `samples` points over [0, 1], as a gnuplot data file.
+///
+/// Each easing becomes a data block of `t value` lines, headed by its quoted name and separated by
+/// two blank lines, so single curves can be selected with `index`:
+///
+/// ```gnuplot
+/// plot for [i=0:*] 'easings.dat' index i with lines title columnheader(1)
+/// ```
+pub fn write_gnuplot_data<W: io::Write>(
+    mut writer: W,
+    easings: &[Easing],
+    samples: usize,
+) -> io::Result<()> {
+    for (index, &easing) in easings.iter().enumerate() {
+        if index != 0 {
+            writeln!(writer, "\n")?;
+        }
+        writeln!(writer, "\"{easing:?}\"")?;
+        for (t, value) in sample(easing, samples) {
+            writeln!(writer, "{t} {value}")?;
+        }
+    }
+    Ok(())
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::{plotly_trace, to_plotly_json, write_gnuplot_data};
+    use crate::Easing;
+
+    #[test]
+    fn plotly() {
+        let trace = to_plotly_json(Easing::InCurve(2.0), 2);
+        assert_eq!(
+            trace,
+            r#"{"type":"scatter","mode":"lines","name":"InCurve(2.0)","x":[0,1],"y":[0,1]}"#
+        );
+
+        let points = [(0.0, f64::NAN), (0.5, 0.5), (1.0, f64::INFINITY)];
+        assert_eq!(
+            plotly_trace("broken", points.into_iter()),
+            r#"{"type":"scatter","mode":"lines","name":"broken","x":[0,0.5,1],"y":[null,0.5,null]}"#
+        );
+    }
+
+    #[test]
+    fn gnuplot() {
+        let mut data = Vec::new();
+        write_gnuplot_data(&mut data, &[Easing::Linear, Easing::InQuad], 3).unwrap();
+        assert_eq!(
+            String::from_utf8(data).unwrap(),
+            "\"Linear\"\n0 0\n0.5 0.5\n1 1\n\n\n\"InQuad\"\n0 0\n0.5 0.25\n1 1\n"
+        );
+    }
+}
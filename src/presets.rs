@@ -0,0 +1,211 @@
+// Copyright (C) 2025 Tim Blechmann
+// SPDX-License-Identifier: MIT
+
+//! Complete motion recipes for common UI patterns, as sensible defaults for app developers.
+//!
+//! Each [`Recipe`] pairs an enter and an exit [`Motion`]. Exits are shorter and accelerate away,
+//! enters decelerate into place, and only small, playful elements overshoot:
+//!
+//! ```
+//! # use nova_easing::presets::FAB;
+//! # use std::time::Duration;
+//! // Scale a floating action button into view.
+//! let mut scale = FAB.enter().tween(0.0f32, 1.0);
+//! scale.advance(Duration::from_millis(200));
+//! assert!(scale.value() > 1.0);
+//! assert_eq!(scale.advance(Duration::from_millis(100)), 1.0);
+//! ```
+
+use core::time::Duration;
+
+use crate::metrics::RESOLUTION;
+use crate::tween::Tween;
+use crate::{Easing, Interpolatable};
+
+/// Notifications sliding in from an edge, popping slightly past their resting position.
+pub const TOAST: Recipe = Recipe::new(
+    Motion::new(Duration::from_millis(250), Easing::OutBack),
+    Motion::new(Duration::from_millis(150), Easing::InCubic),
+);
+
+/// Dialogs fading and scaling in over the content, without overshoot.
+pub const MODAL: Recipe = Recipe::new(
+    Motion::new(Duration::from_millis(300), Easing::OutCubic),
+    Motion::new(Duration::from_millis(200), Easing::InCubic),
+);
+
+/// Navigation drawers and side sheets sliding in from an edge.
+pub const DRAWER: Recipe = Recipe::new(
+    Motion::new(Duration::from_millis(250), Easing::OutQuart),
+    Motion::new(Duration::from_millis(200), Easing::InQuart),
+);
+
+/// Floating action buttons scaling in with a pop, and anticipating before they disappear.
+pub const FAB: Recipe = Recipe::new(
+    Motion::new(Duration::from_millis(300), Easing::OutBack),
+    Motion::new(Duration::from_millis(150), Easing::InBack),
+);
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// A single transition: how long it takes and how it eases.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Motion {
+    duration: Duration,
+    easing: Easing,
+}
+
+impl Motion {
+    /// Creates a motion taking `duration` and following `easing`.
+    pub const fn new(duration: Duration, easing: Easing) -> Self {
+        Self { duration, easing }
+    }
+
+    /// The duration of the transition.
+    pub fn duration(&self) -> Duration {
+        self.duration
+    }
+
+    /// The easing of the transition.
+    pub fn easing(&self) -> Easing {
+        self.easing
+    }
+
+    /// Sets the duration, e.g. to scale all motion for accessibility settings.
+    pub fn with_duration(mut self, duration: Duration) -> Self {
+        self.duration = duration;
+        self
+    }
+
+    /// Sets the easing.
+    pub fn with_easing(mut self, easing: Easing) -> Self {
+        self.easing = easing;
+        self
+    }
+
+    /// The largest excursion of the eased progress beyond [0, 1], as a fraction of the distance,
+    /// e.g. about `0.1` for [`Easing::OutBack`] and `0.0` for easings without overshoot.
+    pub fn overshoot(&self) -> f32 {
+        (0..=RESOLUTION)
+            .map(|i| {
+                let eased = self.easing.apply(i as f32 / RESOLUTION as f32);
+                (eased - 1.0).max(-eased)
+            })
+            .fold(0.0, f32::max)
+    }
+
+    /// Returns the motion with overshooting easings replaced by the cubic easing of the same
+    /// direction, e.g. for users who prefer reduced motion.
+    pub fn without_overshoot(self) -> Self {
+        let easing = match self.easing {
+            Easing::InBack | Easing::InElastic => Easing::InCubic,
+            Easing::OutBack | Easing::OutElastic => Easing::OutCubic,
+            Easing::InOutBack | Easing::InOutElastic => Easing::InOutCubic,
+            easing => easing,
+        };
+        self.with_easing(easing)
+    }
+
+    /// Creates a tween from `from` to `to` following this motion.
+    pub fn tween<T: Interpolatable>(&self, from: T, to: T) -> Tween<T> {
+        Tween::new(from, to, self.duration, self.easing)
+    }
+}
+
+/// The enter and exit motion of a UI element.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Recipe {
+    enter: Motion,
+    exit: Motion,
+}
+
+impl Recipe {
+    /// Creates a recipe from its enter and exit motion.
+    pub const fn new(enter: Motion, exit: Motion) -> Self {
+        Self { enter, exit }
+    }
+
+    /// The motion bringing the element into view.
+    pub fn enter(&self) -> Motion {
+        self.enter
+    }
+
+    /// The motion removing the element from view.
+    pub fn exit(&self) -> Motion {
+        self.exit
+    }
+
+    /// Sets the enter motion.
+    pub fn with_enter(mut self, enter: Motion) -> Self {
+        self.enter = enter;
+        self
+    }
+
+    /// Sets the exit motion.
+    pub fn with_exit(mut self, exit: Motion) -> Self {
+        self.exit = exit;
+        self
+    }
+
+    /// Returns the recipe with the overshoot removed from both motions, see
+    /// [`Motion::without_overshoot`].
+    pub fn without_overshoot(self) -> Self {
+        Self::new(
+            self.enter.without_overshoot(),
+            self.exit.without_overshoot(),
+        )
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::{DRAWER, FAB, MODAL, Motion, Recipe, TOAST};
+    use crate::Easing;
+    use approx::assert_relative_eq;
+    use core::time::Duration;
+
+    const RECIPES: [Recipe; 4] = [TOAST, MODAL, DRAWER, FAB];
+
+    #[test]
+    fn exits_are_faster_than_enters() {
+        for recipe in RECIPES {
+            assert!(recipe.exit().duration() < recipe.enter().duration());
+            assert!(recipe.enter().duration() <= Duration::from_millis(300));
+        }
+    }
+
+    #[test]
+    fn overshoot() {
+        assert_relative_eq!(FAB.enter().overshoot(), 0.1, epsilon = 1e-3);
+        assert_relative_eq!(FAB.exit().overshoot(), 0.1, epsilon = 1e-3);
+        assert_eq!(MODAL.enter().overshoot(), 0.0);
+        assert_eq!(DRAWER.exit().overshoot(), 0.0);
+
+        for recipe in RECIPES.map(Recipe::without_overshoot) {
+            assert_eq!(recipe.enter().overshoot(), 0.0);
+            assert_eq!(recipe.exit().overshoot(), 0.0);
+        }
+        assert_eq!(TOAST.without_overshoot().enter().easing(), Easing::OutCubic);
+        assert_eq!(MODAL.without_overshoot(), MODAL);
+    }
+
+    #[test]
+    fn tweens() {
+        let mut offset = TOAST.exit().tween(0.0f32, 80.0);
+        assert_eq!(offset.duration(), Duration::from_millis(150));
+        assert_relative_eq!(offset.advance(Duration::from_millis(75)), 10.0);
+        assert_eq!(offset.advance(Duration::from_millis(75)), 80.0);
+
+        let slow = Motion::new(Duration::from_secs(1), Easing::Linear);
+        let recipe = DRAWER
+            .with_enter(slow)
+            .with_exit(slow.with_easing(Easing::InSine));
+        assert_eq!(recipe.enter(), slow);
+        assert_eq!(recipe.exit().easing(), Easing::InSine);
+        assert_eq!(recipe.exit().duration(), Duration::from_secs(1));
+    }
+}
@@ -0,0 +1,289 @@
+// Copyright (C) 2025 Tim Blechmann
+// SPDX-License-Identifier: MIT
+
+//! Scalar easings for `f128`, with all constants at full quadruple precision.
+//!
+//! `num_traits` does not cover `f128`, so this mirrors the generic scalar implementation instead of
+//! going through [`Scalar`](crate::Scalar).
+
+use core::f128::consts::{FRAC_PI_2, LN_2, PI};
+
+use crate::{EasingArgument, EasingImplHelper, internal};
+
+impl internal::CurveParam<f128> for f128 {
+    fn to_curve(self) -> f128 {
+        self
+    }
+}
+
+// 2π/3 and 4π/9, the angular frequencies of the elastic easings.
+const ELASTIC_C4: f128 = 2.0 * PI / 3.0;
+const ELASTIC_C5: f128 = 4.0 * PI / 9.0;
+
+const BACK_C1: f128 = 1.70158;
+const BACK_C2: f128 = BACK_C1 * 1.525;
+
+impl EasingImplHelper for f128 {
+    fn from_f32(arg: f32) -> Self {
+        arg as f128
+    }
+    fn curve_threshold() -> f32 {
+        1e-6
+    }
+    #[cfg(feature = "validate")]
+    fn lane(self, _index: usize) -> f64 {
+        self as f64
+    }
+    #[cfg(feature = "validate")]
+    fn max_curve_f64() -> f64 {
+        // Below `ln(f128::MAX)`, about 11356.
+        11000.0
+    }
+    fn sin(self) -> Self {
+        self.sin()
+    }
+    fn cos(self) -> Self {
+        self.cos()
+    }
+    fn powi(self, n: i32) -> Self {
+        self.powi(n)
+    }
+    fn powf(self, other: Self) -> Self {
+        self.powf(other)
+    }
+    fn sqrt(self) -> Self {
+        self.sqrt()
+    }
+    fn floor(self) -> Self {
+        self.floor()
+    }
+    fn exp(self) -> Self {
+        self.exp()
+    }
+    fn mul_add(self, a: Self, b: Self) -> Self {
+        self.mul_add(a, b)
+    }
+    fn select_lt(self, threshold: Self, if_less: Self, otherwise: Self) -> Self {
+        if self < threshold { if_less } else { otherwise }
+    }
+
+    fn frac_pi_2() -> Self {
+        FRAC_PI_2
+    }
+    fn pi() -> Self {
+        PI
+    }
+    fn back_c1() -> Self {
+        BACK_C1
+    }
+    fn back_c3() -> Self {
+        BACK_C1 + 1.0
+    }
+    fn ln_2() -> Self {
+        LN_2
+    }
+
+    fn ease_in_out_quad(self) -> Self {
+        if self < 0.5 {
+            2.0 * self.powi(2)
+        } else {
+            1.0 - (2.0 * self - 2.0).powi(2) * 0.5
+        }
+    }
+    fn ease_in_out_cubic(self) -> Self {
+        if self < 0.5 {
+            4.0 * self.powi(3)
+        } else {
+            1.0 - (2.0 - 2.0 * self).powi(3) * 0.5
+        }
+    }
+    fn ease_in_out_quart(self) -> Self {
+        if self < 0.5 {
+            8.0 * self.powi(4)
+        } else {
+            1.0 - (2.0 - 2.0 * self).powi(4) * 0.5
+        }
+    }
+    fn ease_in_out_quint(self) -> Self {
+        if self < 0.5 {
+            16.0 * self.powi(5)
+        } else {
+            1.0 - (2.0 - 2.0 * self).powi(5) * 0.5
+        }
+    }
+    fn ease_in_out_back(self) -> Self {
+        if self < 0.5 {
+            let two_x = 2.0 * self;
+            two_x.powi(2) * (BACK_C2 + 1.0).mul_add(two_x, -BACK_C2) * 0.5
+        } else {
+            let two_x_minus_2 = 2.0 * self - 2.0;
+            let inner = (BACK_C2 + 1.0).mul_add(two_x_minus_2, BACK_C2);
+            two_x_minus_2.powi(2).mul_add(inner, 2.0) * 0.5
+        }
+    }
+    fn ease_out_bounce(self) -> Self {
+        const N1: f128 = 7.5625;
+        const D1: f128 = 2.75;
+        if self < 1.0 / D1 {
+            N1 * self * self
+        } else if self < 2.0 / D1 {
+            let adjusted = self - 1.5 / D1;
+            (adjusted * adjusted).mul_add(N1, 0.75)
+        } else if self < 2.5 / D1 {
+            let adjusted = self - 2.25 / D1;
+            (adjusted * adjusted).mul_add(N1, 0.9375)
+        } else {
+            let adjusted = self - 2.625 / D1;
+            (adjusted * adjusted).mul_add(N1, 0.984375)
+        }
+    }
+    fn ease_in_out_bounce(self) -> Self {
+        if self < 0.5 {
+            (1.0 - EasingArgument::ease_out_bounce(1.0 - 2.0 * self)) * 0.5
+        } else {
+            (1.0 + EasingArgument::ease_out_bounce(2.0 * self - 1.0)) * 0.5
+        }
+    }
+    fn ease_in_expo(self) -> Self {
+        if self == 0.0 {
+            0.0
+        } else {
+            (2.0f128).powf(10.0f128.mul_add(self, -10.0))
+        }
+    }
+    fn ease_out_expo(self) -> Self {
+        if self == 1.0 {
+            1.0
+        } else {
+            1.0 - (2.0f128).powf(-10.0 * self)
+        }
+    }
+    fn ease_in_out_expo(self) -> Self {
+        if self == 0.0 {
+            0.0
+        } else if self == 1.0 {
+            1.0
+        } else if self < 0.5 {
+            (2.0f128).powf(20.0f128.mul_add(self, -10.0)) * 0.5
+        } else {
+            1.0 - (2.0f128).powf((-20.0f128).mul_add(self, 10.0)) * 0.5
+        }
+    }
+    fn ease_in_elastic(self) -> Self {
+        if self == 0.0 || self == 1.0 {
+            self
+        } else {
+            -(2.0f128).powf(10.0 * self - 10.0) * (self.mul_add(10.0, -10.75) * ELASTIC_C4).sin()
+        }
+    }
+    fn ease_out_elastic(self) -> Self {
+        if self == 0.0 || self == 1.0 {
+            self
+        } else {
+            (2.0f128)
+                .powf(-10.0 * self)
+                .mul_add((self.mul_add(10.0, -0.75) * ELASTIC_C4).sin(), 1.0)
+        }
+    }
+    fn ease_in_out_elastic(self) -> Self {
+        if self == 0.0 || self == 1.0 {
+            self
+        } else if self < 0.5 {
+            -(2.0f128).powf(20.0 * self - 10.0)
+                * (self.mul_add(20.0, -11.125) * ELASTIC_C5).sin()
+                * 0.5
+        } else {
+            (2.0f128)
+                .powf(-20.0 * self + 10.0)
+                .mul_add((self.mul_add(20.0, -11.125) * ELASTIC_C5).sin() * 0.5, 1.0)
+        }
+    }
+    fn ease_in_out_circ(self) -> Self {
+        let double = 2.0 * self;
+        if self < 0.5 {
+            (1.0 - (1.0 - double.powi(2)).sqrt()) * 0.5
+        } else {
+            ((1.0 - (2.0 - double).powi(2)).sqrt() + 1.0) * 0.5
+        }
+    }
+
+    fn ease_in_curve<C>(self, curve: C, threshold: Self) -> Self
+    where
+        C: internal::CurveParam<Self>,
+    {
+        let c = curve.to_curve();
+        if c.abs() < threshold {
+            self.curve_series(c)
+        } else {
+            let grow = c.exp();
+            let a = 1.0 / (1.0 - grow);
+            a - a * grow.powf(self)
+        }
+    }
+
+    fn ease_out_curve<C>(self, curve: C, threshold: Self) -> Self
+    where
+        C: internal::CurveParam<Self>,
+    {
+        1.0 - <Self as EasingImplHelper>::ease_in_curve(1.0 - self, curve, threshold)
+    }
+
+    fn ease_in_out_curve<C>(self, curve: C, threshold: Self) -> Self
+    where
+        C: internal::CurveParam<Self>,
+    {
+        if self < 0.5 {
+            <Self as EasingImplHelper>::ease_in_curve(2.0 * self, curve, threshold) * 0.5
+        } else {
+            let upper = 2.0 * (self - 0.5);
+            0.5 + <Self as EasingImplHelper>::ease_out_curve(upper, curve, threshold) * 0.5
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use crate::{Easing, EasingArgument};
+    use approx::assert_relative_eq;
+
+    // The `f128` path agrees with `f64` up to the precision of `f64`.
+    #[test]
+    fn matches_f64() {
+        let easings = [
+            Easing::InOutQuad,
+            Easing::InOutCubic,
+            Easing::InOutQuart,
+            Easing::InOutQuint,
+            Easing::InOutSine,
+            Easing::InOutCirc,
+            Easing::InBack,
+            Easing::InOutBack,
+            Easing::InOutBounce,
+            Easing::InExpo,
+            Easing::OutExpo,
+            Easing::InOutExpo,
+            Easing::InElastic,
+            Easing::OutElastic,
+            Easing::InOutElastic,
+            Easing::InOutCurve(3.0),
+            Easing::Smootherstep,
+        ];
+        for easing in easings {
+            for i in 0..=20 {
+                let t = i as f64 / 20.0;
+                let quad = easing.apply(t as f128) as f64;
+                assert_relative_eq!(quad, easing.apply(t), epsilon = 1e-6);
+            }
+        }
+    }
+
+    #[test]
+    fn full_precision_constants() {
+        // The `f32` value of π/2 would leave an error of about 4e-8 here.
+        assert_eq!(1.0f128.ease_in_sine(), 1.0);
+        assert!((0.5f128.ease_in_out_sine() - 0.5).abs() < 1e-30);
+        assert!((1.0f128.ease_out_back() - 1.0).abs() < 1e-30);
+    }
+}
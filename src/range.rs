@@ -0,0 +1,64 @@
+// Copyright (C) 2025 Tim Blechmann
+// SPDX-License-Identifier: MIT
+
+//! Output ranges of easings over [0, 1].
+
+use crate::Easing;
+
+// Extrema of the overshooting easings, found with 30-digit arithmetic on their formulas. The
+// mirrored easings reach `1 - minimum`.
+const IN_BACK_MIN: f64 = -0.100_004_070_842_895_95;
+const IN_OUT_BACK_MIN: f64 = -0.100_151_384_199_788_09;
+const IN_ELASTIC_MIN: f64 = -0.373_098_045_012_588_28;
+const IN_OUT_ELASTIC_MIN: f64 = -0.118_347_936_246_081_28;
+
+impl Easing {
+    /// The smallest and largest output for arguments in [0, 1].
+    ///
+    /// Most easings stay within [0, 1]. `Back` and `Elastic` over- and undershoot, e.g. `OutBack`
+    /// reaches `1.1000` and `OutElastic` `1.3731`, so layout and clamping code can reserve room
+    /// for them up front. A `Pow` exponent of 0 degenerates to a constant.
+    ///
+    /// ```
+    /// # use nova_easing::Easing;
+    /// assert_eq!(Easing::InOutCubic.range(), (0.0, 1.0));
+    /// let (_, max) = Easing::OutBack.range();
+    /// assert!((max - 1.1).abs() < 1e-4);
+    /// ```
+    pub fn range(self) -> (f64, f64) {
+        match self {
+            Easing::InBack => (IN_BACK_MIN, 1.0),
+            Easing::OutBack => (0.0, 1.0 - IN_BACK_MIN),
+            Easing::InOutBack => (IN_OUT_BACK_MIN, 1.0 - IN_OUT_BACK_MIN),
+            Easing::InElastic => (IN_ELASTIC_MIN, 1.0),
+            Easing::OutElastic => (0.0, 1.0 - IN_ELASTIC_MIN),
+            Easing::InOutElastic => (IN_OUT_ELASTIC_MIN, 1.0 - IN_OUT_ELASTIC_MIN),
+            Easing::InPow(0.0) => (1.0, 1.0),
+            Easing::OutPow(0.0) => (0.0, 0.0),
+            Easing::InOutPow(0.0) => (0.5, 0.5),
+            _ => (0.0, 1.0),
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use crate::easing::tests::EASINGS;
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn matches_sampled_extrema() {
+        for easing in EASINGS {
+            let (min, max) = (0..=100_000)
+                .map(|i| easing.apply(i as f64 / 100_000.0))
+                .fold((f64::INFINITY, f64::NEG_INFINITY), |(min, max), value| {
+                    (min.min(value), max.max(value))
+                });
+            let (expected_min, expected_max) = easing.range();
+            assert_relative_eq!(min, expected_min, epsilon = 1e-6);
+            assert_relative_eq!(max, expected_max, epsilon = 1e-6);
+        }
+    }
+}
@@ -0,0 +1,191 @@
+// Copyright (C) 2025 Tim Blechmann
+// SPDX-License-Identifier: MIT
+
+//! Point distributions shaped by easings, and adaptive sampling of curves.
+
+use alloc::vec::Vec;
+
+use crate::Easing;
+use crate::track::subdivide;
+
+/// Places points in [0, 1] so that their density follows an easing.
+///
+/// The easing is treated as a density function over [0, 1]; points are drawn through the inverse
+/// of its cumulative distribution. With [`Easing::InQuad`], points crowd towards 1; for a density
+/// falling towards 1, mirror the points (`1.0 - p`). Negative values of overshooting easings count
+/// as zero density.
+///
+/// Useful for staggered UI animations, particle emission ramps or non-uniform tick placement.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DensitySampler {
+    // Normalized cumulative distribution, sampled at `i / RESOLUTION`.
+    cdf: Vec<f32>,
+}
+
+impl DensitySampler {
+    const RESOLUTION: usize = 1024;
+
+    /// Creates a sampler for the density of `easing`.
+    ///
+    /// Easings with zero density everywhere fall back to a uniform distribution.
+    pub fn new(easing: Easing) -> Self {
+        let density = |i: usize| easing.apply(i as f32 / Self::RESOLUTION as f32).max(0.0);
+
+        let mut cdf = Vec::with_capacity(Self::RESOLUTION + 1);
+        let mut sum = 0.0f64;
+        cdf.push(0.0);
+        for i in 1..=Self::RESOLUTION {
+            sum += (density(i - 1) + density(i)) as f64 * 0.5;
+            cdf.push(sum as f32);
+        }
+
+        if sum > 0.0 {
+            cdf.iter_mut()
+                .for_each(|value| *value = (*value as f64 / sum) as f32);
+        } else {
+            cdf.iter_mut()
+                .enumerate()
+                .for_each(|(i, value)| *value = i as f32 / Self::RESOLUTION as f32);
+        }
+        Self { cdf }
+    }
+
+    /// Maps a uniform value `u` in [0, 1] to a point following the density.
+    pub fn sample(&self, u: f32) -> f32 {
+        let u = u.clamp(0.0, 1.0);
+        let index = self
+            .cdf
+            .partition_point(|&value| value < u)
+            .clamp(1, Self::RESOLUTION);
+        let (low, high) = (self.cdf[index - 1], self.cdf[index]);
+        let fraction = if high > low {
+            (u - low) / (high - low)
+        } else {
+            0.0
+        };
+        ((index - 1) as f32 + fraction) / Self::RESOLUTION as f32
+    }
+
+    /// Returns `count` points, in ascending order, at evenly spaced quantiles of the density.
+    pub fn points(&self, count: usize) -> Vec<f32> {
+        (0..count)
+            .map(|i| self.sample((i as f32 + 0.5) / count as f32))
+            .collect()
+    }
+}
+
+/// Returns `count` points in [0, 1] whose density follows `easing`, see [`DensitySampler`].
+pub fn density_points(easing: Easing, count: usize) -> Vec<f32> {
+    DensitySampler::new(easing).points(count)
+}
+
+/// Samples `curve` over [0, 1] as a polyline which stays within `max_error` of it, returning the
+/// `(t, curve(t))` vertices in ascending order of `t`.
+///
+/// Segments are halved until the chord is within `max_error` of the curve at seven evenly spaced
+/// probes, so samples crowd where the curve bends, at the knees of `Bounce` and in the oscillations
+/// of `Elastic`, while straight stretches need a single segment. This keeps exported polylines,
+/// e.g. for CSS `linear()` or SVG paths, compact. Jumps are resolved to a width of `2^-20`.
+///
+/// ```
+/// # use nova_easing::{Easing, sampler::adaptive_sample};
+/// assert_eq!(adaptive_sample(|t| t, 1e-3), [(0.0, 0.0), (1.0, 1.0)]);
+/// let bounce = adaptive_sample(|t| Easing::OutBounce.apply(t), 1e-3);
+/// let quad = adaptive_sample(|t| Easing::OutQuad.apply(t), 1e-3);
+/// assert!(bounce.len() > quad.len());
+/// ```
+///
+/// # Panics
+///
+/// Panics if `max_error` is not positive.
+pub fn adaptive_sample(curve: impl Fn(f32) -> f32, max_error: f32) -> Vec<(f32, f32)> {
+    // Resolves jumps to a width of `2^-20`.
+    const MAX_DEPTH: u32 = 20;
+    assert!(max_error > 0.0, "max_error must be positive");
+
+    let start = (0.0, curve(0.0));
+    let mut samples = Vec::from([start]);
+    subdivide(
+        &curve,
+        start,
+        (1.0, curve(1.0)),
+        max_error,
+        MAX_DEPTH,
+        &mut samples,
+    );
+    samples
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::{DensitySampler, adaptive_sample, density_points};
+    use crate::Easing;
+    use crate::easing::tests::EASINGS;
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn linear_density() {
+        // Density `t` has the CDF `t^2`, hence the inverse CDF `sqrt(u)`.
+        let sampler = DensitySampler::new(Easing::Linear);
+        for u in [0.0f32, 0.1, 0.25, 0.5, 0.9, 1.0] {
+            assert_relative_eq!(sampler.sample(u), u.sqrt(), epsilon = 1e-3);
+        }
+    }
+
+    #[test]
+    fn points_follow_density() {
+        let points = density_points(Easing::InCubic, 10);
+        assert_eq!(points.len(), 10);
+        assert!(points.windows(2).all(|pair| pair[0] < pair[1]));
+        assert!(points[0] > 0.3);
+
+        let gaps: Vec<f32> = points.windows(2).map(|pair| pair[1] - pair[0]).collect();
+        assert!(gaps.first() > gaps.last());
+
+        // The concave density of `OutQuad` is closer to uniform than the one of `InQuad`.
+        assert!(density_points(Easing::OutQuad, 10)[0] < density_points(Easing::InQuad, 10)[0]);
+    }
+
+    #[test]
+    fn degenerate_density() {
+        let sampler = DensitySampler::new(Easing::InCurve(-1000.0));
+        let points = sampler.points(4);
+        assert!(points.iter().all(|p| (0.0..=1.0).contains(p)));
+    }
+
+    #[test]
+    fn adaptive_samples_stay_within_error() {
+        for easing in EASINGS {
+            let curve = |t: f32| easing.apply(t);
+            let samples = adaptive_sample(curve, 1e-3);
+            assert_eq!(samples.first(), Some(&(0.0, curve(0.0))));
+            assert_eq!(samples.last(), Some(&(1.0, curve(1.0))));
+            for pair in samples.windows(2) {
+                let ((a, fa), (b, fb)) = (pair[0], pair[1]);
+                assert!(a < b);
+                // Between the probes, the error may slightly exceed the budget.
+                for i in 1..32 {
+                    let fraction = i as f32 / 32.0;
+                    let chord = fa + (fb - fa) * fraction;
+                    let error = (curve(a + (b - a) * fraction) - chord).abs();
+                    assert!(error < 1.1e-3, "{easing}: {error} in [{a}, {b}]");
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn adaptive_samples_follow_curvature() {
+        let count =
+            |easing: Easing, max_error| adaptive_sample(|t| easing.apply(t), max_error).len();
+        assert!(count(Easing::OutElastic, 1e-3) > 2 * count(Easing::OutCubic, 1e-3));
+        assert!(count(Easing::InOutSine, 1e-4) > count(Easing::InOutSine, 1e-2));
+
+        // A jump is resolved down to the minimum width.
+        let step = adaptive_sample(|t| if t < 0.3 { 0.0 } else { 1.0 }, 1e-3);
+        let jump = step.windows(2).find(|pair| pair[0].1 != pair[1].1).unwrap();
+        assert!(jump[1].0 - jump[0].0 <= 1.0 / (1 << 20) as f32);
+    }
+}
@@ -0,0 +1,374 @@
+// Copyright (C) 2025 Tim Blechmann
+// SPDX-License-Identifier: MIT
+
+//! Eased scales, mapping a data domain to an output range, and parameter ranges for knobs and
+//! sliders.
+
+#[allow(unused_imports)]
+use num_traits::Float;
+
+use crate::{Easing, RangePolicy};
+
+/// Maps a data domain to an output range through an [`Easing`], akin to d3 scales.
+///
+/// ```
+/// # use nova_easing::{Easing, scale::Scale};
+/// let scale = Scale::new((0.0, 100.0), (0.0, 1.0)).with_easing(Easing::InQuad);
+/// assert_eq!(scale.map(50.0), 0.25);
+/// assert!((scale.invert(0.25) - 50.0).abs() < 1e-9);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Scale {
+    domain: (f64, f64),
+    range: (f64, f64),
+    easing: Easing,
+    clamp: bool,
+}
+
+impl Scale {
+    /// Creates a linear, unclamped scale from `domain` to `range`.
+    ///
+    /// Both may be reversed, i.e. `domain.0 > domain.1`.
+    pub fn new(domain: (f64, f64), range: (f64, f64)) -> Self {
+        Self {
+            domain,
+            range,
+            easing: Easing::Linear,
+            clamp: false,
+        }
+    }
+
+    /// Sets the easing applied between domain and range.
+    pub fn with_easing(mut self, easing: Easing) -> Self {
+        self.easing = easing;
+        self
+    }
+
+    /// Enables or disables clamping of values outside of the domain (and, for
+    /// [`invert`](Self::invert), outside of the range).
+    pub fn clamped(mut self, clamp: bool) -> Self {
+        self.clamp = clamp;
+        self
+    }
+
+    /// The data domain.
+    pub fn domain(&self) -> (f64, f64) {
+        self.domain
+    }
+
+    /// The output range.
+    pub fn range(&self) -> (f64, f64) {
+        self.range
+    }
+
+    /// The easing.
+    pub fn easing(&self) -> Easing {
+        self.easing
+    }
+
+    /// Maps `value` from the domain to the range.
+    ///
+    /// Unclamped values outside of the domain evaluate the easing outside of [0, 1].
+    pub fn map(&self, value: f64) -> f64 {
+        let t = normalize(value, self.domain);
+        let t = if self.clamp { t.clamp(0.0, 1.0) } else { t };
+        denormalize(
+            self.easing.apply_with(t, RangePolicy::Unchecked),
+            self.range,
+        )
+    }
+
+    /// Maps `output` from the range back to the domain.
+    ///
+    /// The easing is inverted numerically, which is exact for monotonic easings. Other easings
+    /// (`Back`, `Elastic`, ...) yield one of the matching domain values. Except for linear scales,
+    /// outputs outside of the range are clamped.
+    pub fn invert(&self, output: f64) -> f64 {
+        let target = normalize(output, self.range);
+        let t = match self.easing {
+            Easing::Linear if !self.clamp => target,
+            Easing::Linear => target.clamp(0.0, 1.0),
+            easing => solve(easing, target.clamp(0.0, 1.0)),
+        };
+        denormalize(t, self.domain)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Maps `t` in [0, 1] exponentially to [`min`, `max`], so equal steps of `t` are equal ratios of
+/// the value, as for frequencies or times.
+///
+/// `min` and `max` must be non-zero and of the same sign, otherwise the result is NaN.
+///
+/// ```
+/// # use nova_easing::scale::{exp_map, exp_unmap};
+/// // 20 Hz to 20 kHz: the middle of a frequency knob is at 632 Hz.
+/// assert!((exp_map(0.5, 20.0, 20_000.0) - 632.456).abs() < 1e-3);
+/// assert!((exp_unmap(200.0, 20.0, 20_000.0) - 1.0 / 3.0).abs() < 1e-12);
+/// ```
+pub fn exp_map(t: f64, min: f64, max: f64) -> f64 {
+    min * (max / min).powf(t)
+}
+
+/// The inverse of [`exp_map`]: the position of `value` in [`min`, `max`], measured in ratios.
+///
+/// Returns 0 if `min == max`.
+pub fn exp_unmap(value: f64, min: f64, max: f64) -> f64 {
+    if min == max {
+        0.0
+    } else {
+        (value / min).ln() / (max / min).ln()
+    }
+}
+
+/// A parameter range with a skewed mapping from and to [0, 1], like JUCE's `NormalisableRange`.
+///
+/// A skew below 1 spends more of the normalized range on the start, a skew above 1 on the end.
+/// With a symmetric skew, the resolution is concentrated at (or away from) the middle of the
+/// range instead, e.g. for a pan or detune knob. Values snap to an optional interval.
+///
+/// ```
+/// # use nova_easing::scale::SkewedRange;
+/// // A cutoff knob with 1 kHz at its center.
+/// let cutoff = SkewedRange::new(20.0, 20_000.0).with_center(1000.0);
+/// assert!((cutoff.from_normalized(0.5) - 1000.0).abs() < 1e-9);
+/// assert!((cutoff.to_normalized(1000.0) - 0.5).abs() < 1e-12);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SkewedRange {
+    start: f64,
+    end: f64,
+    skew: f64,
+    symmetric: bool,
+    interval: f64,
+}
+
+impl SkewedRange {
+    /// Creates a linear range from `start` to `end`, without snapping.
+    pub fn new(start: f64, end: f64) -> Self {
+        Self {
+            start,
+            end,
+            skew: 1.0,
+            symmetric: false,
+            interval: 0.0,
+        }
+    }
+
+    /// Sets the skew, a positive exponent: the normalized position is `proportion^skew`.
+    pub fn with_skew(mut self, skew: f64) -> Self {
+        self.skew = skew;
+        self
+    }
+
+    /// Sets the skew such that `center` lies in the middle of the normalized range.
+    ///
+    /// `center` must lie strictly within the range. The skew is not symmetric.
+    pub fn with_center(mut self, center: f64) -> Self {
+        self.skew = 0.5f64.ln() / normalize(center, (self.start, self.end)).ln();
+        self.symmetric = false;
+        self
+    }
+
+    /// Applies the skew symmetrically around the middle of the range.
+    pub fn with_symmetric_skew(mut self, symmetric: bool) -> Self {
+        self.symmetric = symmetric;
+        self
+    }
+
+    /// Snaps values to multiples of `interval` from the start, or not at all for 0.
+    pub fn with_interval(mut self, interval: f64) -> Self {
+        self.interval = interval;
+        self
+    }
+
+    /// The start and end of the range.
+    pub fn range(&self) -> (f64, f64) {
+        (self.start, self.end)
+    }
+
+    /// The skew.
+    pub fn skew(&self) -> f64 {
+        self.skew
+    }
+
+    /// Whether the skew is symmetric.
+    pub fn symmetric(&self) -> bool {
+        self.symmetric
+    }
+
+    /// The snapping interval, or 0.
+    pub fn interval(&self) -> f64 {
+        self.interval
+    }
+
+    /// Maps `value` to its normalized position in [0, 1], clamping it to the range.
+    pub fn to_normalized(&self, value: f64) -> f64 {
+        let proportion = normalize(value, (self.start, self.end)).clamp(0.0, 1.0);
+        self.skewed(proportion, self.skew)
+    }
+
+    /// Maps the normalized position `t`, clamped to [0, 1], to a value of the range, snapped to
+    /// the interval.
+    pub fn from_normalized(&self, t: f64) -> f64 {
+        let proportion = self.skewed(t.clamp(0.0, 1.0), 1.0 / self.skew);
+        self.snap(denormalize(proportion, (self.start, self.end)))
+    }
+
+    /// Snaps `value` to the interval, within the range.
+    pub fn snap(&self, value: f64) -> f64 {
+        let value = if self.interval > 0.0 {
+            self.start + ((value - self.start) / self.interval).round() * self.interval
+        } else {
+            value
+        };
+        let (low, high) = if self.start <= self.end {
+            (self.start, self.end)
+        } else {
+            (self.end, self.start)
+        };
+        value.clamp(low, high)
+    }
+
+    fn skewed(&self, proportion: f64, exponent: f64) -> f64 {
+        if exponent == 1.0 {
+            proportion
+        } else if self.symmetric {
+            let distance = 2.0 * proportion - 1.0;
+            0.5 + 0.5 * distance.signum() * distance.abs().powf(exponent)
+        } else {
+            proportion.powf(exponent)
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+fn normalize(value: f64, (start, end): (f64, f64)) -> f64 {
+    if start == end {
+        0.0
+    } else {
+        (value - start) / (end - start)
+    }
+}
+
+fn denormalize(t: f64, (start, end): (f64, f64)) -> f64 {
+    start + (end - start) * t
+}
+
+// Bisection for `easing(t) == target`, relying on `easing(0) == 0` and `easing(1) == 1`.
+pub(crate) fn solve(easing: Easing, target: f64) -> f64 {
+    let (mut low, mut high) = (0.0f64, 1.0f64);
+    for _ in 0..64 {
+        let middle = (low + high) * 0.5;
+        if easing.apply(middle) < target {
+            low = middle;
+        } else {
+            high = middle;
+        }
+    }
+    (low + high) * 0.5
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::{Scale, SkewedRange, exp_map, exp_unmap};
+    use crate::Easing;
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn linear() {
+        let scale = Scale::new((10.0, 20.0), (100.0, 0.0));
+        assert_eq!(scale.map(15.0), 50.0);
+        assert_eq!(scale.map(30.0), -100.0);
+        assert_eq!(scale.invert(-100.0), 30.0);
+        assert_eq!(scale.clamped(true).map(30.0), 0.0);
+        assert_eq!(scale.clamped(true).invert(-100.0), 20.0);
+    }
+
+    #[test]
+    fn eased_round_trip() {
+        for easing in [Easing::InCubic, Easing::InOutSine] {
+            let scale = Scale::new((-1.0, 1.0), (0.0, 255.0)).with_easing(easing);
+            for value in [-1.0, -0.5, 0.0, 0.3, 0.9, 1.0] {
+                assert_relative_eq!(scale.invert(scale.map(value)), value, epsilon = 1e-6);
+            }
+        }
+
+        // Flat or non-monotonic easings only round-trip in the output space.
+        for easing in [Easing::OutExpo, Easing::OutBounce, Easing::OutBack] {
+            let scale = Scale::new((-1.0, 1.0), (0.0, 255.0)).with_easing(easing);
+            for output in [0.0, 10.0, 128.0, 200.0, 255.0] {
+                assert_relative_eq!(scale.map(scale.invert(output)), output, epsilon = 1e-6);
+            }
+        }
+    }
+
+    #[test]
+    fn clamping() {
+        let scale = Scale::new((0.0, 1.0), (0.0, 10.0)).with_easing(Easing::InQuad);
+        assert_eq!(scale.map(2.0), 40.0);
+        assert_eq!(scale.clamped(true).map(2.0), 10.0);
+        assert_relative_eq!(scale.invert(20.0), 1.0, epsilon = 1e-9);
+    }
+
+    #[test]
+    fn exponential() {
+        assert_eq!(exp_map(0.0, 20.0, 20_000.0), 20.0);
+        assert_relative_eq!(exp_map(1.0, 20.0, 20_000.0), 20_000.0, epsilon = 1e-9);
+        assert_relative_eq!(exp_map(1.0 / 3.0, 20.0, 20_000.0), 200.0, epsilon = 1e-9);
+        assert_relative_eq!(exp_map(0.5, -1.0, -100.0), -10.0, epsilon = 1e-12);
+        for t in [0.0, 0.1, 0.5, 0.77, 1.0] {
+            assert_relative_eq!(
+                exp_unmap(exp_map(t, 0.01, 10.0), 0.01, 10.0),
+                t,
+                epsilon = 1e-12
+            );
+        }
+        assert_eq!(exp_unmap(5.0, 5.0, 5.0), 0.0);
+        assert!(exp_map(0.5, -1.0, 1.0).is_nan());
+    }
+
+    #[test]
+    fn skewed_ranges() {
+        let linear = SkewedRange::new(-10.0, 10.0).with_interval(0.5);
+        assert_eq!(linear.from_normalized(0.28), -4.5);
+        assert_eq!(linear.to_normalized(5.0), 0.75);
+        assert_eq!(linear.to_normalized(50.0), 1.0);
+
+        let attack = SkewedRange::new(0.0, 8.0).with_skew(1.0 / 3.0);
+        assert_eq!(attack.to_normalized(1.0), 0.5);
+        assert_relative_eq!(attack.from_normalized(0.5), 1.0, epsilon = 1e-12);
+
+        let detune = SkewedRange::new(-100.0, 100.0)
+            .with_skew(0.5)
+            .with_symmetric_skew(true);
+        assert_eq!(detune.to_normalized(0.0), 0.5);
+        assert_relative_eq!(detune.to_normalized(25.0), 0.75);
+        assert_relative_eq!(detune.to_normalized(-25.0), 0.25);
+        for t in [0.0, 0.1, 0.4, 0.5, 0.9, 1.0] {
+            assert_relative_eq!(
+                detune.to_normalized(detune.from_normalized(t)),
+                t,
+                epsilon = 1e-12
+            );
+        }
+
+        let cutoff = SkewedRange::new(20.0, 20_000.0)
+            .with_center(1000.0)
+            .with_interval(1.0);
+        assert_eq!(cutoff.from_normalized(0.5), 1000.0);
+        assert_eq!(cutoff.from_normalized(1.0), 20_000.0);
+        assert!(!cutoff.symmetric());
+
+        let reversed = SkewedRange::new(1.0, 0.0).with_interval(0.3);
+        assert_relative_eq!(reversed.snap(0.15), 0.1);
+        assert_eq!(reversed.snap(-0.2), 0.0);
+        assert_eq!(reversed.snap(2.0), 1.0);
+    }
+}
@@ -0,0 +1,159 @@
+// Copyright (C) 2025 Tim Blechmann
+// SPDX-License-Identifier: MIT
+
+//! Frame-rate independent smoothing towards moving targets.
+
+use crate::custom::EasingValue;
+
+/// Moves `current` towards `target` by exponential decay, for a time step of `dt`.
+///
+/// The remaining distance shrinks by the factor `exp(-rate * dt)`, so the result only depends on
+/// the total elapsed time, not on how it is split into frames. This is the frame-rate independent
+/// form of the common `current += (target - current) * factor` per frame, which converges faster at
+/// higher frame rates. See [`rate_from_half_life`] for a more intuitive parameter.
+///
+/// Works for `f32`, `f64` and, with the `nightly` feature, SIMD vectors.
+///
+/// ```
+/// # use nova_easing::smoothing::ease_towards;
+/// let one_step = ease_towards(0.0f64, 10.0, 3.0, 0.1);
+/// let two_steps = ease_towards(ease_towards(0.0f64, 10.0, 3.0, 0.05), 10.0, 3.0, 0.05);
+/// assert!((one_step - two_steps).abs() < 1e-12);
+/// ```
+#[inline]
+pub fn ease_towards<T: EasingValue>(current: T, target: T, rate: T, dt: T) -> T {
+    // Interpolating from the target lands on it exactly once the decay underflows.
+    (current - target).mul_add((-(rate * dt)).exp(), target)
+}
+
+/// Returns the rate for [`ease_towards`] which halves the distance to the target every
+/// `half_life`.
+pub fn rate_from_half_life(half_life: f32) -> f32 {
+    core::f32::consts::LN_2 / half_life
+}
+
+/// Moves `current` towards `target` like a critically damped spring, for a time step of `dt`.
+///
+/// This is the `SmoothDamp` of Unity, for cameras and UI elements following a moving target: the
+/// motion starts and stops smoothly, and `velocity` carries the speed from one call to the next, so
+/// a target which changes mid-flight does not cause a jump in speed. Initialize it to 0, or to the
+/// speed of a preceding motion. `smooth_time` is roughly the time to reach the target; it must be
+/// positive.
+///
+/// Unity approximates the decay with a polynomial. Here the spring is solved exactly, so the result
+/// only depends on the total elapsed time, not on how it is split into frames.
+///
+/// ```
+/// # use nova_easing::smoothing::smooth_damp;
+/// let (mut position, mut velocity) = (0.0f32, 0.0);
+/// for _ in 0..60 {
+///     position = smooth_damp(position, 10.0, &mut velocity, 0.2, 1.0 / 60.0);
+/// }
+/// assert!((position - 10.0).abs() < 0.01);
+/// ```
+#[inline]
+pub fn smooth_damp<T: EasingValue>(
+    current: T,
+    target: T,
+    velocity: &mut T,
+    smooth_time: T,
+    dt: T,
+) -> T {
+    // Relative to the target, the spring with the natural frequency `omega` follows
+    // `x(t) = (x0 + b t) exp(-omega t)` with `b = v0 + omega x0`.
+    let omega = T::splat(2.0) / smooth_time;
+    let decay = (-(omega * dt)).exp();
+    let change = current - target;
+    let drift = (*velocity + omega * change) * dt;
+    *velocity = (*velocity - omega * drift) * decay;
+    (change + drift).mul_add(decay, target)
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::{ease_towards, rate_from_half_life, smooth_damp};
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn frame_rate_independent() {
+        let (target, rate) = (5.0f32, 4.0);
+        let mut at_30 = -1.0;
+        for _ in 0..30 {
+            at_30 = ease_towards(at_30, target, rate, 1.0 / 30.0);
+        }
+        let mut at_144 = -1.0;
+        for _ in 0..144 {
+            at_144 = ease_towards(at_144, target, rate, 1.0 / 144.0);
+        }
+        assert_relative_eq!(at_30, at_144, epsilon = 1e-5);
+        assert_relative_eq!(at_30, ease_towards(-1.0, target, rate, 1.0), epsilon = 1e-5);
+    }
+
+    #[test]
+    fn limits() {
+        assert_eq!(ease_towards(2.0f64, 7.0, 3.0, 0.0), 2.0);
+        assert_eq!(ease_towards(2.0f64, 7.0, 3.0, 1e6), 7.0);
+
+        let rate = rate_from_half_life(0.5);
+        assert_relative_eq!(ease_towards(0.0f32, 1.0, rate, 0.5), 0.5, epsilon = 1e-6);
+    }
+
+    #[test]
+    fn smooth_damp_is_frame_rate_independent() {
+        let run = |steps: usize| {
+            let (mut position, mut velocity) = (-2.0f64, 3.0);
+            for _ in 0..steps {
+                position = smooth_damp(position, 4.0, &mut velocity, 0.3, 0.5 / steps as f64);
+            }
+            (position, velocity)
+        };
+        let (position, velocity) = run(1);
+        for steps in [7, 30, 144] {
+            let (p, v) = run(steps);
+            assert_relative_eq!(p, position, epsilon = 1e-12);
+            assert_relative_eq!(v, velocity, epsilon = 1e-12);
+        }
+
+        // The closed form of the critically damped spring.
+        let (omega, t) = (2.0f64 / 0.3, 0.5);
+        let b = 3.0 + omega * -6.0;
+        assert_relative_eq!(
+            position,
+            4.0 + (-6.0 + b * t) * (-omega * t).exp(),
+            epsilon = 1e-12
+        );
+    }
+
+    #[test]
+    fn smooth_damp_from_rest() {
+        // Starting at rest, the spring approaches without overshooting.
+        let (mut position, mut velocity) = (0.0f32, 0.0);
+        let mut previous = position;
+        for _ in 0..120 {
+            position = smooth_damp(position, 1.0, &mut velocity, 0.2, 1.0 / 60.0);
+            assert!(position >= previous && position <= 1.0);
+            assert!(velocity >= 0.0);
+            previous = position;
+        }
+        assert_relative_eq!(position, 1.0, epsilon = 1e-4);
+        assert_eq!(smooth_damp(1.0f32, 1.0, &mut 0.0, 0.2, 0.1), 1.0);
+    }
+
+    #[cfg(feature = "nightly")]
+    #[test]
+    fn simd() {
+        use core::simd::f32x4;
+
+        let current = f32x4::from_array([0.0, 1.0, -3.0, 10.0]);
+        let target = f32x4::from_array([1.0, 1.0, 3.0, 0.0]);
+        let eased = ease_towards(current, target, f32x4::splat(2.0), f32x4::splat(0.1));
+        for lane in 0..4 {
+            assert_eq!(
+                eased[lane],
+                ease_towards(current[lane], target[lane], 2.0, 0.1)
+            );
+        }
+    }
+}
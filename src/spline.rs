@@ -0,0 +1,266 @@
+// Copyright (C) 2025 Tim Blechmann
+// SPDX-License-Identifier: MIT
+
+//! Custom easings from points drawn in a curve editor.
+//!
+//! A [`SplineEasing`] interpolates the points with a monotone cubic spline (Steffen's method): it
+//! is smooth, passes through every point and never overshoots between them, so points which rise
+//! from (0, 0) to (1, 1) give a monotonic easing.
+//!
+//! ```
+//! # use nova_easing::Easing;
+//! let easing = Easing::from_points(&[(0.3, 0.1), (0.7, 0.9)]).unwrap();
+//! assert_eq!(easing.apply(0.3), 0.1);
+//! assert!(easing.apply(0.5) > 0.1 && easing.apply(0.5) < 0.9);
+//! assert_eq!(easing.apply(1.0), 1.0);
+//! ```
+
+use alloc::vec::Vec;
+use core::fmt;
+
+use crate::Easing;
+
+/// A point of a [`SplineEasing`], with the slope of the curve through it.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+struct Knot {
+    x: f32,
+    y: f32,
+    slope: f32,
+}
+
+/// An easing through user-drawn points, pinned at (0, 0) and (1, 1). See the
+/// [module documentation](self).
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SplineEasing {
+    knots: Vec<Knot>,
+}
+
+impl SplineEasing {
+    /// Creates the easing through `points`, given as `(t, value)` in any order.
+    ///
+    /// Points at `t = 0` or `t = 1` are skipped, since the curve is pinned at (0, 0) and (1, 1)
+    /// there.
+    pub fn new(points: &[(f32, f32)]) -> Result<Self, FromPointsError> {
+        let mut knots = Vec::with_capacity(points.len() + 2);
+        knots.push(Knot::new(0.0, 0.0));
+        for &(x, y) in points {
+            if !(x.is_finite() && y.is_finite()) {
+                return Err(FromPointsError::NotFinite);
+            }
+            if !(0.0..=1.0).contains(&x) {
+                return Err(FromPointsError::OutOfRange(x));
+            }
+            if x > 0.0 && x < 1.0 {
+                knots.push(Knot::new(x, y));
+            }
+        }
+        knots.push(Knot::new(1.0, 1.0));
+
+        knots.sort_by(|a, b| a.x.total_cmp(&b.x));
+        if let Some(pair) = knots.windows(2).find(|pair| pair[0].x == pair[1].x) {
+            return Err(FromPointsError::DuplicatePosition(pair[0].x));
+        }
+        compute_slopes(&mut knots);
+        Ok(Self { knots })
+    }
+
+    /// The points of the curve, including (0, 0) and (1, 1), sorted by `t`.
+    pub fn points(&self) -> impl Iterator<Item = (f32, f32)> + '_ {
+        self.knots.iter().map(|knot| (knot.x, knot.y))
+    }
+
+    /// Evaluates the easing at `t`, which is clamped to [0, 1].
+    pub fn apply(&self, t: f32) -> f32 {
+        let t = t.clamp(0.0, 1.0);
+        let index = self.knots.partition_point(|knot| knot.x <= t);
+        let (Some(start), Some(end)) = (
+            self.knots.get(index.saturating_sub(1)),
+            self.knots.get(index),
+        ) else {
+            // At `t = 1`, or past the knots of malformed deserialized data.
+            return self.knots.last().map_or(t, |knot| knot.y);
+        };
+
+        let width = end.x - start.x;
+        let u = (t - start.x) / width;
+        let (u2, u3) = (u * u, u * u * u);
+        let h00 = 2.0 * u3 - 3.0 * u2 + 1.0;
+        let h10 = u3 - 2.0 * u2 + u;
+        let h01 = 3.0 * u2 - 2.0 * u3;
+        let h11 = u3 - u2;
+        h00 * start.y + h10 * width * start.slope + h01 * end.y + h11 * width * end.slope
+    }
+}
+
+impl Knot {
+    fn new(x: f32, y: f32) -> Self {
+        Self { x, y, slope: 0.0 }
+    }
+}
+
+// Steffen, "A simple method for monotonic interpolation in one dimension" (1990): slopes which keep
+// every segment within the values of its ends.
+fn compute_slopes(knots: &mut [Knot]) {
+    let secant = |a: &Knot, b: &Knot| (b.y - a.y) / (b.x - a.x);
+    let last = knots.len() - 1;
+    if last == 1 {
+        let slope = secant(&knots[0], &knots[1]);
+        knots.iter_mut().for_each(|knot| knot.slope = slope);
+        return;
+    }
+
+    for i in 1..last {
+        let (h0, h1) = (knots[i].x - knots[i - 1].x, knots[i + 1].x - knots[i].x);
+        let (s0, s1) = (
+            secant(&knots[i - 1], &knots[i]),
+            secant(&knots[i], &knots[i + 1]),
+        );
+        let p = (s0 * h1 + s1 * h0) / (h0 + h1);
+        knots[i].slope = if s0 * s1 <= 0.0 {
+            0.0
+        } else {
+            s0.signum() * s0.abs().min(s1.abs()).min(0.5 * p.abs()) * 2.0
+        };
+    }
+
+    // One-sided parabolas at the ends, limited like the interior slopes.
+    let end_slope = |h0: f32, h1: f32, s0: f32, s1: f32| {
+        let p = s0 * (1.0 + h0 / (h0 + h1)) - s1 * h0 / (h0 + h1);
+        if p * s0 <= 0.0 {
+            0.0
+        } else if p.abs() > 2.0 * s0.abs() {
+            2.0 * s0
+        } else {
+            p
+        }
+    };
+    let (h0, h1) = (knots[1].x - knots[0].x, knots[2].x - knots[1].x);
+    let (s0, s1) = (secant(&knots[0], &knots[1]), secant(&knots[1], &knots[2]));
+    knots[0].slope = end_slope(h0, h1, s0, s1);
+
+    let (h0, h1) = (
+        knots[last].x - knots[last - 1].x,
+        knots[last - 1].x - knots[last - 2].x,
+    );
+    let (s0, s1) = (
+        secant(&knots[last - 1], &knots[last]),
+        secant(&knots[last - 2], &knots[last - 1]),
+    );
+    knots[last].slope = end_slope(h0, h1, s0, s1);
+}
+
+impl Easing {
+    /// Creates a custom easing through user-drawn points, see [`SplineEasing::new`].
+    pub fn from_points(points: &[(f32, f32)]) -> Result<SplineEasing, FromPointsError> {
+        SplineEasing::new(points)
+    }
+}
+
+/// The error of creating a [`SplineEasing`] from points.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum FromPointsError {
+    /// A coordinate is infinite or NaN.
+    NotFinite,
+    /// The point at the given `t` lies outside of [0, 1].
+    OutOfRange(f32),
+    /// Several points share the given `t`.
+    DuplicatePosition(f32),
+}
+
+impl fmt::Display for FromPointsError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            FromPointsError::NotFinite => f.write_str("point coordinates must be finite"),
+            FromPointsError::OutOfRange(x) => write!(f, "point at t = {x} is outside of [0, 1]"),
+            FromPointsError::DuplicatePosition(x) => write!(f, "several points at t = {x}"),
+        }
+    }
+}
+
+impl core::error::Error for FromPointsError {}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::{FromPointsError, SplineEasing};
+    use crate::Easing;
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn passes_through_points() {
+        let points = [(0.8, 0.95), (0.2, 0.3), (0.5, 0.5)];
+        let easing = Easing::from_points(&points).unwrap();
+        for (x, y) in points {
+            assert_relative_eq!(easing.apply(x), y, epsilon = 1e-6);
+        }
+        assert_eq!(easing.apply(0.0), 0.0);
+        assert_eq!(easing.apply(1.0), 1.0);
+        assert_eq!(easing.apply(-1.0), 0.0);
+        assert_eq!(easing.apply(2.0), 1.0);
+
+        let sorted: Vec<_> = easing.points().collect();
+        assert_eq!(sorted.len(), 5);
+        assert_eq!(sorted[1], (0.2, 0.3));
+    }
+
+    #[test]
+    fn monotonic_and_smooth() {
+        // A steep rise followed by a plateau, which overshoots with natural splines.
+        let easing = SplineEasing::new(&[(0.1, 0.7), (0.2, 0.9), (0.6, 0.92)]).unwrap();
+        let mut previous = 0.0;
+        for i in 1..=1000 {
+            let value = easing.apply(i as f32 / 1000.0);
+            assert!(value >= previous - 1e-6, "not monotonic at {i}");
+            assert!(value <= 1.0 + 1e-6);
+            previous = value;
+        }
+
+        // Continuous slopes at the knots.
+        let slope = |t: f32| (easing.apply(t + 1e-3) - easing.apply(t - 1e-3)) / 2e-3;
+        for x in [0.1, 0.2, 0.6] {
+            let left = (easing.apply(x) - easing.apply(x - 1e-3)) / 1e-3;
+            let right = (easing.apply(x + 1e-3) - easing.apply(x)) / 1e-3;
+            assert_relative_eq!(left, right, epsilon = 0.05 * slope(x).abs().max(1.0));
+        }
+    }
+
+    #[test]
+    fn linear_without_points() {
+        let easing = Easing::from_points(&[(0.0, 0.0), (1.0, 1.0)]).unwrap();
+        let collinear = Easing::from_points(&[(0.25, 0.25), (0.5, 0.5)]).unwrap();
+        for i in 0..=10 {
+            let t = i as f32 / 10.0;
+            assert_relative_eq!(easing.apply(t), t, epsilon = 1e-6);
+            assert_relative_eq!(collinear.apply(t), t, epsilon = 1e-6);
+        }
+    }
+
+    #[test]
+    fn errors() {
+        assert_eq!(
+            Easing::from_points(&[(0.5, f32::NAN)]),
+            Err(FromPointsError::NotFinite)
+        );
+        assert_eq!(
+            Easing::from_points(&[(1.5, 0.5)]),
+            Err(FromPointsError::OutOfRange(1.5))
+        );
+        assert_eq!(
+            Easing::from_points(&[(0.5, 0.2), (0.5, 0.6)]),
+            Err(FromPointsError::DuplicatePosition(0.5))
+        );
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde_round_trip() {
+        let easing = Easing::from_points(&[(0.4, 0.1)]).unwrap();
+        let json = serde_json::to_string(&easing).unwrap();
+        let restored: SplineEasing = serde_json::from_str(&json).unwrap();
+        assert_eq!(restored, easing);
+        assert_eq!(restored.apply(0.4), 0.1);
+    }
+}
@@ -0,0 +1,557 @@
+// Copyright (C) 2025 Tim Blechmann
+// SPDX-License-Identifier: MIT
+
+//! Spring easings: a damped harmonic oscillator released towards its target.
+//!
+//! A [`Spring`] is parameterized physically, by mass, stiffness and damping, like the springs of
+//! iOS, React Spring or Framer Motion. It moves from 0 to 1, starting at rest or with an initial
+//! velocity, and is evaluated in closed form for all three damping regimes. It can be sampled in
+//! the time domain and, via [`Curve`], as a normalized easing over its settle duration:
+//!
+//! ```
+//! # use nova_easing::{curve::Curve, spring::{Regime, Spring}};
+//! let spring = Spring::new(1.0, 100.0, 10.0);
+//! assert_eq!(spring.regime(), Regime::Underdamped);
+//! assert!(spring.position(0.3) > 1.0);
+//! assert!((spring.settle_duration() - 1.4103).abs() < 1e-4);
+//!
+//! // The same motion as an easing, ending on the target.
+//! let halfway = spring.position(spring.settle_duration() / 2.0);
+//! assert!((spring.eval(0.5f64) - halfway).abs() < 1e-6);
+//! assert_eq!(spring.eval(1.0f32), 1.0);
+//! ```
+
+use crate::curve::Curve;
+use crate::custom::EasingValue;
+
+/// A damped spring moving from 0 to 1. See the [module documentation](self).
+///
+/// With the `serde` feature, only the coefficients, the initial velocity and the tolerance are
+/// serialized; the motion is derived again when deserializing.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(into = "Parameters", try_from = "Parameters")
+)]
+pub struct Spring {
+    mass: f64,
+    stiffness: f64,
+    damping: f64,
+    velocity: f64,
+    tolerance: f64,
+    motion: Motion<f64>,
+    settle_duration: f64,
+}
+
+/// The damping regime of a [`Spring`], from its [damping ratio](Spring::damping_ratio).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Regime {
+    /// A damping ratio below 1: the spring oscillates around the target with a decaying amplitude.
+    Underdamped,
+    /// A damping ratio of 1: the fastest approach without oscillating.
+    CriticallyDamped,
+    /// A damping ratio above 1: the spring creeps towards the target without oscillating.
+    Overdamped,
+}
+
+impl Spring {
+    // Damping ratios this close to 1 are treated as critical, where the closed forms of the other
+    // regimes cancel catastrophically.
+    const CRITICAL_BAND: f64 = 1e-4;
+
+    /// Creates a spring with the given `mass`, `stiffness` and `damping` coefficients, starting at
+    /// rest. It settles once it stays within `1e-3` of the target.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a coefficient is not positive.
+    pub fn new(mass: f64, stiffness: f64, damping: f64) -> Self {
+        assert!(
+            mass > 0.0 && stiffness > 0.0 && damping > 0.0,
+            "spring coefficients must be positive"
+        );
+        Self::build(mass, stiffness, damping, 0.0, 1e-3)
+    }
+
+    /// Starts the spring with an initial `velocity`, in distances per second, e.g. carried over
+    /// from a gesture: a fling of 400 px/s towards a target 200 px away is a velocity of 2.
+    pub fn with_velocity(self, velocity: f64) -> Self {
+        Self::build(
+            self.mass,
+            self.stiffness,
+            self.damping,
+            velocity,
+            self.tolerance,
+        )
+    }
+
+    /// Sets the distance from the target within which the spring counts as settled, as a fraction
+    /// of the distance.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `tolerance` is not positive.
+    pub fn with_tolerance(self, tolerance: f64) -> Self {
+        assert!(tolerance > 0.0, "tolerance must be positive");
+        Self::build(
+            self.mass,
+            self.stiffness,
+            self.damping,
+            self.velocity,
+            tolerance,
+        )
+    }
+
+    fn build(mass: f64, stiffness: f64, damping: f64, velocity: f64, tolerance: f64) -> Self {
+        let omega = (stiffness / mass).sqrt();
+        let ratio = damping / (2.0 * (stiffness * mass).sqrt());
+        // The displacement from the target starts at -1.
+        let a = -1.0;
+        let motion = if (ratio - 1.0).abs() < Self::CRITICAL_BAND {
+            Motion::Critical {
+                decay: omega,
+                a,
+                b: velocity + omega * a,
+            }
+        } else if ratio < 1.0 {
+            let decay = ratio * omega;
+            let frequency = omega * (1.0 - ratio * ratio).sqrt();
+            Motion::Underdamped {
+                decay,
+                frequency,
+                a,
+                b: (velocity + decay * a) / frequency,
+            }
+        } else {
+            let root = omega * (ratio * ratio - 1.0).sqrt();
+            let (slow, fast) = (-ratio * omega + root, -ratio * omega - root);
+            let b = (velocity - slow * a) / (fast - slow);
+            Motion::Overdamped {
+                slow,
+                fast,
+                a: a - b,
+                b,
+            }
+        };
+        Self {
+            mass,
+            stiffness,
+            damping,
+            velocity,
+            tolerance,
+            motion,
+            settle_duration: motion.settle_time(tolerance),
+        }
+    }
+
+    /// The mass.
+    pub fn mass(&self) -> f64 {
+        self.mass
+    }
+
+    /// The stiffness, i.e. the force per unit of displacement.
+    pub fn stiffness(&self) -> f64 {
+        self.stiffness
+    }
+
+    /// The damping coefficient, i.e. the force per unit of velocity.
+    pub fn damping(&self) -> f64 {
+        self.damping
+    }
+
+    /// The initial velocity, in distances per second.
+    pub fn initial_velocity(&self) -> f64 {
+        self.velocity
+    }
+
+    /// The undamped angular frequency `sqrt(stiffness / mass)`, in radians per second.
+    pub fn natural_frequency(&self) -> f64 {
+        (self.stiffness / self.mass).sqrt()
+    }
+
+    /// The damping ratio `damping / (2 sqrt(stiffness mass))`, which selects the [`Regime`].
+    pub fn damping_ratio(&self) -> f64 {
+        self.damping / (2.0 * (self.stiffness * self.mass).sqrt())
+    }
+
+    /// The damping regime.
+    pub fn regime(&self) -> Regime {
+        match self.motion {
+            Motion::Underdamped { .. } => Regime::Underdamped,
+            Motion::Critical { .. } => Regime::CriticallyDamped,
+            Motion::Overdamped { .. } => Regime::Overdamped,
+        }
+    }
+
+    /// The time after which the spring stays within the tolerance of the target, in seconds.
+    ///
+    /// It is derived from the envelope of the motion, which bounds the peaks of an underdamped
+    /// spring, so the last peak may already be within the tolerance slightly earlier.
+    pub fn settle_duration(&self) -> f64 {
+        self.settle_duration
+    }
+
+    /// The position at `time` in seconds, from 0 towards 1. Clamped to 0 before the release.
+    pub fn position(&self, time: f64) -> f64 {
+        1.0 + self.motion.displacement(time.max(0.0))
+    }
+
+    /// The velocity at `time` in seconds, in distances per second.
+    pub fn velocity(&self, time: f64) -> f64 {
+        if time < 0.0 {
+            return 0.0;
+        }
+        match self.motion {
+            Motion::Underdamped {
+                decay,
+                frequency,
+                a,
+                b,
+            } => {
+                let (sin, cos) = ((frequency * time).sin(), (frequency * time).cos());
+                let envelope = (-decay * time).exp();
+                envelope * ((b * frequency - a * decay) * cos - (a * frequency + b * decay) * sin)
+            }
+            Motion::Critical { decay, a, b } => {
+                (-decay * time).exp() * (b - decay * (a + b * time))
+            }
+            Motion::Overdamped { slow, fast, a, b } => {
+                a * slow * (slow * time).exp() + b * fast * (fast * time).exp()
+            }
+        }
+    }
+}
+
+/// The normalized spring: the position over the normalized time [0, 1], which spans the
+/// [settle duration](Spring::settle_duration). The remaining distance is skipped at 1.
+impl Curve for Spring {
+    #[inline]
+    fn eval<T: EasingValue>(&self, t: T) -> T {
+        let zero = T::splat(0.0);
+        let one = T::splat(1.0);
+        let motion = self
+            .motion
+            .scaled(self.settle_duration)
+            .map(|value| T::splat(value as f32));
+        let position = one + motion.displacement(t.select_lt(zero, zero, t));
+        t.select_lt(one, position, one)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+// The serialized form of a `Spring`.
+#[cfg(feature = "serde")]
+#[derive(serde::Serialize, serde::Deserialize)]
+struct Parameters {
+    mass: f64,
+    stiffness: f64,
+    damping: f64,
+    velocity: f64,
+    tolerance: f64,
+}
+
+#[cfg(feature = "serde")]
+impl From<Spring> for Parameters {
+    fn from(spring: Spring) -> Self {
+        Self {
+            mass: spring.mass,
+            stiffness: spring.stiffness,
+            damping: spring.damping,
+            velocity: spring.velocity,
+            tolerance: spring.tolerance,
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl TryFrom<Parameters> for Spring {
+    type Error = &'static str;
+
+    fn try_from(parameters: Parameters) -> Result<Self, Self::Error> {
+        let Parameters {
+            mass,
+            stiffness,
+            damping,
+            velocity,
+            tolerance,
+        } = parameters;
+        match (
+            mass > 0.0 && stiffness > 0.0 && damping > 0.0,
+            tolerance > 0.0,
+        ) {
+            (false, _) => Err("spring coefficients must be positive"),
+            (_, false) => Err("tolerance must be positive"),
+            _ => Ok(Self::build(mass, stiffness, damping, velocity, tolerance)),
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+// The displacement from the target in closed form, with the rates in units of 1 / time.
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Motion<T> {
+    // `e^(-decay t) (a cos(frequency t) + b sin(frequency t))`
+    Underdamped { decay: T, frequency: T, a: T, b: T },
+    // `e^(-decay t) (a + b t)`
+    Critical { decay: T, a: T, b: T },
+    // `a e^(slow t) + b e^(fast t)`, with both rates negative
+    Overdamped { slow: T, fast: T, a: T, b: T },
+}
+
+impl<T: EasingValue> Motion<T> {
+    #[inline]
+    fn displacement(&self, t: T) -> T {
+        match *self {
+            Motion::Underdamped {
+                decay,
+                frequency,
+                a,
+                b,
+            } => {
+                let phase = frequency * t;
+                (-(decay * t)).exp() * a.mul_add(phase.cos(), b * phase.sin())
+            }
+            Motion::Critical { decay, a, b } => (-(decay * t)).exp() * b.mul_add(t, a),
+            Motion::Overdamped { slow, fast, a, b } => {
+                a.mul_add((slow * t).exp(), b * (fast * t).exp())
+            }
+        }
+    }
+}
+
+impl Motion<f64> {
+    // The motion over the time unit `duration`.
+    fn scaled(self, duration: f64) -> Self {
+        match self {
+            Motion::Underdamped {
+                decay,
+                frequency,
+                a,
+                b,
+            } => {
+                Motion::Underdamped {
+                    decay: decay * duration,
+                    frequency: frequency * duration,
+                    a,
+                    b,
+                }
+            }
+            Motion::Critical { decay, a, b } => {
+                Motion::Critical {
+                    decay: decay * duration,
+                    a,
+                    b: b * duration,
+                }
+            }
+            Motion::Overdamped { slow, fast, a, b } => {
+                Motion::Overdamped {
+                    slow: slow * duration,
+                    fast: fast * duration,
+                    a,
+                    b,
+                }
+            }
+        }
+    }
+
+    fn map<U>(self, f: impl Fn(f64) -> U) -> Motion<U> {
+        match self {
+            Motion::Underdamped {
+                decay,
+                frequency,
+                a,
+                b,
+            } => {
+                Motion::Underdamped {
+                    decay: f(decay),
+                    frequency: f(frequency),
+                    a: f(a),
+                    b: f(b),
+                }
+            }
+            Motion::Critical { decay, a, b } => {
+                Motion::Critical {
+                    decay: f(decay),
+                    a: f(a),
+                    b: f(b),
+                }
+            }
+            Motion::Overdamped { slow, fast, a, b } => {
+                Motion::Overdamped {
+                    slow: f(slow),
+                    fast: f(fast),
+                    a: f(a),
+                    b: f(b),
+                }
+            }
+        }
+    }
+
+    // An upper bound of `|displacement(t)|`, which decreases after `envelope_peak`.
+    fn envelope(&self, t: f64) -> f64 {
+        match *self {
+            Motion::Underdamped { decay, a, b, .. } => (-decay * t).exp() * (a * a + b * b).sqrt(),
+            Motion::Critical { decay, a, b } => (-decay * t).exp() * (a.abs() + b.abs() * t),
+            Motion::Overdamped { slow, fast, a, b } => {
+                a.abs() * (slow * t).exp() + b.abs() * (fast * t).exp()
+            }
+        }
+    }
+
+    fn envelope_peak(&self) -> f64 {
+        match *self {
+            // `(|a| + |b| t) e^(-decay t)` peaks at `1 / decay - |a| / |b|`.
+            Motion::Critical { decay, a, b } if b != 0.0 => {
+                (1.0 / decay - a.abs() / b.abs()).max(0.0)
+            }
+            _ => 0.0,
+        }
+    }
+
+    // The time from which the envelope stays below `tolerance`, by doubling and bisection.
+    fn settle_time(&self, tolerance: f64) -> f64 {
+        let start = self.envelope_peak();
+        if self.envelope(start) <= tolerance {
+            return start;
+        }
+        let (mut low, mut high) = (start, start + 1e-3);
+        while self.envelope(high) > tolerance {
+            (low, high) = (high, start + 2.0 * (high - start));
+        }
+        for _ in 0..64 {
+            let middle = 0.5 * (low + high);
+            if self.envelope(middle) > tolerance {
+                low = middle;
+            } else {
+                high = middle;
+            }
+        }
+        high
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::{Regime, Spring};
+    use crate::curve::Curve;
+    use approx::assert_relative_eq;
+
+    // Integrates `m x'' + c x' + k x = 0` by RK4 from (-1, v0), returning the position at `time`.
+    fn simulate(spring: &Spring, time: f64) -> f64 {
+        let (m, k, c) = (spring.mass(), spring.stiffness(), spring.damping());
+        let acceleration = |x: f64, v: f64| -(k * x + c * v) / m;
+        let steps = 20_000;
+        let h = time / steps as f64;
+        let (mut x, mut v) = (-1.0, spring.initial_velocity());
+        for _ in 0..steps {
+            let (k1x, k1v) = (v, acceleration(x, v));
+            let (k2x, k2v) = (
+                v + 0.5 * h * k1v,
+                acceleration(x + 0.5 * h * k1x, v + 0.5 * h * k1v),
+            );
+            let (k3x, k3v) = (
+                v + 0.5 * h * k2v,
+                acceleration(x + 0.5 * h * k2x, v + 0.5 * h * k2v),
+            );
+            let (k4x, k4v) = (v + h * k3v, acceleration(x + h * k3x, v + h * k3v));
+            x += h / 6.0 * (k1x + 2.0 * k2x + 2.0 * k3x + k4x);
+            v += h / 6.0 * (k1v + 2.0 * k2v + 2.0 * k3v + k4v);
+        }
+        1.0 + x
+    }
+
+    const SPRINGS: [(f64, f64, f64, f64, Regime); 6] = [
+        (1.0, 100.0, 10.0, 0.0, Regime::Underdamped),
+        (1.0, 170.0, 26.0, 0.0, Regime::Underdamped),
+        (2.0, 50.0, 20.0, 0.0, Regime::CriticallyDamped),
+        (1.0, 100.0, 20.0, -3.0, Regime::CriticallyDamped),
+        (1.0, 100.0, 40.0, 0.0, Regime::Overdamped),
+        (0.5, 80.0, 30.0, 6.0, Regime::Overdamped),
+    ];
+
+    #[test]
+    fn matches_simulation() {
+        for (mass, stiffness, damping, velocity, regime) in SPRINGS {
+            let spring = Spring::new(mass, stiffness, damping).with_velocity(velocity);
+            assert_eq!(spring.regime(), regime);
+            assert_relative_eq!(spring.position(0.0), 0.0, epsilon = 1e-15);
+            assert_relative_eq!(spring.velocity(0.0), velocity, epsilon = 1e-12);
+            for time in [0.05, 0.1, 0.3, 0.7, 1.5] {
+                assert_relative_eq!(
+                    spring.position(time),
+                    simulate(&spring, time),
+                    epsilon = 1e-9
+                );
+                let h = 1e-6;
+                let numeric = (spring.position(time + h) - spring.position(time - h)) / (2.0 * h);
+                assert_relative_eq!(spring.velocity(time), numeric, epsilon = 1e-6);
+            }
+        }
+        assert_relative_eq!(Spring::new(1.0, 100.0, 10.0).damping_ratio(), 0.5);
+        assert_relative_eq!(Spring::new(4.0, 100.0, 10.0).natural_frequency(), 5.0);
+    }
+
+    #[test]
+    fn settles() {
+        for (mass, stiffness, damping, velocity, regime) in SPRINGS {
+            for tolerance in [1e-2, 1e-3, 1e-5] {
+                let spring = Spring::new(mass, stiffness, damping)
+                    .with_velocity(velocity)
+                    .with_tolerance(tolerance);
+                let duration = spring.settle_duration();
+                for i in 0..=1000 {
+                    let time = duration * (1.0 + i as f64 / 100.0);
+                    // Critically damped springs from rest meet their envelope exactly.
+                    assert!((spring.position(time) - 1.0).abs() <= tolerance * (1.0 + 1e-9));
+                }
+                // The envelopes without oscillation are tight.
+                if regime != Regime::Underdamped {
+                    let before = spring.position(duration * 0.99);
+                    assert!((before - 1.0).abs() > tolerance * 0.9);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn normalized_curve() {
+        for (mass, stiffness, damping, velocity, _) in SPRINGS {
+            let spring = Spring::new(mass, stiffness, damping).with_velocity(velocity);
+            let duration = spring.settle_duration();
+            for i in 0..20 {
+                let u = i as f64 / 20.0;
+                let expected = spring.position(u * duration);
+                assert_relative_eq!(spring.eval(u), expected, epsilon = 1e-6);
+                assert_relative_eq!(spring.eval(u as f32), expected as f32, epsilon = 1e-5);
+            }
+            assert_eq!(spring.eval(1.0f64), 1.0);
+            assert_eq!(spring.eval(-0.5f32), 0.0);
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "spring coefficients must be positive")]
+    fn undamped() {
+        Spring::new(1.0, 100.0, 0.0);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde_round_trip() {
+        let spring = Spring::new(1.0, 170.0, 26.0).with_velocity(1.5);
+        let json = serde_json::to_string(&spring).unwrap();
+        assert_eq!(
+            json,
+            r#"{"mass":1.0,"stiffness":170.0,"damping":26.0,"velocity":1.5,"tolerance":0.001}"#
+        );
+        assert_eq!(serde_json::from_str::<Spring>(&json).unwrap(), spring);
+        let json =
+            r#"{"mass":1.0,"stiffness":170.0,"damping":0.0,"velocity":0.0,"tolerance":0.001}"#;
+        assert!(serde_json::from_str::<Spring>(json).is_err());
+    }
+}
@@ -0,0 +1,155 @@
+// Copyright (C) 2025 Tim Blechmann
+// SPDX-License-Identifier: MIT
+
+//! Staggered progress for groups of items, e.g. list or grid entrance animations.
+
+use crate::Easing;
+
+/// Splits one overall progress into staggered, eased progress values for `count` items.
+///
+/// Each item animates within its own window of the overall progress. The windows have equal
+/// length and start one after the other; `overlap` controls how much consecutive windows
+/// overlap, from `0.0` (strictly sequential) to `1.0` (all items at once).
+///
+/// ```
+/// # use nova_easing::{Easing, stagger::Stagger};
+/// let stagger = Stagger::new(3, Easing::Linear).with_overlap(0.5);
+/// assert_eq!(stagger.window(1), (0.25, 0.75));
+///
+/// let progress: Vec<f32> = stagger.progress(0.5).collect();
+/// assert_eq!(progress, [1.0, 0.5, 0.0]);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Stagger {
+    count: usize,
+    easing: Easing,
+    overlap: f32,
+    offset_easing: Easing,
+}
+
+impl Stagger {
+    /// Creates a sequential stagger of `count` items, each following `easing`.
+    pub fn new(count: usize, easing: Easing) -> Self {
+        Self {
+            count,
+            easing,
+            overlap: 0.0,
+            offset_easing: Easing::Linear,
+        }
+    }
+
+    /// Sets the overlap of consecutive windows, clamped to [0, 1].
+    pub fn with_overlap(mut self, overlap: f32) -> Self {
+        self.overlap = overlap.clamp(0.0, 1.0);
+        self
+    }
+
+    /// Eases the distribution of the window starts, e.g. [`Easing::OutQuad`] starts the first
+    /// items in quick succession and spreads out the later ones.
+    pub fn with_offset_easing(mut self, easing: Easing) -> Self {
+        self.offset_easing = easing;
+        self
+    }
+
+    /// The number of items.
+    pub fn count(&self) -> usize {
+        self.count
+    }
+
+    /// The window `(start, end)` of item `index` within the overall progress.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is out of bounds.
+    pub fn window(&self, index: usize) -> (f32, f32) {
+        assert!(index < self.count, "item index out of bounds");
+        let length = self.window_length();
+        let offset = if self.count > 1 {
+            index as f32 / (self.count - 1) as f32
+        } else {
+            0.0
+        };
+        let start = self.offset_easing.apply(offset) * (1.0 - length);
+        (start, start + length)
+    }
+
+    /// The eased progress of item `index` at the overall progress `t`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is out of bounds.
+    pub fn item_progress(&self, index: usize, t: f32) -> f32 {
+        let (start, end) = self.window(index);
+        let local = if end > start {
+            ((t - start) / (end - start)).clamp(0.0, 1.0)
+        } else if t < start {
+            0.0
+        } else {
+            1.0
+        };
+        self.easing.apply(local)
+    }
+
+    /// The eased progress of all items at the overall progress `t`, in item order.
+    pub fn progress(&self, t: f32) -> impl ExactSizeIterator<Item = f32> + '_ {
+        (0..self.count).map(move |index| self.item_progress(index, t))
+    }
+
+    // n windows of length `l`, each starting `l * (1 - overlap)` after the previous one, span
+    // exactly [0, 1].
+    fn window_length(&self) -> f32 {
+        let n = self.count.max(1) as f32;
+        1.0 / (n - (n - 1.0) * self.overlap)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::Stagger;
+    use crate::{Easing, EasingArgument};
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn sequential_windows() {
+        let stagger = Stagger::new(4, Easing::Linear);
+        assert_eq!(stagger.window(0), (0.0, 0.25));
+        assert_eq!(stagger.window(3), (0.75, 1.0));
+        let progress: Vec<f32> = stagger.progress(0.375).collect();
+        assert_eq!(progress, [1.0, 0.5, 0.0, 0.0]);
+    }
+
+    #[test]
+    fn overlap_and_easing() {
+        let stagger = Stagger::new(5, Easing::InQuad).with_overlap(1.0);
+        assert!(stagger.progress(0.3).all(|p| p == 0.3f32.ease_in_quad()));
+
+        let stagger = Stagger::new(5, Easing::OutCubic).with_overlap(0.6);
+        let (first, last) = (stagger.window(0), stagger.window(4));
+        assert_eq!(first.0, 0.0);
+        assert_relative_eq!(last.1, 1.0);
+        assert_relative_eq!(first.1 - first.0, last.1 - last.0);
+
+        // The overall start and end are reached by every item.
+        assert!(stagger.progress(0.0).all(|p| p == 0.0));
+        assert!(stagger.progress(1.0).all(|p| p == 1.0));
+    }
+
+    #[test]
+    fn offset_easing() {
+        let stagger = Stagger::new(5, Easing::Linear).with_offset_easing(Easing::OutQuad);
+        let starts: Vec<f32> = (0..5).map(|i| stagger.window(i).0).collect();
+        assert!(starts[1] - starts[0] > starts[4] - starts[3]);
+        assert_relative_eq!(stagger.window(4).1, 1.0);
+    }
+
+    #[test]
+    fn degenerate_counts() {
+        assert_eq!(Stagger::new(0, Easing::Linear).progress(0.5).len(), 0);
+        let single = Stagger::new(1, Easing::Linear);
+        assert_eq!(single.window(0), (0.0, 1.0));
+        assert_eq!(single.item_progress(0, 0.25), 0.25);
+    }
+}
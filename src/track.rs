@@ -0,0 +1,260 @@
+// Copyright (C) 2025 Tim Blechmann
+// SPDX-License-Identifier: MIT
+
+//! Keyframe tracks, and baking of curves into them.
+//!
+//! Animation formats like glTF or Lottie only store keyframes. [`bake`] samples an arbitrary curve
+//! and emits a small set of linearly interpolated keyframes reproducing it within a tolerance.
+
+use alloc::{vec, vec::Vec};
+
+use crate::tween::Tween;
+
+/// A single key of a [`Track`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Keyframe {
+    /// Time of the key, in seconds.
+    pub time: f32,
+    /// Value at [`time`](Self::time).
+    pub value: f32,
+}
+
+/// A sequence of keyframes, linearly interpolated.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Track {
+    keyframes: Vec<Keyframe>,
+}
+
+impl Track {
+    /// Creates a track from keyframes.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the keyframes are not sorted by time.
+    pub fn new(keyframes: Vec<Keyframe>) -> Self {
+        assert!(
+            keyframes
+                .windows(2)
+                .all(|pair| pair[0].time <= pair[1].time),
+            "keyframes must be sorted by time"
+        );
+        Self { keyframes }
+    }
+
+    /// The keyframes of the track.
+    pub fn keyframes(&self) -> &[Keyframe] {
+        &self.keyframes
+    }
+
+    /// The time of the last keyframe.
+    pub fn duration(&self) -> f32 {
+        self.keyframes.last().map_or(0.0, |key| key.time)
+    }
+
+    /// Samples the track at `time`, holding the first and last values outside of the keys.
+    ///
+    /// Returns `0.0` for an empty track.
+    pub fn sample(&self, time: f32) -> f32 {
+        let index = self.keyframes.partition_point(|key| key.time <= time);
+        match (
+            index.checked_sub(1).map(|i| self.keyframes[i]),
+            self.keyframes.get(index).copied(),
+        ) {
+            (Some(a), Some(b)) => {
+                a.value + (b.value - a.value) * ((time - a.time) / (b.time - a.time))
+            }
+            (Some(key), None) | (None, Some(key)) => key.value,
+            (None, None) => 0.0,
+        }
+    }
+}
+
+impl From<Track> for Vec<Keyframe> {
+    fn from(track: Track) -> Self {
+        track.keyframes
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+// Bounds the subdivision of `bake` for curves with discontinuities, which never meet the tolerance.
+const MAX_DEPTH: u32 = 16;
+
+// Points checked between two candidate keys.
+const PROBES: usize = 8;
+
+fn within_tolerance(
+    curve: &impl Fn(f32) -> f32,
+    a: (f32, f32),
+    b: (f32, f32),
+    tolerance: f32,
+) -> bool {
+    (1..PROBES).all(|i| {
+        let fraction = i as f32 / PROBES as f32;
+        let t = a.0 + (b.0 - a.0) * fraction;
+        let linear = a.1 + (b.1 - a.1) * fraction;
+        (curve(t) - linear).abs() <= tolerance
+    })
+}
+
+// Halves the segment from `a` to `b` until the chord is within `tolerance` of `curve`, at most
+// `depth` times, and pushes the vertices after `a` to `points`. Shared by `bake` and
+// `sampler::adaptive_sample`.
+pub(crate) fn subdivide(
+    curve: &impl Fn(f32) -> f32,
+    a: (f32, f32),
+    b: (f32, f32),
+    tolerance: f32,
+    depth: u32,
+    points: &mut Vec<(f32, f32)>,
+) {
+    if depth > 0 && !within_tolerance(curve, a, b, tolerance) {
+        let t = (a.0 + b.0) * 0.5;
+        let middle = (t, curve(t));
+        subdivide(curve, a, middle, tolerance, depth - 1, points);
+        subdivide(curve, middle, b, tolerance, depth - 1, points);
+    } else {
+        points.push(b);
+    }
+}
+
+/// Bakes `curve`, defined over [0, 1], into a keyframe track spanning `duration` seconds.
+///
+/// The curve is subdivided until linear interpolation stays within `tolerance` of it, then
+/// redundant keys are dropped, so the track holds few keys where the curve is nearly straight and
+/// more around features like bounce knees.
+pub fn bake(curve: impl Fn(f32) -> f32, duration: f32, tolerance: f32) -> Track {
+    let mut points = vec![(0.0, curve(0.0))];
+    subdivide(
+        &curve,
+        points[0],
+        (1.0, curve(1.0)),
+        tolerance,
+        MAX_DEPTH,
+        &mut points,
+    );
+
+    // Greedily extend each key as far as the curve allows.
+    let mut keys = vec![points[0]];
+    let mut anchor = 0;
+    while anchor + 1 < points.len() {
+        let mut end = anchor + 1;
+        while end + 1 < points.len() {
+            let candidate = end + 1;
+            let fits = points[anchor + 1..candidate].iter().all(|&(t, value)| {
+                let a = points[anchor];
+                let b = points[candidate];
+                let linear = a.1 + (b.1 - a.1) * ((t - a.0) / (b.0 - a.0));
+                (value - linear).abs() <= tolerance
+            }) && within_tolerance(&curve, points[anchor], points[candidate], tolerance);
+            if !fits {
+                break;
+            }
+            end = candidate;
+        }
+        keys.push(points[end]);
+        anchor = end;
+    }
+
+    Track::new(
+        keys.into_iter()
+            .map(|(t, value)| {
+                Keyframe {
+                    time: t * duration,
+                    value,
+                }
+            })
+            .collect(),
+    )
+}
+
+impl Tween<f32> {
+    /// Bakes the tween into a keyframe track, see [`bake`].
+    pub fn bake(&self, tolerance: f32) -> Track {
+        let duration = self.duration();
+        bake(
+            |t| self.value_at(duration.mul_f32(t)),
+            duration.as_secs_f32(),
+            tolerance,
+        )
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::{Keyframe, Track, bake};
+    use crate::tween::Tween;
+    use crate::{Easing, EasingArgument};
+    use core::time::Duration;
+
+    fn max_error(track: &Track, curve: impl Fn(f32) -> f32, duration: f32) -> f32 {
+        (0..=1000)
+            .map(|i| {
+                let t = i as f32 / 1000.0;
+                (track.sample(t * duration) - curve(t)).abs()
+            })
+            .fold(0.0, f32::max)
+    }
+
+    #[test]
+    fn linear_curve_needs_two_keys() {
+        let track = bake(|t| t, 2.0, 1e-4);
+        assert_eq!(
+            track.keyframes(),
+            &[
+                Keyframe {
+                    time: 0.0,
+                    value: 0.0
+                },
+                Keyframe {
+                    time: 2.0,
+                    value: 1.0
+                }
+            ]
+        );
+    }
+
+    #[test]
+    fn baked_curves_stay_within_tolerance() {
+        for easing in [Easing::InOutSine, Easing::OutBounce, Easing::OutElastic] {
+            let curve = |t: f32| easing.apply(t);
+            let track = bake(curve, 1.0, 1e-3);
+            assert!(max_error(&track, curve, 1.0) <= 1.5e-3, "{easing:?}");
+            assert!(track.keyframes().len() < 100, "{easing:?}");
+        }
+
+        let coarse = bake(EasingArgument::ease_in_out_sine, 1.0, 1e-2);
+        let fine = bake(EasingArgument::ease_in_out_sine, 1.0, 1e-4);
+        assert!(coarse.keyframes().len() < fine.keyframes().len());
+    }
+
+    #[test]
+    fn bake_tween() {
+        let tween = Tween::new(10.0f32, 20.0, Duration::from_millis(500), Easing::OutQuad);
+        let track = tween.bake(1e-3);
+        assert_eq!(track.duration(), 0.5);
+        assert_eq!(track.sample(0.0), 10.0);
+        assert_eq!(track.sample(0.5), 20.0);
+        assert!((track.sample(0.25) - tween.value_at(Duration::from_millis(250))).abs() <= 1e-3);
+    }
+
+    #[test]
+    fn sample_holds_ends() {
+        let track = Track::new(vec![
+            Keyframe {
+                time: 1.0,
+                value: 2.0,
+            },
+            Keyframe {
+                time: 2.0,
+                value: 4.0,
+            },
+        ]);
+        assert_eq!(track.sample(0.0), 2.0);
+        assert_eq!(track.sample(1.5), 3.0);
+        assert_eq!(track.sample(3.0), 4.0);
+        assert_eq!(Track::default().sample(1.0), 0.0);
+    }
+}
@@ -0,0 +1,150 @@
+// Copyright (C) 2025 Tim Blechmann
+// SPDX-License-Identifier: MIT
+
+//! Time-based animation of values between two endpoints.
+
+use core::time::Duration;
+
+use crate::{Easing, Interpolatable};
+
+#[cfg(feature = "alloc")]
+mod animated;
+mod counter;
+mod fixed_step;
+mod lens;
+mod looping;
+#[cfg(feature = "alloc")]
+mod markers;
+mod time_scale;
+
+#[cfg(feature = "alloc")]
+pub use animated::{Animated, Subscription};
+pub use counter::Counter;
+pub use fixed_step::{FixedStep, Steps};
+pub use lens::{FnLens, Lens};
+pub use looping::{Looping, PhaseUniform};
+#[cfg(feature = "alloc")]
+pub use markers::{Crossed, Markers};
+pub use time_scale::{set_time_scale, time_scale};
+
+#[cfg(feature = "async")]
+mod stream;
+
+#[cfg(feature = "async")]
+pub use stream::{Finished, TweenStream};
+
+#[cfg(feature = "tokio")]
+pub mod runner;
+
+/// Animates a value from `from` to `to` over `duration`, following an [`Easing`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Tween<T> {
+    from: T,
+    to: T,
+    duration: Duration,
+    easing: Easing,
+    elapsed: Duration,
+}
+
+impl<T: Interpolatable> Tween<T> {
+    /// Creates a tween, starting at `from`.
+    pub fn new(from: T, to: T, duration: Duration, easing: Easing) -> Self {
+        Self {
+            from,
+            to,
+            duration,
+            easing,
+            elapsed: Duration::ZERO,
+        }
+    }
+
+    /// The total duration of the tween.
+    pub fn duration(&self) -> Duration {
+        self.duration
+    }
+
+    /// The time elapsed since the start, saturating at [`duration`](Self::duration).
+    pub fn elapsed(&self) -> Duration {
+        self.elapsed
+    }
+
+    /// The linear progress in [0, 1], before easing is applied.
+    pub fn progress(&self) -> f32 {
+        self.progress_at(self.elapsed)
+    }
+
+    /// Returns `true` once the tween has reached its end value.
+    pub fn is_finished(&self) -> bool {
+        self.elapsed >= self.duration
+    }
+
+    /// The current value.
+    pub fn value(&self) -> T {
+        self.value_at(self.elapsed)
+    }
+
+    /// The value at `elapsed`, without changing the state of the tween.
+    pub fn value_at(&self, elapsed: Duration) -> T {
+        self.from
+            .ease(self.to, self.progress_at(elapsed), self.easing)
+    }
+
+    /// Advances the tween by `dt`, scaled by the global [`time_scale`], and returns the new value.
+    pub fn advance(&mut self, dt: Duration) -> T {
+        self.advance_unscaled(time_scale::scaled(dt))
+    }
+
+    /// Rewinds the tween to its start.
+    pub fn reset(&mut self) {
+        self.elapsed = Duration::ZERO;
+    }
+
+    // Advances by `dt` of already scaled time.
+    pub(crate) fn advance_unscaled(&mut self, dt: Duration) -> T {
+        self.elapsed = self.elapsed.saturating_add(dt).min(self.duration);
+        self.value()
+    }
+
+    fn progress_at(&self, elapsed: Duration) -> f32 {
+        if elapsed >= self.duration {
+            1.0
+        } else {
+            (elapsed.as_secs_f64() / self.duration.as_secs_f64()) as f32
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::Tween;
+    use crate::{Easing, EasingArgument};
+    use approx::assert_relative_eq;
+    use core::time::Duration;
+
+    #[test]
+    fn advance() {
+        let mut tween = Tween::new(10.0f32, 20.0, Duration::from_millis(100), Easing::OutQuad);
+        assert_eq!(tween.value(), 10.0);
+        assert_relative_eq!(
+            tween.advance(Duration::from_millis(25)),
+            10.0 + 10.0 * 0.25f32.ease_out_quad()
+        );
+        assert!(!tween.is_finished());
+
+        assert_eq!(tween.advance(Duration::from_millis(100)), 20.0);
+        assert_eq!(tween.elapsed(), Duration::from_millis(100));
+        assert!(tween.is_finished());
+
+        tween.reset();
+        assert_eq!(tween.value(), 10.0);
+    }
+
+    #[test]
+    fn zero_duration() {
+        let tween = Tween::new(0.0f64, 1.0, Duration::ZERO, Easing::InExpo);
+        assert!(tween.is_finished());
+        assert_eq!(tween.value(), 1.0);
+    }
+}
@@ -0,0 +1,285 @@
+// Copyright (C) 2025 Tim Blechmann
+// SPDX-License-Identifier: MIT
+
+use alloc::boxed::Box;
+use alloc::vec::Vec;
+use core::fmt;
+use core::time::Duration;
+#[allow(unused_imports)]
+use num_traits::Float;
+
+use super::{Tween, time_scale};
+use crate::{Easing, Interpolatable};
+
+/// Identifies a subscriber of an [`Animated`] value, see [`Animated::unsubscribe`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Subscription(u64);
+
+enum Subscriber<T> {
+    Callback(Box<dyn FnMut(&T)>),
+    #[cfg(feature = "std")]
+    Channel(std::sync::mpsc::Sender<T>),
+}
+
+/// A value which animates towards its targets and pushes its changes to subscribers, e.g. the
+/// bindings of a reactive UI.
+///
+/// Instead of polling every animated property each frame, the view subscribes once and hears about
+/// changes only. [`advance`](Self::advance) still has to be called with the frame time, but the
+/// subscribers are only notified when the value has moved by at least the
+/// [minimum change](Self::with_min_change) since their last notification, and once more when an
+/// animation arrives at its target.
+///
+/// ```
+/// # use nova_easing::{Easing, tween::Animated};
+/// # use std::{cell::RefCell, rc::Rc, time::Duration};
+/// let mut opacity = Animated::new(0.0f32).with_min_change(0.25);
+/// let seen = Rc::new(RefCell::new(Vec::new()));
+/// let sink = seen.clone();
+/// opacity.subscribe(move |&value| sink.borrow_mut().push(value));
+///
+/// opacity.animate_to(1.0, Duration::from_millis(100), Easing::Linear);
+/// for _ in 0..20 {
+///     opacity.advance(Duration::from_millis(5));
+/// }
+/// // One notification per quarter of the way, instead of one per frame.
+/// assert_eq!(*seen.borrow(), [0.25, 0.5, 0.75, 1.0]);
+/// ```
+pub struct Animated<T> {
+    tween: Tween<T>,
+    min_change: f32,
+    // The eased progress of the tween at the last notification.
+    notified: f32,
+    subscribers: Vec<(Subscription, Subscriber<T>)>,
+    next_id: u64,
+}
+
+impl<T: Interpolatable> Animated<T> {
+    /// Creates a value resting at `value`, which notifies on every change.
+    pub fn new(value: T) -> Self {
+        Self {
+            tween: Tween::new(value, value, Duration::ZERO, Easing::Linear),
+            min_change: 0.0,
+            notified: 1.0,
+            subscribers: Vec::new(),
+            next_id: 0,
+        }
+    }
+
+    /// Only notifies once the value has changed by `min_change` since the last notification.
+    ///
+    /// As values have no distance in general, the change is measured in eased progress, i.e. as
+    /// a fraction of the distance between the start and the target of the animation: `0.01`
+    /// notifies about every percent of the way. The arrival at the target is always notified.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `min_change` is negative or NaN.
+    pub fn with_min_change(mut self, min_change: f32) -> Self {
+        assert!(min_change >= 0.0, "min_change must not be negative");
+        self.min_change = min_change;
+        self
+    }
+
+    /// The current value.
+    pub fn value(&self) -> T {
+        self.tween.value()
+    }
+
+    /// The value the current animation ends at, or the current value at rest.
+    pub fn target(&self) -> T {
+        self.tween.to
+    }
+
+    /// Returns `true` while an animation is running.
+    pub fn is_animating(&self) -> bool {
+        !self.tween.is_finished()
+    }
+
+    /// The current animation.
+    pub fn tween(&self) -> &Tween<T> {
+        &self.tween
+    }
+
+    /// Starts animating from the current value to `target`. A running animation is replaced, so
+    /// the value continues from where it is.
+    pub fn animate_to(&mut self, target: T, duration: Duration, easing: Easing) {
+        self.tween = Tween::new(self.value(), target, duration, easing);
+        self.notified = easing.apply(0.0);
+    }
+
+    /// Jumps to `value`, stopping a running animation, and notifies the subscribers.
+    pub fn set(&mut self, value: T) {
+        self.tween = Tween::new(value, value, Duration::ZERO, Easing::Linear);
+        self.notify();
+    }
+
+    /// Advances the running animation by `dt`, scaled by the global
+    /// [`time_scale`](super::time_scale), notifies the subscribers if the value changed enough
+    /// and returns the new value.
+    pub fn advance(&mut self, dt: Duration) -> T {
+        let arrived_before = self.tween.is_finished();
+        self.tween.advance_unscaled(time_scale::scaled(dt));
+        let progress = self.tween.easing.apply(self.tween.progress());
+        let arrived = self.tween.is_finished() && !arrived_before;
+        let moved = (progress - self.notified).abs();
+        if arrived || (moved > 0.0 && moved >= self.min_change) {
+            self.notify();
+        }
+        self.value()
+    }
+
+    /// Calls `callback` with the value on every notification.
+    pub fn subscribe(&mut self, callback: impl FnMut(&T) + 'static) -> Subscription {
+        self.insert(Subscriber::Callback(Box::new(callback)))
+    }
+
+    /// Sends the value to the returned receiver on every notification. The subscription ends when
+    /// the receiver is dropped.
+    #[cfg(feature = "std")]
+    pub fn watch(&mut self) -> std::sync::mpsc::Receiver<T> {
+        let (sender, receiver) = std::sync::mpsc::channel();
+        self.insert(Subscriber::Channel(sender));
+        receiver
+    }
+
+    /// Removes a subscriber, returning `false` if it was already removed.
+    pub fn unsubscribe(&mut self, subscription: Subscription) -> bool {
+        let count = self.subscribers.len();
+        self.subscribers.retain(|(id, _)| *id != subscription);
+        self.subscribers.len() != count
+    }
+
+    /// The number of subscribers.
+    pub fn subscriber_count(&self) -> usize {
+        self.subscribers.len()
+    }
+
+    fn insert(&mut self, subscriber: Subscriber<T>) -> Subscription {
+        let id = Subscription(self.next_id);
+        self.next_id += 1;
+        self.subscribers.push((id, subscriber));
+        id
+    }
+
+    fn notify(&mut self) {
+        self.notified = self.tween.easing.apply(self.tween.progress());
+        let value = self.value();
+        self.subscribers.retain_mut(|(_, subscriber)| {
+            match subscriber {
+                Subscriber::Callback(callback) => {
+                    callback(&value);
+                    true
+                }
+                #[cfg(feature = "std")]
+                Subscriber::Channel(sender) => sender.send(value).is_ok(),
+            }
+        });
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for Animated<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Animated")
+            .field("tween", &self.tween)
+            .field("min_change", &self.min_change)
+            .field("subscribers", &self.subscribers.len())
+            .finish()
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::Animated;
+    use crate::{Easing, EasingArgument};
+    use core::time::Duration;
+    use std::{cell::RefCell, rc::Rc, vec::Vec};
+
+    fn recorded(animated: &mut Animated<f32>) -> Rc<RefCell<Vec<f32>>> {
+        let seen = Rc::new(RefCell::new(Vec::new()));
+        let sink = seen.clone();
+        animated.subscribe(move |&value| sink.borrow_mut().push(value));
+        seen
+    }
+
+    #[test]
+    fn notifies_on_changes() {
+        let mut animated = Animated::new(10.0f32).with_min_change(0.25);
+        let seen = recorded(&mut animated);
+
+        // Nothing moves at rest.
+        animated.advance(Duration::from_millis(10));
+        assert!(seen.borrow().is_empty());
+
+        animated.animate_to(20.0, Duration::from_millis(100), Easing::InQuad);
+        assert!(animated.is_animating());
+        assert_eq!(animated.target(), 20.0);
+        for _ in 0..10 {
+            animated.advance(Duration::from_millis(10));
+        }
+        assert!(!animated.is_animating());
+        // Notified at the frames which have moved a quarter of the way since the last
+        // notification: 0.25 at t = 0.5, 0.64 at t = 0.8, and the arrival.
+        let expected = [0.5f32, 0.8, 1.0].map(|t| 10.0 + 10.0 * t.ease_in_quad());
+        assert_eq!(*seen.borrow(), expected);
+
+        animated.advance(Duration::from_millis(10));
+        animated.set(5.0);
+        assert_eq!(seen.borrow().len(), 4);
+        assert_eq!(animated.value(), 5.0);
+    }
+
+    #[test]
+    fn retargets_from_current_value() {
+        let mut animated = Animated::new(0.0f32);
+        let seen = recorded(&mut animated);
+        animated.animate_to(1.0, Duration::from_millis(100), Easing::Linear);
+        animated.advance(Duration::from_millis(50));
+        animated.animate_to(0.0, Duration::from_millis(100), Easing::Linear);
+        assert_eq!(animated.tween().value(), 0.5);
+        animated.advance(Duration::from_millis(50));
+        assert_eq!(*seen.borrow(), [0.5, 0.25]);
+    }
+
+    #[test]
+    fn subscriptions() {
+        let mut animated = Animated::new(0.0f32);
+        let seen = recorded(&mut animated);
+        let other = animated.subscribe(|_| {});
+        assert_eq!(animated.subscriber_count(), 2);
+
+        animated.set(1.0);
+        assert!(animated.unsubscribe(other));
+        assert!(!animated.unsubscribe(other));
+        animated.set(2.0);
+        assert_eq!(*seen.borrow(), [1.0, 2.0]);
+        assert_eq!(animated.subscriber_count(), 1);
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn watch() {
+        let mut animated = Animated::new(0.0f32);
+        let seen = recorded(&mut animated);
+        let receiver = animated.watch();
+        assert_eq!(animated.subscriber_count(), 2);
+
+        animated.set(1.0);
+        animated.set(2.0);
+        assert_eq!(receiver.try_iter().collect::<Vec<_>>(), [1.0, 2.0]);
+        assert_eq!(*seen.borrow(), [1.0, 2.0]);
+
+        // Dropped receivers unsubscribe on the next notification.
+        drop(receiver);
+        animated.set(3.0);
+        assert_eq!(animated.subscriber_count(), 1);
+    }
+
+    #[test]
+    #[should_panic(expected = "min_change must not be negative")]
+    fn negative_min_change() {
+        let _ = Animated::new(0.0f32).with_min_change(-1.0);
+    }
+}
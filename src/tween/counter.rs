@@ -0,0 +1,205 @@
+// Copyright (C) 2025 Tim Blechmann
+// SPDX-License-Identifier: MIT
+
+use core::fmt;
+use core::time::Duration;
+
+#[allow(unused_imports)]
+use num_traits::Float;
+
+use super::Tween;
+use crate::Easing;
+
+/// An animated integer display, counting from `from` to `to`, e.g. a score or a statistic.
+///
+/// The displayed value is rounded to the nearest integer, but never moves backwards and never
+/// leaves the range between `from` and `to`. Overshooting easings (`Back`, `Elastic`) therefore
+/// hold the end value instead of flickering around it, and the end value is reached exactly.
+///
+/// Fractional quantities count in their smallest unit and format it via
+/// [`format_with`](Self::format_with):
+///
+/// ```
+/// # use nova_easing::{Easing, tween::Counter};
+/// # use std::time::Duration;
+/// let mut cents = Counter::new(0, 123_456, Duration::from_secs(1), Easing::OutExpo);
+/// cents.advance(Duration::from_secs(1));
+///
+/// let price = cents.format_with(|cents, f| write!(f, "${}.{:02}", cents / 100, cents % 100));
+/// assert_eq!(price.to_string(), "$1234.56");
+/// assert_eq!(cents.grouped(',').to_string(), "123,456");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Counter {
+    tween: Tween<f64>,
+    from: i64,
+    to: i64,
+    displayed: i64,
+}
+
+impl Counter {
+    /// Creates a counter from `from` to `to` over `duration`, displaying `from`.
+    pub fn new(from: i64, to: i64, duration: Duration, easing: Easing) -> Self {
+        Self {
+            tween: Tween::new(from as f64, to as f64, duration, easing),
+            from,
+            to,
+            displayed: from,
+        }
+    }
+
+    /// The underlying tween of the unrounded value.
+    pub fn tween(&self) -> &Tween<f64> {
+        &self.tween
+    }
+
+    /// The displayed value.
+    pub fn value(&self) -> i64 {
+        self.displayed
+    }
+
+    /// Returns `true` once the counter displays its end value and the tween has finished.
+    pub fn is_finished(&self) -> bool {
+        self.tween.is_finished()
+    }
+
+    /// Advances the counter by `dt` and returns the new displayed value.
+    pub fn advance(&mut self, dt: Duration) -> i64 {
+        let value = self.tween.advance(dt);
+        let rounded = if self.tween.is_finished() {
+            self.to
+        } else {
+            value.round() as i64
+        };
+
+        self.displayed = if self.to >= self.from {
+            rounded.clamp(self.from, self.to).max(self.displayed)
+        } else {
+            rounded.clamp(self.to, self.from).min(self.displayed)
+        };
+        self.displayed
+    }
+
+    /// Rewinds the counter to its start.
+    pub fn reset(&mut self) {
+        self.tween.reset();
+        self.displayed = self.from;
+    }
+
+    /// Formats the displayed value with `format`, e.g. for units, signs or fixed decimals.
+    pub fn format_with<F>(&self, format: F) -> impl fmt::Display
+    where
+        F: Fn(i64, &mut fmt::Formatter<'_>) -> fmt::Result,
+    {
+        FormatWith(self.displayed, format)
+    }
+
+    /// Formats the displayed value with `separator` between groups of thousands, e.g. `1,234,567`.
+    pub fn grouped(&self, separator: char) -> impl fmt::Display {
+        self.format_with(move |value, f| {
+            if value < 0 {
+                f.write_str("-")?;
+            }
+            // Least significant digit first, enough for `u64::MAX`.
+            let mut digits = [0u8; 20];
+            let mut rest = value.unsigned_abs();
+            let mut len = 0;
+            loop {
+                digits[len] = (rest % 10) as u8;
+                rest /= 10;
+                len += 1;
+                if rest == 0 {
+                    break;
+                }
+            }
+            for (index, digit) in digits[..len].iter().enumerate().rev() {
+                write!(f, "{digit}")?;
+                if index > 0 && index % 3 == 0 {
+                    write!(f, "{separator}")?;
+                }
+            }
+            Ok(())
+        })
+    }
+}
+
+/// Displays the value itself, honoring width, fill and sign flags.
+impl fmt::Display for Counter {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.displayed, f)
+    }
+}
+
+struct FormatWith<F>(i64, F);
+
+impl<F> fmt::Display for FormatWith<F>
+where
+    F: Fn(i64, &mut fmt::Formatter<'_>) -> fmt::Result,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        (self.1)(self.0, f)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::Counter;
+    use crate::Easing;
+    use core::time::Duration;
+
+    fn frames(counter: &mut Counter, count: usize) -> Vec<i64> {
+        (0..count)
+            .map(|_| counter.advance(Duration::from_millis(10)))
+            .collect()
+    }
+
+    #[test]
+    fn counts_monotonically() {
+        for easing in [Easing::OutBack, Easing::OutElastic, Easing::InOutBounce] {
+            let mut counter = Counter::new(0, 50, Duration::from_millis(500), easing);
+            let values = frames(&mut counter, 60);
+            assert!(values.windows(2).all(|pair| pair[0] <= pair[1]));
+            assert!(values.iter().all(|value| (0..=50).contains(value)));
+            assert_eq!(values.last(), Some(&50));
+            assert!(counter.is_finished());
+        }
+
+        // `InBack` starts below `from`, which is held as well.
+        let mut counter = Counter::new(100, 0, Duration::from_millis(500), Easing::InBack);
+        let values = frames(&mut counter, 60);
+        assert!(values.windows(2).all(|pair| pair[0] >= pair[1]));
+        assert_eq!(values[0], 100);
+        assert_eq!(values.last(), Some(&0));
+    }
+
+    #[test]
+    fn rounding_and_reset() {
+        let mut counter = Counter::new(-10, 10, Duration::from_secs(1), Easing::Linear);
+        assert_eq!(counter.value(), -10);
+        assert_eq!(counter.advance(Duration::from_millis(480)), 0);
+        assert_eq!(counter.advance(Duration::from_millis(20)), 0);
+        assert_eq!(counter.advance(Duration::from_millis(30)), 1);
+
+        counter.reset();
+        assert_eq!(counter.value(), -10);
+        assert!(!counter.is_finished());
+    }
+
+    #[test]
+    fn formatting() {
+        let mut counter = Counter::new(0, -1_234_567, Duration::ZERO, Easing::Linear);
+        counter.advance(Duration::ZERO);
+        assert_eq!(counter.to_string(), "-1234567");
+        assert_eq!(format!("{counter:>10}"), "  -1234567");
+        assert_eq!(counter.grouped('.').to_string(), "-1.234.567");
+        assert_eq!(
+            counter.format_with(|v, f| write!(f, "{v} pts")).to_string(),
+            "-1234567 pts"
+        );
+
+        let counter = Counter::new(999, 0, Duration::ZERO, Easing::Linear);
+        assert_eq!(counter.grouped(',').to_string(), "999");
+    }
+}
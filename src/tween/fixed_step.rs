@@ -0,0 +1,169 @@
+// Copyright (C) 2025 Tim Blechmann
+// SPDX-License-Identifier: MIT
+
+use core::time::Duration;
+
+use super::Tween;
+use crate::Interpolatable;
+
+/// Drives a [`Tween`] with a fixed timestep, for fixed-update / render-interpolate game loops.
+///
+/// Real frame time is fed in via [`accumulate`](Self::accumulate), whole timesteps are consumed
+/// via [`steps`](Self::steps), and the remainder is exposed as [`alpha`](Self::alpha) for
+/// interpolating between the last two updates when rendering:
+///
+/// ```
+/// # use nova_easing::{Easing, tween::{FixedStep, Tween}};
+/// # use std::time::Duration;
+/// let tween = Tween::new(0.0f32, 1.0, Duration::from_secs(1), Easing::InOutQuad);
+/// let mut driver = FixedStep::new(tween, Duration::from_millis(10));
+///
+/// driver.accumulate(Duration::from_millis(16));
+/// for value in driver.steps() {
+///     // fixed update with `value`
+/// }
+/// let rendered = driver.interpolated();
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct FixedStep<T> {
+    tween: Tween<T>,
+    dt: Duration,
+    accumulator: Duration,
+    previous: T,
+}
+
+impl<T: Interpolatable> FixedStep<T> {
+    /// Creates a driver advancing `tween` in steps of `dt`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `dt` is zero.
+    pub fn new(tween: Tween<T>, dt: Duration) -> Self {
+        assert!(!dt.is_zero(), "timestep must not be zero");
+        Self {
+            previous: tween.value(),
+            tween,
+            dt,
+            accumulator: Duration::ZERO,
+        }
+    }
+
+    /// The fixed timestep.
+    pub fn dt(&self) -> Duration {
+        self.dt
+    }
+
+    /// The underlying tween.
+    pub fn tween(&self) -> &Tween<T> {
+        &self.tween
+    }
+
+    /// Adds elapsed real time, scaled by the global [`time_scale`](super::time_scale), to be
+    /// consumed by [`steps`](Self::steps).
+    pub fn accumulate(&mut self, frame_time: Duration) {
+        let frame_time = super::time_scale::scaled(frame_time);
+        self.accumulator = self.accumulator.saturating_add(frame_time);
+    }
+
+    /// Returns an iterator advancing the tween by one timestep per item, for as long as the
+    /// accumulated time allows, yielding the value after each step.
+    pub fn steps(&mut self) -> Steps<'_, T> {
+        Steps { driver: self }
+    }
+
+    /// The accumulated time not yet consumed, as a fraction of the timestep in [0, 1).
+    pub fn alpha(&self) -> f32 {
+        (self.accumulator.as_secs_f64() / self.dt.as_secs_f64()) as f32
+    }
+
+    /// The value after the last step.
+    pub fn current(&self) -> T {
+        self.tween.value()
+    }
+
+    /// The value before the last step.
+    pub fn previous(&self) -> T {
+        self.previous
+    }
+
+    /// Interpolates between [`previous`](Self::previous) and [`current`](Self::current) by
+    /// [`alpha`](Self::alpha), for rendering in between fixed updates.
+    pub fn interpolated(&self) -> T {
+        self.previous.lerp(self.current(), self.alpha())
+    }
+
+    /// Returns `true` once the tween has reached its end value.
+    pub fn is_finished(&self) -> bool {
+        self.tween.is_finished()
+    }
+}
+
+/// Iterator over the fixed timesteps available in a [`FixedStep`] driver.
+///
+/// Created by [`FixedStep::steps`].
+#[derive(Debug)]
+pub struct Steps<'a, T> {
+    driver: &'a mut FixedStep<T>,
+}
+
+impl<T: Interpolatable> Iterator for Steps<'_, T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        let driver = &mut *self.driver;
+        if driver.accumulator < driver.dt {
+            return None;
+        }
+        driver.accumulator -= driver.dt;
+        driver.previous = driver.tween.value();
+        Some(driver.tween.advance_unscaled(driver.dt))
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::FixedStep;
+    use crate::Easing;
+    use crate::tween::Tween;
+    use approx::assert_relative_eq;
+    use core::time::Duration;
+
+    #[test]
+    fn consumes_whole_steps() {
+        let tween = Tween::new(0.0f32, 1.0, Duration::from_millis(100), Easing::Linear);
+        let mut driver = FixedStep::new(tween, Duration::from_millis(10));
+
+        driver.accumulate(Duration::from_millis(25));
+        let values: Vec<f32> = driver.steps().collect();
+        assert_eq!(values.len(), 2);
+        assert_relative_eq!(values[1], 0.2);
+        assert_relative_eq!(driver.alpha(), 0.5);
+        assert_relative_eq!(driver.previous(), 0.1);
+        assert_relative_eq!(driver.interpolated(), 0.15);
+
+        driver.accumulate(Duration::from_millis(5));
+        assert_eq!(driver.steps().count(), 1);
+        assert_relative_eq!(driver.alpha(), 0.0);
+        assert_relative_eq!(driver.current(), 0.3);
+        assert_relative_eq!(driver.interpolated(), 0.2);
+    }
+
+    #[test]
+    fn holds_end_value() {
+        let tween = Tween::new(0.0f32, 1.0, Duration::from_millis(20), Easing::OutQuad);
+        let mut driver = FixedStep::new(tween, Duration::from_millis(10));
+        driver.accumulate(Duration::from_millis(50));
+        assert_eq!(driver.steps().last(), Some(1.0));
+        assert!(driver.is_finished());
+        assert_eq!(driver.interpolated(), 1.0);
+    }
+
+    #[test]
+    #[should_panic(expected = "timestep must not be zero")]
+    fn zero_timestep() {
+        let tween = Tween::new(0.0f32, 1.0, Duration::from_millis(20), Easing::Linear);
+        FixedStep::new(tween, Duration::ZERO);
+    }
+}
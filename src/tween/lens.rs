@@ -0,0 +1,181 @@
+// Copyright (C) 2025 Tim Blechmann
+// SPDX-License-Identifier: MIT
+
+use core::marker::PhantomData;
+use core::time::Duration;
+
+use super::Tween;
+use crate::{Easing, Interpolatable};
+
+/// Focuses on one `Value` inside a larger `Target`, so a [`Tween`] can drive a single property.
+///
+/// Lenses are usually built from accessor closures via [`FnLens::new`], or from a field path via
+/// [`lens!`](crate::lens).
+pub trait Lens<Target, Value> {
+    /// Reads the value from `target`.
+    fn get(&self, target: &Target) -> Value;
+
+    /// Writes `value` into `target`.
+    fn set(&self, target: &mut Target, value: Value);
+}
+
+/// A [`Lens`] built from a getter and a setter closure.
+pub struct FnLens<Target, Value, G, S> {
+    get: G,
+    set: S,
+    marker: PhantomData<fn(&mut Target, Value)>,
+}
+
+impl<Target, Value, G, S> FnLens<Target, Value, G, S>
+where
+    G: Fn(&Target) -> Value,
+    S: Fn(&mut Target, Value),
+{
+    /// Creates a lens from accessor closures.
+    pub fn new(get: G, set: S) -> Self {
+        Self {
+            get,
+            set,
+            marker: PhantomData,
+        }
+    }
+}
+
+impl<Target, Value, G, S> Lens<Target, Value> for FnLens<Target, Value, G, S>
+where
+    G: Fn(&Target) -> Value,
+    S: Fn(&mut Target, Value),
+{
+    fn get(&self, target: &Target) -> Value {
+        (self.get)(target)
+    }
+
+    fn set(&self, target: &mut Target, value: Value) {
+        (self.set)(target, value)
+    }
+}
+
+impl<Target, Value, G: Clone, S: Clone> Clone for FnLens<Target, Value, G, S> {
+    fn clone(&self) -> Self {
+        Self {
+            get: self.get.clone(),
+            set: self.set.clone(),
+            marker: PhantomData,
+        }
+    }
+}
+
+impl<Target, Value, G, S> core::fmt::Debug for FnLens<Target, Value, G, S> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("FnLens").finish_non_exhaustive()
+    }
+}
+
+/// Builds a [`Lens`] for a (nested) field of a type: `lens!(Transform, translation.x)`.
+///
+/// ```
+/// use nova_easing::{Easing, lens, tween::Tween};
+/// use std::time::Duration;
+///
+/// struct Vec2 { x: f32, y: f32 }
+/// struct Transform { translation: Vec2 }
+///
+/// let mut transform = Transform { translation: Vec2 { x: 0.0, y: 5.0 } };
+/// let lens = lens!(Transform, translation.x);
+///
+/// let mut tween = Tween::from_lens(&lens, &transform, 10.0, Duration::from_secs(1), Easing::Linear);
+/// tween.drive(Duration::from_millis(500), &mut transform, &lens);
+/// assert_eq!(transform.translation.x, 5.0);
+/// assert_eq!(transform.translation.y, 5.0);
+/// ```
+#[macro_export]
+macro_rules! lens {
+    ($target:ty, $($field:tt).+) => {
+        $crate::tween::FnLens::new(
+            |target: &$target| target.$($field).+,
+            |target: &mut $target, value| target.$($field).+ = value,
+        )
+    };
+}
+
+impl<T: Interpolatable> Tween<T> {
+    /// Creates a tween starting at the current value of the property focused by `lens`.
+    pub fn from_lens<Target>(
+        lens: &impl Lens<Target, T>,
+        target: &Target,
+        to: T,
+        duration: Duration,
+        easing: Easing,
+    ) -> Self {
+        Self::new(lens.get(target), to, duration, easing)
+    }
+
+    /// Advances the tween by `dt` and writes the new value into `target` via `lens`.
+    pub fn drive<Target>(
+        &mut self,
+        dt: Duration,
+        target: &mut Target,
+        lens: &impl Lens<Target, T>,
+    ) {
+        let value = self.advance(dt);
+        lens.set(target, value);
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::{FnLens, Lens};
+    use crate::Easing;
+    use crate::tween::Tween;
+    use core::time::Duration;
+
+    #[derive(Debug, Default, PartialEq)]
+    struct Vec3 {
+        x: f32,
+        y: f32,
+        z: f32,
+    }
+
+    #[derive(Debug, Default, PartialEq)]
+    struct Transform {
+        translation: Vec3,
+        scale: f64,
+    }
+
+    #[test]
+    fn closure_lens() {
+        let lens = FnLens::new(|t: &Transform| t.scale, |t: &mut Transform, v| t.scale = v);
+        let mut transform = Transform::default();
+        lens.set(&mut transform, 2.0);
+        assert_eq!(lens.get(&transform), 2.0);
+    }
+
+    #[test]
+    fn field_path_lens() {
+        let mut transform = Transform::default();
+        let lens = crate::lens!(Transform, translation.y);
+
+        let mut tween = Tween::from_lens(
+            &lens,
+            &transform,
+            4.0,
+            Duration::from_secs(1),
+            Easing::InQuad,
+        );
+        tween.drive(Duration::from_millis(500), &mut transform, &lens);
+        assert_eq!(
+            transform.translation,
+            Vec3 {
+                x: 0.0,
+                y: 1.0,
+                z: 0.0
+            }
+        );
+
+        tween.drive(Duration::from_secs(1), &mut transform, &lens);
+        assert_eq!(transform.translation.y, 4.0);
+        assert_eq!(transform.scale, 0.0);
+    }
+}
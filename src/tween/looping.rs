@@ -0,0 +1,224 @@
+// Copyright (C) 2025 Tim Blechmann
+// SPDX-License-Identifier: MIT
+
+use core::time::Duration;
+
+use crate::{Easing, Interpolatable};
+
+/// A tween which restarts at `from` after every `period`, e.g. for idle, pulse or shader effects.
+///
+/// The time within the current cycle is kept as an exact [`Duration`] and wrapped with integer
+/// arithmetic, so the phase does not drift however long the animation runs. The normalized phase
+/// is exposed for evaluation elsewhere, typically in a shader: [`uniform`](Self::uniform) packs it
+/// for upload every frame, optionally shifted per instance.
+///
+/// ```
+/// # use nova_easing::{Easing, tween::Looping};
+/// # use std::time::Duration;
+/// let mut pulse = Looping::new(0.0f32, 1.0, Duration::from_millis(800), Easing::InOutSine);
+/// pulse.advance(Duration::from_millis(2000));
+/// assert_eq!(pulse.cycles(), 2);
+/// assert_eq!(pulse.phase(), 0.5);
+///
+/// // The second instance runs a quarter period ahead.
+/// let uniform = pulse.uniform(Duration::from_millis(200));
+/// assert_eq!((uniform.phase, uniform.cycle), (0.75, 2));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Looping<T> {
+    from: T,
+    to: T,
+    period: Duration,
+    easing: Easing,
+    elapsed: Duration,
+    cycles: u64,
+}
+
+/// The phase of a [`Looping`] tween, laid out for a GPU uniform or instance buffer.
+///
+/// The four 32-bit fields match a `vec4`-sized struct of `float, float, uint, float` in both
+/// std140 and std430 layouts, e.g.
+/// `struct Phase { float phase; float eased; uint cycle; float period; };`.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+#[repr(C)]
+pub struct PhaseUniform {
+    /// The linear phase within the current cycle, in [0, 1).
+    pub phase: f32,
+    /// The phase after easing.
+    pub eased: f32,
+    /// The number of completed cycles, wrapping at `u32::MAX`.
+    pub cycle: u32,
+    /// The period, in seconds.
+    pub period: f32,
+}
+
+impl<T: Interpolatable> Looping<T> {
+    /// Creates a looping tween from `from` to `to`, repeating every `period`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `period` is zero.
+    pub fn new(from: T, to: T, period: Duration, easing: Easing) -> Self {
+        assert!(!period.is_zero(), "period must not be zero");
+        Self {
+            from,
+            to,
+            period,
+            easing,
+            elapsed: Duration::ZERO,
+            cycles: 0,
+        }
+    }
+
+    /// The duration of one cycle.
+    pub fn period(&self) -> Duration {
+        self.period
+    }
+
+    /// The time elapsed within the current cycle, less than [`period`](Self::period).
+    pub fn elapsed(&self) -> Duration {
+        self.elapsed
+    }
+
+    /// The number of completed cycles.
+    pub fn cycles(&self) -> u64 {
+        self.cycles
+    }
+
+    /// The linear phase within the current cycle, in [0, 1).
+    pub fn phase(&self) -> f32 {
+        self.phase_at(Duration::ZERO)
+    }
+
+    /// The phase after easing.
+    pub fn eased_phase(&self) -> f32 {
+        self.easing.apply(self.phase())
+    }
+
+    /// The current value.
+    pub fn value(&self) -> T {
+        self.value_at(Duration::ZERO)
+    }
+
+    /// The linear phase `offset` ahead of the current time, wrapped to [0, 1).
+    ///
+    /// Instances sharing one looping tween use this to run shifted against each other. A lag `d`
+    /// is the offset `period - d`.
+    pub fn phase_at(&self, offset: Duration) -> f32 {
+        self.offset_by(offset).0
+    }
+
+    /// The value `offset` ahead of the current time, see [`phase_at`](Self::phase_at).
+    pub fn value_at(&self, offset: Duration) -> T {
+        self.from.ease(self.to, self.phase_at(offset), self.easing)
+    }
+
+    /// The phase `offset` ahead of the current time, packed for a GPU uniform.
+    pub fn uniform(&self, offset: Duration) -> PhaseUniform {
+        let (phase, cycles) = self.offset_by(offset);
+        PhaseUniform {
+            phase,
+            eased: self.easing.apply(phase),
+            cycle: cycles as u32,
+            period: self.period.as_secs_f32(),
+        }
+    }
+
+    /// Advances the tween by `dt`, scaled by the global [`time_scale`](super::time_scale), wrapping
+    /// into the following cycles, and returns the new value.
+    pub fn advance(&mut self, dt: Duration) -> T {
+        let (elapsed, cycles) = self.wrap(super::time_scale::scaled(dt));
+        self.elapsed = elapsed;
+        self.cycles = self.cycles.wrapping_add(cycles);
+        self.value()
+    }
+
+    /// Rewinds the tween to the start of its first cycle.
+    pub fn reset(&mut self) {
+        self.elapsed = Duration::ZERO;
+        self.cycles = 0;
+    }
+
+    // The time within the cycle and the completed cycles after adding `dt`, exact to the
+    // nanosecond.
+    fn wrap(&self, dt: Duration) -> (Duration, u64) {
+        let period = self.period.as_nanos();
+        let total = self.elapsed.as_nanos() + dt.as_nanos();
+        let elapsed = Duration::from_nanos((total % period) as u64);
+        (elapsed, (total / period) as u64)
+    }
+
+    // The linear phase and the completed cycles, `offset` ahead.
+    fn offset_by(&self, offset: Duration) -> (f32, u64) {
+        let (elapsed, cycles) = self.wrap(offset);
+        let phase = (elapsed.as_secs_f64() / self.period.as_secs_f64()) as f32;
+        // Rounding can reach 1 just before the end of a cycle.
+        (
+            phase.min(1.0 - f32::EPSILON / 2.0),
+            self.cycles.wrapping_add(cycles),
+        )
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::{Looping, PhaseUniform};
+    use crate::{Easing, EasingArgument};
+    use core::time::Duration;
+
+    #[test]
+    fn wraps_exactly() {
+        let period = Duration::from_nanos(16_666_666);
+        let mut looping = Looping::new(0.0f64, 1.0, period, Easing::Linear);
+        // An hour of half-period steps lands exactly on a cycle boundary.
+        for _ in 0..432_000 {
+            looping.advance(period / 2);
+        }
+        assert_eq!(looping.cycles(), 216_000);
+        assert_eq!(looping.elapsed(), Duration::ZERO);
+
+        // The phase stays below 1 up to the last nanosecond of a cycle.
+        looping.advance(period - Duration::from_nanos(1));
+        assert!(looping.phase() < 1.0);
+        looping.advance(Duration::from_nanos(1));
+        assert_eq!((looping.cycles(), looping.phase()), (216_001, 0.0));
+
+        looping.reset();
+        assert_eq!((looping.cycles(), looping.phase()), (0, 0.0));
+    }
+
+    #[test]
+    fn offsets_and_uniforms() {
+        let mut looping = Looping::new(10.0f32, 20.0, Duration::from_secs(2), Easing::OutCubic);
+        assert_eq!(
+            looping.advance(Duration::from_millis(2500)),
+            10.0 + 10.0 * 0.25.ease_out_cubic()
+        );
+        assert_eq!(looping.eased_phase(), 0.25.ease_out_cubic());
+
+        // Offsets wrap into the following cycles, like `advance`.
+        assert_eq!(looping.phase_at(Duration::from_millis(1500)), 0.0);
+        assert_eq!(
+            looping.value_at(Duration::from_millis(2000)),
+            looping.value()
+        );
+        assert_eq!(
+            looping.uniform(Duration::from_millis(3000)),
+            PhaseUniform {
+                phase: 0.75,
+                eased: 0.75.ease_out_cubic(),
+                cycle: 2,
+                period: 2.0,
+            }
+        );
+        assert_eq!(size_of::<PhaseUniform>(), 16);
+    }
+
+    #[test]
+    #[should_panic(expected = "period must not be zero")]
+    fn zero_period() {
+        Looping::new(0.0f32, 1.0, Duration::ZERO, Easing::Linear);
+    }
+}
@@ -0,0 +1,212 @@
+// Copyright (C) 2025 Tim Blechmann
+// SPDX-License-Identifier: MIT
+
+use alloc::vec::Vec;
+#[allow(unused_imports)]
+use num_traits::Float;
+
+/// Event markers at progress values, reporting which markers were crossed between two updates.
+///
+/// The playback position is unwrapped progress: its fractional part is the progress within the
+/// current cycle and its integer part counts loops, so `2.25` is a quarter into the third cycle.
+/// A decreasing position means reverse playback. Moving forward reports markers in `(from, to]`,
+/// moving backward reports markers in `[to, from)`, in the order they are crossed.
+///
+/// ```
+/// # use nova_easing::{Easing, tween::{Markers, Tween}};
+/// # use std::time::Duration;
+/// let mut tween = Tween::new(0.0f32, 1.0, Duration::from_secs(1), Easing::OutBounce);
+/// let mut markers = Markers::new().with_marker(0.36, "impact");
+///
+/// tween.advance(Duration::from_millis(500));
+/// let crossed: Vec<_> = markers.update(tween.progress() as f64).collect();
+/// assert_eq!(crossed, [&"impact"]);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct Markers<M> {
+    markers: Vec<(f32, M)>,
+    position: f64,
+}
+
+impl<M> Default for Markers<M> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<M> Markers<M> {
+    /// Creates an empty set of markers, positioned at 0.
+    pub fn new() -> Self {
+        Self {
+            markers: Vec::new(),
+            position: 0.0,
+        }
+    }
+
+    /// Adds a marker at `progress` in [0, 1].
+    ///
+    /// # Panics
+    ///
+    /// Panics if `progress` is outside of [0, 1].
+    pub fn insert(&mut self, progress: f32, marker: M) {
+        assert!(
+            (0.0..=1.0).contains(&progress),
+            "marker progress must be in [0, 1]"
+        );
+        let index = self.markers.partition_point(|(p, _)| *p <= progress);
+        self.markers.insert(index, (progress, marker));
+    }
+
+    /// Adds a marker at `progress` in [0, 1], builder style.
+    pub fn with_marker(mut self, progress: f32, marker: M) -> Self {
+        self.insert(progress, marker);
+        self
+    }
+
+    /// The markers with their progress values, sorted by progress.
+    pub fn markers(&self) -> &[(f32, M)] {
+        &self.markers
+    }
+
+    /// The position of the last update.
+    pub fn position(&self) -> f64 {
+        self.position
+    }
+
+    /// Jumps to `position` without reporting any markers.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `position` is not finite.
+    pub fn seek(&mut self, position: f64) {
+        assert!(position.is_finite(), "marker position must be finite");
+        self.position = position;
+    }
+
+    /// Moves to `position`, returning the markers crossed since the last update.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `position` is not finite.
+    pub fn update(&mut self, position: f64) -> Crossed<'_, M> {
+        assert!(position.is_finite(), "marker position must be finite");
+        let from = core::mem::replace(&mut self.position, position);
+        Crossed::new(&self.markers, from, position)
+    }
+}
+
+/// Iterator over the markers crossed by [`Markers::update`], in crossing order.
+#[derive(Debug, Clone)]
+pub struct Crossed<'a, M> {
+    markers: &'a [(f32, M)],
+    from: f64,
+    to: f64,
+    first_cycle: f64,
+    index: usize,
+    count: usize,
+}
+
+impl<'a, M> Crossed<'a, M> {
+    fn new(markers: &'a [(f32, M)], from: f64, to: f64) -> Self {
+        let first_cycle = from.min(to).floor();
+        // Saturates for jumps over more cycles than candidates can be counted.
+        let cycles = if markers.is_empty() {
+            0
+        } else {
+            ((from.max(to).floor() - first_cycle) as usize).saturating_add(1)
+        };
+        Self {
+            markers,
+            from,
+            to,
+            first_cycle,
+            index: 0,
+            count: cycles.saturating_mul(markers.len()),
+        }
+    }
+}
+
+impl<'a, M> Iterator for Crossed<'a, M> {
+    type Item = &'a M;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let forward = self.to >= self.from;
+        while self.index < self.count {
+            // Walk candidates in playback order: ascending when moving forward, else descending.
+            let index = if forward {
+                self.index
+            } else {
+                self.count - 1 - self.index
+            };
+            self.index += 1;
+
+            let len = self.markers.len();
+            let (progress, marker) = &self.markers[index % len];
+            let position = self.first_cycle + (index / len) as f64 + *progress as f64;
+            let crossed = if forward {
+                self.from < position && position <= self.to
+            } else {
+                self.to <= position && position < self.from
+            };
+            if crossed {
+                return Some(marker);
+            }
+        }
+        None
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::Markers;
+
+    fn markers() -> Markers<&'static str> {
+        Markers::new()
+            .with_marker(0.75, "c")
+            .with_marker(0.25, "a")
+            .with_marker(0.5, "b")
+    }
+
+    #[test]
+    fn forward() {
+        let mut markers = markers();
+        assert_eq!(markers.update(0.2).count(), 0);
+        assert_eq!(markers.update(0.5).collect::<Vec<_>>(), [&"a", &"b"]);
+        assert_eq!(markers.update(0.5).count(), 0);
+        assert_eq!(markers.update(1.0).collect::<Vec<_>>(), [&"c"]);
+    }
+
+    #[test]
+    fn reverse() {
+        let mut markers = markers();
+        markers.seek(1.0);
+        assert_eq!(markers.update(0.5).collect::<Vec<_>>(), [&"c", &"b"]);
+        assert_eq!(markers.update(0.0).collect::<Vec<_>>(), [&"a"]);
+    }
+
+    #[test]
+    fn looping() {
+        let mut markers = markers();
+        markers.seek(0.6);
+        assert_eq!(
+            markers.update(2.3).collect::<Vec<_>>(),
+            [&"c", &"a", &"b", &"c", &"a"]
+        );
+        assert_eq!(markers.update(1.6).collect::<Vec<_>>(), [&"a", &"c"]);
+    }
+    #[test]
+    fn huge_jump() {
+        let mut markers = markers();
+        markers.seek(1e300);
+        // Does not overflow; the first candidate is already crossed.
+        assert_eq!(markers.update(-1e300).next(), Some(&"c"));
+    }
+
+    #[test]
+    #[should_panic(expected = "marker position must be finite")]
+    fn infinite_position() {
+        let _ = Markers::new().with_marker(0.5, "a").update(f64::INFINITY);
+    }
+}
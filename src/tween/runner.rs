@@ -0,0 +1,152 @@
+// Copyright (C) 2025 Tim Blechmann
+// SPDX-License-Identifier: MIT
+
+//! Runner advancing tweens on a tokio interval.
+//!
+//! Useful for headless applications (lighting controllers, OSC automation, ...) that animate
+//! parameters over time: tweens are registered via a [`RunnerHandle`], and their values are
+//! delivered through [`tokio::sync::watch`] channels.
+
+use core::time::Duration;
+
+use tokio::sync::{mpsc, watch};
+use tokio::time::{Instant, MissedTickBehavior};
+
+use super::Tween;
+use crate::Interpolatable;
+
+// Advances a registered tween to the given instant, returns `false` once it can be dropped.
+type Job = Box<dyn FnMut(Instant) -> bool + Send>;
+
+/// Advances registered tweens on a fixed tokio interval.
+///
+/// The runner is driven by awaiting [`run`](Self::run), typically in a spawned task.
+#[derive(Debug)]
+pub struct TweenRunner {
+    period: Duration,
+    receiver: mpsc::UnboundedReceiver<Job>,
+    handle: RunnerHandle,
+}
+
+/// Registers tweens with a [`TweenRunner`].
+#[derive(Clone)]
+pub struct RunnerHandle {
+    sender: mpsc::UnboundedSender<Job>,
+}
+
+impl core::fmt::Debug for RunnerHandle {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("RunnerHandle").finish_non_exhaustive()
+    }
+}
+
+impl TweenRunner {
+    /// Creates a runner updating its tweens every `period`.
+    pub fn new(period: Duration) -> Self {
+        let (sender, receiver) = mpsc::unbounded_channel();
+        Self {
+            period,
+            receiver,
+            handle: RunnerHandle { sender },
+        }
+    }
+
+    /// Returns a handle for registering tweens.
+    pub fn handle(&self) -> RunnerHandle {
+        self.handle.clone()
+    }
+
+    /// Runs the update loop.
+    ///
+    /// Tweens are dropped once they are finished or all receivers of their channel are gone. The
+    /// loop returns when there are no active tweens left and all handles have been dropped.
+    pub async fn run(self) {
+        let Self {
+            period,
+            mut receiver,
+            handle,
+        } = self;
+        drop(handle);
+
+        let mut jobs: Vec<Job> = Vec::new();
+        let mut interval = tokio::time::interval(period);
+        interval.set_missed_tick_behavior(MissedTickBehavior::Delay);
+
+        loop {
+            if jobs.is_empty() {
+                // idle: wait for a registration instead of ticking
+                match receiver.recv().await {
+                    Some(job) => jobs.push(job),
+                    None => return,
+                }
+                interval.reset();
+            }
+
+            let now = interval.tick().await;
+            while let Ok(job) = receiver.try_recv() {
+                jobs.push(job);
+            }
+            jobs.retain_mut(|job| job(now));
+        }
+    }
+}
+
+impl RunnerHandle {
+    /// Registers `tween` with the runner and returns a receiver for its values.
+    ///
+    /// The tween starts at the time of registration. If the runner has already stopped, the
+    /// receiver keeps the start value.
+    pub fn animate<T>(&self, tween: Tween<T>) -> watch::Receiver<T>
+    where
+        T: Interpolatable + Send + Sync + 'static,
+    {
+        let (sender, receiver) = watch::channel(tween.value());
+        let mut tween = tween;
+        let mut last = Instant::now();
+        let job: Job = Box::new(move |now| {
+            let value = tween.advance(now.saturating_duration_since(last));
+            last = now;
+            sender.send(value).is_ok() && !tween.is_finished()
+        });
+        let _ = self.sender.send(job);
+        receiver
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::TweenRunner;
+    use crate::Easing;
+    use crate::tween::Tween;
+    use core::time::Duration;
+
+    #[tokio::test(start_paused = true)]
+    async fn delivers_values_until_finished() {
+        let runner = TweenRunner::new(Duration::from_millis(10));
+        let handle = runner.handle();
+        let task = tokio::spawn(runner.run());
+
+        let tween = Tween::new(0.0f32, 1.0, Duration::from_millis(100), Easing::Linear);
+        let mut receiver = handle.animate(tween);
+        assert_eq!(*receiver.borrow(), 0.0);
+
+        let mut last = 0.0;
+        while receiver.changed().await.is_ok() {
+            let value = *receiver.borrow_and_update();
+            assert!(value >= last);
+            last = value;
+        }
+        assert_eq!(last, 1.0);
+
+        drop(handle);
+        task.await.unwrap();
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn stops_without_handles() {
+        let runner = TweenRunner::new(Duration::from_millis(10));
+        runner.run().await;
+    }
+}
@@ -0,0 +1,147 @@
+// Copyright (C) 2025 Tim Blechmann
+// SPDX-License-Identifier: MIT
+
+use core::future::Future;
+use core::pin::Pin;
+use core::task::{Context, Poll};
+use core::time::Duration;
+
+use futures_core::Stream;
+
+use super::Tween;
+use crate::Interpolatable;
+
+impl<T: Interpolatable> Tween<T> {
+    /// Turns the tween into a [`Stream`] of values, advanced by `ticks`.
+    ///
+    /// Every item of `ticks` is the time passed since the previous tick, which keeps the tween
+    /// independent of the async runtime: e.g. with tokio, map an `IntervalStream` to the interval
+    /// period. The stream ends after yielding the end value, or when `ticks` ends.
+    pub fn into_stream<S>(self, ticks: S) -> TweenStream<T, S>
+    where
+        S: Stream<Item = Duration>,
+    {
+        TweenStream {
+            tween: self,
+            ticks,
+            done: false,
+        }
+    }
+}
+
+/// A [`Tween`] driven by a stream of timer ticks, yielding its value after every tick.
+///
+/// Created by [`Tween::into_stream`].
+#[derive(Debug)]
+pub struct TweenStream<T, S> {
+    tween: Tween<T>,
+    ticks: S,
+    done: bool,
+}
+
+impl<T, S> TweenStream<T, S>
+where
+    T: Interpolatable + Unpin,
+    S: Stream<Item = Duration> + Unpin,
+{
+    /// The underlying tween.
+    pub fn tween(&self) -> &Tween<T> {
+        &self.tween
+    }
+
+    /// Returns a [`Future`] that drives the tween to completion and resolves to its final value.
+    pub fn finished(self) -> Finished<T, S> {
+        let last = self.tween.value();
+        Finished { stream: self, last }
+    }
+}
+
+impl<T, S> Stream for TweenStream<T, S>
+where
+    T: Interpolatable + Unpin,
+    S: Stream<Item = Duration> + Unpin,
+{
+    type Item = T;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<T>> {
+        let this = self.get_mut();
+        if this.done {
+            return Poll::Ready(None);
+        }
+
+        match Pin::new(&mut this.ticks).poll_next(cx) {
+            Poll::Ready(Some(dt)) => {
+                let value = this.tween.advance(dt);
+                this.done = this.tween.is_finished();
+                Poll::Ready(Some(value))
+            }
+            Poll::Ready(None) => {
+                this.done = true;
+                Poll::Ready(None)
+            }
+            Poll::Pending => Poll::Pending,
+        }
+    }
+}
+
+/// A [`Future`] resolving to the final value of a [`TweenStream`].
+///
+/// If the timer stream ends before the tween is finished, it resolves to the last value reached.
+#[derive(Debug)]
+pub struct Finished<T, S> {
+    stream: TweenStream<T, S>,
+    last: T,
+}
+
+impl<T, S> Future for Finished<T, S>
+where
+    T: Interpolatable + Unpin,
+    S: Stream<Item = Duration> + Unpin,
+{
+    type Output = T;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<T> {
+        let this = self.get_mut();
+        loop {
+            match Pin::new(&mut this.stream).poll_next(cx) {
+                Poll::Ready(Some(value)) => this.last = value,
+                Poll::Ready(None) => return Poll::Ready(this.last),
+                Poll::Pending => return Poll::Pending,
+            }
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use crate::Easing;
+    use crate::tween::Tween;
+    use core::time::Duration;
+    use futures::StreamExt;
+    use futures::executor::block_on;
+    use futures::stream;
+
+    #[test]
+    fn stream_yields_until_finished() {
+        let tween = Tween::new(0.0f32, 1.0, Duration::from_millis(40), Easing::Linear);
+        let ticks = stream::repeat(Duration::from_millis(10));
+        let values: Vec<f32> = block_on(tween.into_stream(ticks).collect());
+        assert_eq!(values, [0.25, 0.5, 0.75, 1.0]);
+    }
+
+    #[test]
+    fn finished_resolves_to_end_value() {
+        let tween = Tween::new(2.0f64, 4.0, Duration::from_millis(50), Easing::InOutCubic);
+        let ticks = stream::repeat(Duration::from_millis(16));
+        assert_eq!(block_on(tween.into_stream(ticks).finished()), 4.0);
+    }
+
+    #[test]
+    fn finished_with_exhausted_timer() {
+        let tween = Tween::new(0.0f32, 1.0, Duration::from_millis(100), Easing::Linear);
+        let ticks = stream::iter([Duration::from_millis(50)]);
+        assert_eq!(block_on(tween.into_stream(ticks).finished()), 0.5);
+    }
+}
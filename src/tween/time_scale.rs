@@ -0,0 +1,81 @@
+// Copyright (C) 2025 Tim Blechmann
+// SPDX-License-Identifier: MIT
+
+use core::sync::atomic::{AtomicU32, Ordering};
+use core::time::Duration;
+
+// The bits of the global time scale, initially 1.
+static TIME_SCALE: AtomicU32 = AtomicU32::new(0x3f80_0000);
+
+/// Sets a global multiplier for the time passed to the tweens of this crate, e.g. `0.1` to inspect
+/// all motion in slow motion while debugging, without touching the call sites.
+///
+/// The scale applies wherever elapsed time enters: [`Tween::advance`](super::Tween::advance),
+/// [`Looping::advance`](super::Looping::advance), [`FixedStep::accumulate`](super::FixedStep::accumulate)
+/// and everything driven through them, such as [`Counter`](super::Counter), tween streams and the
+/// tokio runner, as well as the time step between the samples of `Env::render` and
+/// `EnvRenderer::render`. Queries at explicit times, like `value_at`, are not affected. A scale of
+/// 0 pauses all tweens and holds envelopes at their current level.
+///
+/// ```standalone_crate
+/// # use nova_easing::{Easing, tween::{self, Tween}};
+/// # use std::time::Duration;
+/// tween::set_time_scale(0.5);
+/// let mut tween = Tween::new(0.0f32, 1.0, Duration::from_secs(1), Easing::Linear);
+/// tween.advance(Duration::from_secs(1));
+/// assert_eq!(tween.progress(), 0.5);
+/// # tween::set_time_scale(1.0);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `scale` is negative or not finite.
+pub fn set_time_scale(scale: f32) {
+    assert!(
+        scale.is_finite() && scale >= 0.0,
+        "time scale must be finite and not negative"
+    );
+    TIME_SCALE.store(scale.to_bits(), Ordering::Relaxed);
+}
+
+/// The global time scale, see [`set_time_scale`].
+pub fn time_scale() -> f32 {
+    f32::from_bits(TIME_SCALE.load(Ordering::Relaxed))
+}
+
+// `dt` multiplied by the global time scale.
+pub(super) fn scaled(dt: Duration) -> Duration {
+    scaled_by(dt, time_scale())
+}
+
+fn scaled_by(dt: Duration, scale: f32) -> Duration {
+    if scale == 1.0 {
+        return dt;
+    }
+    Duration::try_from_secs_f64(dt.as_secs_f64() * scale as f64).unwrap_or(Duration::MAX)
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::{scaled_by, set_time_scale, time_scale};
+    use core::time::Duration;
+
+    // The global scale is shared with the tests running in parallel, so it is not changed here.
+    #[test]
+    fn scaling() {
+        assert_eq!(time_scale(), 1.0);
+        let dt = Duration::from_millis(16);
+        assert_eq!(scaled_by(dt, 1.0), dt);
+        assert_eq!(scaled_by(dt, 0.25), Duration::from_millis(4));
+        assert_eq!(scaled_by(dt, 0.0), Duration::ZERO);
+        assert_eq!(scaled_by(Duration::MAX, 2.0), Duration::MAX);
+    }
+
+    #[test]
+    #[should_panic(expected = "time scale must be finite and not negative")]
+    fn negative_scale() {
+        set_time_scale(-1.0);
+    }
+}
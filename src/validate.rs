@@ -0,0 +1,212 @@
+// Copyright (C) 2025 Tim Blechmann
+// SPDX-License-Identifier: MIT
+
+//! Input validation, to catch a bad `t` where it enters an easing.
+//!
+//! A NaN argument silently turns into a NaN result, which tends to surface far from its cause. With
+//! the `validate` feature, [`Easing::apply`] checks its input in debug builds and panics with a
+//! [`ValidationError`], naming the easing and, for SIMD vectors, the lane:
+//!
+//! - a NaN argument,
+//! - an argument outside of [0, 1], unless passed through [`Easing::apply_with`], whose
+//!   [`RangePolicy`](crate::RangePolicy) handles the range explicitly,
+//! - a parameter which is not finite, or a `curve` so large that `exp` overflows.
+//!
+//! The panic is reported at the caller of `apply`. Release builds skip the checks;
+//! [`Easing::validate`] runs them in any build.
+//!
+//! ```
+//! # use nova_easing::{Easing, validate::ValidationError};
+//! assert_eq!(Easing::InQuad.validate(0.5f32), Ok(()));
+//! assert_eq!(
+//!     Easing::InQuad.validate(f32::NAN),
+//!     Err(ValidationError::NotANumber { easing: Easing::InQuad, lane: 0 })
+//! );
+//! assert!(Easing::InCurve(200.0).validate(0.5f32).is_err());
+//! ```
+
+use core::fmt;
+
+use crate::internal::CurveParam;
+use crate::{Easing, EasingImplHelper};
+
+/// The reason an easing input was rejected, see the [module documentation](self).
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ValidationError {
+    /// The argument is NaN in the given lane.
+    NotANumber {
+        /// The easing which was applied.
+        easing: Easing,
+        /// The lane of the argument, 0 for scalars.
+        lane: usize,
+    },
+    /// The argument is outside of [0, 1] in the given lane.
+    OutOfRange {
+        /// The easing which was applied.
+        easing: Easing,
+        /// The lane of the argument, 0 for scalars.
+        lane: usize,
+        /// The argument in that lane.
+        t: f64,
+    },
+    /// The parameter of the easing is not finite, or so large that its evaluation overflows.
+    InvalidParameter {
+        /// The easing, including its parameter.
+        easing: Easing,
+    },
+}
+
+impl fmt::Display for ValidationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ValidationError::NotANumber { easing, lane } => {
+                write!(f, "`{easing}` applied to NaN in lane {lane}")
+            }
+            ValidationError::OutOfRange { easing, lane, t } => {
+                write!(
+                    f,
+                    "`{easing}` applied to {t} outside of [0, 1] in lane {lane}"
+                )
+            }
+            ValidationError::InvalidParameter { easing } => {
+                write!(f, "`{easing}` has a parameter which overflows")
+            }
+        }
+    }
+}
+
+impl core::error::Error for ValidationError {}
+
+impl Easing {
+    /// Checks `t` and the parameter of the easing like [`apply`](Self::apply) does in debug
+    /// builds with the `validate` feature, see the [`validate`](crate::validate) module.
+    #[allow(private_bounds)]
+    pub fn validate<T>(self, t: T) -> Result<(), ValidationError>
+    where
+        T: EasingImplHelper + CurveParam<T>,
+    {
+        check(self, t, true)
+    }
+}
+
+#[cfg(debug_assertions)]
+#[track_caller]
+pub(crate) fn assert_valid<T: EasingImplHelper>(easing: Easing, t: T, in_range: bool) {
+    if let Err(error) = check(easing, t, in_range) {
+        panic!("{error}");
+    }
+}
+
+fn check<T: EasingImplHelper>(easing: Easing, t: T, in_range: bool) -> Result<(), ValidationError> {
+    // `ExpoScaled` is a curve of `scale * ln(2)`.
+    let curve = match easing {
+        Easing::InCurve(curve) | Easing::OutCurve(curve) | Easing::InOutCurve(curve) => {
+            Some(curve as f64)
+        }
+        Easing::InExpoScaled(scale)
+        | Easing::OutExpoScaled(scale)
+        | Easing::InOutExpoScaled(scale) => Some(scale as f64 * core::f64::consts::LN_2),
+        _ => None,
+    };
+    let finite = easing.parameter().is_none_or(f32::is_finite);
+    if !finite || curve.is_some_and(|curve| curve > T::max_curve_f64()) {
+        return Err(ValidationError::InvalidParameter { easing });
+    }
+
+    for lane in 0..T::lane_count() {
+        let t = t.lane(lane);
+        if t.is_nan() {
+            return Err(ValidationError::NotANumber { easing, lane });
+        }
+        if in_range && !(0.0..=1.0).contains(&t) {
+            return Err(ValidationError::OutOfRange { easing, lane, t });
+        }
+    }
+    Ok(())
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::ValidationError;
+    use crate::Easing;
+
+    #[test]
+    fn arguments() {
+        for t in [0.0f64, 0.3, 1.0] {
+            assert_eq!(Easing::OutBounce.validate(t), Ok(()));
+        }
+        assert_eq!(
+            Easing::OutBounce.validate(-0.5f64),
+            Err(ValidationError::OutOfRange {
+                easing: Easing::OutBounce,
+                lane: 0,
+                t: -0.5,
+            })
+        );
+        assert_eq!(
+            Easing::InPow(2.5)
+                .validate(f32::NAN)
+                .unwrap_err()
+                .to_string(),
+            "`ease_in_pow(2.5)` applied to NaN in lane 0"
+        );
+    }
+
+    #[test]
+    fn parameters() {
+        // Steep negative curves only underflow towards a step.
+        assert_eq!(Easing::InCurve(-1000.0).validate(0.5f32), Ok(()));
+        // Fine in `f64`, beyond `f32`.
+        assert_eq!(Easing::OutCurve(200.0).validate(0.5f64), Ok(()));
+        assert_eq!(
+            Easing::OutCurve(200.0).validate(0.5f32),
+            Err(ValidationError::InvalidParameter {
+                easing: Easing::OutCurve(200.0),
+            })
+        );
+        assert!(Easing::InOutExpoScaled(120.0).validate(0.5f32).is_err());
+        assert!(Easing::InPow(f32::INFINITY).validate(0.5f64).is_err());
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    fn apply_panics() {
+        use crate::RangePolicy;
+
+        // Out-of-range arguments are fine with an explicit policy.
+        assert_eq!(
+            Easing::InQuad.apply_with(2.0f32, RangePolicy::Unchecked),
+            4.0
+        );
+
+        let result = std::panic::catch_unwind(|| Easing::InQuad.apply(2.0f32));
+        assert!(result.is_err());
+        let result =
+            std::panic::catch_unwind(|| Easing::InQuad.apply_with(f32::NAN, RangePolicy::Clamp));
+        assert!(result.is_err());
+    }
+
+    #[test]
+    #[cfg(feature = "nightly")]
+    fn lanes() {
+        let t = core::simd::f32x4::from_array([0.0, 0.5, f32::NAN, 2.0]);
+        assert_eq!(
+            Easing::InQuad.validate(t),
+            Err(ValidationError::NotANumber {
+                easing: Easing::InQuad,
+                lane: 2,
+            })
+        );
+        let t = core::simd::f64x2::from_array([0.25, 1.5]);
+        assert_eq!(
+            Easing::InQuad.validate(t),
+            Err(ValidationError::OutOfRange {
+                easing: Easing::InQuad,
+                lane: 1,
+                t: 1.5,
+            })
+        );
+    }
+}
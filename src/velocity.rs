@@ -0,0 +1,204 @@
+// Copyright (C) 2025 Tim Blechmann
+// SPDX-License-Identifier: MIT
+
+//! Conversion between velocity profiles and position curves, to design motion by sketching its
+//! speed instead of its position.
+//!
+//! A [`PositionCurve`] integrates a velocity profile and normalizes it to end at 1, so the moved
+//! distance is preserved whatever the scale of the sketch. A [`VelocityProfile`] goes the other
+//! way, differentiating a position easing and normalizing it to a peak speed of 1:
+//!
+//! ```
+//! # use nova_easing::{Easing, velocity::{PositionCurve, VelocityProfile}};
+//! // Constant acceleration: a linear ramp of speed moves along a quadratic.
+//! let position = PositionCurve::from(Easing::Linear);
+//! assert!((position.eval(0.5) - 0.25).abs() < 1e-9);
+//!
+//! let velocity = VelocityProfile::new(Easing::InQuad);
+//! assert_eq!(velocity.eval(0.5), 0.5);
+//! ```
+
+use alloc::vec::Vec;
+
+use crate::Easing;
+
+/// The position curve of a velocity profile: its integral, normalized to `position(1) = 1`.
+///
+/// Negative velocities move backwards. Profiles which integrate to zero or less fall back to
+/// linear motion. The integral is tabulated once, so evaluation costs a lookup and a cubic
+/// interpolation, which matches the velocity at every node.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PositionCurve {
+    // Normalized position and velocity, sampled at `i / RESOLUTION`.
+    nodes: Vec<(f64, f64)>,
+}
+
+impl PositionCurve {
+    const RESOLUTION: usize = 1024;
+
+    /// Creates the position curve of the velocity profile `velocity` over [0, 1].
+    pub fn new(velocity: impl Fn(f64) -> f64) -> Self {
+        let step = 1.0 / Self::RESOLUTION as f64;
+        let mut nodes = Vec::with_capacity(Self::RESOLUTION + 1);
+        let mut position = 0.0;
+        nodes.push((position, velocity(0.0)));
+        for i in 1..=Self::RESOLUTION {
+            let (start, end) = ((i - 1) as f64 * step, i as f64 * step);
+            let (v0, v1) = (nodes[i - 1].1, velocity(end));
+            // Simpson's rule, exact for cubic profiles.
+            position += step / 6.0 * (v0 + 4.0 * velocity((start + end) * 0.5) + v1);
+            nodes.push((position, v1));
+        }
+
+        if position > 0.0 {
+            nodes
+                .iter_mut()
+                .for_each(|node| *node = (node.0 / position, node.1 / position));
+        } else {
+            nodes
+                .iter_mut()
+                .enumerate()
+                .for_each(|(i, node)| *node = (i as f64 * step, 1.0));
+        }
+        Self { nodes }
+    }
+
+    /// The normalized position at `t`, which is clamped to [0, 1].
+    pub fn eval(&self, t: f64) -> f64 {
+        let (index, fraction) = self.locate(t);
+        let ((p0, v0), (p1, v1)) = (self.nodes[index], self.nodes[index + 1]);
+        let step = 1.0 / Self::RESOLUTION as f64;
+
+        // Cubic Hermite interpolation.
+        let (f2, f3) = (fraction * fraction, fraction * fraction * fraction);
+        let h00 = 2.0 * f3 - 3.0 * f2 + 1.0;
+        let h10 = f3 - 2.0 * f2 + fraction;
+        let h01 = 3.0 * f2 - 2.0 * f3;
+        let h11 = f3 - f2;
+        h00 * p0 + h10 * step * v0 + h01 * p1 + h11 * step * v1
+    }
+
+    /// The normalized velocity `d/dt` of [`eval`](Self::eval) at `t`, which is clamped to [0, 1].
+    pub fn velocity(&self, t: f64) -> f64 {
+        let (index, fraction) = self.locate(t);
+        let ((_, v0), (_, v1)) = (self.nodes[index], self.nodes[index + 1]);
+        v0 + (v1 - v0) * fraction
+    }
+
+    fn locate(&self, t: f64) -> (usize, f64) {
+        let scaled = t.clamp(0.0, 1.0) * Self::RESOLUTION as f64;
+        let index = (scaled as usize).min(Self::RESOLUTION - 1);
+        (index, scaled - index as f64)
+    }
+}
+
+impl From<Easing> for PositionCurve {
+    /// Treats the easing as a velocity profile.
+    fn from(velocity: Easing) -> Self {
+        Self::new(|t| velocity.apply(t))
+    }
+}
+
+/// The velocity profile of a position easing: its derivative, normalized to a peak speed of 1.
+///
+/// Integrating the profile with a [`PositionCurve`] restores the easing.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct VelocityProfile {
+    position: Easing,
+    peak_speed: f64,
+}
+
+impl VelocityProfile {
+    /// Creates the velocity profile of `position`.
+    ///
+    /// The peak speed is searched at the boundaries of 1024 evenly spaced intervals.
+    pub fn new(position: Easing) -> Self {
+        let peak_speed = (0..=PositionCurve::RESOLUTION)
+            .map(|i| {
+                let t = i as f64 / PositionCurve::RESOLUTION as f64;
+                position.apply_derivative_pair(t).1.abs()
+            })
+            .fold(0.0, f64::max);
+        Self {
+            position,
+            peak_speed,
+        }
+    }
+
+    /// The largest absolute speed of the position easing, relative to linear motion.
+    pub fn peak_speed(&self) -> f64 {
+        self.peak_speed
+    }
+
+    /// The normalized velocity at `t`, in [-1, 1].
+    pub fn eval(&self, t: f64) -> f64 {
+        let speed = self.position.apply_derivative_pair(t).1;
+        if self.peak_speed > 0.0 {
+            speed / self.peak_speed
+        } else {
+            speed
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::{PositionCurve, VelocityProfile};
+    use crate::Easing;
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn integrates_velocity() {
+        let position = PositionCurve::from(Easing::Linear);
+        let cruise = PositionCurve::new(|_| 3.0);
+        let sine = PositionCurve::from(Easing::InOutSine);
+        for i in 0..=20 {
+            let t = i as f64 / 20.0;
+            assert_relative_eq!(position.eval(t), t * t, epsilon = 1e-12);
+            assert_relative_eq!(position.velocity(t), 2.0 * t, epsilon = 1e-12);
+            assert_relative_eq!(cruise.eval(t), t, epsilon = 1e-12);
+
+            let expected = t - (core::f64::consts::PI * t).sin() / core::f64::consts::PI;
+            assert_relative_eq!(sine.eval(t), expected, epsilon = 1e-7);
+        }
+        assert_eq!(position.eval(-1.0), 0.0);
+        assert_relative_eq!(position.eval(2.0), 1.0, epsilon = 1e-12);
+    }
+
+    #[test]
+    fn backwards_and_degenerate_velocity() {
+        // Moving back during the first third.
+        let position = PositionCurve::new(|t| t - 1.0 / 3.0);
+        assert!(position.eval(1.0 / 3.0) < 0.0);
+        assert_relative_eq!(position.eval(1.0), 1.0, epsilon = 1e-12);
+
+        let still = PositionCurve::new(|_| 0.0);
+        assert_eq!(still.eval(0.25), 0.25);
+        assert_eq!(still.velocity(0.25), 1.0);
+    }
+
+    #[test]
+    fn differentiates_position() {
+        let velocity = VelocityProfile::new(Easing::InOutQuad);
+        assert_eq!(velocity.peak_speed(), 2.0);
+        assert_eq!(velocity.eval(0.5), 1.0);
+        assert_eq!(velocity.eval(0.25), 0.5);
+
+        // The anticipation of `InBack` moves backwards at first.
+        assert!(VelocityProfile::new(Easing::InBack).eval(0.1) < 0.0);
+    }
+
+    #[test]
+    fn round_trip() {
+        for easing in [Easing::InOutCubic, Easing::OutSine, Easing::OutBack] {
+            let velocity = VelocityProfile::new(easing);
+            let position = PositionCurve::new(|t| velocity.eval(t));
+            for i in 0..=50 {
+                let t = i as f64 / 50.0;
+                assert_relative_eq!(position.eval(t), easing.apply(t), epsilon = 1e-9);
+            }
+        }
+    }
+}
@@ -0,0 +1,142 @@
+// Copyright (C) 2025 Tim Blechmann
+// SPDX-License-Identifier: MIT
+
+//! Eased warps of the unit square, for mesh transitions and image wipes.
+
+use alloc::vec;
+
+use crate::Easing;
+use crate::batch::fill_eased_lerp;
+
+/// Warps normalized `(u, v)` coordinates with independent easings along x and y.
+///
+/// Grid lines stay straight: the columns of a regular grid bunch up where the x easing is flat and
+/// spread out where it is steep, likewise for the rows.
+///
+/// ```
+/// # use nova_easing::{Easing, warp::GridWarp};
+/// let warp = GridWarp::new(Easing::InQuad, Easing::Linear);
+/// assert_eq!(warp.warp(0.5, 0.5), (0.25, 0.5));
+///
+/// let mut vertices = [[0.0; 2]; 6];
+/// warp.fill_vertices(&mut vertices, 3, 2);
+/// assert_eq!(vertices[1], [0.25, 0.0]);
+/// assert_eq!(vertices[5], [1.0, 1.0]);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct GridWarp {
+    x: Easing,
+    y: Easing,
+}
+
+impl GridWarp {
+    /// Creates a warp following `x` horizontally and `y` vertically.
+    pub fn new(x: Easing, y: Easing) -> Self {
+        Self { x, y }
+    }
+
+    /// Creates a warp following `easing` in both directions.
+    pub fn uniform(easing: Easing) -> Self {
+        Self::new(easing, easing)
+    }
+
+    /// The horizontal easing.
+    pub fn x(&self) -> Easing {
+        self.x
+    }
+
+    /// The vertical easing.
+    pub fn y(&self) -> Easing {
+        self.y
+    }
+
+    /// Warps the point `(u, v)`.
+    pub fn warp(&self, u: f32, v: f32) -> (f32, f32) {
+        (self.x.apply(u), self.y.apply(v))
+    }
+
+    /// The warp as a closure `(u, v) -> (u', v')`, e.g. for mesh or shader generators.
+    pub fn into_fn(self) -> impl Fn(f32, f32) -> (f32, f32) {
+        move |u, v| self.warp(u, v)
+    }
+
+    /// Fills `dst` with the warped vertices of a regular grid of `columns` by `rows` vertices,
+    /// row by row.
+    ///
+    /// Both edges are included, so the first vertex is the warped `(0, 0)` and the last one the
+    /// warped `(1, 1)`. Each easing is only evaluated once per column and row, via
+    /// [`batch`](crate::batch), which uses SIMD with the `nightly` feature.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `dst.len()` differs from `columns * rows`.
+    pub fn fill_vertices(&self, dst: &mut [[f32; 2]], columns: usize, rows: usize) {
+        assert_eq!(dst.len(), columns * rows, "vertex buffer size mismatch");
+
+        let mut xs = vec![0.0f32; columns];
+        let mut ys = vec![0.0f32; rows];
+        fill_eased_lerp(&mut xs, 0.0, 1.0, self.x);
+        fill_eased_lerp(&mut ys, 0.0, 1.0, self.y);
+
+        for (row, &y) in dst.chunks_exact_mut(columns.max(1)).zip(&ys) {
+            for (vertex, &x) in row.iter_mut().zip(&xs) {
+                *vertex = [x, y];
+            }
+        }
+    }
+
+    /// Warps `vertices` in place, treating them as `[u, v]` pairs.
+    pub fn warp_vertices(&self, vertices: &mut [[f32; 2]]) {
+        for vertex in vertices {
+            let (x, y) = self.warp(vertex[0], vertex[1]);
+            *vertex = [x, y];
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::GridWarp;
+    use crate::Easing;
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn grid_matches_pointwise_warp() {
+        let warp = GridWarp::new(Easing::InOutCubic, Easing::OutBack);
+        let (columns, rows) = (13, 7);
+        let mut vertices = vec![[0.0; 2]; columns * rows];
+        warp.fill_vertices(&mut vertices, columns, rows);
+
+        let warp_fn = warp.into_fn();
+        for row in 0..rows {
+            for column in 0..columns {
+                let u = column as f32 / (columns - 1) as f32;
+                let v = row as f32 / (rows - 1) as f32;
+                let (x, y) = warp_fn(u, v);
+                let vertex = vertices[row * columns + column];
+                assert_relative_eq!(vertex[0], x, epsilon = 1e-6);
+                assert_relative_eq!(vertex[1], y, epsilon = 1e-6);
+            }
+        }
+    }
+
+    #[test]
+    fn in_place() {
+        let warp = GridWarp::uniform(Easing::InQuad);
+        let mut vertices = [[0.5, 1.0], [0.0, 0.25]];
+        warp.warp_vertices(&mut vertices);
+        assert_eq!(vertices, [[0.25, 1.0], [0.0, 0.0625]]);
+    }
+
+    #[test]
+    fn degenerate_grids() {
+        let warp = GridWarp::uniform(Easing::Linear);
+        warp.fill_vertices(&mut [], 0, 5);
+        let mut single = [[1.0; 2]];
+        warp.fill_vertices(&mut single, 1, 1);
+        assert_eq!(single, [[0.0, 0.0]]);
+    }
+}